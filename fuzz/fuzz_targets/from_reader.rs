@@ -0,0 +1,45 @@
+//! Decode arbitrary bytes as an ELF object and exercise everything which reads from the
+//! object afterward. Every input must produce `Ok` or `Err`, never a panic.
+//!
+//! The test corpus makes a good set of seeds, including the deliberately malformed
+//! objects like `dummy_shnum.elf` and `graalvm-example-truncated`:
+//!
+//! ```sh
+//! cargo fuzz run from_reader fuzz/corpus/from_reader tests/corpus/elf/analysis
+//! ```
+
+#![no_main]
+
+use std::io::Cursor;
+
+use elf::{
+    header::{program::SegmentIndex, section::SectionIndex},
+    Elf, ElfKind, FromReader,
+};
+use libfuzzer_sys::fuzz_target;
+
+fn exercise<const EC: u8, const ED: u8>(elf: &Elf<EC, ED>, reader: &mut Cursor<&[u8]>) {
+    let _ = elf.format_section_table();
+    let _ = elf.format_program_headers(reader);
+    let _ = elf.symbols(reader);
+    let _ = elf.dynamic_symbols(reader);
+    let _ = elf.symbolicate(reader, elf.header.entrypoint.map_or(0, |entry| entry.0));
+    for index in 0..elf.program_headers.len() {
+        let _ = elf.segment_sections(SegmentIndex(index as u32));
+        let _ = elf.segment_data(reader, SegmentIndex(index as u32));
+    }
+    for index in 0..elf.section_headers.len() {
+        let _ = elf.section_data(reader, SectionIndex(index as u32));
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    let mut reader = Cursor::new(data);
+    match ElfKind::from_reader(&mut reader) {
+        Ok(ElfKind::Elf32LE(elf)) => exercise(&elf, &mut reader),
+        Ok(ElfKind::Elf32BE(elf)) => exercise(&elf, &mut reader),
+        Ok(ElfKind::Elf64LE(elf)) => exercise(&elf, &mut reader),
+        Ok(ElfKind::Elf64BE(elf)) => exercise(&elf, &mut reader),
+        Err(_) => {}
+    }
+});
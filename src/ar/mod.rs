@@ -0,0 +1,212 @@
+//! Parsing of `ar` archives, such as static libraries, which hold a sequence of member
+//! files which are usually relocatable ELF objects
+
+use alloc::{string::String, vec::Vec};
+
+use crate::error::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A member file of an `ar` archive
+pub struct ArchiveMember<'a> {
+    /// The name of the member, without the trailing `/` of the GNU format
+    pub name: String,
+    /// The offset of the member's data in the archive
+    pub offset: u64,
+    /// The data of the member, which is usually an ELF object that can be decoded with
+    /// `ElfKind::from_reader`
+    pub data: &'a [u8],
+}
+
+/// The magic which begins an `ar` archive
+pub const ARCHIVE_MAGIC: &[u8] = b"!<arch>\n";
+/// The size of the header which precedes each member's data
+const MEMBER_HEADER_SIZE: usize = 60;
+/// The magic which ends each member's header
+const MEMBER_HEADER_MAGIC: &[u8] = b"`\n";
+/// The prefix of a BSD member name whose length is given after it, with the name
+/// stored at the start of the member's data
+const BSD_LONG_NAME_PREFIX: &str = "#1/";
+
+/// The `size` byte field of `header` at `start`, with trailing space padding removed
+fn field(header: &[u8], start: usize, size: usize) -> Option<&str> {
+    let field = header.get(start..start.checked_add(size)?)?;
+    core::str::from_utf8(field)
+        .ok()
+        .map(|field| field.trim_end())
+}
+
+/// Decode the members of the `ar` archive in `data`. Both the GNU and BSD variants of
+/// the format are supported, including their long member names. The symbol tables and
+/// the GNU long name table are special members which are not returned. Thin archives,
+/// whose members are stored outside the archive, are not supported.
+pub fn archive_members(data: &[u8]) -> Result<Vec<ArchiveMember<'_>>, Error> {
+    if !data.starts_with(ARCHIVE_MAGIC) {
+        return Err(Error::InvalidArchiveMagic);
+    }
+
+    let mut members = Vec::new();
+    let mut long_names: &[u8] = &[];
+    let mut offset = ARCHIVE_MAGIC.len();
+
+    while offset < data.len() {
+        let error = || Error::InvalidArchiveMember {
+            offset: offset as u64,
+        };
+        let header = offset
+            .checked_add(MEMBER_HEADER_SIZE)
+            .and_then(|end| data.get(offset..end))
+            .ok_or_else(error)?;
+
+        if header.get(58..) != Some(MEMBER_HEADER_MAGIC) {
+            return Err(error());
+        }
+
+        let (Some(name), Some(size)) = (
+            field(header, 0, 16),
+            field(header, 48, 10).and_then(|size| size.parse::<usize>().ok()),
+        ) else {
+            return Err(error());
+        };
+        let start = offset + MEMBER_HEADER_SIZE;
+        let mut member = start
+            .checked_add(size)
+            .and_then(|end| data.get(start..end))
+            .ok_or_else(error)?;
+        let mut member_offset = start;
+
+        let name = match name {
+            // The GNU symbol tables
+            "/" | "/SYM64/" => None,
+            // The GNU long name table, which holds names terminated by `/\n`
+            "//" => {
+                long_names = member;
+                None
+            }
+            // A GNU long name, which is at an offset in the long name table
+            name if name.starts_with('/') => {
+                let name = name
+                    .get(1..)
+                    .and_then(|index| index.parse::<usize>().ok())
+                    .and_then(|index| long_names.get(index..))
+                    .and_then(|names| names.split(|b| *b == b'\n').next())
+                    .ok_or_else(error)?;
+                let name = name.strip_suffix(b"/").unwrap_or(name);
+                Some(String::from_utf8_lossy(name).into_owned())
+            }
+            // A BSD long name, which is at the start of the member's data
+            name if name.starts_with(BSD_LONG_NAME_PREFIX) => {
+                let size = name
+                    .get(BSD_LONG_NAME_PREFIX.len()..)
+                    .and_then(|size| size.parse::<usize>().ok())
+                    .ok_or_else(error)?;
+                let name = member.get(..size).ok_or_else(error)?;
+                member = member.get(size..).ok_or_else(error)?;
+                member_offset += size;
+                // The name is padded with null bytes
+                let name = name.split(|b| *b == 0).next().unwrap_or(name);
+                match name {
+                    // The BSD symbol tables
+                    b"__.SYMDEF" | b"__.SYMDEF SORTED" | b"__.SYMDEF_64" => None,
+                    name => Some(String::from_utf8_lossy(name).into_owned()),
+                }
+            }
+            name => Some(String::from(name.strip_suffix('/').unwrap_or(name))),
+        };
+
+        if let Some(name) = name {
+            members.push(ArchiveMember {
+                name,
+                offset: member_offset as u64,
+                data: member,
+            });
+        }
+
+        // Each member's data is padded to an even size
+        offset = start.saturating_add(size).saturating_add(size % 2);
+    }
+
+    Ok(members)
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(all(test, feature = "std"))]
+mod test {
+    use super::{archive_members, ArchiveMember, ARCHIVE_MAGIC};
+    use crate::error::Error;
+
+    /// A member header with the given name and data size
+    fn header(name: &str, size: usize) -> Vec<u8> {
+        let header = format!("{name:<16}{:<12}{:<6}{:<6}{:<8}{size:<10}`\n", 0, 0, 0, 644);
+        assert_eq!(header.len(), 60);
+        header.into_bytes()
+    }
+
+    #[test]
+    fn test_gnu_archive() {
+        let long_names = b"a_very_long_member_name.o/\n";
+        let mut archive = ARCHIVE_MAGIC.to_vec();
+        archive.extend(header("/", 4));
+        archive.extend_from_slice(&[0; 4]);
+        archive.extend(header("//", long_names.len()));
+        archive.extend_from_slice(long_names);
+        archive.push(b'\n');
+        archive.extend(header("short.o/", 3));
+        archive.extend_from_slice(b"abc");
+        archive.push(b'\n');
+        archive.extend(header("/0", 2));
+        archive.extend_from_slice(b"de");
+
+        assert_eq!(
+            archive_members(&archive).unwrap(),
+            vec![
+                ArchiveMember {
+                    name: "short.o".to_string(),
+                    offset: 220,
+                    data: b"abc",
+                },
+                ArchiveMember {
+                    name: "a_very_long_member_name.o".to_string(),
+                    offset: 284,
+                    data: b"de",
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bsd_archive() {
+        let mut archive = ARCHIVE_MAGIC.to_vec();
+        archive.extend(header("#1/20", 20 + 4));
+        archive.extend_from_slice(b"__.SYMDEF SORTED\0\0\0\0");
+        archive.extend_from_slice(&[0; 4]);
+        archive.extend(header("#1/28", 28 + 3));
+        archive.extend_from_slice(b"a_very_long_member_name.o\0\0\0");
+        archive.extend_from_slice(b"abc");
+
+        assert_eq!(
+            archive_members(&archive).unwrap(),
+            vec![ArchiveMember {
+                name: "a_very_long_member_name.o".to_string(),
+                offset: 180,
+                data: b"abc",
+            }]
+        );
+    }
+
+    #[test]
+    fn test_invalid_archive() {
+        assert_eq!(
+            archive_members(b"\x7fELF").unwrap_err(),
+            Error::InvalidArchiveMagic
+        );
+
+        // The member's data extends past the end of the archive
+        let mut archive = ARCHIVE_MAGIC.to_vec();
+        archive.extend(header("short.o/", 8));
+        archive.extend_from_slice(b"abc");
+        assert_eq!(
+            archive_members(&archive).unwrap_err(),
+            Error::InvalidArchiveMember { offset: 8 }
+        );
+    }
+}
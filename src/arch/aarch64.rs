@@ -2,6 +2,7 @@
 
 // NOTE: aarch64 defines no e_flags values
 
+use alloc::vec;
 use num_derive::FromPrimitive;
 
 use crate::{base::ElfWord, error::Error, header::elf::ElfMachine, TryFromWithConfig};
@@ -21,6 +22,14 @@ impl ElfSectionHeaderTypeAARCH64 {
     pub const ATTRIBUTES: u32 = 0x70000003;
 }
 
+impl core::fmt::Display for ElfSectionHeaderTypeAARCH64 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Attributes => "AARCH64_ATTRIBUTES",
+        })
+    }
+}
+
 impl<const EC: u8, const ED: u8> From<ElfSectionHeaderTypeAARCH64> for ElfWord<EC, ED> {
     fn from(value: ElfSectionHeaderTypeAARCH64) -> Self {
         Self(value as u32)
@@ -60,3 +69,903 @@ impl<const EC: u8, const ED: u8> TryFromWithConfig<ElfWord<EC, ED>>
         }
     }
 }
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+/// Program Header Types
+pub enum ElfProgramHeaderTypeAARCH64 {
+    /// Memory tagging extension tags
+    MemtagMte = Self::MEMTAG_MTE,
+}
+
+impl ElfProgramHeaderTypeAARCH64 {
+    /// Constant value for [ElfProgramHeaderTypeAARCH64::MemtagMte]
+    pub const MEMTAG_MTE: u32 = 0x70000002;
+}
+
+impl core::fmt::Display for ElfProgramHeaderTypeAARCH64 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::MemtagMte => "AARCH64_MEMTAG_MTE",
+        })
+    }
+}
+
+impl<const EC: u8, const ED: u8> From<ElfProgramHeaderTypeAARCH64> for ElfWord<EC, ED> {
+    fn from(value: ElfProgramHeaderTypeAARCH64) -> Self {
+        Self(value as u32)
+    }
+}
+
+impl<const EC: u8, const ED: u8> From<&ElfProgramHeaderTypeAARCH64> for ElfWord<EC, ED> {
+    fn from(value: &ElfProgramHeaderTypeAARCH64) -> Self {
+        Self(*value as u32)
+    }
+}
+
+impl<const EC: u8, const ED: u8> TryFromWithConfig<ElfWord<EC, ED>>
+    for ElfProgramHeaderTypeAARCH64
+{
+    type Error = Error;
+
+    fn try_from_with(
+        value: ElfWord<EC, ED>,
+        config: &mut crate::Config,
+    ) -> Result<Self, Self::Error> {
+        if !matches!(config.machine, Some(ElfMachine::AARCH64)) {
+            return Err(Error::InvalidMachineForProgramHeaderType {
+                machine: config.machine,
+                expected_machines: vec![ElfMachine::AARCH64],
+                value: value.0,
+            });
+        }
+
+        match value.0 {
+            Self::MEMTAG_MTE => Ok(Self::MemtagMte),
+            _ => Err(Error::InvalidProgramHeaderType {
+                machine: config.machine,
+                value: value.0,
+            }),
+        }
+    }
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+/// Relocation Types
+pub enum ElfRelocationTypeAARCH64 {
+    /// No relocation
+    None = Self::NONE,
+    /// 64-bit absolute address
+    Abs64 = Self::ABS64,
+    /// 32-bit absolute address
+    Abs32 = Self::ABS32,
+    /// 16-bit absolute address
+    Abs16 = Self::ABS16,
+    /// 64-bit PC-relative address
+    Prel64 = Self::PREL64,
+    /// 32-bit PC-relative address
+    Prel32 = Self::PREL32,
+    /// 16-bit PC-relative address
+    Prel16 = Self::PREL16,
+    /// `MOVZ`/`MOVK` immediate of the absolute address, bits 0 to 15
+    MovwUabsG0 = Self::MOVW_UABS_G0,
+    /// `MOVZ`/`MOVK` immediate of the absolute address, bits 0 to 15, without an overflow
+    /// check
+    MovwUabsG0Nc = Self::MOVW_UABS_G0_NC,
+    /// `MOVZ`/`MOVK` immediate of the absolute address, bits 16 to 31
+    MovwUabsG1 = Self::MOVW_UABS_G1,
+    /// `MOVZ`/`MOVK` immediate of the absolute address, bits 16 to 31, without an overflow
+    /// check
+    MovwUabsG1Nc = Self::MOVW_UABS_G1_NC,
+    /// `MOVZ`/`MOVK` immediate of the absolute address, bits 32 to 47
+    MovwUabsG2 = Self::MOVW_UABS_G2,
+    /// `MOVZ`/`MOVK` immediate of the absolute address, bits 32 to 47, without an overflow
+    /// check
+    MovwUabsG2Nc = Self::MOVW_UABS_G2_NC,
+    /// `MOVZ`/`MOVK` immediate of the absolute address, bits 48 to 63
+    MovwUabsG3 = Self::MOVW_UABS_G3,
+    /// `MOVZ`/`MOVK` immediate of the absolute address, bits 0 to 15
+    MovwSabsG0 = Self::MOVW_SABS_G0,
+    /// `MOVZ`/`MOVK` immediate of the absolute address, bits 16 to 31
+    MovwSabsG1 = Self::MOVW_SABS_G1,
+    /// `MOVZ`/`MOVK` immediate of the absolute address, bits 32 to 47
+    MovwSabsG2 = Self::MOVW_SABS_G2,
+    /// `LDR` literal offset of the PC-relative address
+    LdPrelLo19 = Self::LD_PREL_LO19,
+    /// `ADR` immediate of the PC-relative address
+    AdrPrelLo21 = Self::ADR_PREL_LO21,
+    /// `ADRP` immediate of the PC-relative address
+    AdrPrelPgHi21 = Self::ADR_PREL_PG_HI21,
+    /// `ADRP` immediate of the PC-relative address, without an overflow check
+    AdrPrelPgHi21Nc = Self::ADR_PREL_PG_HI21_NC,
+    /// `ADD` immediate of the absolute address, without an overflow check
+    AddAbsLo12Nc = Self::ADD_ABS_LO12_NC,
+    /// 8-bit load or store immediate of the absolute address, without an overflow check
+    Ldst8AbsLo12Nc = Self::LDST8_ABS_LO12_NC,
+    /// 14-bit PC-relative branch offset for `TBZ`/`TBNZ`
+    Tstbr14 = Self::TSTBR14,
+    /// 19-bit PC-relative branch offset for `B.cond`/`CBZ`/`CBNZ`
+    Condbr19 = Self::CONDBR19,
+    /// 26-bit PC-relative branch offset for `B`
+    Jump26 = Self::JUMP26,
+    /// 26-bit PC-relative branch offset for `BL`
+    Call26 = Self::CALL26,
+    /// 16-bit load or store immediate of the absolute address, without an overflow check
+    Ldst16AbsLo12Nc = Self::LDST16_ABS_LO12_NC,
+    /// 32-bit load or store immediate of the absolute address, without an overflow check
+    Ldst32AbsLo12Nc = Self::LDST32_ABS_LO12_NC,
+    /// 64-bit load or store immediate of the absolute address, without an overflow check
+    Ldst64AbsLo12Nc = Self::LDST64_ABS_LO12_NC,
+    /// `MOVZ`/`MOVK` immediate of the PC-relative address, bits 0 to 15
+    MovwPrelG0 = Self::MOVW_PREL_G0,
+    /// `MOVZ`/`MOVK` immediate of the PC-relative address, bits 0 to 15, without an
+    /// overflow check
+    MovwPrelG0Nc = Self::MOVW_PREL_G0_NC,
+    /// `MOVZ`/`MOVK` immediate of the PC-relative address, bits 16 to 31
+    MovwPrelG1 = Self::MOVW_PREL_G1,
+    /// `MOVZ`/`MOVK` immediate of the PC-relative address, bits 16 to 31, without an
+    /// overflow check
+    MovwPrelG1Nc = Self::MOVW_PREL_G1_NC,
+    /// `MOVZ`/`MOVK` immediate of the PC-relative address, bits 32 to 47
+    MovwPrelG2 = Self::MOVW_PREL_G2,
+    /// `MOVZ`/`MOVK` immediate of the PC-relative address, bits 32 to 47, without an
+    /// overflow check
+    MovwPrelG2Nc = Self::MOVW_PREL_G2_NC,
+    /// `MOVZ`/`MOVK` immediate of the PC-relative address, bits 48 to 63
+    MovwPrelG3 = Self::MOVW_PREL_G3,
+    /// 128-bit load or store immediate of the absolute address, without an overflow check
+    Ldst128AbsLo12Nc = Self::LDST128_ABS_LO12_NC,
+    /// `MOVZ`/`MOVK` immediate of the offset of the GOT entry from the GOT, bits 0 to 15
+    MovwGotoffG0 = Self::MOVW_GOTOFF_G0,
+    /// `MOVZ`/`MOVK` immediate of the offset of the GOT entry from the GOT, bits 0 to 15,
+    /// without an overflow check
+    MovwGotoffG0Nc = Self::MOVW_GOTOFF_G0_NC,
+    /// `MOVZ`/`MOVK` immediate of the offset of the GOT entry from the GOT, bits 16 to 31
+    MovwGotoffG1 = Self::MOVW_GOTOFF_G1,
+    /// `MOVZ`/`MOVK` immediate of the offset of the GOT entry from the GOT, bits 16 to 31,
+    /// without an overflow check
+    MovwGotoffG1Nc = Self::MOVW_GOTOFF_G1_NC,
+    /// `MOVZ`/`MOVK` immediate of the offset of the GOT entry from the GOT, bits 32 to 47
+    MovwGotoffG2 = Self::MOVW_GOTOFF_G2,
+    /// `MOVZ`/`MOVK` immediate of the offset of the GOT entry from the GOT, bits 32 to 47,
+    /// without an overflow check
+    MovwGotoffG2Nc = Self::MOVW_GOTOFF_G2_NC,
+    /// `MOVZ`/`MOVK` immediate of the offset of the GOT entry from the GOT, bits 48 to 63
+    MovwGotoffG3 = Self::MOVW_GOTOFF_G3,
+    /// 64-bit offset of the address from the GOT
+    Gotrel64 = Self::GOTREL64,
+    /// 32-bit offset of the address from the GOT
+    Gotrel32 = Self::GOTREL32,
+    /// `LDR` literal offset of the address of the GOT entry
+    GotLdPrel19 = Self::GOT_LD_PREL19,
+    /// 64-bit `LDR` immediate of the offset of the GOT entry from the GOT
+    Ld64GotoffLo15 = Self::LD64_GOTOFF_LO15,
+    /// Page-relative offset of the GOT entry for `ADRP`
+    AdrGotPage = Self::ADR_GOT_PAGE,
+    /// 64-bit `LDR` immediate of the address of the GOT entry, without an overflow check
+    Ld64GotLo12Nc = Self::LD64_GOT_LO12_NC,
+    /// 64-bit `LDR` immediate of the offset of the GOT entry from the page of the GOT
+    Ld64GotpageLo15 = Self::LD64_GOTPAGE_LO15,
+    /// `ADR` immediate of the GOT entry for the general dynamic TLS model
+    TlsgdAdrPrel21 = Self::TLSGD_ADR_PREL21,
+    /// `ADRP` immediate of the GOT entry for the general dynamic TLS model
+    TlsgdAdrPage21 = Self::TLSGD_ADR_PAGE21,
+    /// `ADD` immediate of the GOT entry for the general dynamic TLS model, without an
+    /// overflow check
+    TlsgdAddLo12Nc = Self::TLSGD_ADD_LO12_NC,
+    /// `MOVZ`/`MOVK` immediate of the GOT entry for the general dynamic TLS model, bits 16
+    /// to 31
+    TlsgdMovwG1 = Self::TLSGD_MOVW_G1,
+    /// `MOVZ`/`MOVK` immediate of the GOT entry for the general dynamic TLS model, bits 0
+    /// to 15, without an overflow check
+    TlsgdMovwG0Nc = Self::TLSGD_MOVW_G0_NC,
+    /// `ADR` immediate of the GOT entry for the local dynamic TLS model
+    TlsldAdrPrel21 = Self::TLSLD_ADR_PREL21,
+    /// `ADRP` immediate of the GOT entry for the local dynamic TLS model
+    TlsldAdrPage21 = Self::TLSLD_ADR_PAGE21,
+    /// `ADD` immediate of the GOT entry for the local dynamic TLS model, without an
+    /// overflow check
+    TlsldAddLo12Nc = Self::TLSLD_ADD_LO12_NC,
+    /// `MOVZ`/`MOVK` immediate of the GOT entry for the local dynamic TLS model, bits 16 to
+    /// 31
+    TlsldMovwG1 = Self::TLSLD_MOVW_G1,
+    /// `MOVZ`/`MOVK` immediate of the GOT entry for the local dynamic TLS model, bits 0 to
+    /// 15, without an overflow check
+    TlsldMovwG0Nc = Self::TLSLD_MOVW_G0_NC,
+    /// `LDR` literal offset of the GOT entry for the local dynamic TLS model
+    TlsldLdPrel19 = Self::TLSLD_LD_PREL19,
+    /// `MOVZ`/`MOVK` immediate of the offset in the module's TLS block for the local
+    /// dynamic TLS model, bits 32 to 47
+    TlsldMovwDtprelG2 = Self::TLSLD_MOVW_DTPREL_G2,
+    /// `MOVZ`/`MOVK` immediate of the offset in the module's TLS block for the local
+    /// dynamic TLS model, bits 16 to 31
+    TlsldMovwDtprelG1 = Self::TLSLD_MOVW_DTPREL_G1,
+    /// `MOVZ`/`MOVK` immediate of the offset in the module's TLS block for the local
+    /// dynamic TLS model, bits 16 to 31, without an overflow check
+    TlsldMovwDtprelG1Nc = Self::TLSLD_MOVW_DTPREL_G1_NC,
+    /// `MOVZ`/`MOVK` immediate of the offset in the module's TLS block for the local
+    /// dynamic TLS model, bits 0 to 15
+    TlsldMovwDtprelG0 = Self::TLSLD_MOVW_DTPREL_G0,
+    /// `MOVZ`/`MOVK` immediate of the offset in the module's TLS block for the local
+    /// dynamic TLS model, bits 0 to 15, without an overflow check
+    TlsldMovwDtprelG0Nc = Self::TLSLD_MOVW_DTPREL_G0_NC,
+    /// `ADD` immediate of the offset in the module's TLS block for the local dynamic TLS
+    /// model
+    TlsldAddDtprelHi12 = Self::TLSLD_ADD_DTPREL_HI12,
+    /// `ADD` immediate of the offset in the module's TLS block for the local dynamic TLS
+    /// model
+    TlsldAddDtprelLo12 = Self::TLSLD_ADD_DTPREL_LO12,
+    /// `ADD` immediate of the offset in the module's TLS block for the local dynamic TLS
+    /// model, without an overflow check
+    TlsldAddDtprelLo12Nc = Self::TLSLD_ADD_DTPREL_LO12_NC,
+    /// 8-bit load or store immediate of the offset in the module's TLS block for the local
+    /// dynamic TLS model
+    TlsldLdst8DtprelLo12 = Self::TLSLD_LDST8_DTPREL_LO12,
+    /// 8-bit load or store immediate of the offset in the module's TLS block for the local
+    /// dynamic TLS model, without an overflow check
+    TlsldLdst8DtprelLo12Nc = Self::TLSLD_LDST8_DTPREL_LO12_NC,
+    /// 16-bit load or store immediate of the offset in the module's TLS block for the local
+    /// dynamic TLS model
+    TlsldLdst16DtprelLo12 = Self::TLSLD_LDST16_DTPREL_LO12,
+    /// 16-bit load or store immediate of the offset in the module's TLS block for the local
+    /// dynamic TLS model, without an overflow check
+    TlsldLdst16DtprelLo12Nc = Self::TLSLD_LDST16_DTPREL_LO12_NC,
+    /// 32-bit load or store immediate of the offset in the module's TLS block for the local
+    /// dynamic TLS model
+    TlsldLdst32DtprelLo12 = Self::TLSLD_LDST32_DTPREL_LO12,
+    /// 32-bit load or store immediate of the offset in the module's TLS block for the local
+    /// dynamic TLS model, without an overflow check
+    TlsldLdst32DtprelLo12Nc = Self::TLSLD_LDST32_DTPREL_LO12_NC,
+    /// 64-bit load or store immediate of the offset in the module's TLS block for the local
+    /// dynamic TLS model
+    TlsldLdst64DtprelLo12 = Self::TLSLD_LDST64_DTPREL_LO12,
+    /// 64-bit load or store immediate of the offset in the module's TLS block for the local
+    /// dynamic TLS model, without an overflow check
+    TlsldLdst64DtprelLo12Nc = Self::TLSLD_LDST64_DTPREL_LO12_NC,
+    /// `MOVZ`/`MOVK` immediate of the GOT entry of the offset from the thread pointer for
+    /// the initial exec TLS model, bits 16 to 31
+    TlsieMovwGottprelG1 = Self::TLSIE_MOVW_GOTTPREL_G1,
+    /// `MOVZ`/`MOVK` immediate of the GOT entry of the offset from the thread pointer for
+    /// the initial exec TLS model, bits 0 to 15, without an overflow check
+    TlsieMovwGottprelG0Nc = Self::TLSIE_MOVW_GOTTPREL_G0_NC,
+    /// `ADRP` immediate of the GOT entry of the offset from the thread pointer for the
+    /// initial exec TLS model
+    TlsieAdrGottprelPage21 = Self::TLSIE_ADR_GOTTPREL_PAGE21,
+    /// 64-bit `LDR` immediate of the GOT entry of the offset from the thread pointer for
+    /// the initial exec TLS model, without an overflow check
+    TlsieLd64GottprelLo12Nc = Self::TLSIE_LD64_GOTTPREL_LO12_NC,
+    /// `LDR` literal offset of the GOT entry of the offset from the thread pointer for the
+    /// initial exec TLS model
+    TlsieLdGottprelPrel19 = Self::TLSIE_LD_GOTTPREL_PREL19,
+    /// `MOVZ`/`MOVK` immediate of the offset from the thread pointer for the local exec TLS
+    /// model, bits 32 to 47
+    TlsleMovwTprelG2 = Self::TLSLE_MOVW_TPREL_G2,
+    /// `MOVZ`/`MOVK` immediate of the offset from the thread pointer for the local exec TLS
+    /// model, bits 16 to 31
+    TlsleMovwTprelG1 = Self::TLSLE_MOVW_TPREL_G1,
+    /// `MOVZ`/`MOVK` immediate of the offset from the thread pointer for the local exec TLS
+    /// model, bits 16 to 31, without an overflow check
+    TlsleMovwTprelG1Nc = Self::TLSLE_MOVW_TPREL_G1_NC,
+    /// `MOVZ`/`MOVK` immediate of the offset from the thread pointer for the local exec TLS
+    /// model, bits 0 to 15
+    TlsleMovwTprelG0 = Self::TLSLE_MOVW_TPREL_G0,
+    /// `MOVZ`/`MOVK` immediate of the offset from the thread pointer for the local exec TLS
+    /// model, bits 0 to 15, without an overflow check
+    TlsleMovwTprelG0Nc = Self::TLSLE_MOVW_TPREL_G0_NC,
+    /// `ADD` immediate of the offset from the thread pointer for the local exec TLS model
+    TlsleAddTprelHi12 = Self::TLSLE_ADD_TPREL_HI12,
+    /// `ADD` immediate of the offset from the thread pointer for the local exec TLS model
+    TlsleAddTprelLo12 = Self::TLSLE_ADD_TPREL_LO12,
+    /// `ADD` immediate of the offset from the thread pointer for the local exec TLS model,
+    /// without an overflow check
+    TlsleAddTprelLo12Nc = Self::TLSLE_ADD_TPREL_LO12_NC,
+    /// 8-bit load or store immediate of the offset from the thread pointer for the local
+    /// exec TLS model
+    TlsleLdst8TprelLo12 = Self::TLSLE_LDST8_TPREL_LO12,
+    /// 8-bit load or store immediate of the offset from the thread pointer for the local
+    /// exec TLS model, without an overflow check
+    TlsleLdst8TprelLo12Nc = Self::TLSLE_LDST8_TPREL_LO12_NC,
+    /// 16-bit load or store immediate of the offset from the thread pointer for the local
+    /// exec TLS model
+    TlsleLdst16TprelLo12 = Self::TLSLE_LDST16_TPREL_LO12,
+    /// 16-bit load or store immediate of the offset from the thread pointer for the local
+    /// exec TLS model, without an overflow check
+    TlsleLdst16TprelLo12Nc = Self::TLSLE_LDST16_TPREL_LO12_NC,
+    /// 32-bit load or store immediate of the offset from the thread pointer for the local
+    /// exec TLS model
+    TlsleLdst32TprelLo12 = Self::TLSLE_LDST32_TPREL_LO12,
+    /// 32-bit load or store immediate of the offset from the thread pointer for the local
+    /// exec TLS model, without an overflow check
+    TlsleLdst32TprelLo12Nc = Self::TLSLE_LDST32_TPREL_LO12_NC,
+    /// 64-bit load or store immediate of the offset from the thread pointer for the local
+    /// exec TLS model
+    TlsleLdst64TprelLo12 = Self::TLSLE_LDST64_TPREL_LO12,
+    /// 64-bit load or store immediate of the offset from the thread pointer for the local
+    /// exec TLS model, without an overflow check
+    TlsleLdst64TprelLo12Nc = Self::TLSLE_LDST64_TPREL_LO12_NC,
+    /// `LDR` literal offset of the GOT entry for the TLS descriptor model
+    TlsdescLdPrel19 = Self::TLSDESC_LD_PREL19,
+    /// `ADR` immediate of the GOT entry for the TLS descriptor model
+    TlsdescAdrPrel21 = Self::TLSDESC_ADR_PREL21,
+    /// `ADRP` immediate of the GOT entry for the TLS descriptor model
+    TlsdescAdrPage21 = Self::TLSDESC_ADR_PAGE21,
+    /// 64-bit `LDR` immediate of the GOT entry for the TLS descriptor model
+    TlsdescLd64Lo12 = Self::TLSDESC_LD64_LO12,
+    /// `ADD` immediate of the GOT entry for the TLS descriptor model
+    TlsdescAddLo12 = Self::TLSDESC_ADD_LO12,
+    /// `MOVZ`/`MOVK` immediate of the GOT entry for the TLS descriptor model, bits 16 to 31
+    TlsdescOffG1 = Self::TLSDESC_OFF_G1,
+    /// `MOVZ`/`MOVK` immediate of the GOT entry for the TLS descriptor model, bits 0 to 15,
+    /// without an overflow check
+    TlsdescOffG0Nc = Self::TLSDESC_OFF_G0_NC,
+    /// Marks the `LDR` of a TLS descriptor sequence
+    TlsdescLdr = Self::TLSDESC_LDR,
+    /// Marks the `ADD` of a TLS descriptor sequence
+    TlsdescAdd = Self::TLSDESC_ADD,
+    /// Marks the call through a TLS descriptor
+    TlsdescCall = Self::TLSDESC_CALL,
+    /// 128-bit load or store immediate of the offset from the thread pointer for the local
+    /// exec TLS model
+    TlsleLdst128TprelLo12 = Self::TLSLE_LDST128_TPREL_LO12,
+    /// 128-bit load or store immediate of the offset from the thread pointer for the local
+    /// exec TLS model, without an overflow check
+    TlsleLdst128TprelLo12Nc = Self::TLSLE_LDST128_TPREL_LO12_NC,
+    /// 128-bit load or store immediate of the offset in the module's TLS block for the
+    /// local dynamic TLS model
+    TlsldLdst128DtprelLo12 = Self::TLSLD_LDST128_DTPREL_LO12,
+    /// 128-bit load or store immediate of the offset in the module's TLS block for the
+    /// local dynamic TLS model, without an overflow check
+    TlsldLdst128DtprelLo12Nc = Self::TLSLD_LDST128_DTPREL_LO12_NC,
+    /// Copy the symbol at run time
+    Copy = Self::COPY,
+    /// Create a GOT entry
+    GlobDat = Self::GLOB_DAT,
+    /// Create a PLT entry
+    JumpSlot = Self::JUMP_SLOT,
+    /// Adjust by the load base
+    Relative = Self::RELATIVE,
+    /// ID of the module containing the symbol
+    TlsDtpmod = Self::TLS_DTPMOD,
+    /// Offset in the module's TLS block
+    TlsDtprel = Self::TLS_DTPREL,
+    /// Offset from the thread pointer
+    TlsTprel = Self::TLS_TPREL,
+    /// TLS descriptor
+    Tlsdesc = Self::TLSDESC,
+    /// Adjust indirectly by the load base
+    Irelative = Self::IRELATIVE,
+}
+
+impl ElfRelocationTypeAARCH64 {
+    /// Constant value for [ElfRelocationTypeAARCH64::None]
+    pub const NONE: u32 = 0;
+    /// Constant value for [ElfRelocationTypeAARCH64::Abs64]
+    pub const ABS64: u32 = 257;
+    /// Constant value for [ElfRelocationTypeAARCH64::Abs32]
+    pub const ABS32: u32 = 258;
+    /// Constant value for [ElfRelocationTypeAARCH64::Abs16]
+    pub const ABS16: u32 = 259;
+    /// Constant value for [ElfRelocationTypeAARCH64::Prel64]
+    pub const PREL64: u32 = 260;
+    /// Constant value for [ElfRelocationTypeAARCH64::Prel32]
+    pub const PREL32: u32 = 261;
+    /// Constant value for [ElfRelocationTypeAARCH64::Prel16]
+    pub const PREL16: u32 = 262;
+    /// Constant value for [ElfRelocationTypeAARCH64::MovwUabsG0]
+    pub const MOVW_UABS_G0: u32 = 263;
+    /// Constant value for [ElfRelocationTypeAARCH64::MovwUabsG0Nc]
+    pub const MOVW_UABS_G0_NC: u32 = 264;
+    /// Constant value for [ElfRelocationTypeAARCH64::MovwUabsG1]
+    pub const MOVW_UABS_G1: u32 = 265;
+    /// Constant value for [ElfRelocationTypeAARCH64::MovwUabsG1Nc]
+    pub const MOVW_UABS_G1_NC: u32 = 266;
+    /// Constant value for [ElfRelocationTypeAARCH64::MovwUabsG2]
+    pub const MOVW_UABS_G2: u32 = 267;
+    /// Constant value for [ElfRelocationTypeAARCH64::MovwUabsG2Nc]
+    pub const MOVW_UABS_G2_NC: u32 = 268;
+    /// Constant value for [ElfRelocationTypeAARCH64::MovwUabsG3]
+    pub const MOVW_UABS_G3: u32 = 269;
+    /// Constant value for [ElfRelocationTypeAARCH64::MovwSabsG0]
+    pub const MOVW_SABS_G0: u32 = 270;
+    /// Constant value for [ElfRelocationTypeAARCH64::MovwSabsG1]
+    pub const MOVW_SABS_G1: u32 = 271;
+    /// Constant value for [ElfRelocationTypeAARCH64::MovwSabsG2]
+    pub const MOVW_SABS_G2: u32 = 272;
+    /// Constant value for [ElfRelocationTypeAARCH64::LdPrelLo19]
+    pub const LD_PREL_LO19: u32 = 273;
+    /// Constant value for [ElfRelocationTypeAARCH64::AdrPrelLo21]
+    pub const ADR_PREL_LO21: u32 = 274;
+    /// Constant value for [ElfRelocationTypeAARCH64::AdrPrelPgHi21]
+    pub const ADR_PREL_PG_HI21: u32 = 275;
+    /// Constant value for [ElfRelocationTypeAARCH64::AdrPrelPgHi21Nc]
+    pub const ADR_PREL_PG_HI21_NC: u32 = 276;
+    /// Constant value for [ElfRelocationTypeAARCH64::AddAbsLo12Nc]
+    pub const ADD_ABS_LO12_NC: u32 = 277;
+    /// Constant value for [ElfRelocationTypeAARCH64::Ldst8AbsLo12Nc]
+    pub const LDST8_ABS_LO12_NC: u32 = 278;
+    /// Constant value for [ElfRelocationTypeAARCH64::Tstbr14]
+    pub const TSTBR14: u32 = 279;
+    /// Constant value for [ElfRelocationTypeAARCH64::Condbr19]
+    pub const CONDBR19: u32 = 280;
+    /// Constant value for [ElfRelocationTypeAARCH64::Jump26]
+    pub const JUMP26: u32 = 282;
+    /// Constant value for [ElfRelocationTypeAARCH64::Call26]
+    pub const CALL26: u32 = 283;
+    /// Constant value for [ElfRelocationTypeAARCH64::Ldst16AbsLo12Nc]
+    pub const LDST16_ABS_LO12_NC: u32 = 284;
+    /// Constant value for [ElfRelocationTypeAARCH64::Ldst32AbsLo12Nc]
+    pub const LDST32_ABS_LO12_NC: u32 = 285;
+    /// Constant value for [ElfRelocationTypeAARCH64::Ldst64AbsLo12Nc]
+    pub const LDST64_ABS_LO12_NC: u32 = 286;
+    /// Constant value for [ElfRelocationTypeAARCH64::MovwPrelG0]
+    pub const MOVW_PREL_G0: u32 = 287;
+    /// Constant value for [ElfRelocationTypeAARCH64::MovwPrelG0Nc]
+    pub const MOVW_PREL_G0_NC: u32 = 288;
+    /// Constant value for [ElfRelocationTypeAARCH64::MovwPrelG1]
+    pub const MOVW_PREL_G1: u32 = 289;
+    /// Constant value for [ElfRelocationTypeAARCH64::MovwPrelG1Nc]
+    pub const MOVW_PREL_G1_NC: u32 = 290;
+    /// Constant value for [ElfRelocationTypeAARCH64::MovwPrelG2]
+    pub const MOVW_PREL_G2: u32 = 291;
+    /// Constant value for [ElfRelocationTypeAARCH64::MovwPrelG2Nc]
+    pub const MOVW_PREL_G2_NC: u32 = 292;
+    /// Constant value for [ElfRelocationTypeAARCH64::MovwPrelG3]
+    pub const MOVW_PREL_G3: u32 = 293;
+    /// Constant value for [ElfRelocationTypeAARCH64::Ldst128AbsLo12Nc]
+    pub const LDST128_ABS_LO12_NC: u32 = 299;
+    /// Constant value for [ElfRelocationTypeAARCH64::MovwGotoffG0]
+    pub const MOVW_GOTOFF_G0: u32 = 300;
+    /// Constant value for [ElfRelocationTypeAARCH64::MovwGotoffG0Nc]
+    pub const MOVW_GOTOFF_G0_NC: u32 = 301;
+    /// Constant value for [ElfRelocationTypeAARCH64::MovwGotoffG1]
+    pub const MOVW_GOTOFF_G1: u32 = 302;
+    /// Constant value for [ElfRelocationTypeAARCH64::MovwGotoffG1Nc]
+    pub const MOVW_GOTOFF_G1_NC: u32 = 303;
+    /// Constant value for [ElfRelocationTypeAARCH64::MovwGotoffG2]
+    pub const MOVW_GOTOFF_G2: u32 = 304;
+    /// Constant value for [ElfRelocationTypeAARCH64::MovwGotoffG2Nc]
+    pub const MOVW_GOTOFF_G2_NC: u32 = 305;
+    /// Constant value for [ElfRelocationTypeAARCH64::MovwGotoffG3]
+    pub const MOVW_GOTOFF_G3: u32 = 306;
+    /// Constant value for [ElfRelocationTypeAARCH64::Gotrel64]
+    pub const GOTREL64: u32 = 307;
+    /// Constant value for [ElfRelocationTypeAARCH64::Gotrel32]
+    pub const GOTREL32: u32 = 308;
+    /// Constant value for [ElfRelocationTypeAARCH64::GotLdPrel19]
+    pub const GOT_LD_PREL19: u32 = 309;
+    /// Constant value for [ElfRelocationTypeAARCH64::Ld64GotoffLo15]
+    pub const LD64_GOTOFF_LO15: u32 = 310;
+    /// Constant value for [ElfRelocationTypeAARCH64::AdrGotPage]
+    pub const ADR_GOT_PAGE: u32 = 311;
+    /// Constant value for [ElfRelocationTypeAARCH64::Ld64GotLo12Nc]
+    pub const LD64_GOT_LO12_NC: u32 = 312;
+    /// Constant value for [ElfRelocationTypeAARCH64::Ld64GotpageLo15]
+    pub const LD64_GOTPAGE_LO15: u32 = 313;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsgdAdrPrel21]
+    pub const TLSGD_ADR_PREL21: u32 = 512;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsgdAdrPage21]
+    pub const TLSGD_ADR_PAGE21: u32 = 513;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsgdAddLo12Nc]
+    pub const TLSGD_ADD_LO12_NC: u32 = 514;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsgdMovwG1]
+    pub const TLSGD_MOVW_G1: u32 = 515;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsgdMovwG0Nc]
+    pub const TLSGD_MOVW_G0_NC: u32 = 516;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsldAdrPrel21]
+    pub const TLSLD_ADR_PREL21: u32 = 517;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsldAdrPage21]
+    pub const TLSLD_ADR_PAGE21: u32 = 518;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsldAddLo12Nc]
+    pub const TLSLD_ADD_LO12_NC: u32 = 519;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsldMovwG1]
+    pub const TLSLD_MOVW_G1: u32 = 520;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsldMovwG0Nc]
+    pub const TLSLD_MOVW_G0_NC: u32 = 521;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsldLdPrel19]
+    pub const TLSLD_LD_PREL19: u32 = 522;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsldMovwDtprelG2]
+    pub const TLSLD_MOVW_DTPREL_G2: u32 = 523;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsldMovwDtprelG1]
+    pub const TLSLD_MOVW_DTPREL_G1: u32 = 524;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsldMovwDtprelG1Nc]
+    pub const TLSLD_MOVW_DTPREL_G1_NC: u32 = 525;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsldMovwDtprelG0]
+    pub const TLSLD_MOVW_DTPREL_G0: u32 = 526;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsldMovwDtprelG0Nc]
+    pub const TLSLD_MOVW_DTPREL_G0_NC: u32 = 527;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsldAddDtprelHi12]
+    pub const TLSLD_ADD_DTPREL_HI12: u32 = 528;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsldAddDtprelLo12]
+    pub const TLSLD_ADD_DTPREL_LO12: u32 = 529;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsldAddDtprelLo12Nc]
+    pub const TLSLD_ADD_DTPREL_LO12_NC: u32 = 530;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsldLdst8DtprelLo12]
+    pub const TLSLD_LDST8_DTPREL_LO12: u32 = 531;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsldLdst8DtprelLo12Nc]
+    pub const TLSLD_LDST8_DTPREL_LO12_NC: u32 = 532;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsldLdst16DtprelLo12]
+    pub const TLSLD_LDST16_DTPREL_LO12: u32 = 533;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsldLdst16DtprelLo12Nc]
+    pub const TLSLD_LDST16_DTPREL_LO12_NC: u32 = 534;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsldLdst32DtprelLo12]
+    pub const TLSLD_LDST32_DTPREL_LO12: u32 = 535;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsldLdst32DtprelLo12Nc]
+    pub const TLSLD_LDST32_DTPREL_LO12_NC: u32 = 536;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsldLdst64DtprelLo12]
+    pub const TLSLD_LDST64_DTPREL_LO12: u32 = 537;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsldLdst64DtprelLo12Nc]
+    pub const TLSLD_LDST64_DTPREL_LO12_NC: u32 = 538;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsieMovwGottprelG1]
+    pub const TLSIE_MOVW_GOTTPREL_G1: u32 = 539;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsieMovwGottprelG0Nc]
+    pub const TLSIE_MOVW_GOTTPREL_G0_NC: u32 = 540;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsieAdrGottprelPage21]
+    pub const TLSIE_ADR_GOTTPREL_PAGE21: u32 = 541;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsieLd64GottprelLo12Nc]
+    pub const TLSIE_LD64_GOTTPREL_LO12_NC: u32 = 542;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsieLdGottprelPrel19]
+    pub const TLSIE_LD_GOTTPREL_PREL19: u32 = 543;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsleMovwTprelG2]
+    pub const TLSLE_MOVW_TPREL_G2: u32 = 544;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsleMovwTprelG1]
+    pub const TLSLE_MOVW_TPREL_G1: u32 = 545;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsleMovwTprelG1Nc]
+    pub const TLSLE_MOVW_TPREL_G1_NC: u32 = 546;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsleMovwTprelG0]
+    pub const TLSLE_MOVW_TPREL_G0: u32 = 547;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsleMovwTprelG0Nc]
+    pub const TLSLE_MOVW_TPREL_G0_NC: u32 = 548;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsleAddTprelHi12]
+    pub const TLSLE_ADD_TPREL_HI12: u32 = 549;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsleAddTprelLo12]
+    pub const TLSLE_ADD_TPREL_LO12: u32 = 550;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsleAddTprelLo12Nc]
+    pub const TLSLE_ADD_TPREL_LO12_NC: u32 = 551;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsleLdst8TprelLo12]
+    pub const TLSLE_LDST8_TPREL_LO12: u32 = 552;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsleLdst8TprelLo12Nc]
+    pub const TLSLE_LDST8_TPREL_LO12_NC: u32 = 553;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsleLdst16TprelLo12]
+    pub const TLSLE_LDST16_TPREL_LO12: u32 = 554;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsleLdst16TprelLo12Nc]
+    pub const TLSLE_LDST16_TPREL_LO12_NC: u32 = 555;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsleLdst32TprelLo12]
+    pub const TLSLE_LDST32_TPREL_LO12: u32 = 556;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsleLdst32TprelLo12Nc]
+    pub const TLSLE_LDST32_TPREL_LO12_NC: u32 = 557;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsleLdst64TprelLo12]
+    pub const TLSLE_LDST64_TPREL_LO12: u32 = 558;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsleLdst64TprelLo12Nc]
+    pub const TLSLE_LDST64_TPREL_LO12_NC: u32 = 559;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsdescLdPrel19]
+    pub const TLSDESC_LD_PREL19: u32 = 560;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsdescAdrPrel21]
+    pub const TLSDESC_ADR_PREL21: u32 = 561;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsdescAdrPage21]
+    pub const TLSDESC_ADR_PAGE21: u32 = 562;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsdescLd64Lo12]
+    pub const TLSDESC_LD64_LO12: u32 = 563;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsdescAddLo12]
+    pub const TLSDESC_ADD_LO12: u32 = 564;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsdescOffG1]
+    pub const TLSDESC_OFF_G1: u32 = 565;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsdescOffG0Nc]
+    pub const TLSDESC_OFF_G0_NC: u32 = 566;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsdescLdr]
+    pub const TLSDESC_LDR: u32 = 567;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsdescAdd]
+    pub const TLSDESC_ADD: u32 = 568;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsdescCall]
+    pub const TLSDESC_CALL: u32 = 569;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsleLdst128TprelLo12]
+    pub const TLSLE_LDST128_TPREL_LO12: u32 = 570;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsleLdst128TprelLo12Nc]
+    pub const TLSLE_LDST128_TPREL_LO12_NC: u32 = 571;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsldLdst128DtprelLo12]
+    pub const TLSLD_LDST128_DTPREL_LO12: u32 = 572;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsldLdst128DtprelLo12Nc]
+    pub const TLSLD_LDST128_DTPREL_LO12_NC: u32 = 573;
+    /// Constant value for [ElfRelocationTypeAARCH64::Copy]
+    pub const COPY: u32 = 1024;
+    /// Constant value for [ElfRelocationTypeAARCH64::GlobDat]
+    pub const GLOB_DAT: u32 = 1025;
+    /// Constant value for [ElfRelocationTypeAARCH64::JumpSlot]
+    pub const JUMP_SLOT: u32 = 1026;
+    /// Constant value for [ElfRelocationTypeAARCH64::Relative]
+    pub const RELATIVE: u32 = 1027;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsDtpmod]
+    pub const TLS_DTPMOD: u32 = 1028;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsDtprel]
+    pub const TLS_DTPREL: u32 = 1029;
+    /// Constant value for [ElfRelocationTypeAARCH64::TlsTprel]
+    pub const TLS_TPREL: u32 = 1030;
+    /// Constant value for [ElfRelocationTypeAARCH64::Tlsdesc]
+    pub const TLSDESC: u32 = 1031;
+    /// Constant value for [ElfRelocationTypeAARCH64::Irelative]
+    pub const IRELATIVE: u32 = 1032;
+}
+
+impl core::fmt::Display for ElfRelocationTypeAARCH64 {
+    /// Display the relocation type as its name in the ABI, such as `R_AARCH64_ABS64`
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::None => "R_AARCH64_NONE",
+            Self::Abs64 => "R_AARCH64_ABS64",
+            Self::Abs32 => "R_AARCH64_ABS32",
+            Self::Abs16 => "R_AARCH64_ABS16",
+            Self::Prel64 => "R_AARCH64_PREL64",
+            Self::Prel32 => "R_AARCH64_PREL32",
+            Self::Prel16 => "R_AARCH64_PREL16",
+            Self::MovwUabsG0 => "R_AARCH64_MOVW_UABS_G0",
+            Self::MovwUabsG0Nc => "R_AARCH64_MOVW_UABS_G0_NC",
+            Self::MovwUabsG1 => "R_AARCH64_MOVW_UABS_G1",
+            Self::MovwUabsG1Nc => "R_AARCH64_MOVW_UABS_G1_NC",
+            Self::MovwUabsG2 => "R_AARCH64_MOVW_UABS_G2",
+            Self::MovwUabsG2Nc => "R_AARCH64_MOVW_UABS_G2_NC",
+            Self::MovwUabsG3 => "R_AARCH64_MOVW_UABS_G3",
+            Self::MovwSabsG0 => "R_AARCH64_MOVW_SABS_G0",
+            Self::MovwSabsG1 => "R_AARCH64_MOVW_SABS_G1",
+            Self::MovwSabsG2 => "R_AARCH64_MOVW_SABS_G2",
+            Self::LdPrelLo19 => "R_AARCH64_LD_PREL_LO19",
+            Self::AdrPrelLo21 => "R_AARCH64_ADR_PREL_LO21",
+            Self::AdrPrelPgHi21 => "R_AARCH64_ADR_PREL_PG_HI21",
+            Self::AdrPrelPgHi21Nc => "R_AARCH64_ADR_PREL_PG_HI21_NC",
+            Self::AddAbsLo12Nc => "R_AARCH64_ADD_ABS_LO12_NC",
+            Self::Ldst8AbsLo12Nc => "R_AARCH64_LDST8_ABS_LO12_NC",
+            Self::Tstbr14 => "R_AARCH64_TSTBR14",
+            Self::Condbr19 => "R_AARCH64_CONDBR19",
+            Self::Jump26 => "R_AARCH64_JUMP26",
+            Self::Call26 => "R_AARCH64_CALL26",
+            Self::Ldst16AbsLo12Nc => "R_AARCH64_LDST16_ABS_LO12_NC",
+            Self::Ldst32AbsLo12Nc => "R_AARCH64_LDST32_ABS_LO12_NC",
+            Self::Ldst64AbsLo12Nc => "R_AARCH64_LDST64_ABS_LO12_NC",
+            Self::MovwPrelG0 => "R_AARCH64_MOVW_PREL_G0",
+            Self::MovwPrelG0Nc => "R_AARCH64_MOVW_PREL_G0_NC",
+            Self::MovwPrelG1 => "R_AARCH64_MOVW_PREL_G1",
+            Self::MovwPrelG1Nc => "R_AARCH64_MOVW_PREL_G1_NC",
+            Self::MovwPrelG2 => "R_AARCH64_MOVW_PREL_G2",
+            Self::MovwPrelG2Nc => "R_AARCH64_MOVW_PREL_G2_NC",
+            Self::MovwPrelG3 => "R_AARCH64_MOVW_PREL_G3",
+            Self::Ldst128AbsLo12Nc => "R_AARCH64_LDST128_ABS_LO12_NC",
+            Self::MovwGotoffG0 => "R_AARCH64_MOVW_GOTOFF_G0",
+            Self::MovwGotoffG0Nc => "R_AARCH64_MOVW_GOTOFF_G0_NC",
+            Self::MovwGotoffG1 => "R_AARCH64_MOVW_GOTOFF_G1",
+            Self::MovwGotoffG1Nc => "R_AARCH64_MOVW_GOTOFF_G1_NC",
+            Self::MovwGotoffG2 => "R_AARCH64_MOVW_GOTOFF_G2",
+            Self::MovwGotoffG2Nc => "R_AARCH64_MOVW_GOTOFF_G2_NC",
+            Self::MovwGotoffG3 => "R_AARCH64_MOVW_GOTOFF_G3",
+            Self::Gotrel64 => "R_AARCH64_GOTREL64",
+            Self::Gotrel32 => "R_AARCH64_GOTREL32",
+            Self::GotLdPrel19 => "R_AARCH64_GOT_LD_PREL19",
+            Self::Ld64GotoffLo15 => "R_AARCH64_LD64_GOTOFF_LO15",
+            Self::AdrGotPage => "R_AARCH64_ADR_GOT_PAGE",
+            Self::Ld64GotLo12Nc => "R_AARCH64_LD64_GOT_LO12_NC",
+            Self::Ld64GotpageLo15 => "R_AARCH64_LD64_GOTPAGE_LO15",
+            Self::TlsgdAdrPrel21 => "R_AARCH64_TLSGD_ADR_PREL21",
+            Self::TlsgdAdrPage21 => "R_AARCH64_TLSGD_ADR_PAGE21",
+            Self::TlsgdAddLo12Nc => "R_AARCH64_TLSGD_ADD_LO12_NC",
+            Self::TlsgdMovwG1 => "R_AARCH64_TLSGD_MOVW_G1",
+            Self::TlsgdMovwG0Nc => "R_AARCH64_TLSGD_MOVW_G0_NC",
+            Self::TlsldAdrPrel21 => "R_AARCH64_TLSLD_ADR_PREL21",
+            Self::TlsldAdrPage21 => "R_AARCH64_TLSLD_ADR_PAGE21",
+            Self::TlsldAddLo12Nc => "R_AARCH64_TLSLD_ADD_LO12_NC",
+            Self::TlsldMovwG1 => "R_AARCH64_TLSLD_MOVW_G1",
+            Self::TlsldMovwG0Nc => "R_AARCH64_TLSLD_MOVW_G0_NC",
+            Self::TlsldLdPrel19 => "R_AARCH64_TLSLD_LD_PREL19",
+            Self::TlsldMovwDtprelG2 => "R_AARCH64_TLSLD_MOVW_DTPREL_G2",
+            Self::TlsldMovwDtprelG1 => "R_AARCH64_TLSLD_MOVW_DTPREL_G1",
+            Self::TlsldMovwDtprelG1Nc => "R_AARCH64_TLSLD_MOVW_DTPREL_G1_NC",
+            Self::TlsldMovwDtprelG0 => "R_AARCH64_TLSLD_MOVW_DTPREL_G0",
+            Self::TlsldMovwDtprelG0Nc => "R_AARCH64_TLSLD_MOVW_DTPREL_G0_NC",
+            Self::TlsldAddDtprelHi12 => "R_AARCH64_TLSLD_ADD_DTPREL_HI12",
+            Self::TlsldAddDtprelLo12 => "R_AARCH64_TLSLD_ADD_DTPREL_LO12",
+            Self::TlsldAddDtprelLo12Nc => "R_AARCH64_TLSLD_ADD_DTPREL_LO12_NC",
+            Self::TlsldLdst8DtprelLo12 => "R_AARCH64_TLSLD_LDST8_DTPREL_LO12",
+            Self::TlsldLdst8DtprelLo12Nc => "R_AARCH64_TLSLD_LDST8_DTPREL_LO12_NC",
+            Self::TlsldLdst16DtprelLo12 => "R_AARCH64_TLSLD_LDST16_DTPREL_LO12",
+            Self::TlsldLdst16DtprelLo12Nc => "R_AARCH64_TLSLD_LDST16_DTPREL_LO12_NC",
+            Self::TlsldLdst32DtprelLo12 => "R_AARCH64_TLSLD_LDST32_DTPREL_LO12",
+            Self::TlsldLdst32DtprelLo12Nc => "R_AARCH64_TLSLD_LDST32_DTPREL_LO12_NC",
+            Self::TlsldLdst64DtprelLo12 => "R_AARCH64_TLSLD_LDST64_DTPREL_LO12",
+            Self::TlsldLdst64DtprelLo12Nc => "R_AARCH64_TLSLD_LDST64_DTPREL_LO12_NC",
+            Self::TlsieMovwGottprelG1 => "R_AARCH64_TLSIE_MOVW_GOTTPREL_G1",
+            Self::TlsieMovwGottprelG0Nc => "R_AARCH64_TLSIE_MOVW_GOTTPREL_G0_NC",
+            Self::TlsieAdrGottprelPage21 => "R_AARCH64_TLSIE_ADR_GOTTPREL_PAGE21",
+            Self::TlsieLd64GottprelLo12Nc => "R_AARCH64_TLSIE_LD64_GOTTPREL_LO12_NC",
+            Self::TlsieLdGottprelPrel19 => "R_AARCH64_TLSIE_LD_GOTTPREL_PREL19",
+            Self::TlsleMovwTprelG2 => "R_AARCH64_TLSLE_MOVW_TPREL_G2",
+            Self::TlsleMovwTprelG1 => "R_AARCH64_TLSLE_MOVW_TPREL_G1",
+            Self::TlsleMovwTprelG1Nc => "R_AARCH64_TLSLE_MOVW_TPREL_G1_NC",
+            Self::TlsleMovwTprelG0 => "R_AARCH64_TLSLE_MOVW_TPREL_G0",
+            Self::TlsleMovwTprelG0Nc => "R_AARCH64_TLSLE_MOVW_TPREL_G0_NC",
+            Self::TlsleAddTprelHi12 => "R_AARCH64_TLSLE_ADD_TPREL_HI12",
+            Self::TlsleAddTprelLo12 => "R_AARCH64_TLSLE_ADD_TPREL_LO12",
+            Self::TlsleAddTprelLo12Nc => "R_AARCH64_TLSLE_ADD_TPREL_LO12_NC",
+            Self::TlsleLdst8TprelLo12 => "R_AARCH64_TLSLE_LDST8_TPREL_LO12",
+            Self::TlsleLdst8TprelLo12Nc => "R_AARCH64_TLSLE_LDST8_TPREL_LO12_NC",
+            Self::TlsleLdst16TprelLo12 => "R_AARCH64_TLSLE_LDST16_TPREL_LO12",
+            Self::TlsleLdst16TprelLo12Nc => "R_AARCH64_TLSLE_LDST16_TPREL_LO12_NC",
+            Self::TlsleLdst32TprelLo12 => "R_AARCH64_TLSLE_LDST32_TPREL_LO12",
+            Self::TlsleLdst32TprelLo12Nc => "R_AARCH64_TLSLE_LDST32_TPREL_LO12_NC",
+            Self::TlsleLdst64TprelLo12 => "R_AARCH64_TLSLE_LDST64_TPREL_LO12",
+            Self::TlsleLdst64TprelLo12Nc => "R_AARCH64_TLSLE_LDST64_TPREL_LO12_NC",
+            Self::TlsdescLdPrel19 => "R_AARCH64_TLSDESC_LD_PREL19",
+            Self::TlsdescAdrPrel21 => "R_AARCH64_TLSDESC_ADR_PREL21",
+            Self::TlsdescAdrPage21 => "R_AARCH64_TLSDESC_ADR_PAGE21",
+            Self::TlsdescLd64Lo12 => "R_AARCH64_TLSDESC_LD64_LO12",
+            Self::TlsdescAddLo12 => "R_AARCH64_TLSDESC_ADD_LO12",
+            Self::TlsdescOffG1 => "R_AARCH64_TLSDESC_OFF_G1",
+            Self::TlsdescOffG0Nc => "R_AARCH64_TLSDESC_OFF_G0_NC",
+            Self::TlsdescLdr => "R_AARCH64_TLSDESC_LDR",
+            Self::TlsdescAdd => "R_AARCH64_TLSDESC_ADD",
+            Self::TlsdescCall => "R_AARCH64_TLSDESC_CALL",
+            Self::TlsleLdst128TprelLo12 => "R_AARCH64_TLSLE_LDST128_TPREL_LO12",
+            Self::TlsleLdst128TprelLo12Nc => "R_AARCH64_TLSLE_LDST128_TPREL_LO12_NC",
+            Self::TlsldLdst128DtprelLo12 => "R_AARCH64_TLSLD_LDST128_DTPREL_LO12",
+            Self::TlsldLdst128DtprelLo12Nc => "R_AARCH64_TLSLD_LDST128_DTPREL_LO12_NC",
+            Self::Copy => "R_AARCH64_COPY",
+            Self::GlobDat => "R_AARCH64_GLOB_DAT",
+            Self::JumpSlot => "R_AARCH64_JUMP_SLOT",
+            Self::Relative => "R_AARCH64_RELATIVE",
+            Self::TlsDtpmod => "R_AARCH64_TLS_DTPMOD",
+            Self::TlsDtprel => "R_AARCH64_TLS_DTPREL",
+            Self::TlsTprel => "R_AARCH64_TLS_TPREL",
+            Self::Tlsdesc => "R_AARCH64_TLSDESC",
+            Self::Irelative => "R_AARCH64_IRELATIVE",
+        })
+    }
+}
+
+impl From<ElfRelocationTypeAARCH64> for u32 {
+    fn from(value: ElfRelocationTypeAARCH64) -> Self {
+        value as u32
+    }
+}
+
+impl TryFromWithConfig<u32> for ElfRelocationTypeAARCH64 {
+    type Error = Error;
+
+    /// Decode the type of a relocation entry, as returned by `ElfRelocation::r#type`
+    fn try_from_with(value: u32, config: &mut crate::Config) -> Result<Self, Self::Error> {
+        if !matches!(config.machine, Some(ElfMachine::AARCH64)) {
+            return Err(Error::InvalidMachineForRelocationType {
+                machine: config.machine,
+                expected_machines: vec![ElfMachine::AARCH64],
+                value,
+            });
+        }
+
+        match value {
+            Self::NONE => Ok(Self::None),
+            Self::ABS64 => Ok(Self::Abs64),
+            Self::ABS32 => Ok(Self::Abs32),
+            Self::ABS16 => Ok(Self::Abs16),
+            Self::PREL64 => Ok(Self::Prel64),
+            Self::PREL32 => Ok(Self::Prel32),
+            Self::PREL16 => Ok(Self::Prel16),
+            Self::MOVW_UABS_G0 => Ok(Self::MovwUabsG0),
+            Self::MOVW_UABS_G0_NC => Ok(Self::MovwUabsG0Nc),
+            Self::MOVW_UABS_G1 => Ok(Self::MovwUabsG1),
+            Self::MOVW_UABS_G1_NC => Ok(Self::MovwUabsG1Nc),
+            Self::MOVW_UABS_G2 => Ok(Self::MovwUabsG2),
+            Self::MOVW_UABS_G2_NC => Ok(Self::MovwUabsG2Nc),
+            Self::MOVW_UABS_G3 => Ok(Self::MovwUabsG3),
+            Self::MOVW_SABS_G0 => Ok(Self::MovwSabsG0),
+            Self::MOVW_SABS_G1 => Ok(Self::MovwSabsG1),
+            Self::MOVW_SABS_G2 => Ok(Self::MovwSabsG2),
+            Self::LD_PREL_LO19 => Ok(Self::LdPrelLo19),
+            Self::ADR_PREL_LO21 => Ok(Self::AdrPrelLo21),
+            Self::ADR_PREL_PG_HI21 => Ok(Self::AdrPrelPgHi21),
+            Self::ADR_PREL_PG_HI21_NC => Ok(Self::AdrPrelPgHi21Nc),
+            Self::ADD_ABS_LO12_NC => Ok(Self::AddAbsLo12Nc),
+            Self::LDST8_ABS_LO12_NC => Ok(Self::Ldst8AbsLo12Nc),
+            Self::TSTBR14 => Ok(Self::Tstbr14),
+            Self::CONDBR19 => Ok(Self::Condbr19),
+            Self::JUMP26 => Ok(Self::Jump26),
+            Self::CALL26 => Ok(Self::Call26),
+            Self::LDST16_ABS_LO12_NC => Ok(Self::Ldst16AbsLo12Nc),
+            Self::LDST32_ABS_LO12_NC => Ok(Self::Ldst32AbsLo12Nc),
+            Self::LDST64_ABS_LO12_NC => Ok(Self::Ldst64AbsLo12Nc),
+            Self::MOVW_PREL_G0 => Ok(Self::MovwPrelG0),
+            Self::MOVW_PREL_G0_NC => Ok(Self::MovwPrelG0Nc),
+            Self::MOVW_PREL_G1 => Ok(Self::MovwPrelG1),
+            Self::MOVW_PREL_G1_NC => Ok(Self::MovwPrelG1Nc),
+            Self::MOVW_PREL_G2 => Ok(Self::MovwPrelG2),
+            Self::MOVW_PREL_G2_NC => Ok(Self::MovwPrelG2Nc),
+            Self::MOVW_PREL_G3 => Ok(Self::MovwPrelG3),
+            Self::LDST128_ABS_LO12_NC => Ok(Self::Ldst128AbsLo12Nc),
+            Self::MOVW_GOTOFF_G0 => Ok(Self::MovwGotoffG0),
+            Self::MOVW_GOTOFF_G0_NC => Ok(Self::MovwGotoffG0Nc),
+            Self::MOVW_GOTOFF_G1 => Ok(Self::MovwGotoffG1),
+            Self::MOVW_GOTOFF_G1_NC => Ok(Self::MovwGotoffG1Nc),
+            Self::MOVW_GOTOFF_G2 => Ok(Self::MovwGotoffG2),
+            Self::MOVW_GOTOFF_G2_NC => Ok(Self::MovwGotoffG2Nc),
+            Self::MOVW_GOTOFF_G3 => Ok(Self::MovwGotoffG3),
+            Self::GOTREL64 => Ok(Self::Gotrel64),
+            Self::GOTREL32 => Ok(Self::Gotrel32),
+            Self::GOT_LD_PREL19 => Ok(Self::GotLdPrel19),
+            Self::LD64_GOTOFF_LO15 => Ok(Self::Ld64GotoffLo15),
+            Self::ADR_GOT_PAGE => Ok(Self::AdrGotPage),
+            Self::LD64_GOT_LO12_NC => Ok(Self::Ld64GotLo12Nc),
+            Self::LD64_GOTPAGE_LO15 => Ok(Self::Ld64GotpageLo15),
+            Self::TLSGD_ADR_PREL21 => Ok(Self::TlsgdAdrPrel21),
+            Self::TLSGD_ADR_PAGE21 => Ok(Self::TlsgdAdrPage21),
+            Self::TLSGD_ADD_LO12_NC => Ok(Self::TlsgdAddLo12Nc),
+            Self::TLSGD_MOVW_G1 => Ok(Self::TlsgdMovwG1),
+            Self::TLSGD_MOVW_G0_NC => Ok(Self::TlsgdMovwG0Nc),
+            Self::TLSLD_ADR_PREL21 => Ok(Self::TlsldAdrPrel21),
+            Self::TLSLD_ADR_PAGE21 => Ok(Self::TlsldAdrPage21),
+            Self::TLSLD_ADD_LO12_NC => Ok(Self::TlsldAddLo12Nc),
+            Self::TLSLD_MOVW_G1 => Ok(Self::TlsldMovwG1),
+            Self::TLSLD_MOVW_G0_NC => Ok(Self::TlsldMovwG0Nc),
+            Self::TLSLD_LD_PREL19 => Ok(Self::TlsldLdPrel19),
+            Self::TLSLD_MOVW_DTPREL_G2 => Ok(Self::TlsldMovwDtprelG2),
+            Self::TLSLD_MOVW_DTPREL_G1 => Ok(Self::TlsldMovwDtprelG1),
+            Self::TLSLD_MOVW_DTPREL_G1_NC => Ok(Self::TlsldMovwDtprelG1Nc),
+            Self::TLSLD_MOVW_DTPREL_G0 => Ok(Self::TlsldMovwDtprelG0),
+            Self::TLSLD_MOVW_DTPREL_G0_NC => Ok(Self::TlsldMovwDtprelG0Nc),
+            Self::TLSLD_ADD_DTPREL_HI12 => Ok(Self::TlsldAddDtprelHi12),
+            Self::TLSLD_ADD_DTPREL_LO12 => Ok(Self::TlsldAddDtprelLo12),
+            Self::TLSLD_ADD_DTPREL_LO12_NC => Ok(Self::TlsldAddDtprelLo12Nc),
+            Self::TLSLD_LDST8_DTPREL_LO12 => Ok(Self::TlsldLdst8DtprelLo12),
+            Self::TLSLD_LDST8_DTPREL_LO12_NC => Ok(Self::TlsldLdst8DtprelLo12Nc),
+            Self::TLSLD_LDST16_DTPREL_LO12 => Ok(Self::TlsldLdst16DtprelLo12),
+            Self::TLSLD_LDST16_DTPREL_LO12_NC => Ok(Self::TlsldLdst16DtprelLo12Nc),
+            Self::TLSLD_LDST32_DTPREL_LO12 => Ok(Self::TlsldLdst32DtprelLo12),
+            Self::TLSLD_LDST32_DTPREL_LO12_NC => Ok(Self::TlsldLdst32DtprelLo12Nc),
+            Self::TLSLD_LDST64_DTPREL_LO12 => Ok(Self::TlsldLdst64DtprelLo12),
+            Self::TLSLD_LDST64_DTPREL_LO12_NC => Ok(Self::TlsldLdst64DtprelLo12Nc),
+            Self::TLSIE_MOVW_GOTTPREL_G1 => Ok(Self::TlsieMovwGottprelG1),
+            Self::TLSIE_MOVW_GOTTPREL_G0_NC => Ok(Self::TlsieMovwGottprelG0Nc),
+            Self::TLSIE_ADR_GOTTPREL_PAGE21 => Ok(Self::TlsieAdrGottprelPage21),
+            Self::TLSIE_LD64_GOTTPREL_LO12_NC => Ok(Self::TlsieLd64GottprelLo12Nc),
+            Self::TLSIE_LD_GOTTPREL_PREL19 => Ok(Self::TlsieLdGottprelPrel19),
+            Self::TLSLE_MOVW_TPREL_G2 => Ok(Self::TlsleMovwTprelG2),
+            Self::TLSLE_MOVW_TPREL_G1 => Ok(Self::TlsleMovwTprelG1),
+            Self::TLSLE_MOVW_TPREL_G1_NC => Ok(Self::TlsleMovwTprelG1Nc),
+            Self::TLSLE_MOVW_TPREL_G0 => Ok(Self::TlsleMovwTprelG0),
+            Self::TLSLE_MOVW_TPREL_G0_NC => Ok(Self::TlsleMovwTprelG0Nc),
+            Self::TLSLE_ADD_TPREL_HI12 => Ok(Self::TlsleAddTprelHi12),
+            Self::TLSLE_ADD_TPREL_LO12 => Ok(Self::TlsleAddTprelLo12),
+            Self::TLSLE_ADD_TPREL_LO12_NC => Ok(Self::TlsleAddTprelLo12Nc),
+            Self::TLSLE_LDST8_TPREL_LO12 => Ok(Self::TlsleLdst8TprelLo12),
+            Self::TLSLE_LDST8_TPREL_LO12_NC => Ok(Self::TlsleLdst8TprelLo12Nc),
+            Self::TLSLE_LDST16_TPREL_LO12 => Ok(Self::TlsleLdst16TprelLo12),
+            Self::TLSLE_LDST16_TPREL_LO12_NC => Ok(Self::TlsleLdst16TprelLo12Nc),
+            Self::TLSLE_LDST32_TPREL_LO12 => Ok(Self::TlsleLdst32TprelLo12),
+            Self::TLSLE_LDST32_TPREL_LO12_NC => Ok(Self::TlsleLdst32TprelLo12Nc),
+            Self::TLSLE_LDST64_TPREL_LO12 => Ok(Self::TlsleLdst64TprelLo12),
+            Self::TLSLE_LDST64_TPREL_LO12_NC => Ok(Self::TlsleLdst64TprelLo12Nc),
+            Self::TLSDESC_LD_PREL19 => Ok(Self::TlsdescLdPrel19),
+            Self::TLSDESC_ADR_PREL21 => Ok(Self::TlsdescAdrPrel21),
+            Self::TLSDESC_ADR_PAGE21 => Ok(Self::TlsdescAdrPage21),
+            Self::TLSDESC_LD64_LO12 => Ok(Self::TlsdescLd64Lo12),
+            Self::TLSDESC_ADD_LO12 => Ok(Self::TlsdescAddLo12),
+            Self::TLSDESC_OFF_G1 => Ok(Self::TlsdescOffG1),
+            Self::TLSDESC_OFF_G0_NC => Ok(Self::TlsdescOffG0Nc),
+            Self::TLSDESC_LDR => Ok(Self::TlsdescLdr),
+            Self::TLSDESC_ADD => Ok(Self::TlsdescAdd),
+            Self::TLSDESC_CALL => Ok(Self::TlsdescCall),
+            Self::TLSLE_LDST128_TPREL_LO12 => Ok(Self::TlsleLdst128TprelLo12),
+            Self::TLSLE_LDST128_TPREL_LO12_NC => Ok(Self::TlsleLdst128TprelLo12Nc),
+            Self::TLSLD_LDST128_DTPREL_LO12 => Ok(Self::TlsldLdst128DtprelLo12),
+            Self::TLSLD_LDST128_DTPREL_LO12_NC => Ok(Self::TlsldLdst128DtprelLo12Nc),
+            Self::COPY => Ok(Self::Copy),
+            Self::GLOB_DAT => Ok(Self::GlobDat),
+            Self::JUMP_SLOT => Ok(Self::JumpSlot),
+            Self::RELATIVE => Ok(Self::Relative),
+            Self::TLS_DTPMOD => Ok(Self::TlsDtpmod),
+            Self::TLS_DTPREL => Ok(Self::TlsDtprel),
+            Self::TLS_TPREL => Ok(Self::TlsTprel),
+            Self::TLSDESC => Ok(Self::Tlsdesc),
+            Self::IRELATIVE => Ok(Self::Irelative),
+            _ => Err(Error::InvalidRelocationType {
+                machine: config.machine,
+                value,
+            }),
+        }
+    }
+}
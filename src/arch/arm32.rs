@@ -1,9 +1,10 @@
 //! Platform-specific structures for the ARM32 architecture
 
-use std::io::Write;
+use alloc::{vec, vec::Vec};
 
 use crate::{
-    base::ElfWord, error::Error, header::elf::ElfMachine, Config, ToWriter, TryFromWithConfig,
+    base::ElfWord, error::Error, header::elf::ElfMachine, io::Write, Config, ToWriter,
+    TryFromWithConfig,
 };
 
 #[repr(u32)]
@@ -55,6 +56,18 @@ pub struct ElfHeaderFlagsARM32<const EC: u8, const ED: u8> {
     value: ElfWord<EC, ED>,
 }
 
+impl<const EC: u8, const ED: u8> ElfHeaderFlagsARM32<EC, ED> {
+    /// The flags decoded from the header's flags field
+    pub fn flags(&self) -> &[ElfHeaderFlagARM32] {
+        &self.flags
+    }
+
+    /// The raw value of the header's flags field
+    pub fn value(&self) -> u32 {
+        self.value.0
+    }
+}
+
 impl<const EC: u8, const ED: u8> TryFromWithConfig<ElfWord<EC, ED>>
     for ElfHeaderFlagsARM32<EC, ED>
 {
@@ -87,6 +100,36 @@ impl<const EC: u8, const ED: u8> TryFromWithConfig<ElfWord<EC, ED>>
     }
 }
 
+impl<const EC: u8, const ED: u8> core::fmt::Display for ElfHeaderFlagsARM32<EC, ED> {
+    /// Formats the flags like the `Flags:` line printed by `readelf -h`
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:#x}", self.value.0)?;
+
+        for flag in &self.flags {
+            match flag {
+                ElfHeaderFlagARM32::AbiVersion { version } if (1..=5).contains(version) => {
+                    write!(f, ", Version{version} EABI")?
+                }
+                ElfHeaderFlagARM32::Be8 => f.write_str(", BE8")?,
+                ElfHeaderFlagARM32::FloatSoft => f.write_str(", soft-float ABI")?,
+                ElfHeaderFlagARM32::FloatHard => f.write_str(", hard-float ABI")?,
+                _ => {}
+            }
+        }
+
+        let known = ElfHeaderFlagARM32::ABIMASK
+            | ElfHeaderFlagARM32::FLOAT_SOFT
+            | ElfHeaderFlagARM32::FLOAT_HARD
+            | ElfHeaderFlagARM32::BE8;
+
+        if self.value.0 & !known != 0 {
+            f.write_str(", <unknown>")?;
+        }
+
+        Ok(())
+    }
+}
+
 impl<const EC: u8, const ED: u8, W> ToWriter<W> for ElfHeaderFlagsARM32<EC, ED>
 where
     W: Write,
@@ -129,6 +172,18 @@ impl ElfSectionHeaderTypeARM32 {
     pub const OVERLAY: u32 = 0x70000005;
 }
 
+impl core::fmt::Display for ElfSectionHeaderTypeARM32 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::ExIdx => "ARM_EXIDX",
+            Self::PreemptMap => "ARM_PREEMPTMAP",
+            Self::Attributes => "ARM_ATTRIBUTES",
+            Self::DebugOverlay => "ARM_DEBUGOVERLAY",
+            Self::Overlay => "ARM_OVERLAY",
+        })
+    }
+}
+
 impl<const EC: u8, const ED: u8> From<ElfSectionHeaderTypeARM32> for ElfWord<EC, ED> {
     fn from(value: ElfSectionHeaderTypeARM32) -> Self {
         Self(value as u32)
@@ -174,3 +229,834 @@ impl<const EC: u8, const ED: u8> TryFromWithConfig<ElfWord<EC, ED>> for ElfSecti
         }
     }
 }
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+/// Program Header Types
+pub enum ElfProgramHeaderTypeARM32 {
+    /// Platform architecture compatibility information
+    ArchExt = Self::ARCHEXT,
+    /// Exception unwind tables
+    ExIdx = Self::EXIDX,
+}
+
+impl ElfProgramHeaderTypeARM32 {
+    /// Constant value for [ElfProgramHeaderTypeARM32::ArchExt]
+    pub const ARCHEXT: u32 = 0x70000000;
+    /// Constant value for [ElfProgramHeaderTypeARM32::ExIdx]
+    pub const EXIDX: u32 = 0x70000001;
+}
+
+impl core::fmt::Display for ElfProgramHeaderTypeARM32 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::ArchExt => "ARM_ARCHEXT",
+            Self::ExIdx => "EXIDX",
+        })
+    }
+}
+
+impl<const EC: u8, const ED: u8> From<ElfProgramHeaderTypeARM32> for ElfWord<EC, ED> {
+    fn from(value: ElfProgramHeaderTypeARM32) -> Self {
+        Self(value as u32)
+    }
+}
+
+impl<const EC: u8, const ED: u8> From<&ElfProgramHeaderTypeARM32> for ElfWord<EC, ED> {
+    fn from(value: &ElfProgramHeaderTypeARM32) -> Self {
+        Self(*value as u32)
+    }
+}
+
+impl<const EC: u8, const ED: u8> TryFromWithConfig<ElfWord<EC, ED>> for ElfProgramHeaderTypeARM32 {
+    type Error = Error;
+
+    fn try_from_with(
+        value: ElfWord<EC, ED>,
+        config: &mut crate::Config,
+    ) -> Result<Self, Self::Error> {
+        if !matches!(config.machine, Some(ElfMachine::ARM)) {
+            return Err(Error::InvalidMachineForProgramHeaderType {
+                machine: config.machine,
+                expected_machines: vec![ElfMachine::ARM],
+                value: value.0,
+            });
+        }
+
+        match value.0 {
+            Self::ARCHEXT => Ok(Self::ArchExt),
+            Self::EXIDX => Ok(Self::ExIdx),
+            _ => Err(Error::InvalidProgramHeaderType {
+                machine: config.machine,
+                value: value.0,
+            }),
+        }
+    }
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+/// Relocation Types
+pub enum ElfRelocationTypeARM32 {
+    /// No relocation
+    None = Self::NONE,
+    /// 24-bit PC-relative branch offset (deprecated)
+    Pc24 = Self::PC24,
+    /// 32-bit absolute address
+    Abs32 = Self::ABS32,
+    /// 32-bit PC-relative address
+    Rel32 = Self::REL32,
+    /// `LDR` immediate of group 0 of the PC-relative address
+    LdrPcG0 = Self::LDR_PC_G0,
+    /// 16-bit absolute address
+    Abs16 = Self::ABS16,
+    /// 12-bit absolute address in an `LDR` immediate
+    Abs12 = Self::ABS12,
+    /// 5-bit absolute address in a Thumb `LDR` immediate
+    ThmAbs5 = Self::THM_ABS5,
+    /// 8-bit absolute address
+    Abs8 = Self::ABS8,
+    /// 32-bit offset of the address from the static base
+    Sbrel32 = Self::SBREL32,
+    /// 22-bit PC-relative branch offset for Thumb `BL` or `BLX`
+    ThmCall = Self::THM_CALL,
+    /// 8-bit PC-relative address in a Thumb `LDR` or `ADD` immediate
+    ThmPc8 = Self::THM_PC8,
+    /// Dynamic adjustment of an offset from the static base
+    BrelAdj = Self::BREL_ADJ,
+    /// TLS descriptor
+    TlsDesc = Self::TLS_DESC,
+    /// Obsolete
+    ThmSwi8 = Self::THM_SWI8,
+    /// Obsolete
+    Xpc25 = Self::XPC25,
+    /// Obsolete
+    ThmXpc22 = Self::THM_XPC22,
+    /// ID of the module containing the symbol
+    TlsDtpmod32 = Self::TLS_DTPMOD32,
+    /// Offset in the module's TLS block
+    TlsDtpoff32 = Self::TLS_DTPOFF32,
+    /// Offset from the thread pointer
+    TlsTpoff32 = Self::TLS_TPOFF32,
+    /// Copy the symbol at run time
+    Copy = Self::COPY,
+    /// Create a GOT entry
+    GlobDat = Self::GLOB_DAT,
+    /// Create a PLT entry
+    JumpSlot = Self::JUMP_SLOT,
+    /// Adjust by the load base
+    Relative = Self::RELATIVE,
+    /// 32-bit offset of the address from the GOT
+    Gotoff32 = Self::GOTOFF32,
+    /// 32-bit PC-relative address of the GOT
+    BasePrel = Self::BASE_PREL,
+    /// 32-bit offset of the GOT entry from the GOT
+    GotBrel = Self::GOT_BREL,
+    /// 24-bit PC-relative branch offset through the PLT (deprecated)
+    Plt32 = Self::PLT32,
+    /// 24-bit PC-relative branch offset for `BL` or `BLX`
+    Call = Self::CALL,
+    /// 24-bit PC-relative branch offset for `B` or a conditional `BL`
+    Jump24 = Self::JUMP24,
+    /// 24-bit PC-relative branch offset for Thumb-2 `B.W`
+    ThmJump24 = Self::THM_JUMP24,
+    /// 32-bit absolute address of the static base
+    BaseAbs = Self::BASE_ABS,
+    /// Obsolete
+    AluPcrel7_0 = Self::ALU_PCREL_7_0,
+    /// Obsolete
+    AluPcrel15_8 = Self::ALU_PCREL_15_8,
+    /// Obsolete
+    AluPcrel23_15 = Self::ALU_PCREL_23_15,
+    /// `LDR` immediate of bits 0 to 11 of the offset from the static base
+    LdrSbrel11_0Nc = Self::LDR_SBREL_11_0_NC,
+    /// `ADD` immediate of bits 12 to 19 of the offset from the static base
+    AluSbrel19_12Nc = Self::ALU_SBREL_19_12_NC,
+    /// `ADD` immediate of bits 20 to 27 of the offset from the static base
+    AluSbrel27_20Ck = Self::ALU_SBREL_27_20_CK,
+    /// 32-bit address, absolute or relative as chosen by the platform
+    Target1 = Self::TARGET1,
+    /// 31-bit offset of the address from the static base
+    Sbrel31 = Self::SBREL31,
+    /// Marks a `BX` instruction, for ARMv4 interworking
+    V4bx = Self::V4BX,
+    /// 32-bit address, with a meaning chosen by the platform
+    Target2 = Self::TARGET2,
+    /// 31-bit PC-relative address
+    Prel31 = Self::PREL31,
+    /// `MOVW` immediate of the low 16 bits of the absolute address
+    MovwAbsNc = Self::MOVW_ABS_NC,
+    /// `MOVT` immediate of the high 16 bits of the absolute address
+    MovtAbs = Self::MOVT_ABS,
+    /// `MOVW` immediate of the low 16 bits of the PC-relative address
+    MovwPrelNc = Self::MOVW_PREL_NC,
+    /// `MOVT` immediate of the high 16 bits of the PC-relative address
+    MovtPrel = Self::MOVT_PREL,
+    /// Thumb `MOVW` immediate of the low 16 bits of the absolute address
+    ThmMovwAbsNc = Self::THM_MOVW_ABS_NC,
+    /// Thumb `MOVT` immediate of the high 16 bits of the absolute address
+    ThmMovtAbs = Self::THM_MOVT_ABS,
+    /// Thumb `MOVW` immediate of the low 16 bits of the PC-relative address
+    ThmMovwPrelNc = Self::THM_MOVW_PREL_NC,
+    /// Thumb `MOVT` immediate of the high 16 bits of the PC-relative address
+    ThmMovtPrel = Self::THM_MOVT_PREL,
+    /// 20-bit PC-relative branch offset for Thumb-2 `B<cond>.W`
+    ThmJump19 = Self::THM_JUMP19,
+    /// 6-bit PC-relative branch offset for Thumb `CBZ` or `CBNZ`
+    ThmJump6 = Self::THM_JUMP6,
+    /// 12-bit PC-relative address in a Thumb-2 `ADR.W` immediate
+    ThmAluPrel11_0 = Self::THM_ALU_PREL_11_0,
+    /// 12-bit PC-relative address in a Thumb-2 `LDR` literal immediate
+    ThmPc12 = Self::THM_PC12,
+    /// 32-bit absolute address, without Thumb interworking
+    Abs32Noi = Self::ABS32_NOI,
+    /// 32-bit PC-relative address, without Thumb interworking
+    Rel32Noi = Self::REL32_NOI,
+    /// `ADD` or `SUB` immediate of group 0 of the PC-relative address, without an overflow check
+    AluPcG0Nc = Self::ALU_PC_G0_NC,
+    /// `ADD` or `SUB` immediate of group 0 of the PC-relative address
+    AluPcG0 = Self::ALU_PC_G0,
+    /// `ADD` or `SUB` immediate of group 1 of the PC-relative address, without an overflow check
+    AluPcG1Nc = Self::ALU_PC_G1_NC,
+    /// `ADD` or `SUB` immediate of group 1 of the PC-relative address
+    AluPcG1 = Self::ALU_PC_G1,
+    /// `ADD` or `SUB` immediate of group 2 of the PC-relative address
+    AluPcG2 = Self::ALU_PC_G2,
+    /// `LDR` immediate of group 1 of the PC-relative address
+    LdrPcG1 = Self::LDR_PC_G1,
+    /// `LDR` immediate of group 2 of the PC-relative address
+    LdrPcG2 = Self::LDR_PC_G2,
+    /// `LDRD` or `LDRH` immediate of group 0 of the PC-relative address
+    LdrsPcG0 = Self::LDRS_PC_G0,
+    /// `LDRD` or `LDRH` immediate of group 1 of the PC-relative address
+    LdrsPcG1 = Self::LDRS_PC_G1,
+    /// `LDRD` or `LDRH` immediate of group 2 of the PC-relative address
+    LdrsPcG2 = Self::LDRS_PC_G2,
+    /// `LDC` immediate of group 0 of the PC-relative address
+    LdcPcG0 = Self::LDC_PC_G0,
+    /// `LDC` immediate of group 1 of the PC-relative address
+    LdcPcG1 = Self::LDC_PC_G1,
+    /// `LDC` immediate of group 2 of the PC-relative address
+    LdcPcG2 = Self::LDC_PC_G2,
+    /// `ADD` or `SUB` immediate of group 0 of the offset from the static base, without an overflow check
+    AluSbG0Nc = Self::ALU_SB_G0_NC,
+    /// `ADD` or `SUB` immediate of group 0 of the offset from the static base
+    AluSbG0 = Self::ALU_SB_G0,
+    /// `ADD` or `SUB` immediate of group 1 of the offset from the static base, without an overflow check
+    AluSbG1Nc = Self::ALU_SB_G1_NC,
+    /// `ADD` or `SUB` immediate of group 1 of the offset from the static base
+    AluSbG1 = Self::ALU_SB_G1,
+    /// `ADD` or `SUB` immediate of group 2 of the offset from the static base
+    AluSbG2 = Self::ALU_SB_G2,
+    /// `LDR` immediate of group 0 of the offset from the static base
+    LdrSbG0 = Self::LDR_SB_G0,
+    /// `LDR` immediate of group 1 of the offset from the static base
+    LdrSbG1 = Self::LDR_SB_G1,
+    /// `LDR` immediate of group 2 of the offset from the static base
+    LdrSbG2 = Self::LDR_SB_G2,
+    /// `LDRD` or `LDRH` immediate of group 0 of the offset from the static base
+    LdrsSbG0 = Self::LDRS_SB_G0,
+    /// `LDRD` or `LDRH` immediate of group 1 of the offset from the static base
+    LdrsSbG1 = Self::LDRS_SB_G1,
+    /// `LDRD` or `LDRH` immediate of group 2 of the offset from the static base
+    LdrsSbG2 = Self::LDRS_SB_G2,
+    /// `LDC` immediate of group 0 of the offset from the static base
+    LdcSbG0 = Self::LDC_SB_G0,
+    /// `LDC` immediate of group 1 of the offset from the static base
+    LdcSbG1 = Self::LDC_SB_G1,
+    /// `LDC` immediate of group 2 of the offset from the static base
+    LdcSbG2 = Self::LDC_SB_G2,
+    /// `MOVW` immediate of the low 16 bits of the offset from the static base
+    MovwBrelNc = Self::MOVW_BREL_NC,
+    /// `MOVT` immediate of the high 16 bits of the offset from the static base
+    MovtBrel = Self::MOVT_BREL,
+    /// `MOVW` immediate of the offset from the static base, with an overflow check
+    MovwBrel = Self::MOVW_BREL,
+    /// Thumb `MOVW` immediate of the low 16 bits of the offset from the static base
+    ThmMovwBrelNc = Self::THM_MOVW_BREL_NC,
+    /// Thumb `MOVT` immediate of the high 16 bits of the offset from the static base
+    ThmMovtBrel = Self::THM_MOVT_BREL,
+    /// Thumb `MOVW` immediate of the offset from the static base, with an overflow check
+    ThmMovwBrel = Self::THM_MOVW_BREL,
+    /// 32-bit offset of the GOT entry of the TLS descriptor
+    TlsGotdesc = Self::TLS_GOTDESC,
+    /// Marks the `BL` to the TLS descriptor resolver
+    TlsCall = Self::TLS_CALL,
+    /// Marks an instruction of a TLS descriptor sequence
+    TlsDescseq = Self::TLS_DESCSEQ,
+    /// Marks the Thumb `BL` to the TLS descriptor resolver
+    ThmTlsCall = Self::THM_TLS_CALL,
+    /// 32-bit absolute address of the PLT entry
+    Plt32Abs = Self::PLT32_ABS,
+    /// 32-bit absolute address of the GOT entry
+    GotAbs = Self::GOT_ABS,
+    /// 32-bit PC-relative address of the GOT entry
+    GotPrel = Self::GOT_PREL,
+    /// 12-bit offset of the GOT entry from the GOT in an `LDR` immediate
+    GotBrel12 = Self::GOT_BREL12,
+    /// 12-bit offset of the address from the GOT in an `LDR` immediate
+    Gotoff12 = Self::GOTOFF12,
+    /// Marks an instruction which may be relaxed to avoid a GOT access
+    Gotrelax = Self::GOTRELAX,
+    /// Records a C++ vtable entry for garbage collection
+    GnuVtentry = Self::GNU_VTENTRY,
+    /// Records C++ vtable inheritance for garbage collection
+    GnuVtinherit = Self::GNU_VTINHERIT,
+    /// 11-bit PC-relative branch offset for Thumb `B`
+    ThmJump11 = Self::THM_JUMP11,
+    /// 8-bit PC-relative branch offset for Thumb `B<cond>`
+    ThmJump8 = Self::THM_JUMP8,
+    /// 32-bit PC-relative address of the GOT entry for the general dynamic TLS model
+    TlsGd32 = Self::TLS_GD32,
+    /// 32-bit PC-relative address of the GOT entry for the local dynamic TLS model
+    TlsLdm32 = Self::TLS_LDM32,
+    /// 32-bit offset in the module's TLS block for the local dynamic TLS model
+    TlsLdo32 = Self::TLS_LDO32,
+    /// 32-bit PC-relative address of the GOT entry for the initial exec TLS model
+    TlsIe32 = Self::TLS_IE32,
+    /// 32-bit offset from the thread pointer for the local exec TLS model
+    TlsLe32 = Self::TLS_LE32,
+    /// 12-bit offset in the module's TLS block for the local dynamic TLS model
+    TlsLdo12 = Self::TLS_LDO12,
+    /// 12-bit offset from the thread pointer for the local exec TLS model
+    TlsLe12 = Self::TLS_LE12,
+    /// 12-bit offset of the GOT entry from the GOT for the initial exec TLS model
+    TlsIe12gp = Self::TLS_IE12GP,
+    /// Marks a 16-bit Thumb instruction of a TLS descriptor sequence
+    ThmTlsDescseq16 = Self::THM_TLS_DESCSEQ16,
+    /// Marks a 32-bit Thumb instruction of a TLS descriptor sequence
+    ThmTlsDescseq32 = Self::THM_TLS_DESCSEQ32,
+    /// 12-bit offset of the GOT entry from the GOT in a Thumb-2 `LDR` immediate
+    ThmGotBrel12 = Self::THM_GOT_BREL12,
+    /// Thumb `ADD` or `MOV` immediate of bits 0 to 7 of the absolute address
+    ThmAluAbsG0Nc = Self::THM_ALU_ABS_G0_NC,
+    /// Thumb `ADD` or `MOV` immediate of bits 8 to 15 of the absolute address
+    ThmAluAbsG1Nc = Self::THM_ALU_ABS_G1_NC,
+    /// Thumb `ADD` or `MOV` immediate of bits 16 to 23 of the absolute address
+    ThmAluAbsG2Nc = Self::THM_ALU_ABS_G2_NC,
+    /// Thumb `ADD` or `MOV` immediate of bits 24 to 31 of the absolute address
+    ThmAluAbsG3 = Self::THM_ALU_ABS_G3,
+    /// Adjust indirectly by the load base
+    Irelative = Self::IRELATIVE,
+}
+
+impl ElfRelocationTypeARM32 {
+    /// Constant value for [ElfRelocationTypeARM32::None]
+    pub const NONE: u32 = 0;
+    /// Constant value for [ElfRelocationTypeARM32::Pc24]
+    pub const PC24: u32 = 1;
+    /// Constant value for [ElfRelocationTypeARM32::Abs32]
+    pub const ABS32: u32 = 2;
+    /// Constant value for [ElfRelocationTypeARM32::Rel32]
+    pub const REL32: u32 = 3;
+    /// Constant value for [ElfRelocationTypeARM32::LdrPcG0]
+    pub const LDR_PC_G0: u32 = 4;
+    /// Constant value for [ElfRelocationTypeARM32::Abs16]
+    pub const ABS16: u32 = 5;
+    /// Constant value for [ElfRelocationTypeARM32::Abs12]
+    pub const ABS12: u32 = 6;
+    /// Constant value for [ElfRelocationTypeARM32::ThmAbs5]
+    pub const THM_ABS5: u32 = 7;
+    /// Constant value for [ElfRelocationTypeARM32::Abs8]
+    pub const ABS8: u32 = 8;
+    /// Constant value for [ElfRelocationTypeARM32::Sbrel32]
+    pub const SBREL32: u32 = 9;
+    /// Constant value for [ElfRelocationTypeARM32::ThmCall]
+    pub const THM_CALL: u32 = 10;
+    /// Constant value for [ElfRelocationTypeARM32::ThmPc8]
+    pub const THM_PC8: u32 = 11;
+    /// Constant value for [ElfRelocationTypeARM32::BrelAdj]
+    pub const BREL_ADJ: u32 = 12;
+    /// Constant value for [ElfRelocationTypeARM32::TlsDesc]
+    pub const TLS_DESC: u32 = 13;
+    /// Constant value for [ElfRelocationTypeARM32::ThmSwi8]
+    pub const THM_SWI8: u32 = 14;
+    /// Constant value for [ElfRelocationTypeARM32::Xpc25]
+    pub const XPC25: u32 = 15;
+    /// Constant value for [ElfRelocationTypeARM32::ThmXpc22]
+    pub const THM_XPC22: u32 = 16;
+    /// Constant value for [ElfRelocationTypeARM32::TlsDtpmod32]
+    pub const TLS_DTPMOD32: u32 = 17;
+    /// Constant value for [ElfRelocationTypeARM32::TlsDtpoff32]
+    pub const TLS_DTPOFF32: u32 = 18;
+    /// Constant value for [ElfRelocationTypeARM32::TlsTpoff32]
+    pub const TLS_TPOFF32: u32 = 19;
+    /// Constant value for [ElfRelocationTypeARM32::Copy]
+    pub const COPY: u32 = 20;
+    /// Constant value for [ElfRelocationTypeARM32::GlobDat]
+    pub const GLOB_DAT: u32 = 21;
+    /// Constant value for [ElfRelocationTypeARM32::JumpSlot]
+    pub const JUMP_SLOT: u32 = 22;
+    /// Constant value for [ElfRelocationTypeARM32::Relative]
+    pub const RELATIVE: u32 = 23;
+    /// Constant value for [ElfRelocationTypeARM32::Gotoff32]
+    pub const GOTOFF32: u32 = 24;
+    /// Constant value for [ElfRelocationTypeARM32::BasePrel]
+    pub const BASE_PREL: u32 = 25;
+    /// Constant value for [ElfRelocationTypeARM32::GotBrel]
+    pub const GOT_BREL: u32 = 26;
+    /// Constant value for [ElfRelocationTypeARM32::Plt32]
+    pub const PLT32: u32 = 27;
+    /// Constant value for [ElfRelocationTypeARM32::Call]
+    pub const CALL: u32 = 28;
+    /// Constant value for [ElfRelocationTypeARM32::Jump24]
+    pub const JUMP24: u32 = 29;
+    /// Constant value for [ElfRelocationTypeARM32::ThmJump24]
+    pub const THM_JUMP24: u32 = 30;
+    /// Constant value for [ElfRelocationTypeARM32::BaseAbs]
+    pub const BASE_ABS: u32 = 31;
+    /// Constant value for [ElfRelocationTypeARM32::AluPcrel7_0]
+    pub const ALU_PCREL_7_0: u32 = 32;
+    /// Constant value for [ElfRelocationTypeARM32::AluPcrel15_8]
+    pub const ALU_PCREL_15_8: u32 = 33;
+    /// Constant value for [ElfRelocationTypeARM32::AluPcrel23_15]
+    pub const ALU_PCREL_23_15: u32 = 34;
+    /// Constant value for [ElfRelocationTypeARM32::LdrSbrel11_0Nc]
+    pub const LDR_SBREL_11_0_NC: u32 = 35;
+    /// Constant value for [ElfRelocationTypeARM32::AluSbrel19_12Nc]
+    pub const ALU_SBREL_19_12_NC: u32 = 36;
+    /// Constant value for [ElfRelocationTypeARM32::AluSbrel27_20Ck]
+    pub const ALU_SBREL_27_20_CK: u32 = 37;
+    /// Constant value for [ElfRelocationTypeARM32::Target1]
+    pub const TARGET1: u32 = 38;
+    /// Constant value for [ElfRelocationTypeARM32::Sbrel31]
+    pub const SBREL31: u32 = 39;
+    /// Constant value for [ElfRelocationTypeARM32::V4bx]
+    pub const V4BX: u32 = 40;
+    /// Constant value for [ElfRelocationTypeARM32::Target2]
+    pub const TARGET2: u32 = 41;
+    /// Constant value for [ElfRelocationTypeARM32::Prel31]
+    pub const PREL31: u32 = 42;
+    /// Constant value for [ElfRelocationTypeARM32::MovwAbsNc]
+    pub const MOVW_ABS_NC: u32 = 43;
+    /// Constant value for [ElfRelocationTypeARM32::MovtAbs]
+    pub const MOVT_ABS: u32 = 44;
+    /// Constant value for [ElfRelocationTypeARM32::MovwPrelNc]
+    pub const MOVW_PREL_NC: u32 = 45;
+    /// Constant value for [ElfRelocationTypeARM32::MovtPrel]
+    pub const MOVT_PREL: u32 = 46;
+    /// Constant value for [ElfRelocationTypeARM32::ThmMovwAbsNc]
+    pub const THM_MOVW_ABS_NC: u32 = 47;
+    /// Constant value for [ElfRelocationTypeARM32::ThmMovtAbs]
+    pub const THM_MOVT_ABS: u32 = 48;
+    /// Constant value for [ElfRelocationTypeARM32::ThmMovwPrelNc]
+    pub const THM_MOVW_PREL_NC: u32 = 49;
+    /// Constant value for [ElfRelocationTypeARM32::ThmMovtPrel]
+    pub const THM_MOVT_PREL: u32 = 50;
+    /// Constant value for [ElfRelocationTypeARM32::ThmJump19]
+    pub const THM_JUMP19: u32 = 51;
+    /// Constant value for [ElfRelocationTypeARM32::ThmJump6]
+    pub const THM_JUMP6: u32 = 52;
+    /// Constant value for [ElfRelocationTypeARM32::ThmAluPrel11_0]
+    pub const THM_ALU_PREL_11_0: u32 = 53;
+    /// Constant value for [ElfRelocationTypeARM32::ThmPc12]
+    pub const THM_PC12: u32 = 54;
+    /// Constant value for [ElfRelocationTypeARM32::Abs32Noi]
+    pub const ABS32_NOI: u32 = 55;
+    /// Constant value for [ElfRelocationTypeARM32::Rel32Noi]
+    pub const REL32_NOI: u32 = 56;
+    /// Constant value for [ElfRelocationTypeARM32::AluPcG0Nc]
+    pub const ALU_PC_G0_NC: u32 = 57;
+    /// Constant value for [ElfRelocationTypeARM32::AluPcG0]
+    pub const ALU_PC_G0: u32 = 58;
+    /// Constant value for [ElfRelocationTypeARM32::AluPcG1Nc]
+    pub const ALU_PC_G1_NC: u32 = 59;
+    /// Constant value for [ElfRelocationTypeARM32::AluPcG1]
+    pub const ALU_PC_G1: u32 = 60;
+    /// Constant value for [ElfRelocationTypeARM32::AluPcG2]
+    pub const ALU_PC_G2: u32 = 61;
+    /// Constant value for [ElfRelocationTypeARM32::LdrPcG1]
+    pub const LDR_PC_G1: u32 = 62;
+    /// Constant value for [ElfRelocationTypeARM32::LdrPcG2]
+    pub const LDR_PC_G2: u32 = 63;
+    /// Constant value for [ElfRelocationTypeARM32::LdrsPcG0]
+    pub const LDRS_PC_G0: u32 = 64;
+    /// Constant value for [ElfRelocationTypeARM32::LdrsPcG1]
+    pub const LDRS_PC_G1: u32 = 65;
+    /// Constant value for [ElfRelocationTypeARM32::LdrsPcG2]
+    pub const LDRS_PC_G2: u32 = 66;
+    /// Constant value for [ElfRelocationTypeARM32::LdcPcG0]
+    pub const LDC_PC_G0: u32 = 67;
+    /// Constant value for [ElfRelocationTypeARM32::LdcPcG1]
+    pub const LDC_PC_G1: u32 = 68;
+    /// Constant value for [ElfRelocationTypeARM32::LdcPcG2]
+    pub const LDC_PC_G2: u32 = 69;
+    /// Constant value for [ElfRelocationTypeARM32::AluSbG0Nc]
+    pub const ALU_SB_G0_NC: u32 = 70;
+    /// Constant value for [ElfRelocationTypeARM32::AluSbG0]
+    pub const ALU_SB_G0: u32 = 71;
+    /// Constant value for [ElfRelocationTypeARM32::AluSbG1Nc]
+    pub const ALU_SB_G1_NC: u32 = 72;
+    /// Constant value for [ElfRelocationTypeARM32::AluSbG1]
+    pub const ALU_SB_G1: u32 = 73;
+    /// Constant value for [ElfRelocationTypeARM32::AluSbG2]
+    pub const ALU_SB_G2: u32 = 74;
+    /// Constant value for [ElfRelocationTypeARM32::LdrSbG0]
+    pub const LDR_SB_G0: u32 = 75;
+    /// Constant value for [ElfRelocationTypeARM32::LdrSbG1]
+    pub const LDR_SB_G1: u32 = 76;
+    /// Constant value for [ElfRelocationTypeARM32::LdrSbG2]
+    pub const LDR_SB_G2: u32 = 77;
+    /// Constant value for [ElfRelocationTypeARM32::LdrsSbG0]
+    pub const LDRS_SB_G0: u32 = 78;
+    /// Constant value for [ElfRelocationTypeARM32::LdrsSbG1]
+    pub const LDRS_SB_G1: u32 = 79;
+    /// Constant value for [ElfRelocationTypeARM32::LdrsSbG2]
+    pub const LDRS_SB_G2: u32 = 80;
+    /// Constant value for [ElfRelocationTypeARM32::LdcSbG0]
+    pub const LDC_SB_G0: u32 = 81;
+    /// Constant value for [ElfRelocationTypeARM32::LdcSbG1]
+    pub const LDC_SB_G1: u32 = 82;
+    /// Constant value for [ElfRelocationTypeARM32::LdcSbG2]
+    pub const LDC_SB_G2: u32 = 83;
+    /// Constant value for [ElfRelocationTypeARM32::MovwBrelNc]
+    pub const MOVW_BREL_NC: u32 = 84;
+    /// Constant value for [ElfRelocationTypeARM32::MovtBrel]
+    pub const MOVT_BREL: u32 = 85;
+    /// Constant value for [ElfRelocationTypeARM32::MovwBrel]
+    pub const MOVW_BREL: u32 = 86;
+    /// Constant value for [ElfRelocationTypeARM32::ThmMovwBrelNc]
+    pub const THM_MOVW_BREL_NC: u32 = 87;
+    /// Constant value for [ElfRelocationTypeARM32::ThmMovtBrel]
+    pub const THM_MOVT_BREL: u32 = 88;
+    /// Constant value for [ElfRelocationTypeARM32::ThmMovwBrel]
+    pub const THM_MOVW_BREL: u32 = 89;
+    /// Constant value for [ElfRelocationTypeARM32::TlsGotdesc]
+    pub const TLS_GOTDESC: u32 = 90;
+    /// Constant value for [ElfRelocationTypeARM32::TlsCall]
+    pub const TLS_CALL: u32 = 91;
+    /// Constant value for [ElfRelocationTypeARM32::TlsDescseq]
+    pub const TLS_DESCSEQ: u32 = 92;
+    /// Constant value for [ElfRelocationTypeARM32::ThmTlsCall]
+    pub const THM_TLS_CALL: u32 = 93;
+    /// Constant value for [ElfRelocationTypeARM32::Plt32Abs]
+    pub const PLT32_ABS: u32 = 94;
+    /// Constant value for [ElfRelocationTypeARM32::GotAbs]
+    pub const GOT_ABS: u32 = 95;
+    /// Constant value for [ElfRelocationTypeARM32::GotPrel]
+    pub const GOT_PREL: u32 = 96;
+    /// Constant value for [ElfRelocationTypeARM32::GotBrel12]
+    pub const GOT_BREL12: u32 = 97;
+    /// Constant value for [ElfRelocationTypeARM32::Gotoff12]
+    pub const GOTOFF12: u32 = 98;
+    /// Constant value for [ElfRelocationTypeARM32::Gotrelax]
+    pub const GOTRELAX: u32 = 99;
+    /// Constant value for [ElfRelocationTypeARM32::GnuVtentry]
+    pub const GNU_VTENTRY: u32 = 100;
+    /// Constant value for [ElfRelocationTypeARM32::GnuVtinherit]
+    pub const GNU_VTINHERIT: u32 = 101;
+    /// Constant value for [ElfRelocationTypeARM32::ThmJump11]
+    pub const THM_JUMP11: u32 = 102;
+    /// Constant value for [ElfRelocationTypeARM32::ThmJump8]
+    pub const THM_JUMP8: u32 = 103;
+    /// Constant value for [ElfRelocationTypeARM32::TlsGd32]
+    pub const TLS_GD32: u32 = 104;
+    /// Constant value for [ElfRelocationTypeARM32::TlsLdm32]
+    pub const TLS_LDM32: u32 = 105;
+    /// Constant value for [ElfRelocationTypeARM32::TlsLdo32]
+    pub const TLS_LDO32: u32 = 106;
+    /// Constant value for [ElfRelocationTypeARM32::TlsIe32]
+    pub const TLS_IE32: u32 = 107;
+    /// Constant value for [ElfRelocationTypeARM32::TlsLe32]
+    pub const TLS_LE32: u32 = 108;
+    /// Constant value for [ElfRelocationTypeARM32::TlsLdo12]
+    pub const TLS_LDO12: u32 = 109;
+    /// Constant value for [ElfRelocationTypeARM32::TlsLe12]
+    pub const TLS_LE12: u32 = 110;
+    /// Constant value for [ElfRelocationTypeARM32::TlsIe12gp]
+    pub const TLS_IE12GP: u32 = 111;
+    /// Constant value for [ElfRelocationTypeARM32::ThmTlsDescseq16]
+    pub const THM_TLS_DESCSEQ16: u32 = 129;
+    /// Constant value for [ElfRelocationTypeARM32::ThmTlsDescseq32]
+    pub const THM_TLS_DESCSEQ32: u32 = 130;
+    /// Constant value for [ElfRelocationTypeARM32::ThmGotBrel12]
+    pub const THM_GOT_BREL12: u32 = 131;
+    /// Constant value for [ElfRelocationTypeARM32::ThmAluAbsG0Nc]
+    pub const THM_ALU_ABS_G0_NC: u32 = 132;
+    /// Constant value for [ElfRelocationTypeARM32::ThmAluAbsG1Nc]
+    pub const THM_ALU_ABS_G1_NC: u32 = 133;
+    /// Constant value for [ElfRelocationTypeARM32::ThmAluAbsG2Nc]
+    pub const THM_ALU_ABS_G2_NC: u32 = 134;
+    /// Constant value for [ElfRelocationTypeARM32::ThmAluAbsG3]
+    pub const THM_ALU_ABS_G3: u32 = 135;
+    /// Constant value for [ElfRelocationTypeARM32::Irelative]
+    pub const IRELATIVE: u32 = 160;
+}
+
+impl core::fmt::Display for ElfRelocationTypeARM32 {
+    /// Display the relocation type as its name in the ABI, such as `R_ARM_ABS32`
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::None => "R_ARM_NONE",
+            Self::Pc24 => "R_ARM_PC24",
+            Self::Abs32 => "R_ARM_ABS32",
+            Self::Rel32 => "R_ARM_REL32",
+            Self::LdrPcG0 => "R_ARM_LDR_PC_G0",
+            Self::Abs16 => "R_ARM_ABS16",
+            Self::Abs12 => "R_ARM_ABS12",
+            Self::ThmAbs5 => "R_ARM_THM_ABS5",
+            Self::Abs8 => "R_ARM_ABS8",
+            Self::Sbrel32 => "R_ARM_SBREL32",
+            Self::ThmCall => "R_ARM_THM_CALL",
+            Self::ThmPc8 => "R_ARM_THM_PC8",
+            Self::BrelAdj => "R_ARM_BREL_ADJ",
+            Self::TlsDesc => "R_ARM_TLS_DESC",
+            Self::ThmSwi8 => "R_ARM_THM_SWI8",
+            Self::Xpc25 => "R_ARM_XPC25",
+            Self::ThmXpc22 => "R_ARM_THM_XPC22",
+            Self::TlsDtpmod32 => "R_ARM_TLS_DTPMOD32",
+            Self::TlsDtpoff32 => "R_ARM_TLS_DTPOFF32",
+            Self::TlsTpoff32 => "R_ARM_TLS_TPOFF32",
+            Self::Copy => "R_ARM_COPY",
+            Self::GlobDat => "R_ARM_GLOB_DAT",
+            Self::JumpSlot => "R_ARM_JUMP_SLOT",
+            Self::Relative => "R_ARM_RELATIVE",
+            Self::Gotoff32 => "R_ARM_GOTOFF32",
+            Self::BasePrel => "R_ARM_BASE_PREL",
+            Self::GotBrel => "R_ARM_GOT_BREL",
+            Self::Plt32 => "R_ARM_PLT32",
+            Self::Call => "R_ARM_CALL",
+            Self::Jump24 => "R_ARM_JUMP24",
+            Self::ThmJump24 => "R_ARM_THM_JUMP24",
+            Self::BaseAbs => "R_ARM_BASE_ABS",
+            Self::AluPcrel7_0 => "R_ARM_ALU_PCREL_7_0",
+            Self::AluPcrel15_8 => "R_ARM_ALU_PCREL_15_8",
+            Self::AluPcrel23_15 => "R_ARM_ALU_PCREL_23_15",
+            Self::LdrSbrel11_0Nc => "R_ARM_LDR_SBREL_11_0_NC",
+            Self::AluSbrel19_12Nc => "R_ARM_ALU_SBREL_19_12_NC",
+            Self::AluSbrel27_20Ck => "R_ARM_ALU_SBREL_27_20_CK",
+            Self::Target1 => "R_ARM_TARGET1",
+            Self::Sbrel31 => "R_ARM_SBREL31",
+            Self::V4bx => "R_ARM_V4BX",
+            Self::Target2 => "R_ARM_TARGET2",
+            Self::Prel31 => "R_ARM_PREL31",
+            Self::MovwAbsNc => "R_ARM_MOVW_ABS_NC",
+            Self::MovtAbs => "R_ARM_MOVT_ABS",
+            Self::MovwPrelNc => "R_ARM_MOVW_PREL_NC",
+            Self::MovtPrel => "R_ARM_MOVT_PREL",
+            Self::ThmMovwAbsNc => "R_ARM_THM_MOVW_ABS_NC",
+            Self::ThmMovtAbs => "R_ARM_THM_MOVT_ABS",
+            Self::ThmMovwPrelNc => "R_ARM_THM_MOVW_PREL_NC",
+            Self::ThmMovtPrel => "R_ARM_THM_MOVT_PREL",
+            Self::ThmJump19 => "R_ARM_THM_JUMP19",
+            Self::ThmJump6 => "R_ARM_THM_JUMP6",
+            Self::ThmAluPrel11_0 => "R_ARM_THM_ALU_PREL_11_0",
+            Self::ThmPc12 => "R_ARM_THM_PC12",
+            Self::Abs32Noi => "R_ARM_ABS32_NOI",
+            Self::Rel32Noi => "R_ARM_REL32_NOI",
+            Self::AluPcG0Nc => "R_ARM_ALU_PC_G0_NC",
+            Self::AluPcG0 => "R_ARM_ALU_PC_G0",
+            Self::AluPcG1Nc => "R_ARM_ALU_PC_G1_NC",
+            Self::AluPcG1 => "R_ARM_ALU_PC_G1",
+            Self::AluPcG2 => "R_ARM_ALU_PC_G2",
+            Self::LdrPcG1 => "R_ARM_LDR_PC_G1",
+            Self::LdrPcG2 => "R_ARM_LDR_PC_G2",
+            Self::LdrsPcG0 => "R_ARM_LDRS_PC_G0",
+            Self::LdrsPcG1 => "R_ARM_LDRS_PC_G1",
+            Self::LdrsPcG2 => "R_ARM_LDRS_PC_G2",
+            Self::LdcPcG0 => "R_ARM_LDC_PC_G0",
+            Self::LdcPcG1 => "R_ARM_LDC_PC_G1",
+            Self::LdcPcG2 => "R_ARM_LDC_PC_G2",
+            Self::AluSbG0Nc => "R_ARM_ALU_SB_G0_NC",
+            Self::AluSbG0 => "R_ARM_ALU_SB_G0",
+            Self::AluSbG1Nc => "R_ARM_ALU_SB_G1_NC",
+            Self::AluSbG1 => "R_ARM_ALU_SB_G1",
+            Self::AluSbG2 => "R_ARM_ALU_SB_G2",
+            Self::LdrSbG0 => "R_ARM_LDR_SB_G0",
+            Self::LdrSbG1 => "R_ARM_LDR_SB_G1",
+            Self::LdrSbG2 => "R_ARM_LDR_SB_G2",
+            Self::LdrsSbG0 => "R_ARM_LDRS_SB_G0",
+            Self::LdrsSbG1 => "R_ARM_LDRS_SB_G1",
+            Self::LdrsSbG2 => "R_ARM_LDRS_SB_G2",
+            Self::LdcSbG0 => "R_ARM_LDC_SB_G0",
+            Self::LdcSbG1 => "R_ARM_LDC_SB_G1",
+            Self::LdcSbG2 => "R_ARM_LDC_SB_G2",
+            Self::MovwBrelNc => "R_ARM_MOVW_BREL_NC",
+            Self::MovtBrel => "R_ARM_MOVT_BREL",
+            Self::MovwBrel => "R_ARM_MOVW_BREL",
+            Self::ThmMovwBrelNc => "R_ARM_THM_MOVW_BREL_NC",
+            Self::ThmMovtBrel => "R_ARM_THM_MOVT_BREL",
+            Self::ThmMovwBrel => "R_ARM_THM_MOVW_BREL",
+            Self::TlsGotdesc => "R_ARM_TLS_GOTDESC",
+            Self::TlsCall => "R_ARM_TLS_CALL",
+            Self::TlsDescseq => "R_ARM_TLS_DESCSEQ",
+            Self::ThmTlsCall => "R_ARM_THM_TLS_CALL",
+            Self::Plt32Abs => "R_ARM_PLT32_ABS",
+            Self::GotAbs => "R_ARM_GOT_ABS",
+            Self::GotPrel => "R_ARM_GOT_PREL",
+            Self::GotBrel12 => "R_ARM_GOT_BREL12",
+            Self::Gotoff12 => "R_ARM_GOTOFF12",
+            Self::Gotrelax => "R_ARM_GOTRELAX",
+            Self::GnuVtentry => "R_ARM_GNU_VTENTRY",
+            Self::GnuVtinherit => "R_ARM_GNU_VTINHERIT",
+            Self::ThmJump11 => "R_ARM_THM_JUMP11",
+            Self::ThmJump8 => "R_ARM_THM_JUMP8",
+            Self::TlsGd32 => "R_ARM_TLS_GD32",
+            Self::TlsLdm32 => "R_ARM_TLS_LDM32",
+            Self::TlsLdo32 => "R_ARM_TLS_LDO32",
+            Self::TlsIe32 => "R_ARM_TLS_IE32",
+            Self::TlsLe32 => "R_ARM_TLS_LE32",
+            Self::TlsLdo12 => "R_ARM_TLS_LDO12",
+            Self::TlsLe12 => "R_ARM_TLS_LE12",
+            Self::TlsIe12gp => "R_ARM_TLS_IE12GP",
+            Self::ThmTlsDescseq16 => "R_ARM_THM_TLS_DESCSEQ16",
+            Self::ThmTlsDescseq32 => "R_ARM_THM_TLS_DESCSEQ32",
+            Self::ThmGotBrel12 => "R_ARM_THM_GOT_BREL12",
+            Self::ThmAluAbsG0Nc => "R_ARM_THM_ALU_ABS_G0_NC",
+            Self::ThmAluAbsG1Nc => "R_ARM_THM_ALU_ABS_G1_NC",
+            Self::ThmAluAbsG2Nc => "R_ARM_THM_ALU_ABS_G2_NC",
+            Self::ThmAluAbsG3 => "R_ARM_THM_ALU_ABS_G3",
+            Self::Irelative => "R_ARM_IRELATIVE",
+        })
+    }
+}
+
+impl From<ElfRelocationTypeARM32> for u32 {
+    fn from(value: ElfRelocationTypeARM32) -> Self {
+        value as u32
+    }
+}
+
+impl TryFromWithConfig<u32> for ElfRelocationTypeARM32 {
+    type Error = Error;
+
+    /// Decode the type of a relocation entry, as returned by `ElfRelocation::r#type`
+    fn try_from_with(value: u32, config: &mut crate::Config) -> Result<Self, Self::Error> {
+        if !matches!(config.machine, Some(ElfMachine::ARM)) {
+            return Err(Error::InvalidMachineForRelocationType {
+                machine: config.machine,
+                expected_machines: vec![ElfMachine::ARM],
+                value,
+            });
+        }
+
+        match value {
+            Self::NONE => Ok(Self::None),
+            Self::PC24 => Ok(Self::Pc24),
+            Self::ABS32 => Ok(Self::Abs32),
+            Self::REL32 => Ok(Self::Rel32),
+            Self::LDR_PC_G0 => Ok(Self::LdrPcG0),
+            Self::ABS16 => Ok(Self::Abs16),
+            Self::ABS12 => Ok(Self::Abs12),
+            Self::THM_ABS5 => Ok(Self::ThmAbs5),
+            Self::ABS8 => Ok(Self::Abs8),
+            Self::SBREL32 => Ok(Self::Sbrel32),
+            Self::THM_CALL => Ok(Self::ThmCall),
+            Self::THM_PC8 => Ok(Self::ThmPc8),
+            Self::BREL_ADJ => Ok(Self::BrelAdj),
+            Self::TLS_DESC => Ok(Self::TlsDesc),
+            Self::THM_SWI8 => Ok(Self::ThmSwi8),
+            Self::XPC25 => Ok(Self::Xpc25),
+            Self::THM_XPC22 => Ok(Self::ThmXpc22),
+            Self::TLS_DTPMOD32 => Ok(Self::TlsDtpmod32),
+            Self::TLS_DTPOFF32 => Ok(Self::TlsDtpoff32),
+            Self::TLS_TPOFF32 => Ok(Self::TlsTpoff32),
+            Self::COPY => Ok(Self::Copy),
+            Self::GLOB_DAT => Ok(Self::GlobDat),
+            Self::JUMP_SLOT => Ok(Self::JumpSlot),
+            Self::RELATIVE => Ok(Self::Relative),
+            Self::GOTOFF32 => Ok(Self::Gotoff32),
+            Self::BASE_PREL => Ok(Self::BasePrel),
+            Self::GOT_BREL => Ok(Self::GotBrel),
+            Self::PLT32 => Ok(Self::Plt32),
+            Self::CALL => Ok(Self::Call),
+            Self::JUMP24 => Ok(Self::Jump24),
+            Self::THM_JUMP24 => Ok(Self::ThmJump24),
+            Self::BASE_ABS => Ok(Self::BaseAbs),
+            Self::ALU_PCREL_7_0 => Ok(Self::AluPcrel7_0),
+            Self::ALU_PCREL_15_8 => Ok(Self::AluPcrel15_8),
+            Self::ALU_PCREL_23_15 => Ok(Self::AluPcrel23_15),
+            Self::LDR_SBREL_11_0_NC => Ok(Self::LdrSbrel11_0Nc),
+            Self::ALU_SBREL_19_12_NC => Ok(Self::AluSbrel19_12Nc),
+            Self::ALU_SBREL_27_20_CK => Ok(Self::AluSbrel27_20Ck),
+            Self::TARGET1 => Ok(Self::Target1),
+            Self::SBREL31 => Ok(Self::Sbrel31),
+            Self::V4BX => Ok(Self::V4bx),
+            Self::TARGET2 => Ok(Self::Target2),
+            Self::PREL31 => Ok(Self::Prel31),
+            Self::MOVW_ABS_NC => Ok(Self::MovwAbsNc),
+            Self::MOVT_ABS => Ok(Self::MovtAbs),
+            Self::MOVW_PREL_NC => Ok(Self::MovwPrelNc),
+            Self::MOVT_PREL => Ok(Self::MovtPrel),
+            Self::THM_MOVW_ABS_NC => Ok(Self::ThmMovwAbsNc),
+            Self::THM_MOVT_ABS => Ok(Self::ThmMovtAbs),
+            Self::THM_MOVW_PREL_NC => Ok(Self::ThmMovwPrelNc),
+            Self::THM_MOVT_PREL => Ok(Self::ThmMovtPrel),
+            Self::THM_JUMP19 => Ok(Self::ThmJump19),
+            Self::THM_JUMP6 => Ok(Self::ThmJump6),
+            Self::THM_ALU_PREL_11_0 => Ok(Self::ThmAluPrel11_0),
+            Self::THM_PC12 => Ok(Self::ThmPc12),
+            Self::ABS32_NOI => Ok(Self::Abs32Noi),
+            Self::REL32_NOI => Ok(Self::Rel32Noi),
+            Self::ALU_PC_G0_NC => Ok(Self::AluPcG0Nc),
+            Self::ALU_PC_G0 => Ok(Self::AluPcG0),
+            Self::ALU_PC_G1_NC => Ok(Self::AluPcG1Nc),
+            Self::ALU_PC_G1 => Ok(Self::AluPcG1),
+            Self::ALU_PC_G2 => Ok(Self::AluPcG2),
+            Self::LDR_PC_G1 => Ok(Self::LdrPcG1),
+            Self::LDR_PC_G2 => Ok(Self::LdrPcG2),
+            Self::LDRS_PC_G0 => Ok(Self::LdrsPcG0),
+            Self::LDRS_PC_G1 => Ok(Self::LdrsPcG1),
+            Self::LDRS_PC_G2 => Ok(Self::LdrsPcG2),
+            Self::LDC_PC_G0 => Ok(Self::LdcPcG0),
+            Self::LDC_PC_G1 => Ok(Self::LdcPcG1),
+            Self::LDC_PC_G2 => Ok(Self::LdcPcG2),
+            Self::ALU_SB_G0_NC => Ok(Self::AluSbG0Nc),
+            Self::ALU_SB_G0 => Ok(Self::AluSbG0),
+            Self::ALU_SB_G1_NC => Ok(Self::AluSbG1Nc),
+            Self::ALU_SB_G1 => Ok(Self::AluSbG1),
+            Self::ALU_SB_G2 => Ok(Self::AluSbG2),
+            Self::LDR_SB_G0 => Ok(Self::LdrSbG0),
+            Self::LDR_SB_G1 => Ok(Self::LdrSbG1),
+            Self::LDR_SB_G2 => Ok(Self::LdrSbG2),
+            Self::LDRS_SB_G0 => Ok(Self::LdrsSbG0),
+            Self::LDRS_SB_G1 => Ok(Self::LdrsSbG1),
+            Self::LDRS_SB_G2 => Ok(Self::LdrsSbG2),
+            Self::LDC_SB_G0 => Ok(Self::LdcSbG0),
+            Self::LDC_SB_G1 => Ok(Self::LdcSbG1),
+            Self::LDC_SB_G2 => Ok(Self::LdcSbG2),
+            Self::MOVW_BREL_NC => Ok(Self::MovwBrelNc),
+            Self::MOVT_BREL => Ok(Self::MovtBrel),
+            Self::MOVW_BREL => Ok(Self::MovwBrel),
+            Self::THM_MOVW_BREL_NC => Ok(Self::ThmMovwBrelNc),
+            Self::THM_MOVT_BREL => Ok(Self::ThmMovtBrel),
+            Self::THM_MOVW_BREL => Ok(Self::ThmMovwBrel),
+            Self::TLS_GOTDESC => Ok(Self::TlsGotdesc),
+            Self::TLS_CALL => Ok(Self::TlsCall),
+            Self::TLS_DESCSEQ => Ok(Self::TlsDescseq),
+            Self::THM_TLS_CALL => Ok(Self::ThmTlsCall),
+            Self::PLT32_ABS => Ok(Self::Plt32Abs),
+            Self::GOT_ABS => Ok(Self::GotAbs),
+            Self::GOT_PREL => Ok(Self::GotPrel),
+            Self::GOT_BREL12 => Ok(Self::GotBrel12),
+            Self::GOTOFF12 => Ok(Self::Gotoff12),
+            Self::GOTRELAX => Ok(Self::Gotrelax),
+            Self::GNU_VTENTRY => Ok(Self::GnuVtentry),
+            Self::GNU_VTINHERIT => Ok(Self::GnuVtinherit),
+            Self::THM_JUMP11 => Ok(Self::ThmJump11),
+            Self::THM_JUMP8 => Ok(Self::ThmJump8),
+            Self::TLS_GD32 => Ok(Self::TlsGd32),
+            Self::TLS_LDM32 => Ok(Self::TlsLdm32),
+            Self::TLS_LDO32 => Ok(Self::TlsLdo32),
+            Self::TLS_IE32 => Ok(Self::TlsIe32),
+            Self::TLS_LE32 => Ok(Self::TlsLe32),
+            Self::TLS_LDO12 => Ok(Self::TlsLdo12),
+            Self::TLS_LE12 => Ok(Self::TlsLe12),
+            Self::TLS_IE12GP => Ok(Self::TlsIe12gp),
+            Self::THM_TLS_DESCSEQ16 => Ok(Self::ThmTlsDescseq16),
+            Self::THM_TLS_DESCSEQ32 => Ok(Self::ThmTlsDescseq32),
+            Self::THM_GOT_BREL12 => Ok(Self::ThmGotBrel12),
+            Self::THM_ALU_ABS_G0_NC => Ok(Self::ThmAluAbsG0Nc),
+            Self::THM_ALU_ABS_G1_NC => Ok(Self::ThmAluAbsG1Nc),
+            Self::THM_ALU_ABS_G2_NC => Ok(Self::ThmAluAbsG2Nc),
+            Self::THM_ALU_ABS_G3 => Ok(Self::ThmAluAbsG3),
+            Self::IRELATIVE => Ok(Self::Irelative),
+            _ => Err(Error::InvalidRelocationType {
+                machine: config.machine,
+                value,
+            }),
+        }
+    }
+}
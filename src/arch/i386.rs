@@ -3,6 +3,7 @@
 // NOTE: i386 defines no e_flags values
 
 use crate::{base::ElfWord, error::Error, header::elf::ElfMachine, TryFromWithConfig};
+use alloc::vec;
 
 #[repr(u32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -18,6 +19,14 @@ impl ElfSectionHeaderTypeI386 {
     pub const UNWIND: u32 = 0x70000001;
 }
 
+impl core::fmt::Display for ElfSectionHeaderTypeI386 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Unwind => "I386_UNWIND",
+        })
+    }
+}
+
 impl<const EC: u8, const ED: u8> From<ElfSectionHeaderTypeI386> for ElfWord<EC, ED> {
     fn from(value: ElfSectionHeaderTypeI386) -> Self {
         Self(value as u32)
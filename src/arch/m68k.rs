@@ -1,8 +1,8 @@
 //! Architecture specific definitions for m68k
 
-use std::io::Write;
+use alloc::vec::Vec;
 
-use crate::{base::ElfWord, error::Error, Config, ToWriter, TryFromWithConfig};
+use crate::{base::ElfWord, error::Error, io::Write, Config, ToWriter, TryFromWithConfig};
 
 #[repr(u32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -26,6 +26,18 @@ pub struct ElfHeaderFlagsM68K<const EC: u8, const ED: u8> {
     value: ElfWord<EC, ED>,
 }
 
+impl<const EC: u8, const ED: u8> ElfHeaderFlagsM68K<EC, ED> {
+    /// The flags decoded from the header's flags field
+    pub fn flags(&self) -> &[ElfHeaderFlagM68K] {
+        &self.flags
+    }
+
+    /// The raw value of the header's flags field
+    pub fn value(&self) -> u32 {
+        self.value.0
+    }
+}
+
 impl<const EC: u8, const ED: u8> TryFromWithConfig<ElfWord<EC, ED>> for ElfHeaderFlagsM68K<EC, ED> {
     type Error = Error;
 
@@ -40,6 +52,21 @@ impl<const EC: u8, const ED: u8> TryFromWithConfig<ElfWord<EC, ED>> for ElfHeade
     }
 }
 
+impl<const EC: u8, const ED: u8> core::fmt::Display for ElfHeaderFlagsM68K<EC, ED> {
+    /// Formats the flags like the `Flags:` line printed by `readelf -h`
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:#x}", self.value.0)?;
+
+        for flag in &self.flags {
+            match flag {
+                ElfHeaderFlagM68K::Cpu32 => f.write_str(", cpu32")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl<const EC: u8, const ED: u8, W> ToWriter<W> for ElfHeaderFlagsM68K<EC, ED>
 where
     W: Write,
@@ -1,11 +1,20 @@
 //! Architecture specific definitions for mips
 
 use crate::{
-    base::ElfWord, error::Error, header::elf::ElfMachine, Config, ToWriter, TryFromWithConfig,
+    base::{ElfAddress, ElfHalfWord, ElfWord},
+    dynamic::ElfDynamicTag,
+    error::Error,
+    header::{
+        elf::{identification::ElfClass, ElfMachine},
+        section::ElfSectionHeaderType,
+    },
+    io::{Cursor, Read, Seek, Write},
+    symbol::ElfSymbol,
+    Config, Elf, FromReader, HasWrittenSize, ToWriter, TryFromWithConfig,
 };
+use alloc::{vec, vec::Vec};
 use num_derive::FromPrimitive;
 use num_traits::FromPrimitive as _;
-use std::io::Write;
 
 #[repr(u32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, FromPrimitive)]
@@ -64,6 +73,24 @@ impl ElfHeaderFlagMIPSArchitecture {
     pub const MASK: u32 = 0xf0000000;
 }
 
+impl core::fmt::Display for ElfHeaderFlagMIPSArchitecture {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Mips1 => "mips1",
+            Self::Mips2 => "mips2",
+            Self::Mips3 => "mips3",
+            Self::Mips4 => "mips4",
+            Self::Mips5 => "mips5",
+            Self::Mips32 => "mips32",
+            Self::Mips64 => "mips64",
+            Self::Mips32R2 => "mips32r2",
+            Self::Mips64R2 => "mips64r2",
+            Self::Mips32R6 => "mips32r6",
+            Self::Mips64R6 => "mips64r6",
+        })
+    }
+}
+
 #[repr(u32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, FromPrimitive)]
 #[non_exhaustive]
@@ -89,6 +116,16 @@ impl ElfHeaderFlagMIPSArchitectureExtension {
     pub const MASK: u32 = 0x0f000000;
 }
 
+impl core::fmt::Display for ElfHeaderFlagMIPSArchitectureExtension {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Mdmx => "mdmx",
+            Self::Mips16 => "mips16",
+            Self::Micromips => "micromips",
+        })
+    }
+}
+
 #[repr(u32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, FromPrimitive)]
 #[non_exhaustive]
@@ -118,6 +155,17 @@ impl ElfHeaderFlagMIPSABI {
     pub const MASK: u32 = 0x0000f000;
 }
 
+impl core::fmt::Display for ElfHeaderFlagMIPSABI {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::O32 => "o32",
+            Self::O64 => "o64",
+            Self::EABI32 => "eabi32",
+            Self::EABI64 => "eabi64",
+        })
+    }
+}
+
 #[repr(u32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, FromPrimitive)]
 #[non_exhaustive]
@@ -219,6 +267,35 @@ impl ElfHeaderFlagMIPSMachine {
     pub const MACHINE_MASK: u32 = 0x00ff0000;
 }
 
+impl core::fmt::Display for ElfHeaderFlagMIPSMachine {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Machine3900 => "3900",
+            Self::Machine4010 => "4010",
+            Self::Machine4100 => "4100",
+            Self::MachineALLEGREX => "allegrex",
+            Self::Machine4650 => "4650",
+            Self::Machine4120 => "4120",
+            Self::Machine4111 => "4111",
+            Self::MachineSB1 => "sb1",
+            Self::MachineOCTEON => "octeon",
+            Self::MachineXLR => "xlr",
+            Self::MachineOCTEON2 => "octeon2",
+            Self::MachineOCTEON3 => "octeon3",
+            Self::Machine5400 => "5400",
+            Self::Machine5900 => "5900",
+            Self::MachineIAMR2 => "interaptiv-mr2",
+            Self::Machine5500 => "5500",
+            Self::Machine9000 => "9000",
+            Self::MachineLS2E => "loongson-2e",
+            Self::MachineLS2F => "loongson-2f",
+            Self::MachineGS464 => "gs464",
+            Self::MachineGS464E => "gs464e",
+            Self::MachineGS264E => "gs264e",
+        })
+    }
+}
+
 #[repr(u32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[non_exhaustive]
@@ -282,6 +359,28 @@ impl ElfHeaderFlagMIPS {
     pub const NAN_2008: u32 = 1024;
 }
 
+impl core::fmt::Display for ElfHeaderFlagMIPS {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NoReorder => f.write_str("noreorder"),
+            Self::Pic => f.write_str("pic"),
+            Self::CPic => f.write_str("cpic"),
+            Self::XGot => f.write_str("xgot"),
+            Self::UCode => f.write_str("ugen_reserved"),
+            Self::Abi2 => f.write_str("abi2"),
+            Self::AbiOn32 => f.write_str("on32"),
+            Self::OptionsFirst => f.write_str("odk first"),
+            Self::BitMode32 => f.write_str("32bitmode"),
+            Self::FloatingPoint64 => f.write_str("fp64"),
+            Self::NotANumber2008 => f.write_str("nan2008"),
+            Self::Architecture(architecture) => architecture.fmt(f),
+            Self::Extension(extension) => extension.fmt(f),
+            Self::Abi(abi) => abi.fmt(f),
+            Self::Machine(machine) => machine.fmt(f),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 /// A set of semantically useful flags retrieved from the set of flags in the ELF header
 pub struct ElfHeaderFlagsMIPS<const EC: u8, const ED: u8> {
@@ -289,6 +388,18 @@ pub struct ElfHeaderFlagsMIPS<const EC: u8, const ED: u8> {
     value: ElfWord<EC, ED>,
 }
 
+impl<const EC: u8, const ED: u8> ElfHeaderFlagsMIPS<EC, ED> {
+    /// The flags decoded from the header's flags field
+    pub fn flags(&self) -> &[ElfHeaderFlagMIPS] {
+        &self.flags
+    }
+
+    /// The raw value of the header's flags field
+    pub fn value(&self) -> u32 {
+        self.value.0
+    }
+}
+
 impl<const EC: u8, const ED: u8> TryFromWithConfig<ElfWord<EC, ED>> for ElfHeaderFlagsMIPS<EC, ED> {
     type Error = Error;
 
@@ -391,6 +502,39 @@ impl<const EC: u8, const ED: u8> TryFromWithConfig<ElfWord<EC, ED>> for ElfHeade
     }
 }
 
+impl<const EC: u8, const ED: u8> core::fmt::Display for ElfHeaderFlagsMIPS<EC, ED> {
+    /// Formats the flags like the `Flags:` line printed by `readelf -h`, which lists the
+    /// machine, ABI, extension, and architecture after the other flags
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:#x}", self.value.0)?;
+
+        let rank = |flag: &ElfHeaderFlagMIPS| match flag {
+            ElfHeaderFlagMIPS::Machine(_) => 1,
+            ElfHeaderFlagMIPS::Abi(_) => 2,
+            ElfHeaderFlagMIPS::Extension(_) => 3,
+            ElfHeaderFlagMIPS::Architecture(_) => 4,
+            _ => 0,
+        };
+        let mut flags = self.flags.iter().collect::<Vec<_>>();
+        flags.sort_by_key(|flag| rank(flag));
+
+        for flag in flags {
+            write!(f, ", {flag}")?;
+        }
+
+        // The architecture field of MIPS1 code is zero, so it is never decoded
+        if !self
+            .flags
+            .iter()
+            .any(|flag| matches!(flag, ElfHeaderFlagMIPS::Architecture(_)))
+        {
+            write!(f, ", {}", ElfHeaderFlagMIPSArchitecture::Mips1)?;
+        }
+
+        Ok(())
+    }
+}
+
 impl<const EC: u8, const ED: u8, W> ToWriter<W> for ElfHeaderFlagsMIPS<EC, ED>
 where
     W: Write,
@@ -601,6 +745,53 @@ impl ElfSectionHeaderTypeMIPS {
     pub const XHASH: u32 = 0x7000002b;
 }
 
+impl core::fmt::Display for ElfSectionHeaderTypeMIPS {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::LibList => "MIPS_LIBLIST",
+            Self::Conflict => "MIPS_CONFLICT",
+            Self::GpTable => "MIPS_GPTAB",
+            Self::UCode => "MIPS_UCODE",
+            Self::Debug => "MIPS_DEBUG",
+            Self::RegInfo => "MIPS_REGINFO",
+            Self::Package => "MIPS_PACKAGE",
+            Self::PackSym => "MIPS_PACKSYM",
+            Self::RelD => "MIPS_RELD",
+            Self::IFace => "MIPS_IFACE",
+            Self::Content => "MIPS_CONTENT",
+            Self::Options => "MIPS_OPTIONS",
+            Self::Shdr => "MIPS_SHDR",
+            Self::FDesc => "MIPS_FDESC",
+            Self::ExtSym => "MIPS_EXTSYM",
+            Self::Dense => "MIPS_DENSE",
+            Self::PDesc => "MIPS_PDESC",
+            Self::LocSym => "MIPS_LOCSYM",
+            Self::AuxSym => "MIPS_AUXSYM",
+            Self::OptSym => "MIPS_OPTSYM",
+            Self::LocStr => "MIPS_LOCSTR",
+            Self::Line => "MIPS_LINE",
+            Self::RfdDesc => "MIPS_RFDESC",
+            Self::DeltaSYm => "MIPS_DELTASYM",
+            Self::DeltaInst => "MIPS_DELTAINST",
+            Self::DeltaClass => "MIPS_DELTACLASS",
+            Self::Dwarf => "MIPS_DWARF",
+            Self::DeltaDecl => "MIPS_DELTADECL",
+            Self::SymbolLib => "MIPS_SYMBOL_LIB",
+            Self::Events => "MIPS_EVENTS",
+            Self::Translate => "MIPS_TRANSLATE",
+            Self::Pixie => "MIPS_PIXIE",
+            Self::XLate => "MIPS_XLATE",
+            Self::XLateDebug => "MIPS_XLATE_DEBUG",
+            Self::Whirl => "MIPS_WHIRL",
+            Self::EhRegion => "MIPS_EH_REGION",
+            Self::XLateOld => "MIPS_XLATE_OLD",
+            Self::PdrException => "MIPS_PDR_EXCEPTION",
+            Self::AbiFlags => "MIPS_ABIFLAGS",
+            Self::XHash => "MIPS_XHASH",
+        })
+    }
+}
+
 impl<const EC: u8, const ED: u8> From<ElfSectionHeaderTypeMIPS> for ElfWord<EC, ED> {
     fn from(value: ElfSectionHeaderTypeMIPS) -> Self {
         Self(value as u32)
@@ -677,3 +868,527 @@ impl<const EC: u8, const ED: u8> TryFromWithConfig<ElfWord<EC, ED>> for ElfSecti
         }
     }
 }
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+/// Program Header Types
+pub enum ElfProgramHeaderTypeMIPS {
+    /// Register usage information
+    RegInfo = Self::REG_INFO,
+    /// Runtime procedure table
+    RuntimeProcedureTable = Self::RTPROC,
+    /// Options segment
+    Options = Self::OPTIONS,
+    /// ABI flags segment
+    AbiFlags = Self::ABIFLAGS,
+}
+
+impl ElfProgramHeaderTypeMIPS {
+    /// Constant value for [ElfProgramHeaderTypeMIPS::RegInfo]
+    pub const REG_INFO: u32 = 0x70000000;
+    /// Constant value for [ElfProgramHeaderTypeMIPS::RuntimeProcedureTable]
+    pub const RTPROC: u32 = 0x70000001;
+    /// Constant value for [ElfProgramHeaderTypeMIPS::Options]
+    pub const OPTIONS: u32 = 0x70000002;
+    /// Constant value for [ElfProgramHeaderTypeMIPS::AbiFlags]
+    pub const ABIFLAGS: u32 = 0x70000003;
+}
+
+impl core::fmt::Display for ElfProgramHeaderTypeMIPS {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::RegInfo => "REGINFO",
+            Self::RuntimeProcedureTable => "RTPROC",
+            Self::Options => "OPTIONS",
+            Self::AbiFlags => "ABIFLAGS",
+        })
+    }
+}
+
+impl<const EC: u8, const ED: u8> From<ElfProgramHeaderTypeMIPS> for ElfWord<EC, ED> {
+    fn from(value: ElfProgramHeaderTypeMIPS) -> Self {
+        Self(value as u32)
+    }
+}
+
+impl<const EC: u8, const ED: u8> From<&ElfProgramHeaderTypeMIPS> for ElfWord<EC, ED> {
+    fn from(value: &ElfProgramHeaderTypeMIPS) -> Self {
+        Self(*value as u32)
+    }
+}
+
+impl<const EC: u8, const ED: u8> TryFromWithConfig<ElfWord<EC, ED>> for ElfProgramHeaderTypeMIPS {
+    type Error = Error;
+
+    fn try_from_with(
+        value: ElfWord<EC, ED>,
+        config: &mut crate::Config,
+    ) -> Result<Self, Self::Error> {
+        if !matches!(config.machine, Some(ElfMachine::MIPS)) {
+            return Err(Error::InvalidMachineForProgramHeaderType {
+                machine: config.machine,
+                expected_machines: vec![ElfMachine::MIPS],
+                value: value.0,
+            });
+        }
+
+        match value.0 {
+            Self::REG_INFO => Ok(Self::RegInfo),
+            Self::RTPROC => Ok(Self::RuntimeProcedureTable),
+            Self::OPTIONS => Ok(Self::Options),
+            Self::ABIFLAGS => Ok(Self::AbiFlags),
+            _ => Err(Error::InvalidProgramHeaderType {
+                machine: config.machine,
+                value: value.0,
+            }),
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FromPrimitive)]
+#[non_exhaustive]
+/// The kind of an option descriptor in a `.MIPS.options` section
+pub enum ElfMipsOptionKind {
+    /// Undefined, which ends the list of options
+    Null = Self::NULL,
+    /// Register usage information
+    RegInfo = Self::REG_INFO,
+    /// Exception processing options
+    Exceptions = Self::EXCEPTIONS,
+    /// Section padding options
+    Pad = Self::PAD,
+    /// Hardware workarounds performed
+    HwPatch = Self::HW_PATCH,
+    /// The fill value used by the linker
+    Fill = Self::FILL,
+    /// Space reserved for descriptive tags
+    Tags = Self::TAGS,
+    /// Hardware workarounds, AND bits when merging
+    HwAnd = Self::HW_AND,
+    /// Hardware workarounds, OR bits when merging
+    HwOr = Self::HW_OR,
+    /// The GP group to use for text and data sections
+    GpGroup = Self::GP_GROUP,
+    /// The ID information
+    Ident = Self::IDENT,
+    /// The page size information
+    PageSize = Self::PAGE_SIZE,
+}
+
+impl ElfMipsOptionKind {
+    /// Constant value for [ElfMipsOptionKind::Null]
+    pub const NULL: u8 = 0;
+    /// Constant value for [ElfMipsOptionKind::RegInfo]
+    pub const REG_INFO: u8 = 1;
+    /// Constant value for [ElfMipsOptionKind::Exceptions]
+    pub const EXCEPTIONS: u8 = 2;
+    /// Constant value for [ElfMipsOptionKind::Pad]
+    pub const PAD: u8 = 3;
+    /// Constant value for [ElfMipsOptionKind::HwPatch]
+    pub const HW_PATCH: u8 = 4;
+    /// Constant value for [ElfMipsOptionKind::Fill]
+    pub const FILL: u8 = 5;
+    /// Constant value for [ElfMipsOptionKind::Tags]
+    pub const TAGS: u8 = 6;
+    /// Constant value for [ElfMipsOptionKind::HwAnd]
+    pub const HW_AND: u8 = 7;
+    /// Constant value for [ElfMipsOptionKind::HwOr]
+    pub const HW_OR: u8 = 8;
+    /// Constant value for [ElfMipsOptionKind::GpGroup]
+    pub const GP_GROUP: u8 = 9;
+    /// Constant value for [ElfMipsOptionKind::Ident]
+    pub const IDENT: u8 = 10;
+    /// Constant value for [ElfMipsOptionKind::PageSize]
+    pub const PAGE_SIZE: u8 = 11;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Register usage information, held by an `ODK_REGINFO` option descriptor or by the
+/// `.reginfo` section
+pub struct ElfMipsRegInfo {
+    /// The mask of general purpose registers used by the object
+    pub gpr_mask: u32,
+    /// The masks of the coprocessor registers used by the object
+    pub cpr_mask: [u32; 4],
+    /// The initial value of the GP register
+    pub gp_value: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// An option descriptor in a `.MIPS.options` section
+pub struct ElfMipsOption {
+    /// The raw kind of the option, which can be decoded with
+    /// `ElfMipsOptionKind::from_u8`
+    pub kind: u8,
+    /// The index of the section the option applies to, or 0 if it applies to the
+    /// whole object
+    pub section: u16,
+    /// Information specific to the kind of the option
+    pub info: u32,
+    /// The data of the option after its header
+    pub data: Vec<u8>,
+    /// The register usage information of an `ODK_REGINFO` option
+    pub reg_info: Option<ElfMipsRegInfo>,
+}
+
+/// The size of the header of an option descriptor
+const MIPS_OPTION_HEADER_SIZE: usize = 8;
+
+/// Decode the option descriptors in the data of a `.MIPS.options` section. The list
+/// ends at an `ODK_NULL` descriptor, or at the first descriptor which does not fit in
+/// the data.
+fn mips_options_from_data<const EC: u8, const ED: u8>(data: &[u8]) -> Vec<ElfMipsOption> {
+    let mut config = Config::default();
+    let mut options = Vec::new();
+    let mut rest = data;
+
+    while let (Some(&[kind, size]), Some(header)) = (rest.get(..2), rest.get(2..8)) {
+        let size = size as usize;
+
+        if kind == ElfMipsOptionKind::NULL || size < MIPS_OPTION_HEADER_SIZE {
+            break;
+        }
+
+        let Some(option) = rest.get(MIPS_OPTION_HEADER_SIZE..size) else {
+            break;
+        };
+        let mut cursor = Cursor::new(header);
+        let (Ok(section), Ok(info)) = (
+            ElfHalfWord::<EC, ED>::from_reader_with(&mut cursor, &mut config),
+            ElfWord::<EC, ED>::from_reader_with(&mut cursor, &mut config),
+        ) else {
+            break;
+        };
+
+        let reg_info = (kind == ElfMipsOptionKind::REG_INFO)
+            .then(|| {
+                let mut cursor = Cursor::new(option);
+                let mut word = || ElfWord::<EC, ED>::from_reader_with(&mut cursor, &mut config);
+                let gpr_mask = word()?.0;
+                // The 64-bit register information pads the GPR mask to 8 bytes
+                if EC == ElfClass::Elf64 as u8 {
+                    word()?;
+                }
+                let cpr_mask = [word()?.0, word()?.0, word()?.0, word()?.0];
+                let gp_value = ElfAddress::<EC, ED>::from_reader_with(&mut cursor, &mut config)?.0;
+                Ok::<_, Error>(ElfMipsRegInfo {
+                    gpr_mask,
+                    cpr_mask,
+                    gp_value,
+                })
+            })
+            .and_then(Result::ok);
+
+        options.push(ElfMipsOption {
+            kind,
+            section: section.0,
+            info: info.0,
+            data: option.to_vec(),
+            reg_info,
+        });
+
+        rest = rest.get(size..).unwrap_or_default();
+    }
+
+    options
+}
+
+impl<const EC: u8, const ED: u8> Elf<EC, ED> {
+    /// Decode the option descriptors in the object's `.MIPS.options` sections, which
+    /// are identified by their `SHT_MIPS_OPTIONS` type. Objects which are not MIPS
+    /// objects have no such sections, in which case no options are returned.
+    pub fn mips_options<R>(&self, reader: &mut R) -> Result<Vec<ElfMipsOption>, Error>
+    where
+        R: Read + Seek,
+    {
+        let mut options = Vec::new();
+
+        for (index, header) in self.indexed_sections() {
+            if matches!(
+                header.r#type(),
+                ElfSectionHeaderType::Mips(ElfSectionHeaderTypeMIPS::Options)
+            ) {
+                options.extend(mips_options_from_data::<EC, ED>(
+                    &self.section_data(reader, index)?,
+                ));
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FromPrimitive)]
+#[non_exhaustive]
+/// Dynamic Array Tags, which MIPS objects use in place of the usual procedure linkage
+/// table relocations to describe the global offset table
+pub enum ElfDynamicTagMIPS {
+    /// The version of the runtime linker interface (`DT_MIPS_RLD_VERSION`)
+    RldVersion = Self::RLD_VERSION,
+    /// The time stamp of the object (`DT_MIPS_TIME_STAMP`)
+    TimeStamp = Self::TIME_STAMP,
+    /// The checksum of the external strings and common sizes (`DT_MIPS_ICHECKSUM`)
+    IChecksum = Self::ICHECKSUM,
+    /// The string table offset of the version string (`DT_MIPS_IVERSION`)
+    IVersion = Self::IVERSION,
+    /// Flags for the runtime linker (`DT_MIPS_FLAGS`)
+    Flags = Self::FLAGS,
+    /// The base address the object was linked at (`DT_MIPS_BASE_ADDRESS`)
+    BaseAddress = Self::BASE_ADDRESS,
+    /// The address of the `.msym` section (`DT_MIPS_MSYM`)
+    Msym = Self::MSYM,
+    /// The address of the `.conflict` section (`DT_MIPS_CONFLICT`)
+    Conflict = Self::CONFLICT,
+    /// The address of the `.liblist` section (`DT_MIPS_LIBLIST`)
+    LibList = Self::LIBLIST,
+    /// The number of local entries in the global offset table (`DT_MIPS_LOCAL_GOTNO`)
+    LocalGotNo = Self::LOCAL_GOTNO,
+    /// The number of entries in the `.conflict` section (`DT_MIPS_CONFLICTNO`)
+    ConflictNo = Self::CONFLICTNO,
+    /// The number of entries in the `.liblist` section (`DT_MIPS_LIBLISTNO`)
+    LibListNo = Self::LIBLISTNO,
+    /// The number of entries in the dynamic symbol table (`DT_MIPS_SYMTABNO`)
+    SymTabNo = Self::SYMTABNO,
+    /// The index of the first external dynamic symbol not referenced locally (`DT_MIPS_UNREFEXTNO`)
+    UnrefExtNo = Self::UNREFEXTNO,
+    /// The index of the first dynamic symbol which has an entry in the global offset
+    /// table (`DT_MIPS_GOTSYM`)
+    GotSym = Self::GOTSYM,
+    /// The number of page table entries in the global offset table (`DT_MIPS_HIPAGENO`)
+    HiPageNo = Self::HIPAGENO,
+    /// The address of the word the runtime linker stores its debug map in (`DT_MIPS_RLD_MAP`)
+    RldMap = Self::RLD_MAP,
+    /// The address of the `.got.plt` section (`DT_MIPS_PLTGOT`)
+    PltGot = Self::PLTGOT,
+    /// The address of a writable procedure linkage table (`DT_MIPS_RWPLT`)
+    RwPlt = Self::RWPLT,
+    /// The offset from this entry to the word the runtime linker stores its debug
+    /// map in (`DT_MIPS_RLD_MAP_REL`)
+    RldMapRel = Self::RLD_MAP_REL,
+}
+
+impl ElfDynamicTagMIPS {
+    /// Constant value for [ElfDynamicTagMIPS::RldVersion]
+    pub const RLD_VERSION: u32 = 0x70000001;
+    /// Constant value for [ElfDynamicTagMIPS::TimeStamp]
+    pub const TIME_STAMP: u32 = 0x70000002;
+    /// Constant value for [ElfDynamicTagMIPS::IChecksum]
+    pub const ICHECKSUM: u32 = 0x70000003;
+    /// Constant value for [ElfDynamicTagMIPS::IVersion]
+    pub const IVERSION: u32 = 0x70000004;
+    /// Constant value for [ElfDynamicTagMIPS::Flags]
+    pub const FLAGS: u32 = 0x70000005;
+    /// Constant value for [ElfDynamicTagMIPS::BaseAddress]
+    pub const BASE_ADDRESS: u32 = 0x70000006;
+    /// Constant value for [ElfDynamicTagMIPS::Msym]
+    pub const MSYM: u32 = 0x70000007;
+    /// Constant value for [ElfDynamicTagMIPS::Conflict]
+    pub const CONFLICT: u32 = 0x70000008;
+    /// Constant value for [ElfDynamicTagMIPS::LibList]
+    pub const LIBLIST: u32 = 0x70000009;
+    /// Constant value for [ElfDynamicTagMIPS::LocalGotNo]
+    pub const LOCAL_GOTNO: u32 = 0x7000000a;
+    /// Constant value for [ElfDynamicTagMIPS::ConflictNo]
+    pub const CONFLICTNO: u32 = 0x7000000b;
+    /// Constant value for [ElfDynamicTagMIPS::LibListNo]
+    pub const LIBLISTNO: u32 = 0x70000010;
+    /// Constant value for [ElfDynamicTagMIPS::SymTabNo]
+    pub const SYMTABNO: u32 = 0x70000011;
+    /// Constant value for [ElfDynamicTagMIPS::UnrefExtNo]
+    pub const UNREFEXTNO: u32 = 0x70000012;
+    /// Constant value for [ElfDynamicTagMIPS::GotSym]
+    pub const GOTSYM: u32 = 0x70000013;
+    /// Constant value for [ElfDynamicTagMIPS::HiPageNo]
+    pub const HIPAGENO: u32 = 0x70000014;
+    /// Constant value for [ElfDynamicTagMIPS::RldMap]
+    pub const RLD_MAP: u32 = 0x70000016;
+    /// Constant value for [ElfDynamicTagMIPS::PltGot]
+    pub const PLTGOT: u32 = 0x70000032;
+    /// Constant value for [ElfDynamicTagMIPS::RwPlt]
+    pub const RWPLT: u32 = 0x70000034;
+    /// Constant value for [ElfDynamicTagMIPS::RldMapRel]
+    pub const RLD_MAP_REL: u32 = 0x70000035;
+}
+
+impl core::fmt::Display for ElfDynamicTagMIPS {
+    /// Display the tag as its name in `readelf` output
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::RldVersion => "MIPS_RLD_VERSION",
+            Self::TimeStamp => "MIPS_TIME_STAMP",
+            Self::IChecksum => "MIPS_ICHECKSUM",
+            Self::IVersion => "MIPS_IVERSION",
+            Self::Flags => "MIPS_FLAGS",
+            Self::BaseAddress => "MIPS_BASE_ADDRESS",
+            Self::Msym => "MIPS_MSYM",
+            Self::Conflict => "MIPS_CONFLICT",
+            Self::LibList => "MIPS_LIBLIST",
+            Self::LocalGotNo => "MIPS_LOCAL_GOTNO",
+            Self::ConflictNo => "MIPS_CONFLICTNO",
+            Self::LibListNo => "MIPS_LIBLISTNO",
+            Self::SymTabNo => "MIPS_SYMTABNO",
+            Self::UnrefExtNo => "MIPS_UNREFEXTNO",
+            Self::GotSym => "MIPS_GOTSYM",
+            Self::HiPageNo => "MIPS_HIPAGENO",
+            Self::RldMap => "MIPS_RLD_MAP",
+            Self::PltGot => "MIPS_PLTGOT",
+            Self::RwPlt => "MIPS_RWPLT",
+            Self::RldMapRel => "MIPS_RLD_MAP_REL",
+        })
+    }
+}
+
+impl<const EC: u8, const ED: u8> From<ElfDynamicTagMIPS> for ElfDynamicTag<EC, ED> {
+    fn from(value: ElfDynamicTagMIPS) -> Self {
+        Self::from(value as u64)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// The layout of the global offset table of a dynamically linked MIPS object, as
+/// described by its dynamic array and reported by `Elf::mips_got`. The table begins
+/// with its local entries, followed by one global entry for each dynamic symbol from
+/// `first_global_symbol` to the end of the dynamic symbol table, in order.
+pub struct MipsGot {
+    /// The address of the global offset table (`DT_PLTGOT`)
+    pub address: u64,
+    /// The size of an entry in the table, which is the size of an address
+    pub entry_size: u64,
+    /// The number of local entries, including the reserved entries for the lazy
+    /// resolver and module pointer (`DT_MIPS_LOCAL_GOTNO`)
+    pub local_count: u64,
+    /// The index of the first dynamic symbol with a global entry (`DT_MIPS_GOTSYM`)
+    pub first_global_symbol: u64,
+    /// The number of entries in the dynamic symbol table (`DT_MIPS_SYMTABNO`)
+    pub symbol_count: u64,
+    /// The address of the word the runtime linker stores its debug map in
+    /// (`DT_MIPS_RLD_MAP`), if there is one
+    pub rld_map: Option<u64>,
+}
+
+impl MipsGot {
+    /// The global entries of the table, as the address of each entry and the index of
+    /// the dynamic symbol it holds the address of
+    pub fn global_entries(&self) -> Vec<(u64, u64)> {
+        (self.first_global_symbol..self.symbol_count)
+            .zip(self.local_count..)
+            .map(|(symbol, entry)| {
+                (
+                    self.address
+                        .saturating_add(entry.saturating_mul(self.entry_size)),
+                    symbol,
+                )
+            })
+            .collect()
+    }
+}
+
+impl<const EC: u8, const ED: u8> Elf<EC, ED> {
+    /// Decode the layout of the global offset table of a dynamically linked MIPS object
+    /// from the MIPS-specific entries of its dynamic array. MIPS objects bind imported
+    /// functions and data through the global entries of this table rather than through
+    /// procedure linkage table relocations. Objects which are not MIPS objects, or
+    /// which are missing any of `DT_PLTGOT`, `DT_MIPS_LOCAL_GOTNO`, `DT_MIPS_GOTSYM`, and
+    /// `DT_MIPS_SYMTABNO`, have no such table, in which case `None` is returned.
+    pub fn mips_got<R>(&self, reader: &mut R) -> Result<Option<MipsGot>, Error>
+    where
+        R: Read + Seek,
+    {
+        if !matches!(self.header.machine, ElfMachine::MIPS) {
+            return Ok(None);
+        }
+
+        let entries = self.dynamic_entries(reader)?;
+        let value = |tag: ElfDynamicTag<EC, ED>| {
+            entries
+                .iter()
+                .find(|entry| entry.r#type() == tag)
+                .map(|entry| entry.value())
+        };
+
+        let (Some(address), Some(local_count), Some(first_global_symbol), Some(symbol_count)) = (
+            value(ElfDynamicTag::PltGot),
+            value(ElfDynamicTagMIPS::LocalGotNo.into()),
+            value(ElfDynamicTagMIPS::GotSym.into()),
+            value(ElfDynamicTagMIPS::SymTabNo.into()),
+        ) else {
+            return Ok(None);
+        };
+
+        Ok(Some(MipsGot {
+            address,
+            entry_size: ElfAddress::<EC, ED>::SIZE as u64,
+            local_count,
+            first_global_symbol,
+            symbol_count,
+            rld_map: value(ElfDynamicTagMIPS::RldMap.into()),
+        }))
+    }
+
+    /// The dynamic symbols with global entries in the global offset table of a MIPS
+    /// object, with the address of the entry of each, which are the symbols the object
+    /// imports or exports through the table. Objects without such a table have no such
+    /// symbols.
+    pub fn mips_got_symbols<R>(
+        &self,
+        reader: &mut R,
+    ) -> Result<Vec<(u64, ElfSymbol<EC, ED>)>, Error>
+    where
+        R: Read + Seek,
+    {
+        let Some(got) = self.mips_got(reader)? else {
+            return Ok(Vec::new());
+        };
+        let symbols = self.dynamic_symbols(reader)?;
+
+        Ok(got
+            .global_entries()
+            .into_iter()
+            .filter_map(|(address, symbol)| {
+                let symbol = symbols.get(usize::try_from(symbol).ok()?)?;
+                Some((address, symbol.clone()))
+            })
+            .collect())
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod test {
+    use super::{mips_options_from_data, ElfMipsOptionKind, ElfMipsRegInfo};
+    use crate::header::elf::identification::{ElfClass, ElfDataEncoding};
+
+    #[test]
+    fn test_mips_options() {
+        let mut data = vec![ElfMipsOptionKind::REG_INFO, 40, 0, 0, 0, 0, 0, 0];
+        for word in [0x1234_5678u32, 0, 1, 2, 3, 4] {
+            data.extend_from_slice(&word.to_be_bytes());
+        }
+        data.extend_from_slice(&0x1200_7ff0u64.to_be_bytes());
+        data.extend_from_slice(&[ElfMipsOptionKind::PAGE_SIZE, 8, 0, 1, 0, 0, 0x10, 0]);
+        data.extend_from_slice(&[ElfMipsOptionKind::NULL; 8]);
+
+        let options = mips_options_from_data::<
+            { ElfClass::Elf64 as u8 },
+            { ElfDataEncoding::BigEndian as u8 },
+        >(&data);
+
+        assert_eq!(options.len(), 2);
+        assert_eq!(
+            options.first().unwrap().reg_info,
+            Some(ElfMipsRegInfo {
+                gpr_mask: 0x1234_5678,
+                cpr_mask: [1, 2, 3, 4],
+                gp_value: 0x1200_7ff0,
+            })
+        );
+        let page_size = options.get(1).unwrap();
+        assert_eq!(page_size.kind, ElfMipsOptionKind::PAGE_SIZE);
+        assert_eq!(page_size.section, 1);
+        assert_eq!(page_size.info, 0x1000);
+        assert_eq!(page_size.reg_info, None);
+    }
+}
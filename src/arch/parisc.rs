@@ -1,12 +1,13 @@
 //! Architecture specific definitions for parisc
 
-use std::io::Write;
+use alloc::{vec, vec::Vec};
 
 use num_derive::FromPrimitive;
 use num_traits::FromPrimitive as _;
 
 use crate::{
-    base::ElfWord, error::Error, header::elf::ElfMachine, Config, ToWriter, TryFromWithConfig,
+    base::ElfWord, error::Error, header::elf::ElfMachine, io::Write, Config, ToWriter,
+    TryFromWithConfig,
 };
 
 #[repr(u32)]
@@ -94,6 +95,18 @@ pub struct ElfHeaderFlagsPARISC<const EC: u8, const ED: u8> {
     value: ElfWord<EC, ED>,
 }
 
+impl<const EC: u8, const ED: u8> ElfHeaderFlagsPARISC<EC, ED> {
+    /// The flags decoded from the header's flags field
+    pub fn flags(&self) -> &[ElfHeaderFlagPARISC] {
+        &self.flags
+    }
+
+    /// The raw value of the header's flags field
+    pub fn value(&self) -> u32 {
+        self.value.0
+    }
+}
+
 impl<const EC: u8, const ED: u8> TryFromWithConfig<ElfWord<EC, ED>>
     for ElfHeaderFlagsPARISC<EC, ED>
 {
@@ -142,6 +155,38 @@ impl<const EC: u8, const ED: u8> TryFromWithConfig<ElfWord<EC, ED>>
     }
 }
 
+impl<const EC: u8, const ED: u8> core::fmt::Display for ElfHeaderFlagsPARISC<EC, ED> {
+    /// Formats the flags like the `Flags:` line printed by `readelf -h`, which lists the
+    /// architecture version first
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:#x}", self.value.0)?;
+
+        for flag in &self.flags {
+            if let ElfHeaderFlagPARISC::ArchitectureVersion(version) = flag {
+                f.write_str(match version {
+                    ElfHeaderFlagPARISCArchitectureVersion::PaRisc10 => ", PA-RISC 1.0",
+                    ElfHeaderFlagPARISCArchitectureVersion::PaRisc11 => ", PA-RISC 1.1",
+                    ElfHeaderFlagPARISCArchitectureVersion::PaRisc20 => ", PA-RISC 2.0",
+                })?;
+            }
+        }
+
+        for flag in &self.flags {
+            f.write_str(match flag {
+                ElfHeaderFlagPARISC::TrapNil => ", trapnil",
+                ElfHeaderFlagPARISC::Extensions => ", ext",
+                ElfHeaderFlagPARISC::LittleEndianMode => ", lsb",
+                ElfHeaderFlagPARISC::WideMode => ", wide",
+                ElfHeaderFlagPARISC::NoKernelAssistedBranchPrediction => ", no kabp",
+                ElfHeaderFlagPARISC::LazySwap => ", lazyswap",
+                ElfHeaderFlagPARISC::ArchitectureVersion(_) => "",
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
 impl<const EC: u8, const ED: u8, W> ToWriter<W> for ElfHeaderFlagsPARISC<EC, ED>
 where
     W: Write,
@@ -212,6 +257,25 @@ impl ElfSectionHeaderTypePARISC {
     pub const HP_ANNOT: u32 = 0x60000004;
 }
 
+impl core::fmt::Display for ElfSectionHeaderTypePARISC {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::PariscExt => "PARISC_EXT",
+            Self::PariscUnwind => "PARISC_UNWIND",
+            Self::PariscDoc => "PARISC_DOC",
+            Self::PariscAnnot => "PARISC_ANNOT",
+            Self::PariscDlkm => "PARISC_DLKM",
+            Self::PariscSymextn => "PARISC_SYMEXTN",
+            Self::PariscStubs => "PARISC_STUBS",
+            Self::HpOvlbits => "HP_OVLBITS",
+            Self::HpDlkm => "HP_DLKM",
+            Self::HpComdat => "HP_COMDAT",
+            Self::HpObjdict => "HP_OBJDICT",
+            Self::HpAnnot => "HP_ANNOT",
+        })
+    }
+}
+
 impl<const EC: u8, const ED: u8> From<ElfSectionHeaderTypePARISC> for ElfWord<EC, ED> {
     fn from(value: ElfSectionHeaderTypePARISC) -> Self {
         Self(value as u32)
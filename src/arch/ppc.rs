@@ -3,6 +3,7 @@
 // NOTE: No architecture-specific ELF Header flags for PPC
 
 use crate::{base::ElfWord, error::Error, header::elf::ElfMachine, TryFromWithConfig};
+use alloc::vec;
 
 #[repr(u32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -20,6 +21,14 @@ impl ElfSectionHeaderTypePPC {
     pub const ORDERED: u32 = 0x7FFFFFFF;
 }
 
+impl core::fmt::Display for ElfSectionHeaderTypePPC {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Ordered => "ORDERED",
+        })
+    }
+}
+
 impl<const EC: u8, const ED: u8> From<ElfSectionHeaderTypePPC> for ElfWord<EC, ED> {
     fn from(value: ElfSectionHeaderTypePPC) -> Self {
         Self(value as u32)
@@ -1,11 +1,17 @@
 //! Architecture specific definitions for RISC-V
 
-use std::io::Write;
+use alloc::{string::String, vec, vec::Vec};
 
 use num_derive::FromPrimitive;
 use num_traits::FromPrimitive as _;
 
-use crate::{base::ElfWord, error::Error, header::elf::ElfMachine, ToWriter, TryFromWithConfig};
+use crate::{
+    base::ElfWord,
+    error::Error,
+    header::{elf::ElfMachine, section::ElfSectionHeaderType},
+    io::{Cursor, Read, Seek, Write},
+    Config, Elf, FromReader, ToWriter, TryFromWithConfig,
+};
 
 #[repr(u32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, FromPrimitive)]
@@ -127,6 +133,18 @@ pub struct ElfHeaderFlagsRISCV<const EC: u8, const ED: u8> {
     value: ElfWord<EC, ED>,
 }
 
+impl<const EC: u8, const ED: u8> ElfHeaderFlagsRISCV<EC, ED> {
+    /// The flags decoded from the header's flags field
+    pub fn flags(&self) -> &[ElfHeaderFlagRISCV] {
+        &self.flags
+    }
+
+    /// The raw value of the header's flags field
+    pub fn value(&self) -> u32 {
+        self.value.0
+    }
+}
+
 impl<const EC: u8, const ED: u8> TryFromWithConfig<ElfWord<EC, ED>>
     for ElfHeaderFlagsRISCV<EC, ED>
 {
@@ -186,6 +204,33 @@ impl<const EC: u8, const ED: u8> TryFromWithConfig<ElfWord<EC, ED>>
     }
 }
 
+impl<const EC: u8, const ED: u8> core::fmt::Display for ElfHeaderFlagsRISCV<EC, ED> {
+    /// Formats the flags like the `Flags:` line printed by `readelf -h`
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:#x}", self.value.0)?;
+
+        for flag in &self.flags {
+            f.write_str(match flag {
+                ElfHeaderFlagRISCV::Rvc(ElfHeaderFlagRISCVRVC::Rvc) => ", RVC",
+                ElfHeaderFlagRISCV::FloatAbi(ElfHeaderFlagRISCVFloatAbi::Single) => {
+                    ", single-float ABI"
+                }
+                ElfHeaderFlagRISCV::FloatAbi(ElfHeaderFlagRISCVFloatAbi::Double) => {
+                    ", double-float ABI"
+                }
+                ElfHeaderFlagRISCV::FloatAbi(ElfHeaderFlagRISCVFloatAbi::Quad) => {
+                    ", quad-float ABI"
+                }
+                ElfHeaderFlagRISCV::EAbi(ElfHeaderFlagRISCVEAbi::EIsa) => ", RVE",
+                ElfHeaderFlagRISCV::MemoryModel(ElfHeaderFlagRISCVMemoryModel::RvtsO) => ", TSO",
+                _ => "",
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
 impl<const EC: u8, const ED: u8, W> ToWriter<W> for ElfHeaderFlagsRISCV<EC, ED>
 where
     W: Write,
@@ -212,6 +257,14 @@ impl ElfSectionHeaderTypeRISCV {
     pub const ATTRIBUTES: u32 = 0x70000003;
 }
 
+impl core::fmt::Display for ElfSectionHeaderTypeRISCV {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Attributes => "RISCV_ATTRIBUTES",
+        })
+    }
+}
+
 impl<const EC: u8, const ED: u8> From<ElfSectionHeaderTypeRISCV> for ElfWord<EC, ED> {
     fn from(value: ElfSectionHeaderTypeRISCV) -> Self {
         Self(value as u32)
@@ -249,3 +302,234 @@ impl<const EC: u8, const ED: u8> TryFromWithConfig<ElfWord<EC, ED>> for ElfSecti
         }
     }
 }
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+/// Program Header Types
+pub enum ElfProgramHeaderTypeRISCV {
+    /// RISC-V ELF attribute section
+    Attributes = Self::ATTRIBUTES,
+}
+
+impl ElfProgramHeaderTypeRISCV {
+    /// Constant value for [ElfProgramHeaderTypeRISCV::Attributes]
+    pub const ATTRIBUTES: u32 = 0x70000003;
+}
+
+impl core::fmt::Display for ElfProgramHeaderTypeRISCV {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Attributes => "RISCV_ATTRIBUT",
+        })
+    }
+}
+
+impl<const EC: u8, const ED: u8> From<ElfProgramHeaderTypeRISCV> for ElfWord<EC, ED> {
+    fn from(value: ElfProgramHeaderTypeRISCV) -> Self {
+        Self(value as u32)
+    }
+}
+
+impl<const EC: u8, const ED: u8> From<&ElfProgramHeaderTypeRISCV> for ElfWord<EC, ED> {
+    fn from(value: &ElfProgramHeaderTypeRISCV) -> Self {
+        Self(*value as u32)
+    }
+}
+
+impl<const EC: u8, const ED: u8> TryFromWithConfig<ElfWord<EC, ED>> for ElfProgramHeaderTypeRISCV {
+    type Error = Error;
+
+    fn try_from_with(
+        value: ElfWord<EC, ED>,
+        config: &mut crate::Config,
+    ) -> Result<Self, Self::Error> {
+        if !matches!(config.machine, Some(ElfMachine::Riscv)) {
+            return Err(Error::InvalidMachineForProgramHeaderType {
+                machine: config.machine,
+                expected_machines: vec![ElfMachine::Riscv],
+                value: value.0,
+            });
+        }
+
+        match value.0 {
+            Self::ATTRIBUTES => Ok(Self::Attributes),
+            _ => Err(Error::InvalidProgramHeaderType {
+                machine: config.machine,
+                value: value.0,
+            }),
+        }
+    }
+}
+
+/// The version of the attributes format, which begins a `.riscv.attributes` section
+const ATTRIBUTES_FORMAT_VERSION: u8 = b'A';
+/// The vendor name of the subsection holding the RISC-V attributes
+const ATTRIBUTES_VENDOR: &[u8] = b"riscv";
+/// The tag of a sub-subsection holding attributes which apply to the whole file
+const TAG_FILE: u64 = 1;
+/// The tag of the attribute holding the ISA string of the file
+const TAG_RISCV_ARCH: u64 = 5;
+
+/// Decode an unsigned LEB128 value from the start of `data`, and return it with the
+/// rest of `data`
+fn uleb128(data: &[u8]) -> Option<(u64, &[u8])> {
+    let mut value = 0u64;
+    let mut shift = 0u32;
+    let mut rest = data;
+
+    loop {
+        let (byte, next) = rest.split_first()?;
+        rest = next;
+        value |= ((byte & 0x7f) as u64).checked_shl(shift)?;
+        shift += 7;
+
+        if byte & 0x80 == 0 {
+            return Some((value, rest));
+        }
+    }
+}
+
+/// Split `data`, which begins with a 4 byte size which includes itself and any bytes
+/// before it in `prefix`, into its contents after the size and the data after it
+fn sized<const EC: u8, const ED: u8>(data: &[u8], prefix: usize) -> Option<(&[u8], &[u8])> {
+    let size = ElfWord::<EC, ED>::from_reader_with(
+        &mut Cursor::new(data.get(..4)?),
+        &mut Config::default(),
+    )
+    .ok()?
+    .0;
+    let size = usize::try_from(size).ok()?.checked_sub(prefix)?;
+
+    (size >= 4).then_some(())?;
+    Some((data.get(4..size)?, data.get(size..)?))
+}
+
+/// Find the `Tag_RISCV_arch` attribute in the contents of a `.riscv.attributes`
+/// section. Attributes with odd tags are null-terminated strings, and attributes with
+/// even tags are unsigned LEB128 integers.
+fn riscv_arch_from_attributes<const EC: u8, const ED: u8>(data: &[u8]) -> Option<String> {
+    let (&version, mut subsections) = data.split_first()?;
+
+    if version != ATTRIBUTES_FORMAT_VERSION {
+        return None;
+    }
+
+    while let Some((subsection, rest)) = sized::<EC, ED>(subsections, 0) {
+        subsections = rest;
+
+        let mut parts = subsection.splitn(2, |b| *b == 0);
+        let (Some(ATTRIBUTES_VENDOR), Some(mut subsubsections)) = (parts.next(), parts.next())
+        else {
+            continue;
+        };
+
+        while let Some((tag, rest)) = uleb128(subsubsections) {
+            let tag_size = subsubsections.len() - rest.len();
+            let (mut attributes, rest) = sized::<EC, ED>(rest, tag_size)?;
+            subsubsections = rest;
+
+            if tag != TAG_FILE {
+                continue;
+            }
+
+            while let Some((tag, rest)) = uleb128(attributes) {
+                if tag % 2 == 1 {
+                    let mut parts = rest.splitn(2, |b| *b == 0);
+                    let value = parts.next()?;
+                    if tag == TAG_RISCV_ARCH {
+                        return Some(String::from_utf8_lossy(value).into_owned());
+                    }
+                    attributes = parts.next()?;
+                } else {
+                    attributes = uleb128(rest)?.1;
+                }
+            }
+        }
+    }
+
+    None
+}
+
+impl<const EC: u8, const ED: u8> Elf<EC, ED> {
+    /// The ISA string of the object, such as `rv64i2p1_m2p0_a2p1_c2p0`, which is held
+    /// in the `Tag_RISCV_arch` attribute of its `.riscv.attributes` section. `None` if
+    /// the object has no such section, as is the case for objects which are not RISC-V
+    /// objects and those built by older toolchains, or if the section has no
+    /// `Tag_RISCV_arch` attribute.
+    pub fn riscv_isa_string<R>(&self, reader: &mut R) -> Result<Option<String>, Error>
+    where
+        R: Read + Seek,
+    {
+        let Some(index) = self.section_position(|header| {
+            matches!(
+                header.r#type(),
+                ElfSectionHeaderType::Riscv(ElfSectionHeaderTypeRISCV::Attributes)
+            )
+        }) else {
+            return Ok(None);
+        };
+
+        Ok(riscv_arch_from_attributes::<EC, ED>(
+            &self.section_data(reader, index)?,
+        ))
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod test {
+    use super::riscv_arch_from_attributes;
+    use crate::header::elf::identification::{ElfClass, ElfDataEncoding};
+
+    #[test]
+    fn test_riscv_arch_from_attributes() {
+        let arch = b"rv64i2p1_m2p0_a2p1_f2p2_d2p2_c2p0_zicsr2p0_zifencei2p0\0";
+        // Tag_RISCV_stack_align, which is an integer, then Tag_RISCV_arch, then
+        // Tag_RISCV_unaligned_access
+        let mut attributes = vec![4, 16, 5];
+        attributes.extend_from_slice(arch);
+        attributes.extend_from_slice(&[6, 0]);
+
+        let mut data = vec![b'A'];
+        let size = 4 + b"riscv\0".len() + 1 + 4 + attributes.len();
+        data.extend_from_slice(&(size as u32).to_le_bytes());
+        data.extend_from_slice(b"riscv\0");
+        data.push(1);
+        data.extend_from_slice(&(1 + 4 + attributes.len() as u32).to_le_bytes());
+        data.extend_from_slice(&attributes);
+
+        assert_eq!(
+            riscv_arch_from_attributes::<
+                { ElfClass::Elf64 as u8 },
+                { ElfDataEncoding::LittleEndian as u8 },
+            >(&data)
+            .unwrap(),
+            "rv64i2p1_m2p0_a2p1_f2p2_d2p2_c2p0_zicsr2p0_zifencei2p0"
+        );
+
+        // Attributes of another vendor are skipped
+        let mut other = vec![b'A'];
+        other.extend_from_slice(&(4 + 4u32 + 1 + 4 + 3).to_le_bytes());
+        other.extend_from_slice(b"gnu\0");
+        other.push(1);
+        other.extend_from_slice(&(1 + 4 + 3u32).to_le_bytes());
+        other.extend_from_slice(&[5, b'x', 0]);
+        assert_eq!(
+            riscv_arch_from_attributes::<
+                { ElfClass::Elf64 as u8 },
+                { ElfDataEncoding::LittleEndian as u8 },
+            >(&other),
+            None
+        );
+        other.extend_from_slice(data.get(1..).unwrap());
+        assert_eq!(
+            riscv_arch_from_attributes::<
+                { ElfClass::Elf64 as u8 },
+                { ElfDataEncoding::LittleEndian as u8 },
+            >(&other)
+            .unwrap(),
+            "rv64i2p1_m2p0_a2p1_f2p2_d2p2_c2p0_zicsr2p0_zifencei2p0"
+        );
+    }
+}
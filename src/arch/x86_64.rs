@@ -3,6 +3,7 @@
 // NOTE: x86_64 defines no e_flags values
 
 use crate::{base::ElfWord, error::Error, header::elf::ElfMachine, TryFromWithConfig};
+use alloc::vec;
 
 #[repr(u32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -18,6 +19,14 @@ impl ElfSectionHeaderTypeX86_64 {
     pub const UNWIND: u32 = 0x70000001;
 }
 
+impl core::fmt::Display for ElfSectionHeaderTypeX86_64 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Unwind => "X86_64_UNWIND",
+        })
+    }
+}
+
 impl<const EC: u8, const ED: u8> From<ElfSectionHeaderTypeX86_64> for ElfWord<EC, ED> {
     fn from(value: ElfSectionHeaderTypeX86_64) -> Self {
         Self(value as u32)
@@ -55,3 +64,295 @@ impl<const EC: u8, const ED: u8> TryFromWithConfig<ElfWord<EC, ED>> for ElfSecti
         }
     }
 }
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+/// Relocation Types
+pub enum ElfRelocationTypeX86_64 {
+    /// No relocation
+    None = Self::NONE,
+    /// Direct 64-bit address
+    Direct64 = Self::DIRECT_64,
+    /// PC-relative 32-bit signed address
+    Pc32 = Self::PC_32,
+    /// 32-bit GOT entry
+    Got32 = Self::GOT_32,
+    /// 32-bit PC-relative PLT address
+    Plt32 = Self::PLT_32,
+    /// Copy the symbol at run time
+    Copy = Self::COPY,
+    /// Create a GOT entry
+    GlobalData = Self::GLOBAL_DATA,
+    /// Create a PLT entry
+    JumpSlot = Self::JUMP_SLOT,
+    /// Adjust by the load base
+    Relative = Self::RELATIVE,
+    /// 32-bit signed PC-relative offset to a GOT entry
+    GotPcRelative = Self::GOT_PC_RELATIVE,
+    /// Direct 32-bit zero-extended address
+    Direct32 = Self::DIRECT_32,
+    /// Direct 32-bit sign-extended address
+    Direct32Signed = Self::DIRECT_32_SIGNED,
+    /// Direct 16-bit zero-extended address
+    Direct16 = Self::DIRECT_16,
+    /// 16-bit sign-extended PC-relative address
+    Pc16 = Self::PC_16,
+    /// Direct 8-bit sign-extended address
+    Direct8 = Self::DIRECT_8,
+    /// 8-bit sign-extended PC-relative address
+    Pc8 = Self::PC_8,
+    /// ID of the module containing the symbol
+    DtpModule64 = Self::DTP_MODULE_64,
+    /// Offset in the module's TLS block
+    DtpOffset64 = Self::DTP_OFFSET_64,
+    /// Offset in the initial TLS block
+    TpOffset64 = Self::TP_OFFSET_64,
+    /// 32-bit signed PC-relative offset to a GD GOT entry
+    TlsGd = Self::TLS_GD,
+    /// 32-bit signed PC-relative offset to a LD GOT entry
+    TlsLd = Self::TLS_LD,
+    /// Offset in the TLS block
+    DtpOffset32 = Self::DTP_OFFSET_32,
+    /// 32-bit signed PC-relative offset to an IE GOT entry
+    GotTpOffset = Self::GOT_TP_OFFSET,
+    /// Offset in the initial TLS block
+    TpOffset32 = Self::TP_OFFSET_32,
+    /// PC-relative 64-bit address
+    Pc64 = Self::PC_64,
+    /// 64-bit offset to the GOT
+    GotOffset64 = Self::GOT_OFFSET_64,
+    /// 32-bit signed PC-relative offset to the GOT
+    GotPc32 = Self::GOT_PC_32,
+    /// 64-bit GOT entry offset
+    Got64 = Self::GOT_64,
+    /// 64-bit PC-relative offset to a GOT entry
+    GotPcRelative64 = Self::GOT_PC_RELATIVE_64,
+    /// 64-bit PC-relative offset to the GOT
+    GotPc64 = Self::GOT_PC_64,
+    /// Like `Got64`, but indicates that a PLT entry is needed
+    GotPlt64 = Self::GOT_PLT_64,
+    /// 64-bit GOT-relative offset to a PLT entry
+    PltOffset64 = Self::PLT_OFFSET_64,
+    /// Size of the symbol plus a 32-bit addend
+    Size32 = Self::SIZE_32,
+    /// Size of the symbol plus a 64-bit addend
+    Size64 = Self::SIZE_64,
+    /// 32-bit PC-relative offset to a TLS descriptor in the GOT
+    GotPc32TlsDescriptor = Self::GOT_PC_32_TLS_DESCRIPTOR,
+    /// Marks the call through a TLS descriptor
+    TlsDescriptorCall = Self::TLS_DESCRIPTOR_CALL,
+    /// TLS descriptor
+    TlsDescriptor = Self::TLS_DESCRIPTOR,
+    /// Adjust indirectly by the load base
+    IRelative = Self::I_RELATIVE,
+    /// 64-bit adjust by the load base
+    Relative64 = Self::RELATIVE_64,
+    /// Like `GotPcRelative`, but the instruction may be relaxed
+    GotPcRelativeX = Self::GOT_PC_RELATIVE_X,
+    /// Like `GotPcRelativeX`, for an instruction with a REX prefix
+    RexGotPcRelativeX = Self::REX_GOT_PC_RELATIVE_X,
+}
+
+impl ElfRelocationTypeX86_64 {
+    /// Constant value for [ElfRelocationTypeX86_64::None]
+    pub const NONE: u32 = 0;
+    /// Constant value for [ElfRelocationTypeX86_64::Direct64]
+    pub const DIRECT_64: u32 = 1;
+    /// Constant value for [ElfRelocationTypeX86_64::Pc32]
+    pub const PC_32: u32 = 2;
+    /// Constant value for [ElfRelocationTypeX86_64::Got32]
+    pub const GOT_32: u32 = 3;
+    /// Constant value for [ElfRelocationTypeX86_64::Plt32]
+    pub const PLT_32: u32 = 4;
+    /// Constant value for [ElfRelocationTypeX86_64::Copy]
+    pub const COPY: u32 = 5;
+    /// Constant value for [ElfRelocationTypeX86_64::GlobalData]
+    pub const GLOBAL_DATA: u32 = 6;
+    /// Constant value for [ElfRelocationTypeX86_64::JumpSlot]
+    pub const JUMP_SLOT: u32 = 7;
+    /// Constant value for [ElfRelocationTypeX86_64::Relative]
+    pub const RELATIVE: u32 = 8;
+    /// Constant value for [ElfRelocationTypeX86_64::GotPcRelative]
+    pub const GOT_PC_RELATIVE: u32 = 9;
+    /// Constant value for [ElfRelocationTypeX86_64::Direct32]
+    pub const DIRECT_32: u32 = 10;
+    /// Constant value for [ElfRelocationTypeX86_64::Direct32Signed]
+    pub const DIRECT_32_SIGNED: u32 = 11;
+    /// Constant value for [ElfRelocationTypeX86_64::Direct16]
+    pub const DIRECT_16: u32 = 12;
+    /// Constant value for [ElfRelocationTypeX86_64::Pc16]
+    pub const PC_16: u32 = 13;
+    /// Constant value for [ElfRelocationTypeX86_64::Direct8]
+    pub const DIRECT_8: u32 = 14;
+    /// Constant value for [ElfRelocationTypeX86_64::Pc8]
+    pub const PC_8: u32 = 15;
+    /// Constant value for [ElfRelocationTypeX86_64::DtpModule64]
+    pub const DTP_MODULE_64: u32 = 16;
+    /// Constant value for [ElfRelocationTypeX86_64::DtpOffset64]
+    pub const DTP_OFFSET_64: u32 = 17;
+    /// Constant value for [ElfRelocationTypeX86_64::TpOffset64]
+    pub const TP_OFFSET_64: u32 = 18;
+    /// Constant value for [ElfRelocationTypeX86_64::TlsGd]
+    pub const TLS_GD: u32 = 19;
+    /// Constant value for [ElfRelocationTypeX86_64::TlsLd]
+    pub const TLS_LD: u32 = 20;
+    /// Constant value for [ElfRelocationTypeX86_64::DtpOffset32]
+    pub const DTP_OFFSET_32: u32 = 21;
+    /// Constant value for [ElfRelocationTypeX86_64::GotTpOffset]
+    pub const GOT_TP_OFFSET: u32 = 22;
+    /// Constant value for [ElfRelocationTypeX86_64::TpOffset32]
+    pub const TP_OFFSET_32: u32 = 23;
+    /// Constant value for [ElfRelocationTypeX86_64::Pc64]
+    pub const PC_64: u32 = 24;
+    /// Constant value for [ElfRelocationTypeX86_64::GotOffset64]
+    pub const GOT_OFFSET_64: u32 = 25;
+    /// Constant value for [ElfRelocationTypeX86_64::GotPc32]
+    pub const GOT_PC_32: u32 = 26;
+    /// Constant value for [ElfRelocationTypeX86_64::Got64]
+    pub const GOT_64: u32 = 27;
+    /// Constant value for [ElfRelocationTypeX86_64::GotPcRelative64]
+    pub const GOT_PC_RELATIVE_64: u32 = 28;
+    /// Constant value for [ElfRelocationTypeX86_64::GotPc64]
+    pub const GOT_PC_64: u32 = 29;
+    /// Constant value for [ElfRelocationTypeX86_64::GotPlt64]
+    pub const GOT_PLT_64: u32 = 30;
+    /// Constant value for [ElfRelocationTypeX86_64::PltOffset64]
+    pub const PLT_OFFSET_64: u32 = 31;
+    /// Constant value for [ElfRelocationTypeX86_64::Size32]
+    pub const SIZE_32: u32 = 32;
+    /// Constant value for [ElfRelocationTypeX86_64::Size64]
+    pub const SIZE_64: u32 = 33;
+    /// Constant value for [ElfRelocationTypeX86_64::GotPc32TlsDescriptor]
+    pub const GOT_PC_32_TLS_DESCRIPTOR: u32 = 34;
+    /// Constant value for [ElfRelocationTypeX86_64::TlsDescriptorCall]
+    pub const TLS_DESCRIPTOR_CALL: u32 = 35;
+    /// Constant value for [ElfRelocationTypeX86_64::TlsDescriptor]
+    pub const TLS_DESCRIPTOR: u32 = 36;
+    /// Constant value for [ElfRelocationTypeX86_64::IRelative]
+    pub const I_RELATIVE: u32 = 37;
+    /// Constant value for [ElfRelocationTypeX86_64::Relative64]
+    pub const RELATIVE_64: u32 = 38;
+    /// Constant value for [ElfRelocationTypeX86_64::GotPcRelativeX]
+    pub const GOT_PC_RELATIVE_X: u32 = 41;
+    /// Constant value for [ElfRelocationTypeX86_64::RexGotPcRelativeX]
+    pub const REX_GOT_PC_RELATIVE_X: u32 = 42;
+}
+
+impl core::fmt::Display for ElfRelocationTypeX86_64 {
+    /// Display the relocation type as its name in the ABI, such as `R_X86_64_64`
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::None => "R_X86_64_NONE",
+            Self::Direct64 => "R_X86_64_64",
+            Self::Pc32 => "R_X86_64_PC32",
+            Self::Got32 => "R_X86_64_GOT32",
+            Self::Plt32 => "R_X86_64_PLT32",
+            Self::Copy => "R_X86_64_COPY",
+            Self::GlobalData => "R_X86_64_GLOB_DAT",
+            Self::JumpSlot => "R_X86_64_JUMP_SLOT",
+            Self::Relative => "R_X86_64_RELATIVE",
+            Self::GotPcRelative => "R_X86_64_GOTPCREL",
+            Self::Direct32 => "R_X86_64_32",
+            Self::Direct32Signed => "R_X86_64_32S",
+            Self::Direct16 => "R_X86_64_16",
+            Self::Pc16 => "R_X86_64_PC16",
+            Self::Direct8 => "R_X86_64_8",
+            Self::Pc8 => "R_X86_64_PC8",
+            Self::DtpModule64 => "R_X86_64_DTPMOD64",
+            Self::DtpOffset64 => "R_X86_64_DTPOFF64",
+            Self::TpOffset64 => "R_X86_64_TPOFF64",
+            Self::TlsGd => "R_X86_64_TLSGD",
+            Self::TlsLd => "R_X86_64_TLSLD",
+            Self::DtpOffset32 => "R_X86_64_DTPOFF32",
+            Self::GotTpOffset => "R_X86_64_GOTTPOFF",
+            Self::TpOffset32 => "R_X86_64_TPOFF32",
+            Self::Pc64 => "R_X86_64_PC64",
+            Self::GotOffset64 => "R_X86_64_GOTOFF64",
+            Self::GotPc32 => "R_X86_64_GOTPC32",
+            Self::Got64 => "R_X86_64_GOT64",
+            Self::GotPcRelative64 => "R_X86_64_GOTPCREL64",
+            Self::GotPc64 => "R_X86_64_GOTPC64",
+            Self::GotPlt64 => "R_X86_64_GOTPLT64",
+            Self::PltOffset64 => "R_X86_64_PLTOFF64",
+            Self::Size32 => "R_X86_64_SIZE32",
+            Self::Size64 => "R_X86_64_SIZE64",
+            Self::GotPc32TlsDescriptor => "R_X86_64_GOTPC32_TLSDESC",
+            Self::TlsDescriptorCall => "R_X86_64_TLSDESC_CALL",
+            Self::TlsDescriptor => "R_X86_64_TLSDESC",
+            Self::IRelative => "R_X86_64_IRELATIVE",
+            Self::Relative64 => "R_X86_64_RELATIVE64",
+            Self::GotPcRelativeX => "R_X86_64_GOTPCRELX",
+            Self::RexGotPcRelativeX => "R_X86_64_REX_GOTPCRELX",
+        })
+    }
+}
+
+impl From<ElfRelocationTypeX86_64> for u32 {
+    fn from(value: ElfRelocationTypeX86_64) -> Self {
+        value as u32
+    }
+}
+
+impl TryFromWithConfig<u32> for ElfRelocationTypeX86_64 {
+    type Error = Error;
+
+    /// Decode the type of a relocation entry, as returned by `ElfRelocation::r#type`
+    fn try_from_with(value: u32, config: &mut crate::Config) -> Result<Self, Self::Error> {
+        if !matches!(config.machine, Some(ElfMachine::X86_64)) {
+            return Err(Error::InvalidMachineForRelocationType {
+                machine: config.machine,
+                expected_machines: vec![ElfMachine::X86_64],
+                value,
+            });
+        }
+
+        match value {
+            Self::NONE => Ok(Self::None),
+            Self::DIRECT_64 => Ok(Self::Direct64),
+            Self::PC_32 => Ok(Self::Pc32),
+            Self::GOT_32 => Ok(Self::Got32),
+            Self::PLT_32 => Ok(Self::Plt32),
+            Self::COPY => Ok(Self::Copy),
+            Self::GLOBAL_DATA => Ok(Self::GlobalData),
+            Self::JUMP_SLOT => Ok(Self::JumpSlot),
+            Self::RELATIVE => Ok(Self::Relative),
+            Self::GOT_PC_RELATIVE => Ok(Self::GotPcRelative),
+            Self::DIRECT_32 => Ok(Self::Direct32),
+            Self::DIRECT_32_SIGNED => Ok(Self::Direct32Signed),
+            Self::DIRECT_16 => Ok(Self::Direct16),
+            Self::PC_16 => Ok(Self::Pc16),
+            Self::DIRECT_8 => Ok(Self::Direct8),
+            Self::PC_8 => Ok(Self::Pc8),
+            Self::DTP_MODULE_64 => Ok(Self::DtpModule64),
+            Self::DTP_OFFSET_64 => Ok(Self::DtpOffset64),
+            Self::TP_OFFSET_64 => Ok(Self::TpOffset64),
+            Self::TLS_GD => Ok(Self::TlsGd),
+            Self::TLS_LD => Ok(Self::TlsLd),
+            Self::DTP_OFFSET_32 => Ok(Self::DtpOffset32),
+            Self::GOT_TP_OFFSET => Ok(Self::GotTpOffset),
+            Self::TP_OFFSET_32 => Ok(Self::TpOffset32),
+            Self::PC_64 => Ok(Self::Pc64),
+            Self::GOT_OFFSET_64 => Ok(Self::GotOffset64),
+            Self::GOT_PC_32 => Ok(Self::GotPc32),
+            Self::GOT_64 => Ok(Self::Got64),
+            Self::GOT_PC_RELATIVE_64 => Ok(Self::GotPcRelative64),
+            Self::GOT_PC_64 => Ok(Self::GotPc64),
+            Self::GOT_PLT_64 => Ok(Self::GotPlt64),
+            Self::PLT_OFFSET_64 => Ok(Self::PltOffset64),
+            Self::SIZE_32 => Ok(Self::Size32),
+            Self::SIZE_64 => Ok(Self::Size64),
+            Self::GOT_PC_32_TLS_DESCRIPTOR => Ok(Self::GotPc32TlsDescriptor),
+            Self::TLS_DESCRIPTOR_CALL => Ok(Self::TlsDescriptorCall),
+            Self::TLS_DESCRIPTOR => Ok(Self::TlsDescriptor),
+            Self::I_RELATIVE => Ok(Self::IRelative),
+            Self::RELATIVE_64 => Ok(Self::Relative64),
+            Self::GOT_PC_RELATIVE_X => Ok(Self::GotPcRelativeX),
+            Self::REX_GOT_PC_RELATIVE_X => Ok(Self::RexGotPcRelativeX),
+            _ => Err(Error::InvalidRelocationType {
+                machine: config.machine,
+                value,
+            }),
+        }
+    }
+}
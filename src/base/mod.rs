@@ -1,15 +1,16 @@
 //! Base types for ELF object file structures
 
-use num_traits::FromPrimitive;
-use std::{
-    fmt::Display,
-    io::{Read, Seek, Write},
+use core::{
+    fmt::{Display, LowerHex, UpperHex},
     mem::size_of,
+    ops::Range,
 };
+use num_traits::FromPrimitive;
 
 use crate::{
     error::Error,
     header::elf::identification::{ElfClass, ElfDataEncoding},
+    io::{Read, Seek, Write},
     Config, FromReader, HasWrittenSize, ToWriter,
 };
 
@@ -66,14 +67,8 @@ where
         let mut buf = [0; size_of::<RawElfByte>()];
         reader
             .read_exact(&mut buf)
-            .map_err(|e| Error::Io { kind: e.kind() })
-            .or_else(|e| {
-                if config.ignore.contains(&e) {
-                    Ok(())
-                } else {
-                    Err(e)
-                }
-            })?;
+            .map_err(Error::from)
+            .or_else(|e| if config.ignores(&e) { Ok(()) } else { Err(e) })?;
         Ok(ElfByte(buf[0]))
     }
 }
@@ -85,9 +80,7 @@ where
     type Error = Error;
 
     fn to_writer(&self, writer: &mut W) -> Result<(), Self::Error> {
-        writer
-            .write_all(&[self.0])
-            .map_err(|e| Error::Io { kind: e.kind() })?;
+        writer.write_all(&[self.0])?;
         Ok(())
     }
 }
@@ -97,8 +90,8 @@ impl HasWrittenSize for ElfByte {
 }
 
 impl Display for ElfByte {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.0, f)
     }
 }
 
@@ -131,28 +124,16 @@ where
                 let mut buf = [0; size_of::<RawElf64HalfWord>()];
                 reader
                     .read_exact(&mut buf)
-                    .map_err(|e| Error::Io { kind: e.kind() })
-                    .or_else(|e| {
-                        if config.ignore.contains(&e) {
-                            Ok(())
-                        } else {
-                            Err(e)
-                        }
-                    })?;
+                    .map_err(Error::from)
+                    .or_else(|e| if config.ignores(&e) { Ok(()) } else { Err(e) })?;
                 Ok(ElfHalfWord::<EC, ED>(RawElf64HalfWord::from_le_bytes(buf)))
             }
             (ElfClass::Elf32 | ElfClass::Elf64, ElfDataEncoding::BigEndian) => {
                 let mut buf = [0; size_of::<RawElf64HalfWord>()];
                 reader
                     .read_exact(&mut buf)
-                    .map_err(|e| Error::Io { kind: e.kind() })
-                    .or_else(|e| {
-                        if config.ignore.contains(&e) {
-                            Ok(())
-                        } else {
-                            Err(e)
-                        }
-                    })?;
+                    .map_err(Error::from)
+                    .or_else(|e| if config.ignores(&e) { Ok(()) } else { Err(e) })?;
                 Ok(ElfHalfWord::<EC, ED>(RawElf64HalfWord::from_be_bytes(buf)))
             }
             (_, _) => Err(Error::InvalidConstantClass { class: EC }),
@@ -175,15 +156,11 @@ where
         };
         match (class, data_encoding) {
             (ElfClass::Elf32 | ElfClass::Elf64, ElfDataEncoding::LittleEndian) => {
-                writer
-                    .write_all(&self.0.to_le_bytes())
-                    .map_err(|e| Error::Io { kind: e.kind() })?;
+                writer.write_all(&self.0.to_le_bytes())?;
                 Ok(())
             }
             (ElfClass::Elf32 | ElfClass::Elf64, ElfDataEncoding::BigEndian) => {
-                writer
-                    .write_all(&self.0.to_be_bytes())
-                    .map_err(|e| Error::Io { kind: e.kind() })?;
+                writer.write_all(&self.0.to_be_bytes())?;
                 Ok(())
             }
             (_, _) => Err(Error::InvalidConstantClass { class: EC }),
@@ -202,8 +179,8 @@ impl<const EC: u8, const ED: u8> HasWrittenSize for ElfHalfWord<EC, ED> {
 }
 
 impl<const EC: u8, const ED: u8> Display for ElfHalfWord<EC, ED> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.0, f)
     }
 }
 
@@ -236,28 +213,16 @@ where
                 let mut buf = [0; size_of::<RawElf64Word>()];
                 reader
                     .read_exact(&mut buf)
-                    .map_err(|e| Error::Io { kind: e.kind() })
-                    .or_else(|e| {
-                        if config.ignore.contains(&e) {
-                            Ok(())
-                        } else {
-                            Err(e)
-                        }
-                    })?;
+                    .map_err(Error::from)
+                    .or_else(|e| if config.ignores(&e) { Ok(()) } else { Err(e) })?;
                 Ok(ElfWord::<EC, ED>(RawElf64Word::from_le_bytes(buf)))
             }
             (ElfClass::Elf32 | ElfClass::Elf64, ElfDataEncoding::BigEndian) => {
                 let mut buf = [0; size_of::<RawElf64Word>()];
                 reader
                     .read_exact(&mut buf)
-                    .map_err(|e| Error::Io { kind: e.kind() })
-                    .or_else(|e| {
-                        if config.ignore.contains(&e) {
-                            Ok(())
-                        } else {
-                            Err(e)
-                        }
-                    })?;
+                    .map_err(Error::from)
+                    .or_else(|e| if config.ignores(&e) { Ok(()) } else { Err(e) })?;
                 Ok(ElfWord::<EC, ED>(RawElf64Word::from_be_bytes(buf)))
             }
             (_, _) => Err(Error::InvalidConstantClass { class: EC }),
@@ -280,15 +245,11 @@ where
         };
         match (class, data_encoding) {
             (ElfClass::Elf32 | ElfClass::Elf64, ElfDataEncoding::LittleEndian) => {
-                writer
-                    .write_all(&self.0.to_le_bytes())
-                    .map_err(|e| Error::Io { kind: e.kind() })?;
+                writer.write_all(&self.0.to_le_bytes())?;
                 Ok(())
             }
             (ElfClass::Elf32 | ElfClass::Elf64, ElfDataEncoding::BigEndian) => {
-                writer
-                    .write_all(&self.0.to_be_bytes())
-                    .map_err(|e| Error::Io { kind: e.kind() })?;
+                writer.write_all(&self.0.to_be_bytes())?;
                 Ok(())
             }
             (_, _) => Err(Error::InvalidConstantClass { class: EC }),
@@ -307,8 +268,8 @@ impl<const EC: u8, const ED: u8> HasWrittenSize for ElfWord<EC, ED> {
 }
 
 impl<const EC: u8, const ED: u8> Display for ElfWord<EC, ED> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.0, f)
     }
 }
 
@@ -341,14 +302,8 @@ where
                 let mut buf = [0; size_of::<RawElf64SignedWord>()];
                 reader
                     .read_exact(&mut buf)
-                    .map_err(|e| Error::Io { kind: e.kind() })
-                    .or_else(|e| {
-                        if config.ignore.contains(&e) {
-                            Ok(())
-                        } else {
-                            Err(e)
-                        }
-                    })?;
+                    .map_err(Error::from)
+                    .or_else(|e| if config.ignores(&e) { Ok(()) } else { Err(e) })?;
                 Ok(ElfSignedWord::<EC, ED>(RawElf64SignedWord::from_le_bytes(
                     buf,
                 )))
@@ -357,14 +312,8 @@ where
                 let mut buf = [0; size_of::<RawElf64SignedWord>()];
                 reader
                     .read_exact(&mut buf)
-                    .map_err(|e| Error::Io { kind: e.kind() })
-                    .or_else(|e| {
-                        if config.ignore.contains(&e) {
-                            Ok(())
-                        } else {
-                            Err(e)
-                        }
-                    })?;
+                    .map_err(Error::from)
+                    .or_else(|e| if config.ignores(&e) { Ok(()) } else { Err(e) })?;
                 Ok(ElfSignedWord::<EC, ED>(RawElf64SignedWord::from_be_bytes(
                     buf,
                 )))
@@ -389,15 +338,11 @@ where
         };
         match (class, data_encoding) {
             (ElfClass::Elf32 | ElfClass::Elf64, ElfDataEncoding::LittleEndian) => {
-                writer
-                    .write_all(&self.0.to_le_bytes())
-                    .map_err(|e| Error::Io { kind: e.kind() })?;
+                writer.write_all(&self.0.to_le_bytes())?;
                 Ok(())
             }
             (ElfClass::Elf32 | ElfClass::Elf64, ElfDataEncoding::BigEndian) => {
-                writer
-                    .write_all(&self.0.to_be_bytes())
-                    .map_err(|e| Error::Io { kind: e.kind() })?;
+                writer.write_all(&self.0.to_be_bytes())?;
                 Ok(())
             }
             (_, _) => Err(Error::InvalidConstantClass { class: EC }),
@@ -416,8 +361,8 @@ impl<const EC: u8, const ED: u8> HasWrittenSize for ElfSignedWord<EC, ED> {
 }
 
 impl<const EC: u8, const ED: u8> Display for ElfSignedWord<EC, ED> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.0, f)
     }
 }
 
@@ -450,14 +395,8 @@ where
                 let mut buf = [0; size_of::<RawElf64ExtendedWord>()];
                 reader
                     .read_exact(&mut buf)
-                    .map_err(|e| Error::Io { kind: e.kind() })
-                    .or_else(|e| {
-                        if config.ignore.contains(&e) {
-                            Ok(())
-                        } else {
-                            Err(e)
-                        }
-                    })?;
+                    .map_err(Error::from)
+                    .or_else(|e| if config.ignores(&e) { Ok(()) } else { Err(e) })?;
                 Ok(ElfExtendedWord::<EC, ED>(
                     RawElf64ExtendedWord::from_le_bytes(buf),
                 ))
@@ -466,14 +405,8 @@ where
                 let mut buf = [0; size_of::<RawElf64ExtendedWord>()];
                 reader
                     .read_exact(&mut buf)
-                    .map_err(|e| Error::Io { kind: e.kind() })
-                    .or_else(|e| {
-                        if config.ignore.contains(&e) {
-                            Ok(())
-                        } else {
-                            Err(e)
-                        }
-                    })?;
+                    .map_err(Error::from)
+                    .or_else(|e| if config.ignores(&e) { Ok(()) } else { Err(e) })?;
                 Ok(ElfExtendedWord::<EC, ED>(
                     RawElf64ExtendedWord::from_be_bytes(buf),
                 ))
@@ -498,15 +431,11 @@ where
         };
         match (class, data_encoding) {
             (ElfClass::Elf32 | ElfClass::Elf64, ElfDataEncoding::LittleEndian) => {
-                writer
-                    .write_all(&self.0.to_le_bytes())
-                    .map_err(|e| Error::Io { kind: e.kind() })?;
+                writer.write_all(&self.0.to_le_bytes())?;
                 Ok(())
             }
             (ElfClass::Elf32 | ElfClass::Elf64, ElfDataEncoding::BigEndian) => {
-                writer
-                    .write_all(&self.0.to_be_bytes())
-                    .map_err(|e| Error::Io { kind: e.kind() })?;
+                writer.write_all(&self.0.to_be_bytes())?;
                 Ok(())
             }
             (_, _) => Err(Error::InvalidConstantClass { class: EC }),
@@ -525,8 +454,8 @@ impl<const EC: u8, const ED: u8> HasWrittenSize for ElfExtendedWord<EC, ED> {
 }
 
 impl<const EC: u8, const ED: u8> Display for ElfExtendedWord<EC, ED> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.0, f)
     }
 }
 
@@ -552,14 +481,8 @@ where
                 let mut buf = [0; size_of::<RawElf64SignedExtendedWord>()];
                 reader
                     .read_exact(&mut buf)
-                    .map_err(|e| Error::Io { kind: e.kind() })
-                    .or_else(|e| {
-                        if config.ignore.contains(&e) {
-                            Ok(())
-                        } else {
-                            Err(e)
-                        }
-                    })?;
+                    .map_err(Error::from)
+                    .or_else(|e| if config.ignores(&e) { Ok(()) } else { Err(e) })?;
                 Ok(ElfSignedExtendedWord::<EC, ED>(
                     RawElf64SignedExtendedWord::from_le_bytes(buf),
                 ))
@@ -568,14 +491,8 @@ where
                 let mut buf = [0; size_of::<RawElf64SignedExtendedWord>()];
                 reader
                     .read_exact(&mut buf)
-                    .map_err(|e| Error::Io { kind: e.kind() })
-                    .or_else(|e| {
-                        if config.ignore.contains(&e) {
-                            Ok(())
-                        } else {
-                            Err(e)
-                        }
-                    })?;
+                    .map_err(Error::from)
+                    .or_else(|e| if config.ignores(&e) { Ok(()) } else { Err(e) })?;
                 Ok(ElfSignedExtendedWord::<EC, ED>(
                     RawElf64SignedExtendedWord::from_be_bytes(buf),
                 ))
@@ -600,15 +517,11 @@ where
         };
         match (class, data_encoding) {
             (ElfClass::Elf32 | ElfClass::Elf64, ElfDataEncoding::LittleEndian) => {
-                writer
-                    .write_all(&self.0.to_le_bytes())
-                    .map_err(|e| Error::Io { kind: e.kind() })?;
+                writer.write_all(&self.0.to_le_bytes())?;
                 Ok(())
             }
             (ElfClass::Elf32 | ElfClass::Elf64, ElfDataEncoding::BigEndian) => {
-                writer
-                    .write_all(&self.0.to_be_bytes())
-                    .map_err(|e| Error::Io { kind: e.kind() })?;
+                writer.write_all(&self.0.to_be_bytes())?;
                 Ok(())
             }
             (_, _) => Err(Error::InvalidConstantClass { class: EC }),
@@ -627,8 +540,8 @@ impl<const EC: u8, const ED: u8> HasWrittenSize for ElfSignedExtendedWord<EC, ED
 }
 
 impl<const EC: u8, const ED: u8> Display for ElfSignedExtendedWord<EC, ED> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.0, f)
     }
 }
 
@@ -660,14 +573,8 @@ where
                 let mut buf = [0; size_of::<RawElf32Address>()];
                 reader
                     .read_exact(&mut buf)
-                    .map_err(|e| Error::Io { kind: e.kind() })
-                    .or_else(|e| {
-                        if config.ignore.contains(&e) {
-                            Ok(())
-                        } else {
-                            Err(e)
-                        }
-                    })?;
+                    .map_err(Error::from)
+                    .or_else(|e| if config.ignores(&e) { Ok(()) } else { Err(e) })?;
                 Ok(ElfAddress::<EC, ED>(
                     RawElf32Address::from_le_bytes(buf) as u64
                 ))
@@ -676,14 +583,8 @@ where
                 let mut buf = [0; size_of::<RawElf32Address>()];
                 reader
                     .read_exact(&mut buf)
-                    .map_err(|e| Error::Io { kind: e.kind() })
-                    .or_else(|e| {
-                        if config.ignore.contains(&e) {
-                            Ok(())
-                        } else {
-                            Err(e)
-                        }
-                    })?;
+                    .map_err(Error::from)
+                    .or_else(|e| if config.ignores(&e) { Ok(()) } else { Err(e) })?;
                 Ok(ElfAddress::<EC, ED>(
                     RawElf32Address::from_be_bytes(buf) as u64
                 ))
@@ -692,28 +593,16 @@ where
                 let mut buf = [0; size_of::<RawElf64Address>()];
                 reader
                     .read_exact(&mut buf)
-                    .map_err(|e| Error::Io { kind: e.kind() })
-                    .or_else(|e| {
-                        if config.ignore.contains(&e) {
-                            Ok(())
-                        } else {
-                            Err(e)
-                        }
-                    })?;
+                    .map_err(Error::from)
+                    .or_else(|e| if config.ignores(&e) { Ok(()) } else { Err(e) })?;
                 Ok(ElfAddress::<EC, ED>(RawElf64Address::from_le_bytes(buf)))
             }
             (ElfClass::Elf64, ElfDataEncoding::BigEndian) => {
                 let mut buf = [0; size_of::<RawElf64Address>()];
                 reader
                     .read_exact(&mut buf)
-                    .map_err(|e| Error::Io { kind: e.kind() })
-                    .or_else(|e| {
-                        if config.ignore.contains(&e) {
-                            Ok(())
-                        } else {
-                            Err(e)
-                        }
-                    })?;
+                    .map_err(Error::from)
+                    .or_else(|e| if config.ignores(&e) { Ok(()) } else { Err(e) })?;
                 Ok(ElfAddress::<EC, ED>(RawElf64Address::from_be_bytes(buf)))
             }
             (_, _) => Err(Error::InvalidConstantClass { class: EC }),
@@ -736,27 +625,19 @@ where
         };
         match (class, data_encoding) {
             (ElfClass::Elf32, ElfDataEncoding::LittleEndian) => {
-                writer
-                    .write_all(&(self.0 as u32).to_le_bytes())
-                    .map_err(|e| Error::Io { kind: e.kind() })?;
+                writer.write_all(&(self.0 as u32).to_le_bytes())?;
                 Ok(())
             }
             (ElfClass::Elf32, ElfDataEncoding::BigEndian) => {
-                writer
-                    .write_all(&(self.0 as u32).to_be_bytes())
-                    .map_err(|e| Error::Io { kind: e.kind() })?;
+                writer.write_all(&(self.0 as u32).to_be_bytes())?;
                 Ok(())
             }
             (ElfClass::Elf64, ElfDataEncoding::LittleEndian) => {
-                writer
-                    .write_all(&self.0.to_le_bytes())
-                    .map_err(|e| Error::Io { kind: e.kind() })?;
+                writer.write_all(&self.0.to_le_bytes())?;
                 Ok(())
             }
             (ElfClass::Elf64, ElfDataEncoding::BigEndian) => {
-                writer
-                    .write_all(&self.0.to_be_bytes())
-                    .map_err(|e| Error::Io { kind: e.kind() })?;
+                writer.write_all(&self.0.to_be_bytes())?;
                 Ok(())
             }
             (_, _) => Err(Error::InvalidConstantClass { class: EC }),
@@ -775,8 +656,8 @@ impl<const EC: u8, const ED: u8> HasWrittenSize for ElfAddress<EC, ED> {
 }
 
 impl<const EC: u8, const ED: u8> Display for ElfAddress<EC, ED> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.0, f)
     }
 }
 
@@ -786,6 +667,40 @@ impl<const EC: u8, const ED: u8> From<ElfAddress<EC, ED>> for u64 {
     }
 }
 
+impl<const EC: u8, const ED: u8> ElfAddress<EC, ED> {
+    /// The largest address representable in this class
+    pub const MAX: u64 = match EC {
+        ElfClass::ELF_CLASS_32 => RawElf32Address::MAX as u64,
+        _ => u64::MAX,
+    };
+
+    /// Add `rhs` to this address, returning `None` if the result does not fit in this class
+    pub fn checked_add(self, rhs: u64) -> Option<Self> {
+        self.0
+            .checked_add(rhs)
+            .filter(|value| *value <= Self::MAX)
+            .map(Self)
+    }
+
+    /// Subtract `rhs` from this address, returning `None` if the result is negative
+    pub fn checked_sub(self, rhs: u64) -> Option<Self> {
+        self.0.checked_sub(rhs).map(Self)
+    }
+
+    /// The range of `size` bytes starting at this address, or `None` if its end does not
+    /// fit in this class
+    pub fn range(self, size: u64) -> Option<Range<u64>> {
+        Some(self.0..self.checked_add(size)?.0)
+    }
+
+    /// Whether `address` is in the range of `size` bytes starting at this address. A range
+    /// whose end does not fit in this class contains nothing.
+    pub fn range_contains(self, size: u64, address: u64) -> bool {
+        self.range(size)
+            .is_some_and(|range| range.contains(&address))
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 /// An offset in an ELF file. Represented as 32 bits for class 32 and 64 bits for class 64.
 pub struct ElfOffset<const EC: u8, const ED: u8>(pub u64);
@@ -808,14 +723,8 @@ where
                 let mut buf = [0; size_of::<RawElf32Offset>()];
                 reader
                     .read_exact(&mut buf)
-                    .map_err(|e| Error::Io { kind: e.kind() })
-                    .or_else(|e| {
-                        if config.ignore.contains(&e) {
-                            Ok(())
-                        } else {
-                            Err(e)
-                        }
-                    })?;
+                    .map_err(Error::from)
+                    .or_else(|e| if config.ignores(&e) { Ok(()) } else { Err(e) })?;
                 Ok(ElfOffset::<EC, ED>(
                     RawElf32Offset::from_le_bytes(buf) as u64
                 ))
@@ -824,14 +733,8 @@ where
                 let mut buf = [0; size_of::<RawElf32Offset>()];
                 reader
                     .read_exact(&mut buf)
-                    .map_err(|e| Error::Io { kind: e.kind() })
-                    .or_else(|e| {
-                        if config.ignore.contains(&e) {
-                            Ok(())
-                        } else {
-                            Err(e)
-                        }
-                    })?;
+                    .map_err(Error::from)
+                    .or_else(|e| if config.ignores(&e) { Ok(()) } else { Err(e) })?;
                 Ok(ElfOffset::<EC, ED>(
                     RawElf32Offset::from_be_bytes(buf) as u64
                 ))
@@ -840,28 +743,16 @@ where
                 let mut buf = [0; size_of::<RawElf64Offset>()];
                 reader
                     .read_exact(&mut buf)
-                    .map_err(|e| Error::Io { kind: e.kind() })
-                    .or_else(|e| {
-                        if config.ignore.contains(&e) {
-                            Ok(())
-                        } else {
-                            Err(e)
-                        }
-                    })?;
+                    .map_err(Error::from)
+                    .or_else(|e| if config.ignores(&e) { Ok(()) } else { Err(e) })?;
                 Ok(ElfOffset::<EC, ED>(RawElf64Offset::from_le_bytes(buf)))
             }
             (ElfClass::Elf64, ElfDataEncoding::BigEndian) => {
                 let mut buf = [0; size_of::<RawElf64Offset>()];
                 reader
                     .read_exact(&mut buf)
-                    .map_err(|e| Error::Io { kind: e.kind() })
-                    .or_else(|e| {
-                        if config.ignore.contains(&e) {
-                            Ok(())
-                        } else {
-                            Err(e)
-                        }
-                    })?;
+                    .map_err(Error::from)
+                    .or_else(|e| if config.ignores(&e) { Ok(()) } else { Err(e) })?;
                 Ok(ElfOffset::<EC, ED>(RawElf64Offset::from_be_bytes(buf)))
             }
             (_, _) => Err(Error::InvalidConstantClass { class: EC }),
@@ -884,27 +775,19 @@ where
         };
         match (class, data_encoding) {
             (ElfClass::Elf32, ElfDataEncoding::LittleEndian) => {
-                writer
-                    .write_all(&(self.0 as u32).to_le_bytes())
-                    .map_err(|e| Error::Io { kind: e.kind() })?;
+                writer.write_all(&(self.0 as u32).to_le_bytes())?;
                 Ok(())
             }
             (ElfClass::Elf32, ElfDataEncoding::BigEndian) => {
-                writer
-                    .write_all(&(self.0 as u32).to_be_bytes())
-                    .map_err(|e| Error::Io { kind: e.kind() })?;
+                writer.write_all(&(self.0 as u32).to_be_bytes())?;
                 Ok(())
             }
             (ElfClass::Elf64, ElfDataEncoding::LittleEndian) => {
-                writer
-                    .write_all(&self.0.to_le_bytes())
-                    .map_err(|e| Error::Io { kind: e.kind() })?;
+                writer.write_all(&self.0.to_le_bytes())?;
                 Ok(())
             }
             (ElfClass::Elf64, ElfDataEncoding::BigEndian) => {
-                writer
-                    .write_all(&self.0.to_be_bytes())
-                    .map_err(|e| Error::Io { kind: e.kind() })?;
+                writer.write_all(&self.0.to_be_bytes())?;
                 Ok(())
             }
             (_, _) => Err(Error::InvalidConstantClass { class: EC }),
@@ -923,8 +806,8 @@ impl<const EC: u8, const ED: u8> HasWrittenSize for ElfOffset<EC, ED> {
 }
 
 impl<const EC: u8, const ED: u8> Display for ElfOffset<EC, ED> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.0, f)
     }
 }
 
@@ -934,6 +817,40 @@ impl<const EC: u8, const ED: u8> From<ElfOffset<EC, ED>> for u64 {
     }
 }
 
+impl<const EC: u8, const ED: u8> ElfOffset<EC, ED> {
+    /// The largest offset representable in this class
+    pub const MAX: u64 = match EC {
+        ElfClass::ELF_CLASS_32 => RawElf32Offset::MAX as u64,
+        _ => u64::MAX,
+    };
+
+    /// Add `rhs` to this offset, returning `None` if the result does not fit in this class
+    pub fn checked_add(self, rhs: u64) -> Option<Self> {
+        self.0
+            .checked_add(rhs)
+            .filter(|value| *value <= Self::MAX)
+            .map(Self)
+    }
+
+    /// Subtract `rhs` from this offset, returning `None` if the result is negative
+    pub fn checked_sub(self, rhs: u64) -> Option<Self> {
+        self.0.checked_sub(rhs).map(Self)
+    }
+
+    /// The range of `size` bytes starting at this offset, or `None` if its end does not
+    /// fit in this class
+    pub fn range(self, size: u64) -> Option<Range<u64>> {
+        Some(self.0..self.checked_add(size)?.0)
+    }
+
+    /// Whether `offset` is in the range of `size` bytes starting at this offset. A range
+    /// whose end does not fit in this class contains nothing.
+    pub fn range_contains(self, size: u64, offset: u64) -> bool {
+        self.range(size)
+            .is_some_and(|range| range.contains(&offset))
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 /// A section index in an ELF file. Represented as 16 bits for both classes.
 pub struct ElfSection<const EC: u8, const ED: u8>(pub u16);
@@ -956,28 +873,16 @@ where
                 let mut buf = [0; size_of::<RawElf64Section>()];
                 reader
                     .read_exact(&mut buf)
-                    .map_err(|e| Error::Io { kind: e.kind() })
-                    .or_else(|e| {
-                        if config.ignore.contains(&e) {
-                            Ok(())
-                        } else {
-                            Err(e)
-                        }
-                    })?;
+                    .map_err(Error::from)
+                    .or_else(|e| if config.ignores(&e) { Ok(()) } else { Err(e) })?;
                 Ok(ElfSection::<EC, ED>(RawElf64Section::from_le_bytes(buf)))
             }
             (ElfClass::Elf32 | ElfClass::Elf64, ElfDataEncoding::BigEndian) => {
                 let mut buf = [0; size_of::<RawElf64Section>()];
                 reader
                     .read_exact(&mut buf)
-                    .map_err(|e| Error::Io { kind: e.kind() })
-                    .or_else(|e| {
-                        if config.ignore.contains(&e) {
-                            Ok(())
-                        } else {
-                            Err(e)
-                        }
-                    })?;
+                    .map_err(Error::from)
+                    .or_else(|e| if config.ignores(&e) { Ok(()) } else { Err(e) })?;
                 Ok(ElfSection::<EC, ED>(RawElf64Section::from_be_bytes(buf)))
             }
             (_, _) => Err(Error::InvalidConstantClass { class: EC }),
@@ -1000,15 +905,11 @@ where
         };
         match (class, data_encoding) {
             (ElfClass::Elf32 | ElfClass::Elf64, ElfDataEncoding::LittleEndian) => {
-                writer
-                    .write_all(&self.0.to_le_bytes())
-                    .map_err(|e| Error::Io { kind: e.kind() })?;
+                writer.write_all(&self.0.to_le_bytes())?;
                 Ok(())
             }
             (ElfClass::Elf32 | ElfClass::Elf64, ElfDataEncoding::BigEndian) => {
-                writer
-                    .write_all(&self.0.to_be_bytes())
-                    .map_err(|e| Error::Io { kind: e.kind() })?;
+                writer.write_all(&self.0.to_be_bytes())?;
                 Ok(())
             }
             (_, _) => Err(Error::InvalidConstantClass { class: EC }),
@@ -1027,8 +928,8 @@ impl<const EC: u8, const ED: u8> HasWrittenSize for ElfSection<EC, ED> {
 }
 
 impl<const EC: u8, const ED: u8> Display for ElfSection<EC, ED> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.0, f)
     }
 }
 
@@ -1060,14 +961,8 @@ where
                 let mut buf = [0; size_of::<RawElf64VersionSymbol>()];
                 reader
                     .read_exact(&mut buf)
-                    .map_err(|e| Error::Io { kind: e.kind() })
-                    .or_else(|e| {
-                        if config.ignore.contains(&e) {
-                            Ok(())
-                        } else {
-                            Err(e)
-                        }
-                    })?;
+                    .map_err(Error::from)
+                    .or_else(|e| if config.ignores(&e) { Ok(()) } else { Err(e) })?;
                 Ok(ElfVersionSymbol::<EC, ED>(
                     RawElf64VersionSymbol::from_le_bytes(buf),
                 ))
@@ -1076,14 +971,8 @@ where
                 let mut buf = [0; size_of::<RawElf64VersionSymbol>()];
                 reader
                     .read_exact(&mut buf)
-                    .map_err(|e| Error::Io { kind: e.kind() })
-                    .or_else(|e| {
-                        if config.ignore.contains(&e) {
-                            Ok(())
-                        } else {
-                            Err(e)
-                        }
-                    })?;
+                    .map_err(Error::from)
+                    .or_else(|e| if config.ignores(&e) { Ok(()) } else { Err(e) })?;
                 Ok(ElfVersionSymbol::<EC, ED>(
                     RawElf64VersionSymbol::from_be_bytes(buf),
                 ))
@@ -1108,15 +997,11 @@ where
         };
         match (class, data_encoding) {
             (ElfClass::Elf32 | ElfClass::Elf64, ElfDataEncoding::LittleEndian) => {
-                writer
-                    .write_all(&self.0.to_le_bytes())
-                    .map_err(|e| Error::Io { kind: e.kind() })?;
+                writer.write_all(&self.0.to_le_bytes())?;
                 Ok(())
             }
             (ElfClass::Elf32 | ElfClass::Elf64, ElfDataEncoding::BigEndian) => {
-                writer
-                    .write_all(&self.0.to_be_bytes())
-                    .map_err(|e| Error::Io { kind: e.kind() })?;
+                writer.write_all(&self.0.to_be_bytes())?;
                 Ok(())
             }
             (_, _) => Err(Error::InvalidConstantClass { class: EC }),
@@ -1135,8 +1020,8 @@ impl<const EC: u8, const ED: u8> HasWrittenSize for ElfVersionSymbol<EC, ED> {
 }
 
 impl<const EC: u8, const ED: u8> Display for ElfVersionSymbol<EC, ED> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.0, f)
     }
 }
 
@@ -1146,6 +1031,81 @@ impl<const EC: u8, const ED: u8> From<ElfVersionSymbol<EC, ED>> for u16 {
     }
 }
 
+impl LowerHex for ElfByte {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        LowerHex::fmt(&self.0, f)
+    }
+}
+
+impl UpperHex for ElfByte {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        UpperHex::fmt(&self.0, f)
+    }
+}
+
+/// Implement `LowerHex` and `UpperHex` for integer types, which format the decoded
+/// value, so the data encoding of the object does not affect the output
+macro_rules! impl_hex {
+    ($($type:ident),* $(,)?) => {
+        $(
+            impl<const EC: u8, const ED: u8> LowerHex for $type<EC, ED> {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    LowerHex::fmt(&self.0, f)
+                }
+            }
+
+            impl<const EC: u8, const ED: u8> UpperHex for $type<EC, ED> {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    UpperHex::fmt(&self.0, f)
+                }
+            }
+        )*
+    };
+}
+
+impl_hex!(
+    ElfHalfWord,
+    ElfWord,
+    ElfSignedWord,
+    ElfExtendedWord,
+    ElfSignedExtendedWord,
+    ElfAddress,
+    ElfOffset,
+    ElfSection,
+    ElfVersionSymbol,
+);
+
+/// Convert `value` to a `usize` for use as a size or index, which fails on hosts whose
+/// `usize` is narrower than the value, such as a 64-bit size on a 32-bit host
+pub(crate) fn usize_from(value: u64) -> Result<usize, Error> {
+    usize::try_from(value).map_err(|_| Error::UsizeOverflow { value })
+}
+
+/// Implement `as_usize` for unsigned integer types
+macro_rules! impl_as_usize {
+    ($($type:ident),* $(,)?) => {
+        $(
+            impl<const EC: u8, const ED: u8> $type<EC, ED> {
+                /// Convert this value to a `usize` for use as a size or index, returning
+                /// `Error::UsizeOverflow` if it does not fit in a `usize` on this host
+                pub fn as_usize(self) -> Result<usize, Error> {
+                    usize_from(u64::from(self.0))
+                }
+            }
+        )*
+    };
+}
+
+impl_as_usize!(
+    ElfHalfWord,
+    ElfWord,
+    ElfExtendedWord,
+    ElfAddress,
+    ElfOffset,
+    ElfSection,
+    ElfVersionSymbol,
+);
+
 /// 32-bit little-endian half word
 pub type Elf32LEHalfWord =
     ElfHalfWord<{ ElfClass::Elf32 as u8 }, { ElfDataEncoding::LittleEndian as u8 }>;
@@ -1250,7 +1210,7 @@ pub type Elf64BEVersionSymbol =
     ElfVersionSymbol<{ ElfClass::Elf64 as u8 }, { ElfDataEncoding::BigEndian as u8 }>;
 
 #[allow(clippy::unwrap_used)]
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod test {
     use super::*;
     use paste::paste;
@@ -1652,4 +1612,67 @@ mod test {
         be64vs.to_writer(&mut val_out).unwrap();
         assert_eq!(val_out, val);
     }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let address: Elf32LEAddress = ElfAddress(0xffff_fff0);
+        assert_eq!(address.checked_add(0xf), Some(ElfAddress(0xffff_ffff)));
+        assert_eq!(address.checked_add(0x10), None);
+        assert_eq!(address.checked_sub(0xffff_fff0), Some(ElfAddress(0)));
+        assert_eq!(address.checked_sub(0xffff_fff1), None);
+        assert_eq!(address.range(0x8), Some(0xffff_fff0..0xffff_fff8));
+        assert_eq!(address.range(0x20), None);
+        assert!(address.range_contains(0x8, 0xffff_fff7));
+        assert!(!address.range_contains(0x8, 0xffff_fff8));
+        assert!(!address.range_contains(0x20, 0xffff_fff0));
+
+        let offset: Elf64LEOffset = ElfOffset(0xffff_fff0);
+        assert_eq!(offset.checked_add(0x10), Some(ElfOffset(0x1_0000_0000)));
+        assert_eq!(
+            ElfOffset::<{ ElfClass::Elf64 as u8 }, { ElfDataEncoding::LittleEndian as u8 }>(
+                u64::MAX
+            )
+            .checked_add(1),
+            None
+        );
+        assert_eq!(offset.range(0), Some(0xffff_fff0..0xffff_fff0));
+        assert!(!offset.range_contains(0, 0xffff_fff0));
+        assert_eq!(Elf32BEOffset::MAX, u32::MAX as u64);
+        assert_eq!(Elf64BEOffset::MAX, u64::MAX);
+    }
+
+    #[test]
+    fn test_as_usize() {
+        let half_word: Elf32LEHalfWord = ElfHalfWord(0xffff);
+        assert_eq!(half_word.as_usize(), Ok(0xffff));
+        let offset: Elf64LEOffset = ElfOffset(0x1000);
+        assert_eq!(offset.as_usize(), Ok(0x1000));
+
+        let offset: Elf64LEOffset = ElfOffset(u64::MAX);
+        match usize::BITS {
+            64 => assert_eq!(offset.as_usize(), Ok(usize::MAX)),
+            _ => assert_eq!(
+                offset.as_usize(),
+                Err(Error::UsizeOverflow { value: u64::MAX })
+            ),
+        }
+    }
+
+    #[test]
+    fn test_format() {
+        let address: Elf64BEAddress = ElfAddress(0x401000);
+        assert_eq!(format!("{address:#x}"), "0x401000");
+        assert_eq!(format!("{address:016X}"), "0000000000401000");
+        assert_eq!(format!("{address:>8}"), " 4198400");
+
+        // The decoded value is formatted, whatever the data encoding
+        let word: Elf32LEWord = ElfWord(0xdeadbeef);
+        assert_eq!(format!("{word:x}"), "deadbeef");
+        let word: Elf32BEWord = ElfWord(0xdeadbeef);
+        assert_eq!(format!("{word:x}"), "deadbeef");
+
+        assert_eq!(format!("{:#04x}", ElfByte(0x7f)), "0x7f");
+        let addend: Elf64LESignedExtendedWord = ElfSignedExtendedWord(-8);
+        assert_eq!(format!("{addend}"), "-8");
+    }
 }
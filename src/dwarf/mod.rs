@@ -0,0 +1,448 @@
+//! Name indexes of DWARF debugging information, which are held in the `.debug_names`
+//! section defined by DWARF 5 and in the older `.gdb_index` section built by the GNU
+//! linkers, and map names to the debugging information entries which define them
+
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    error::Error,
+    header::elf::identification::ElfDataEncoding,
+    header::section::string_at,
+    io::{Read, Seek},
+    Elf,
+};
+
+/// `DW_IDX_compile_unit`, the index of the entry's compilation unit in the CU list
+pub const DW_IDX_COMPILE_UNIT: u64 = 0x1;
+/// `DW_IDX_type_unit`, the index of the entry's type unit in the TU lists
+pub const DW_IDX_TYPE_UNIT: u64 = 0x2;
+/// `DW_IDX_die_offset`, the offset of the entry's DIE relative to its unit
+pub const DW_IDX_DIE_OFFSET: u64 = 0x3;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// An entry of a `.debug_names` name index, as reported by `Elf::debug_names`
+pub struct DebugName {
+    /// The name the entry is indexed by
+    pub name: String,
+    /// The DWARF tag of the entry's DIE, such as `DW_TAG_subprogram`
+    pub tag: u64,
+    /// The offset in `.debug_info` of the unit holding the DIE, or `None` if it is in
+    /// a foreign type unit or its unit could not be determined
+    pub unit_offset: Option<u64>,
+    /// The offset in `.debug_info` of the DIE, or `None` if it could not be determined
+    pub die_offset: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// The kind of symbol a `.gdb_index` entry names
+pub enum GdbIndexSymbolKind {
+    /// The kind was not recorded
+    None,
+    /// A type, such as a structure or a typedef
+    Type,
+    /// A variable or an enumerator
+    Variable,
+    /// A function
+    Function,
+    /// Any other kind of symbol
+    Other,
+}
+
+impl From<u32> for GdbIndexSymbolKind {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::None,
+            1 => Self::Type,
+            2 => Self::Variable,
+            3 => Self::Function,
+            _ => Self::Other,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// An entry of a `.gdb_index` symbol table, as reported by `Elf::gdb_index`. A name
+/// defined in several units has an entry for each of them.
+pub struct GdbIndexSymbol {
+    /// The name of the symbol
+    pub name: String,
+    /// The offset of the unit which defines the symbol, in `.debug_info` for a
+    /// compilation unit or in `.debug_types` for a type unit
+    pub unit_offset: u64,
+    /// Whether the unit is a type unit
+    pub type_unit: bool,
+    /// Whether the symbol is local to its unit
+    pub is_static: bool,
+    /// The kind of the symbol
+    pub kind: GdbIndexSymbolKind,
+}
+
+/// A cursor over the data of a DWARF section, which reads values in the object's
+/// data encoding and returns `None` for a value truncated by the end of the data
+struct DwarfData<'a> {
+    data: &'a [u8],
+    offset: usize,
+    little_endian: bool,
+}
+
+impl<'a> DwarfData<'a> {
+    fn new(data: &'a [u8], offset: usize, little_endian: bool) -> Self {
+        Self {
+            data,
+            offset,
+            little_endian,
+        }
+    }
+
+    fn bytes(&mut self, size: usize) -> Option<&'a [u8]> {
+        let bytes = self.data.get(self.offset..self.offset.checked_add(size)?)?;
+        self.offset += size;
+        Some(bytes)
+    }
+
+    fn uint(&mut self, size: usize) -> Option<u64> {
+        let bytes = self.bytes(size)?;
+        let value = |byte: &u8| *byte as u64;
+
+        Some(if self.little_endian {
+            bytes.iter().rev().map(value).fold(0, |v, b| v << 8 | b)
+        } else {
+            bytes.iter().map(value).fold(0, |v, b| v << 8 | b)
+        })
+    }
+
+    fn u32(&mut self) -> Option<u64> {
+        self.uint(4)
+    }
+
+    fn uleb(&mut self) -> Option<u64> {
+        let mut value = 0u64;
+        let mut shift = 0u32;
+
+        loop {
+            let byte = *self.bytes(1)?.first()?;
+            value |= ((byte & 0x7f) as u64).checked_shl(shift).unwrap_or(0);
+            shift = shift.saturating_add(7);
+
+            if byte & 0x80 == 0 {
+                return Some(value);
+            }
+        }
+    }
+
+    /// Read a value of the DWARF attribute form `form`, where `offset_size` is the
+    /// size of a section offset. Values wider than 64 bits, which are only type
+    /// signatures, are read as `None`, as are forms which are not valid in an index.
+    fn form(&mut self, form: u64, offset_size: usize) -> Option<Option<u64>> {
+        match form {
+            // DW_FORM_flag_present
+            0x19 => Some(Some(1)),
+            // DW_FORM_data1, DW_FORM_ref1, DW_FORM_flag
+            0x0b | 0x11 | 0x0c => self.uint(1).map(Some),
+            // DW_FORM_data2, DW_FORM_ref2
+            0x05 | 0x12 => self.uint(2).map(Some),
+            // DW_FORM_data4, DW_FORM_ref4
+            0x06 | 0x13 => self.uint(4).map(Some),
+            // DW_FORM_data8, DW_FORM_ref8, DW_FORM_ref_sig8
+            0x07 | 0x14 | 0x20 => self.uint(8).map(Some),
+            // DW_FORM_data16
+            0x1e => self.bytes(16).map(|_| None),
+            // DW_FORM_udata, DW_FORM_ref_udata, DW_FORM_sdata
+            0x0f | 0x15 | 0x0d => self.uleb().map(Some),
+            // DW_FORM_sec_offset, DW_FORM_strp, DW_FORM_line_strp
+            0x17 | 0x0e | 0x1f => self.uint(offset_size).map(Some),
+            _ => None,
+        }
+    }
+}
+
+/// An abbreviation of a `.debug_names` name index
+struct NameAbbreviation {
+    code: u64,
+    tag: u64,
+    attributes: Vec<(u64, u64)>,
+}
+
+impl<const EC: u8, const ED: u8> Elf<EC, ED> {
+    /// The data of the first section named `name`, or `None` if there is no such section
+    fn named_section_data<R>(&self, reader: &mut R, name: &str) -> Result<Option<Vec<u8>>, Error>
+    where
+        R: Read + Seek,
+    {
+        self.section_by_name(name)
+            .map(|(index, _)| index)
+            .map(|index| self.section_data(reader, index))
+            .transpose()
+    }
+
+    /// Read the entries of the `.debug_names` section, which holds a DWARF 5 name
+    /// index for each compilation unit or for the whole object. Each name is resolved
+    /// through the `.debug_str` section, and each entry of its entry pool is reported
+    /// with the offset in `.debug_info` of its DIE. Decoding stops at the first name
+    /// index which is truncated or which uses a form that cannot appear in an index.
+    /// Objects without debugging information have no such section, in which case no
+    /// entries are returned.
+    pub fn debug_names<R>(&self, reader: &mut R) -> Result<Vec<DebugName>, Error>
+    where
+        R: Read + Seek,
+    {
+        let Some(data) = self.named_section_data(reader, ".debug_names")? else {
+            return Ok(Vec::new());
+        };
+        let strings = self
+            .named_section_data(reader, ".debug_str")?
+            .unwrap_or_default();
+        let little_endian = ED == ElfDataEncoding::LittleEndian as u8;
+        let mut names = Vec::new();
+        let mut offset = 0;
+
+        while offset < data.len() {
+            match debug_names_index(&data, &strings, offset, little_endian, &mut names) {
+                Some(next) => offset = next,
+                None => break,
+            }
+        }
+
+        Ok(names)
+    }
+
+    /// Read the symbol table of the `.gdb_index` section, in which each name is mapped
+    /// to the units which define it. The section is always little-endian. Only versions
+    /// 7 and 8 of the section record the kind of each symbol, and older versions are
+    /// rejected. Objects not linked with `--gdb-index` have no such section, in which
+    /// case no entries are returned.
+    pub fn gdb_index<R>(&self, reader: &mut R) -> Result<Vec<GdbIndexSymbol>, Error>
+    where
+        R: Read + Seek,
+    {
+        let Some(data) = self.named_section_data(reader, ".gdb_index")? else {
+            return Ok(Vec::new());
+        };
+        let mut header = DwarfData::new(&data, 0, true);
+        let version = header.u32().unwrap_or_default();
+
+        if !(7..=8).contains(&version) {
+            return Err(Error::UnsupportedGdbIndexVersion {
+                version: version as u32,
+            });
+        }
+
+        let offsets = (0..5)
+            .map(|_| header.u32().map(|offset| offset as usize))
+            .collect::<Option<Vec<_>>>()
+            .unwrap_or_default();
+        let &[cu_list, types_list, address_area, symbol_table, constant_pool] = &offsets[..] else {
+            return Ok(Vec::new());
+        };
+
+        let units = |list: usize, end: usize, size: usize| {
+            let mut units = DwarfData::new(&data, list, true);
+            (0..end.saturating_sub(list) / size)
+                .map_while(|_| {
+                    let offset = units.uint(8)?;
+                    units.bytes(size - 8)?;
+                    Some(offset)
+                })
+                .collect::<Vec<_>>()
+        };
+        let compilation_units = units(cu_list, types_list, 16);
+        let type_units = units(types_list, address_area, 24);
+        let mut symbols = Vec::new();
+        let mut slots = DwarfData::new(&data, symbol_table, true);
+
+        for _ in 0..constant_pool.saturating_sub(symbol_table) / 8 {
+            let (Some(name), Some(vector)) = (slots.u32(), slots.u32()) else {
+                break;
+            };
+
+            if name == 0 && vector == 0 {
+                continue;
+            }
+
+            let Some(name) = constant_pool
+                .checked_add(name as usize)
+                .and_then(|offset| string_at(&data, offset))
+            else {
+                continue;
+            };
+            let mut vector = DwarfData::new(&data, constant_pool + vector as usize, true);
+            let count = vector.u32().unwrap_or_default();
+
+            for _ in 0..count {
+                let Some(attributes) = vector.u32() else {
+                    break;
+                };
+                let unit = (attributes & 0xffffff) as usize;
+                let (unit_offset, type_unit) = match compilation_units.get(unit) {
+                    Some(offset) => (*offset, false),
+                    None => match type_units.get(unit - compilation_units.len()) {
+                        Some(offset) => (*offset, true),
+                        None => continue,
+                    },
+                };
+
+                symbols.push(GdbIndexSymbol {
+                    name: name.clone(),
+                    unit_offset,
+                    type_unit,
+                    is_static: attributes & (1 << 31) != 0,
+                    kind: GdbIndexSymbolKind::from(((attributes >> 28) & 0x7) as u32),
+                });
+            }
+        }
+
+        Ok(symbols)
+    }
+
+    /// Look up `name` in the object's name indexes, and return the offsets in
+    /// `.debug_info` of the DIEs it names from `.debug_names`. If the object has no
+    /// `.debug_names` section, the offsets of the compilation units which define the
+    /// name are returned from `.gdb_index` instead.
+    pub fn lookup_debug_name<R>(&self, reader: &mut R, name: &str) -> Result<Vec<u64>, Error>
+    where
+        R: Read + Seek,
+    {
+        if self.section_by_name(".debug_names").is_some() {
+            return Ok(self
+                .debug_names(reader)?
+                .into_iter()
+                .filter(|entry| entry.name == name)
+                .filter_map(|entry| entry.die_offset)
+                .collect());
+        }
+
+        Ok(self
+            .gdb_index(reader)?
+            .into_iter()
+            .filter(|symbol| symbol.name == name && !symbol.type_unit)
+            .map(|symbol| symbol.unit_offset)
+            .collect())
+    }
+}
+
+/// Decode the name index at `offset` in the `.debug_names` data, appending its entries
+/// to `names`, and return the offset of the next name index, or `None` if the index is
+/// malformed
+fn debug_names_index(
+    data: &[u8],
+    strings: &[u8],
+    offset: usize,
+    little_endian: bool,
+    names: &mut Vec<DebugName>,
+) -> Option<usize> {
+    let mut header = DwarfData::new(data, offset, little_endian);
+    let (length, offset_size) = match header.u32()? {
+        0xffffffff => (header.uint(8)?, 8),
+        length => (length, 4),
+    };
+    let end = header.offset.checked_add(usize::try_from(length).ok()?)?;
+    let data = data.get(..end)?;
+    let mut header = DwarfData::new(data, header.offset, little_endian);
+
+    // The version and padding
+    header.bytes(4)?;
+
+    let cu_count = header.u32()? as usize;
+    let local_tu_count = header.u32()? as usize;
+    let foreign_tu_count = header.u32()? as usize;
+    let bucket_count = header.u32()? as usize;
+    let name_count = header.u32()? as usize;
+    let abbreviations_size = header.u32()? as usize;
+    let augmentation_size = header.u32()? as usize;
+
+    header.bytes(augmentation_size.checked_add(3)? & !3)?;
+
+    let mut units = Vec::new();
+
+    for _ in 0..cu_count.checked_add(local_tu_count)? {
+        units.push(header.uint(offset_size)?);
+    }
+
+    header.bytes(foreign_tu_count.checked_mul(8)?)?;
+    header.bytes(bucket_count.checked_mul(4)?)?;
+
+    if bucket_count != 0 {
+        header.bytes(name_count.checked_mul(4)?)?;
+    }
+
+    let string_offsets = header.offset;
+    let entry_offsets = string_offsets.checked_add(name_count.checked_mul(offset_size)?)?;
+    let abbreviation_table = entry_offsets.checked_add(name_count.checked_mul(offset_size)?)?;
+    let entry_pool = abbreviation_table.checked_add(abbreviations_size)?;
+    let mut abbreviations = Vec::new();
+    let mut table = DwarfData::new(data.get(..entry_pool)?, abbreviation_table, little_endian);
+
+    loop {
+        let code = table.uleb()?;
+
+        if code == 0 {
+            break;
+        }
+
+        let tag = table.uleb()?;
+        let mut attributes = Vec::new();
+
+        loop {
+            match (table.uleb()?, table.uleb()?) {
+                (0, 0) => break,
+                attribute => attributes.push(attribute),
+            }
+        }
+
+        abbreviations.push(NameAbbreviation {
+            code,
+            tag,
+            attributes,
+        });
+    }
+
+    let mut string_offsets = DwarfData::new(data, string_offsets, little_endian);
+    let mut entry_offsets = DwarfData::new(data, entry_offsets, little_endian);
+
+    for _ in 0..name_count {
+        let string = usize::try_from(string_offsets.uint(offset_size)?).ok()?;
+        let name = string_at(strings, string).unwrap_or_default();
+        let entry = usize::try_from(entry_offsets.uint(offset_size)?).ok()?;
+        let mut entries = DwarfData::new(data, entry_pool.checked_add(entry)?, little_endian);
+
+        loop {
+            let code = entries.uleb()?;
+
+            if code == 0 {
+                break;
+            }
+
+            let abbreviation = abbreviations.iter().find(|a| a.code == code)?;
+            let mut unit = (cu_count == 1).then_some(0);
+            let mut die = None;
+
+            for (index, form) in &abbreviation.attributes {
+                let value = entries.form(*form, offset_size)?;
+
+                match (*index, value) {
+                    (DW_IDX_COMPILE_UNIT, Some(value)) => unit = usize::try_from(value).ok(),
+                    (DW_IDX_TYPE_UNIT, Some(value)) => {
+                        unit = usize::try_from(value)
+                            .ok()
+                            .and_then(|value| value.checked_add(cu_count))
+                    }
+                    (DW_IDX_DIE_OFFSET, value) => die = value,
+                    _ => {}
+                }
+            }
+
+            let unit_offset = unit.and_then(|unit| units.get(unit)).copied();
+
+            names.push(DebugName {
+                name: name.clone(),
+                tag: abbreviation.tag,
+                unit_offset,
+                die_offset: unit_offset
+                    .zip(die)
+                    .and_then(|(unit, die)| unit.checked_add(die)),
+            });
+        }
+    }
+
+    Some(end)
+}
@@ -0,0 +1,452 @@
+//! Implementation of ELF dynamic section entries, which are held in the `SHT_DYNAMIC`
+//! section and `PT_DYNAMIC` segment and describe the object to the dynamic linker
+
+use alloc::vec::Vec;
+use bitflags::bitflags;
+use num_traits::FromPrimitive;
+use typed_builder::TypedBuilder;
+
+use crate::{
+    base::{ElfByte, ElfExtendedWord, ElfSignedExtendedWord, ElfSignedWord, ElfWord},
+    error::Error,
+    from_primitive,
+    header::{
+        elf::identification::ElfClass, program::ElfProgramHeaderType, section::ElfSectionHeaderType,
+    },
+    io::{Cursor, Read, Seek},
+    Config, Elf, FromReader,
+};
+
+from_primitive! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[non_exhaustive]
+    /// The tag of a dynamic array entry, which determines the interpretation of its value
+    enum ElfDynamicTag<const EC: u8, const ED: u8> {
+        /// Marks the end of the dynamic array (`DT_NULL`)
+        Null = 0,
+        /// The string table offset of the name of a needed library (`DT_NEEDED`)
+        Needed = 1,
+        /// The total size of the relocation entries associated with the procedure
+        /// linkage table (`DT_PLTRELSZ`)
+        PltRelocationSize = 2,
+        /// An address associated with the procedure linkage table or global offset table
+        /// (`DT_PLTGOT`)
+        PltGot = 3,
+        /// The address of the symbol hash table (`DT_HASH`)
+        Hash = 4,
+        /// The address of the dynamic string table (`DT_STRTAB`)
+        StringTable = 5,
+        /// The address of the dynamic symbol table (`DT_SYMTAB`)
+        SymbolTable = 6,
+        /// The address of the relocation table with explicit addends (`DT_RELA`)
+        RelocationExplicit = 7,
+        /// The total size of the `DT_RELA` relocation table (`DT_RELASZ`)
+        RelocationExplicitSize = 8,
+        /// The size of a `DT_RELA` relocation entry (`DT_RELAENT`)
+        RelocationExplicitEntrySize = 9,
+        /// The size of the dynamic string table (`DT_STRSZ`)
+        StringTableSize = 10,
+        /// The size of a dynamic symbol table entry (`DT_SYMENT`)
+        SymbolEntrySize = 11,
+        /// The address of the initialization function (`DT_INIT`)
+        Initializer = 12,
+        /// The address of the termination function (`DT_FINI`)
+        Finalizer = 13,
+        /// The string table offset of the name of the shared object (`DT_SONAME`)
+        SharedObjectName = 14,
+        /// The string table offset of a library search path, superseded by `DT_RUNPATH`
+        /// (`DT_RPATH`)
+        RunPathDeprecated = 15,
+        /// Symbol resolution starts from the object itself (`DT_SYMBOLIC`)
+        Symbolic = 16,
+        /// The address of the relocation table with implicit addends (`DT_REL`)
+        RelocationImplicit = 17,
+        /// The total size of the `DT_REL` relocation table (`DT_RELSZ`)
+        RelocationImplicitSize = 18,
+        /// The size of a `DT_REL` relocation entry (`DT_RELENT`)
+        RelocationImplicitEntrySize = 19,
+        /// The type of relocation entry the procedure linkage table uses, `DT_REL` or
+        /// `DT_RELA` (`DT_PLTREL`)
+        PltRelocationType = 20,
+        /// Used for debugging, with contents not specified by the ABI (`DT_DEBUG`)
+        Debug = 21,
+        /// Relocation entries may modify a non-writable segment (`DT_TEXTREL`)
+        TextRelocation = 22,
+        /// The address of the relocation entries associated with the procedure linkage
+        /// table (`DT_JMPREL`)
+        JumpRelocation = 23,
+        /// All relocations must be processed before control is transferred to the
+        /// program (`DT_BIND_NOW`)
+        BindNow = 24,
+        /// The address of the array of initialization functions (`DT_INIT_ARRAY`)
+        InitializerArray = 25,
+        /// The address of the array of termination functions (`DT_FINI_ARRAY`)
+        FinalizerArray = 26,
+        /// The size of the `DT_INIT_ARRAY` array (`DT_INIT_ARRAYSZ`)
+        InitializerArraySize = 27,
+        /// The size of the `DT_FINI_ARRAY` array (`DT_FINI_ARRAYSZ`)
+        FinalizerArraySize = 28,
+        /// The string table offset of a library search path (`DT_RUNPATH`)
+        RunPath = 29,
+        /// Flag values specific to the object (`DT_FLAGS`)
+        Flags = 30,
+        /// The address of the array of pre-initialization functions
+        /// (`DT_PREINIT_ARRAY`)
+        PreInitializerArray = 32,
+        /// The size of the `DT_PREINIT_ARRAY` array (`DT_PREINIT_ARRAYSZ`)
+        PreInitializerArraySize = 33,
+        /// The address of the `SHT_SYMTAB_SHNDX` section of the dynamic symbol table
+        /// (`DT_SYMTAB_SHNDX`)
+        SymbolTableSectionHeaderIndex = 34,
+        /// The total size of the `DT_RELR` relocation table (`DT_RELRSZ`)
+        RelRSize = 35,
+        /// The address of the relative relocation table (`DT_RELR`)
+        RelR = 36,
+        /// The size of a `DT_RELR` relocation entry (`DT_RELRENT`)
+        RelREntrySize = 37,
+        /// The address of the GNU symbol hash table (`DT_GNU_HASH`)
+        GnuHash = 0x6fff_fef5,
+        /// The address of the symbol version table (`DT_VERSYM`)
+        VersionSymbol = 0x6fff_fff0,
+        /// The number of relative relocations in the `DT_RELA` relocation table
+        /// (`DT_RELACOUNT`)
+        RelocationExplicitCount = 0x6fff_fff9,
+        /// The number of relative relocations in the `DT_REL` relocation table
+        /// (`DT_RELCOUNT`)
+        RelocationImplicitCount = 0x6fff_fffa,
+        /// Additional flag values specific to the object (`DT_FLAGS_1`)
+        Flags1 = 0x6fff_fffb,
+        /// The address of the version definition table (`DT_VERDEF`)
+        VersionDefinition = 0x6fff_fffc,
+        /// The number of entries in the version definition table (`DT_VERDEFNUM`)
+        VersionDefinitionCount = 0x6fff_fffd,
+        /// The address of the version requirement table (`DT_VERNEED`)
+        VersionNeeded = 0x6fff_fffe,
+        /// The number of entries in the version requirement table (`DT_VERNEEDNUM`)
+        VersionNeededCount = 0x6fff_ffff,
+        /// The string table offset of the name of a shared object whose symbols take
+        /// precedence over the object's own (`DT_AUXILIARY`)
+        Auxiliary = 0x7fff_fffd,
+        /// The string table offset of the name of a shared object the object is a
+        /// filter for (`DT_FILTER`)
+        Filter = 0x7fff_ffff,
+    }
+    /// A tag which is not otherwise defined, including OS and processor-specific tags
+    other Other(u64);
+}
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    /// The flags held by the `DT_FLAGS` entry of the dynamic array
+    pub struct ElfDynamicFlags: u64 {
+        /// The object may reference the `$ORIGIN` substitution string
+        const ORIGIN = 0x1;
+        /// Symbol resolution starts from the object itself, rather than the executable
+        const SYMBOLIC = 0x2;
+        /// Relocation entries may modify a non-writable segment
+        const TEXTREL = 0x4;
+        /// All relocations must be processed before control is transferred to the
+        /// program, which together with a `PT_GNU_RELRO` segment gives full RELRO
+        const BIND_NOW = 0x8;
+        /// The object uses the static thread-local storage model, and cannot be loaded
+        /// with `dlopen`
+        const STATIC_TLS = 0x10;
+    }
+}
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    /// The flags held by the `DT_FLAGS_1` entry of the dynamic array
+    pub struct ElfDynamicFlags1: u64 {
+        /// All relocations must be processed before control is transferred to the
+        /// program
+        const NOW = 0x1;
+        /// The object's symbols are made available to subsequently loaded objects
+        const GLOBAL = 0x2;
+        /// The object is a member of a group
+        const GROUP = 0x4;
+        /// The object cannot be unloaded
+        const NODELETE = 0x8;
+        /// Filtees of the object are loaded immediately
+        const LOADFLTR = 0x10;
+        /// The object is initialized before any other object
+        const INITFIRST = 0x20;
+        /// The object cannot be loaded with `dlopen`
+        const NOOPEN = 0x40;
+        /// The object requires `$ORIGIN` processing
+        const ORIGIN = 0x80;
+        /// Direct binding is enabled for the object
+        const DIRECT = 0x100;
+        /// Reserved for internal use by the Solaris linker
+        const TRANS = 0x200;
+        /// The object's symbols interpose all symbols except those of the executable
+        const INTERPOSE = 0x400;
+        /// The default library search path is ignored
+        const NODEFLIB = 0x800;
+        /// The object is not dumped by `dldump`
+        const NODUMP = 0x1000;
+        /// The object is a configuration alternative object
+        const CONFALT = 0x2000;
+        /// The filtee terminates the filter's search
+        const ENDFILTEE = 0x4000;
+        /// Displacement relocations have been applied
+        const DISPRELDNE = 0x8000;
+        /// Displacement relocations are pending
+        const DISPRELPND = 0x10000;
+        /// The object has no symbols available for direct binding
+        const NODIRECT = 0x20000;
+        /// Reserved for internal use by the Solaris linker
+        const IGNMULDEF = 0x40000;
+        /// Reserved for internal use by the Solaris linker
+        const NOKSYMS = 0x80000;
+        /// Reserved for internal use by the Solaris linker
+        const NOHDR = 0x100000;
+        /// The object has been modified after it was built
+        const EDITED = 0x200000;
+        /// Reserved for internal use by the Solaris linker
+        const NORELOC = 0x400000;
+        /// The object has individual symbols which interpose other symbols
+        const SYMINTPOSE = 0x800000;
+        /// The object requires global auditing
+        const GLOBAUDIT = 0x1000000;
+        /// The object defines singleton symbols
+        const SINGLETON = 0x2000000;
+        /// The object is a stub
+        const STUB = 0x4000000;
+        /// The object is a position-independent executable
+        const PIE = 0x8000000;
+        /// The object is a kernel module
+        const KMOD = 0x10000000;
+        /// The object's filtees are weak
+        const WEAKFILTER = 0x20000000;
+        /// Common symbols are not allocated by the object
+        const NOCOMMON = 0x40000000;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, TypedBuilder)]
+/// ELF 32-bit Dynamic Array Entry
+pub struct Elf32DynamicEntry<const ED: u8> {
+    /// The kind of entry, which determines the interpretation of `value`
+    pub tag: ElfSignedWord<{ ElfClass::Elf32 as u8 }, ED>,
+    /// An integer or a virtual address, depending on the tag
+    pub value: ElfWord<{ ElfClass::Elf32 as u8 }, ED>,
+}
+
+impl<R, const ED: u8> FromReader<R> for Elf32DynamicEntry<ED>
+where
+    R: Read + Seek,
+{
+    type Error = Error;
+
+    fn from_reader_with(reader: &mut R, config: &mut Config) -> Result<Self, Self::Error> {
+        let tag = ElfSignedWord::<{ ElfClass::Elf32 as u8 }, ED>::from_reader_with(reader, config)?;
+        let value = ElfWord::<{ ElfClass::Elf32 as u8 }, ED>::from_reader_with(reader, config)?;
+
+        Ok(Self { tag, value })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, TypedBuilder)]
+/// ELF 64-bit Dynamic Array Entry
+pub struct Elf64DynamicEntry<const ED: u8> {
+    /// The kind of entry, which determines the interpretation of `value`
+    pub tag: ElfSignedExtendedWord<{ ElfClass::Elf64 as u8 }, ED>,
+    /// An integer or a virtual address, depending on the tag
+    pub value: ElfExtendedWord<{ ElfClass::Elf64 as u8 }, ED>,
+}
+
+impl<R, const ED: u8> FromReader<R> for Elf64DynamicEntry<ED>
+where
+    R: Read + Seek,
+{
+    type Error = Error;
+
+    fn from_reader_with(reader: &mut R, config: &mut Config) -> Result<Self, Self::Error> {
+        let tag = ElfSignedExtendedWord::<{ ElfClass::Elf64 as u8 }, ED>::from_reader_with(
+            reader, config,
+        )?;
+        let value =
+            ElfExtendedWord::<{ ElfClass::Elf64 as u8 }, ED>::from_reader_with(reader, config)?;
+
+        Ok(Self { tag, value })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// ELF dynamic array entry for either 32-bit or 64-bit ELF files
+pub enum ElfDynamicEntry<const EC: u8, const ED: u8> {
+    /// A 32-bit ELF dynamic array entry
+    Elf32(Elf32DynamicEntry<ED>),
+    /// A 64-bit ELF dynamic array entry
+    Elf64(Elf64DynamicEntry<ED>),
+}
+
+impl<const EC: u8, const ED: u8> ElfDynamicEntry<EC, ED> {
+    /// The raw tag of the entry
+    pub fn tag(&self) -> i64 {
+        match self {
+            ElfDynamicEntry::Elf32(entry) => entry.tag.0 as i64,
+            ElfDynamicEntry::Elf64(entry) => entry.tag.0,
+        }
+    }
+
+    /// The kind of entry, which determines the interpretation of `value`
+    pub fn r#type(&self) -> ElfDynamicTag<EC, ED> {
+        match self {
+            ElfDynamicEntry::Elf32(entry) => ElfDynamicTag::from(entry.tag.0 as u32 as u64),
+            ElfDynamicEntry::Elf64(entry) => ElfDynamicTag::from(entry.tag.0 as u64),
+        }
+    }
+
+    /// The value of the entry, which is an integer or a virtual address depending on
+    /// the tag
+    pub fn value(&self) -> u64 {
+        match self {
+            ElfDynamicEntry::Elf32(entry) => entry.value.0 as u64,
+            ElfDynamicEntry::Elf64(entry) => entry.value.0,
+        }
+    }
+}
+
+impl<R, const EC: u8, const ED: u8> FromReader<R> for ElfDynamicEntry<EC, ED>
+where
+    R: Read + Seek,
+{
+    type Error = Error;
+
+    fn from_reader_with(reader: &mut R, config: &mut Config) -> Result<Self, Self::Error> {
+        match ElfClass::from_u8(EC).ok_or(Error::InvalidClass { class: ElfByte(EC) })? {
+            ElfClass::None => Err(Error::InvalidClass { class: ElfByte(EC) }),
+            ElfClass::Elf32 => Ok(ElfDynamicEntry::Elf32(Elf32DynamicEntry::from_reader_with(
+                reader, config,
+            )?)),
+            ElfClass::Elf64 => Ok(ElfDynamicEntry::Elf64(Elf64DynamicEntry::from_reader_with(
+                reader, config,
+            )?)),
+        }
+    }
+}
+
+impl<const EC: u8, const ED: u8> Elf<EC, ED> {
+    /// Read the entries of the dynamic array before its terminating `DT_NULL` entry,
+    /// from the `SHT_DYNAMIC` section, or from the `PT_DYNAMIC` segment if the object
+    /// has no `SHT_DYNAMIC` section. Objects which are not dynamically linked have no
+    /// dynamic array, in which case no entries are returned. A dynamic array which is
+    /// missing its `DT_NULL` entry ends with its last complete entry.
+    pub fn dynamic_entries<R>(&self, reader: &mut R) -> Result<Vec<ElfDynamicEntry<EC, ED>>, Error>
+    where
+        R: Read + Seek,
+    {
+        let data = match self
+            .section_position(|header| matches!(header.r#type(), ElfSectionHeaderType::Dynamic))
+        {
+            Some(index) => self.section_data(reader, index)?,
+            None => match self
+                .program_headers
+                .iter()
+                .find(|header| matches!(header.r#type(), ElfProgramHeaderType::Dynamic))
+            {
+                Some(header) => header.data_from_reader(reader)?,
+                None => return Ok(Vec::new()),
+            },
+        };
+        let mut config = Config::default();
+        let mut cursor = Cursor::new(data);
+        let mut entries = Vec::new();
+
+        while let Ok(entry) = ElfDynamicEntry::from_reader_with(&mut cursor, &mut config) {
+            if entry.r#type() == ElfDynamicTag::Null {
+                break;
+            }
+
+            entries.push(entry);
+        }
+
+        Ok(entries)
+    }
+
+    /// The first entry of the dynamic array with the tag `tag`, for tags which appear
+    /// at most once, such as `DT_SONAME`, or `None` if there is no such entry
+    pub fn dynamic_entry<R>(
+        &self,
+        reader: &mut R,
+        tag: ElfDynamicTag<EC, ED>,
+    ) -> Result<Option<ElfDynamicEntry<EC, ED>>, Error>
+    where
+        R: Read + Seek,
+    {
+        Ok(self
+            .dynamic_entries(reader)?
+            .into_iter()
+            .find(|entry| entry.r#type() == tag))
+    }
+
+    /// The entries of the dynamic array with the tag `tag`, in order, for tags which may
+    /// appear several times, such as `DT_NEEDED`
+    pub fn dynamic_entries_of<R>(
+        &self,
+        reader: &mut R,
+        tag: ElfDynamicTag<EC, ED>,
+    ) -> Result<Vec<ElfDynamicEntry<EC, ED>>, Error>
+    where
+        R: Read + Seek,
+    {
+        Ok(self
+            .dynamic_entries(reader)?
+            .into_iter()
+            .filter(|entry| entry.r#type() == tag)
+            .collect())
+    }
+
+    /// The flags of the `DT_FLAGS` entry of the dynamic array, or `None` if there is no
+    /// such entry. Flags which are not defined are retained.
+    pub fn dynamic_flags<R>(&self, reader: &mut R) -> Result<Option<ElfDynamicFlags>, Error>
+    where
+        R: Read + Seek,
+    {
+        Ok(self
+            .dynamic_entry(reader, ElfDynamicTag::Flags)?
+            .map(|entry| ElfDynamicFlags::from_bits_retain(entry.value())))
+    }
+
+    /// The flags of the `DT_FLAGS_1` entry of the dynamic array, or `None` if there is
+    /// no such entry. Flags which are not defined are retained.
+    pub fn dynamic_flags_1<R>(&self, reader: &mut R) -> Result<Option<ElfDynamicFlags1>, Error>
+    where
+        R: Read + Seek,
+    {
+        Ok(self
+            .dynamic_entry(reader, ElfDynamicTag::Flags1)?
+            .map(|entry| ElfDynamicFlags1::from_bits_retain(entry.value())))
+    }
+
+    /// Whether the object is a static position-independent executable, which relocates
+    /// itself at startup rather than being loaded by a program interpreter. Such an
+    /// object is a shared object (`ET_DYN`) with a `PT_DYNAMIC` segment but no
+    /// `PT_INTERP` segment, which is also the shape of a shared library or of the
+    /// dynamic linker itself, so it is told apart from them by the `DF_1_PIE` flag
+    /// which the link editor sets in `DT_FLAGS_1` for any position-independent
+    /// executable.
+    pub fn is_static_pie<R>(&self, reader: &mut R) -> Result<bool, Error>
+    where
+        R: Read + Seek,
+    {
+        let has_segment = |r#type| {
+            self.program_headers
+                .iter()
+                .any(|header| header.r#type() == r#type)
+        };
+
+        if !self.header.r#type.is_shared_object()
+            || !has_segment(ElfProgramHeaderType::Dynamic)
+            || has_segment(ElfProgramHeaderType::Interpreter)
+        {
+            return Ok(false);
+        }
+
+        Ok(self
+            .dynamic_flags_1(reader)?
+            .is_some_and(|flags| flags.contains(ElfDynamicFlags1::PIE)))
+    }
+}
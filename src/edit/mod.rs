@@ -0,0 +1,399 @@
+//! Rewriting of ELF objects, which produces a modified copy of an object while keeping
+//! its headers consistent with its new contents
+
+use alloc::vec::Vec;
+
+use crate::{
+    base::{ElfAddress, ElfOffset},
+    error::Error,
+    header::{
+        elf::ElfHeader,
+        program::{ElfProgramHeader, ElfProgramHeaderFlags, ElfProgramHeaderType},
+        section::{ElfSectionHeader, ElfSectionHeaderType, SectionIndex},
+    },
+    io::{Cursor, Read, Seek, SeekFrom, Write},
+    Elf, HasWrittenSize, ToWriter,
+};
+
+/// Encode `header` over the start of `file`, and the entries of its program header and
+/// section header tables over `file` at the offsets the header gives the tables. The
+/// file grows to fit a table which extends past its end.
+fn encode_headers<const EC: u8, const ED: u8>(
+    file: Vec<u8>,
+    header: &ElfHeader<EC, ED>,
+    program_headers: &[ElfProgramHeader<EC, ED>],
+    section_headers: &[ElfSectionHeader<EC, ED>],
+) -> Result<Vec<u8>, Error> {
+    let mut cursor = Cursor::new(file);
+    header.to_writer(&mut cursor)?;
+
+    if let Some(offset) = header.program_header_offset {
+        let entry_size = header.program_header_entry_size.0 as u64;
+
+        for (entry, program_header) in (0..).zip(program_headers.iter()) {
+            cursor.seek(SeekFrom::Start(offset.0.saturating_add(entry * entry_size)))?;
+            program_header.to_writer(&mut cursor)?;
+        }
+    }
+
+    if let Some(offset) = header.section_header_offset {
+        let entry_size = header.section_header_entry_size.0 as u64;
+
+        for (entry, section_header) in (0..).zip(section_headers.iter()) {
+            cursor.seek(SeekFrom::Start(offset.0.saturating_add(entry * entry_size)))?;
+            section_header.to_writer(&mut cursor)?;
+        }
+    }
+
+    Ok(cursor.into_inner())
+}
+
+impl<const EC: u8, const ED: u8> Elf<EC, ED> {
+    /// Write a copy of the object read from `reader` to `writer`, with its ELF header,
+    /// program header table, and section header table encoded from their decoded values
+    /// rather than copied. For an object which decodes without ignoring any errors, the
+    /// copy is identical to the original, so this checks that every header field
+    /// survives decoding and encoding unchanged. The contents of sections and segments
+    /// are copied as they are.
+    pub fn write_to<R, W>(&self, reader: &mut R, writer: &mut W) -> Result<(), Error>
+    where
+        R: Read + Seek,
+        W: Write,
+    {
+        let mut file = Vec::new();
+        reader.seek(SeekFrom::Start(0))?;
+        reader.read_to_end(&mut file)?;
+
+        let file = encode_headers(
+            file,
+            &self.header,
+            &self.program_headers,
+            &self.section_headers,
+        )?;
+
+        writer.write_all(&file)?;
+
+        Ok(())
+    }
+
+    /// Write a copy of the object read from `reader` to `writer`, with the contents of
+    /// the section at `index` in the section header table replaced by `data`.
+    ///
+    /// The file grows or shrinks to fit the new contents, and the offsets of the
+    /// program header table, section header table, sections, and segments after the
+    /// section are moved to match. The distance they are moved by is rounded to a
+    /// multiple of their alignment, so loadable segments stay congruent to their
+    /// virtual addresses, and any space this leaves after the new contents is zero
+    /// filled. Segments containing the section grow or shrink with it, and a segment
+    /// which ends with the section ends with its new contents.
+    ///
+    /// Virtual addresses are not changed, so the new contents of a section in a
+    /// `PT_LOAD` segment must fit in the section's existing space, and nothing after it
+    /// is moved. Replacing it with larger contents is an `Error::SectionDataTooLarge`.
+    pub fn replace_section_data<R, W>(
+        &self,
+        reader: &mut R,
+        writer: &mut W,
+        index: SectionIndex,
+        data: &[u8],
+    ) -> Result<(), Error>
+    where
+        R: Read + Seek,
+        W: Write,
+    {
+        let section = self
+            .section(index)
+            .ok_or(Error::InvalidSectionIndex { index: index.get() })?;
+
+        if matches!(section.r#type(), ElfSectionHeaderType::NoBits) {
+            return Err(Error::SectionNotInFile { index: index.get() });
+        }
+
+        let mut file = Vec::new();
+        reader.seek(SeekFrom::Start(0))?;
+        reader.read_to_end(&mut file)?;
+
+        let start = section.offset();
+        let (Some(end), Ok(start_index), Ok(end_index)) = (
+            start
+                .checked_add(section.size())
+                .filter(|end| *end <= file.len() as u64),
+            usize::try_from(start),
+            usize::try_from(start.saturating_add(section.size())),
+        ) else {
+            return Err(Error::SectionNotInFile { index: index.get() });
+        };
+
+        // Everything after the section must be moved by a multiple of the largest
+        // alignment after it, and at least of the size of an address so the header
+        // tables stay aligned
+        let align = self
+            .program_headers
+            .iter()
+            .filter(|header| matches!(header.r#type(), ElfProgramHeaderType::Load))
+            .filter(|header| header.offset() >= end)
+            .map(|header| header.align())
+            .chain(
+                self.section_headers
+                    .iter()
+                    .filter(|header| header.offset() >= end)
+                    .map(|header| header.address_align()),
+            )
+            .fold(ElfAddress::<EC, ED>::SIZE as u64, u64::max);
+        let old_size = section.size();
+        let new_size = data.len() as u64;
+        // A section which is loaded cannot move in memory, so its new contents must fit
+        // in its old space and nothing after it is moved
+        let loaded = self.program_headers.iter().any(|header| {
+            matches!(header.r#type(), ElfProgramHeaderType::Load)
+                && header.contains_section(section)
+        });
+
+        if loaded && new_size > old_size {
+            return Err(Error::SectionDataTooLarge {
+                index: index.get(),
+                size: old_size,
+            });
+        }
+
+        let size = (file.len() as u64).saturating_add(new_size);
+        let too_large = || Error::FileTooLarge { size };
+        // The distance everything after the section moves forward, or back if the
+        // section shrinks
+        let (grow, shrink) = if loaded {
+            (0, 0)
+        } else if new_size >= old_size {
+            let grow = (new_size - old_size)
+                .checked_next_multiple_of(align)
+                .ok_or_else(too_large)?;
+            (grow, 0)
+        } else {
+            (0, (old_size - new_size) / align * align)
+        };
+        let moved = |offset: u64| offset.saturating_add(grow).saturating_sub(shrink);
+        let padding = usize::try_from(moved(old_size) - new_size).map_err(|_| too_large())?;
+        let tail = file.get(end_index..).unwrap_or_default().to_vec();
+
+        file.truncate(start_index);
+        file.extend_from_slice(data);
+        file.resize(file.len().saturating_add(padding), 0);
+        file.extend(tail);
+
+        if file.len() as u64 > ElfOffset::<EC, ED>::MAX {
+            return Err(too_large());
+        }
+
+        let mut header = self.header.clone();
+        let mut program_headers = self.program_headers.clone();
+        let mut section_headers = self.section_headers.clone();
+
+        for offset in [
+            &mut header.program_header_offset,
+            &mut header.section_header_offset,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            if offset.0 >= end {
+                offset.0 = moved(offset.0);
+            }
+        }
+
+        for segment in program_headers.iter_mut() {
+            let segment_end = segment.offset().saturating_add(segment.file_size());
+
+            if segment.offset() >= end {
+                segment.set_offset(moved(segment.offset()));
+            } else if segment.offset() <= start && segment_end >= end {
+                // A segment which ends with the section ends with its new contents
+                let file_size = match segment_end == end {
+                    true => start + new_size - segment.offset(),
+                    false => moved(segment.file_size()),
+                };
+                let memory_size =
+                    (segment.memory_size() + file_size).saturating_sub(segment.file_size());
+                segment.set_sizes(file_size, memory_size);
+            }
+        }
+
+        for (section_index, section) in section_headers.iter_mut().enumerate() {
+            if section_index == index.get() {
+                section.set_size(new_size);
+            } else if section.offset() >= end {
+                section.set_offset(moved(section.offset()));
+            }
+        }
+
+        let file = encode_headers(file, &header, &program_headers, &section_headers)?;
+
+        writer.write_all(&file).map_err(Error::from)
+    }
+
+    /// Write a copy of the object read from `reader` to `writer` which starts executing
+    /// at a new entry point, and return the new entry point.
+    ///
+    /// Without `stub`, `entry` is the virtual address of the new entry point, and only
+    /// `e_entry` is changed. With `stub`, the stub is loaded by a new readable and
+    /// executable `PT_LOAD` segment, and `entry` is the offset of the new entry point in
+    /// the stub. The stub is appended to the file at the next page boundary and is
+    /// loaded at the first page boundary after the highest loadable segment, so the
+    /// offsets and addresses of existing segments and sections do not change.
+    ///
+    /// The program header for the new segment is added to the end of the program header
+    /// table in place, and a `PT_PHDR` segment grows to include it. This needs space
+    /// for one more entry after the table which is not used by a section, the section
+    /// header table, or a segment other than the one holding the table. Linkers usually
+    /// place the table directly before other contents, in which case this is an
+    /// `Error::ProgramHeaderTableFull`. For the dynamic linker to see the new entry, the
+    /// space must also be loaded along with the table.
+    pub fn redirect_entry<R, W>(
+        &self,
+        reader: &mut R,
+        writer: &mut W,
+        entry: u64,
+        stub: Option<&[u8]>,
+    ) -> Result<u64, Error>
+    where
+        R: Read + Seek,
+        W: Write,
+    {
+        let mut file = Vec::new();
+        reader.seek(SeekFrom::Start(0))?;
+        reader.read_to_end(&mut file)?;
+
+        let mut header = self.header.clone();
+        let mut program_headers = self.program_headers.clone();
+
+        let entry = match stub {
+            None => entry,
+            Some(stub) => {
+                let table = header
+                    .program_header_offset
+                    .map(|offset| offset.0)
+                    .filter(|offset| *offset != 0)
+                    .ok_or(Error::ProgramHeaderTableFull)?;
+                let entry_size = header.program_header_entry_size.0 as u64;
+                let count = header.program_header_entry_count.0;
+
+                // A count of `PN_XNUM` or more is held in the first section header
+                if count as usize != program_headers.len() || count >= u16::MAX - 1 {
+                    return Err(Error::ProgramHeaderTableFull);
+                }
+
+                let start = table
+                    .checked_add(entry_size.saturating_mul(count as u64))
+                    .ok_or(Error::ProgramHeaderTableFull)?;
+                let end = start
+                    .checked_add(entry_size)
+                    .ok_or(Error::ProgramHeaderTableFull)?;
+                let overlaps =
+                    |offset: u64, size: u64| size != 0 && offset < end && start < offset + size;
+                let section_table = header.section_header_offset.map(|offset| {
+                    (
+                        offset.0,
+                        header.section_header_entry_size.0 as u64
+                            * self.section_headers.len() as u64,
+                    )
+                });
+
+                if self
+                    .section_headers
+                    .iter()
+                    .filter(|section| {
+                        !matches!(
+                            section.r#type(),
+                            ElfSectionHeaderType::NullUndefined | ElfSectionHeaderType::NoBits
+                        )
+                    })
+                    .map(|section| (section.offset(), section.size()))
+                    .chain(section_table)
+                    .chain(
+                        self.program_headers
+                            .iter()
+                            .filter(|segment| {
+                                !matches!(
+                                    segment.r#type(),
+                                    ElfProgramHeaderType::Load
+                                        | ElfProgramHeaderType::ProgramHeader
+                                )
+                            })
+                            .map(|segment| (segment.offset(), segment.file_size())),
+                    )
+                    .any(|(offset, size)| overlaps(offset, size))
+                {
+                    return Err(Error::ProgramHeaderTableFull);
+                }
+
+                let page_size = self.page_size.max(1);
+                let size = (file.len() as u64).max(end);
+                let too_large = || Error::FileTooLarge { size };
+                let offset = size
+                    .checked_next_multiple_of(page_size)
+                    .ok_or_else(too_large)?;
+                let stub_size = stub.len() as u64;
+
+                if offset.saturating_add(stub_size) > ElfOffset::<EC, ED>::MAX {
+                    return Err(too_large());
+                }
+
+                let loaded_end = self
+                    .program_headers
+                    .iter()
+                    .filter(|segment| matches!(segment.r#type(), ElfProgramHeaderType::Load))
+                    .map(|segment| {
+                        segment
+                            .virtual_address()
+                            .saturating_add(segment.memory_size())
+                    })
+                    .max()
+                    .unwrap_or(0);
+                let address = loaded_end.checked_next_multiple_of(page_size).ok_or(
+                    Error::AddressTooLarge {
+                        address: loaded_end,
+                    },
+                )?;
+
+                if address.saturating_add(stub_size) > ElfAddress::<EC, ED>::MAX {
+                    return Err(Error::AddressTooLarge {
+                        address: address.saturating_add(stub_size),
+                    });
+                }
+
+                for segment in program_headers.iter_mut() {
+                    if matches!(segment.r#type(), ElfProgramHeaderType::ProgramHeader) {
+                        segment.set_sizes(
+                            segment.file_size() + entry_size,
+                            segment.memory_size() + entry_size,
+                        );
+                    }
+                }
+
+                program_headers.push(ElfProgramHeader::load(
+                    offset,
+                    address,
+                    stub_size,
+                    ElfProgramHeaderFlags::READ | ElfProgramHeaderFlags::EXECUTE,
+                    page_size,
+                )?);
+                header.program_header_entry_count.0 = count + 1;
+
+                file.resize(usize::try_from(offset).map_err(|_| too_large())?, 0);
+                file.extend_from_slice(stub);
+
+                address.saturating_add(entry)
+            }
+        };
+
+        if entry > ElfAddress::<EC, ED>::MAX {
+            return Err(Error::AddressTooLarge { address: entry });
+        }
+
+        header.entrypoint = Some(ElfAddress(entry));
+
+        let file = encode_headers(file, &header, &program_headers, &[])?;
+
+        writer.write_all(&file)?;
+
+        Ok(entry)
+    }
+}
@@ -1,9 +1,9 @@
 //! Error types for ELF parsing and decoding
 
-use std::{
-    fmt::Display,
-    io::{Read, Seek},
-};
+use alloc::{vec, vec::Vec};
+use core::fmt::Display;
+#[cfg(feature = "std")]
+use std::path::PathBuf;
 
 use typed_builder::TypedBuilder;
 
@@ -15,16 +15,40 @@ use crate::{
         },
         ElfMachine,
     },
+    io::{Read, Seek, SeekFrom},
 };
 
-#[derive(thiserror::Error, Debug, PartialEq, Eq, Hash)]
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq, Hash)]
 /// Error type for errors during ELF object handling
 pub enum Error {
-    #[error("I/O Error {kind}")]
-    /// A wrapped I/O error that is hashable and comparable
+    #[error("I/O Error {kind}{}", OsError(*errno))]
+    /// A wrapped I/O error that is hashable and comparable. I/O errors convert to this
+    /// error with `?`, keeping the OS error code if there is one.
     Io {
         /// The kind of I/O error
-        kind: std::io::ErrorKind,
+        kind: crate::io::ErrorKind,
+        /// The raw OS error code, such as `errno` on Unix, if the error came from the OS
+        errno: Option<i32>,
+    },
+    #[cfg(feature = "std")]
+    #[error("I/O Error {kind}{} for path {}", OsError(*errno), path.display())]
+    /// A wrapped I/O error that occurred while reading a file at a path
+    IoPath {
+        /// The path of the file being read
+        path: PathBuf,
+        /// The kind of I/O error
+        kind: crate::io::ErrorKind,
+        /// The raw OS error code, such as `errno` on Unix, if the error came from the OS
+        errno: Option<i32>,
+    },
+    #[error("Invalid ELF magic {magic:02x?}")]
+    /// The identifier did not begin with the `\x7fELF` magic, so the data is not an ELF
+    /// object. When ignored, the rest of the identifier is decoded as if the magic were
+    /// present, which is only useful for objects whose magic has been deliberately
+    /// damaged.
+    InvalidMagic {
+        /// The first four bytes of the identifier
+        magic: [u8; 4],
     },
     #[error("Invalid ELF class {class}")]
     /// Invalid ELF class value
@@ -58,6 +82,24 @@ pub enum Error {
         /// The value that could not be interpreted as a data encoding value
         encoding: ElfDataEncoding,
     },
+    #[error(
+        "ELF class ({class:?}) and data encoding ({encoding:?}) do not match the expected \
+         class ({expected_class:?}) and data encoding ({expected_encoding:?})"
+    )]
+    /// The class or data encoding in the identifier differs from the class and data
+    /// encoding an object is being decoded as, such as when decoding a 64-bit object as an
+    /// `Elf<{ ElfClass::Elf32 as u8 }, _>`. When ignored, the object is decoded as the
+    /// expected class and data encoding anyway.
+    ClassEncodingMismatch {
+        /// The class in the identifier
+        class: ElfClass,
+        /// The data encoding in the identifier
+        encoding: ElfDataEncoding,
+        /// The class the object is being decoded as
+        expected_class: ElfClass,
+        /// The data encoding the object is being decoded as
+        expected_encoding: ElfDataEncoding,
+    },
     #[error("Invalid ELF identifier version {version}")]
     /// Invalid ELF version value
     InvalidIdentifierVersion {
@@ -119,7 +161,10 @@ pub enum Error {
         context: ErrorContext,
     },
     #[error("Invalid ELF header size {context}")]
-    /// Invalid ELF header size value
+    /// Invalid ELF header size value, which is too small for the fields of the header,
+    /// leaves more than `ElfHeader::MAX_DATA_SIZE` bytes of extra data, or runs past the
+    /// end of the file. When ignored, the extra data is empty, cut to
+    /// `ElfHeader::MAX_DATA_SIZE` bytes, or cut to the bytes which could be read.
     InvalidHeaderSize {
         /// The decoding context
         context: ErrorContext,
@@ -212,6 +257,329 @@ pub enum Error {
         /// The value that was invalid
         value: u32,
     },
+    #[error(
+        "Invalid ELF Machine {machine:?} for expected machine(s) {expected_machines:?} ELF Program Header Type {value}"
+    )]
+    /// The machine was invalid for a processor-specific program header type
+    InvalidMachineForProgramHeaderType {
+        /// The machine that was invalid
+        machine: Option<ElfMachine<ELF_CLASS_DEFAULT, ELF_DATA_ENCODING_DEFAULT>>,
+        /// The expected machine
+        expected_machines: Vec<ElfMachine<ELF_CLASS_DEFAULT, ELF_DATA_ENCODING_DEFAULT>>,
+        /// The value that was invalid
+        value: u32,
+    },
+    #[error(
+        "Invalid ELF OS/ABI {os_abi:?} for expected OS/ABI(s) {expected_os_abis:?} ELF Program Header Type {value}"
+    )]
+    /// The OS/ABI was invalid for an OS-specific program header type
+    InvalidOsAbiForProgramHeaderType {
+        /// The OS/ABI that was invalid
+        os_abi: Option<ElfOSABI>,
+        /// The expected OS/ABI
+        expected_os_abis: Vec<ElfOSABI>,
+        /// The value that was invalid
+        value: u32,
+    },
+    #[error("Invalid ELF Program Header Type {value} for {machine:?}")]
+    /// The PT_ value was invalid for the machine or OS/ABI
+    InvalidProgramHeaderType {
+        /// The machine the program header type is invalid for
+        machine: Option<ElfMachine<ELF_CLASS_DEFAULT, ELF_DATA_ENCODING_DEFAULT>>,
+        /// The value that was invalid
+        value: u32,
+    },
+    #[error(
+        "Invalid ELF Machine {machine:?} for expected machine(s) {expected_machines:?} ELF Relocation Type {value}"
+    )]
+    /// The machine was invalid for a processor-specific relocation type
+    InvalidMachineForRelocationType {
+        /// The machine that was invalid
+        machine: Option<ElfMachine<ELF_CLASS_DEFAULT, ELF_DATA_ENCODING_DEFAULT>>,
+        /// The expected machine
+        expected_machines: Vec<ElfMachine<ELF_CLASS_DEFAULT, ELF_DATA_ENCODING_DEFAULT>>,
+        /// The value that was invalid
+        value: u32,
+    },
+    #[error("Invalid ELF Relocation Type {value} for {machine:?}")]
+    /// The relocation type was invalid for the machine
+    InvalidRelocationType {
+        /// The machine the relocation type is invalid for
+        machine: Option<ElfMachine<ELF_CLASS_DEFAULT, ELF_DATA_ENCODING_DEFAULT>>,
+        /// The value that was invalid
+        value: u32,
+    },
+    #[error("Invalid ELF entry size {entry_size} for section {index} of size {size}")]
+    /// A section holding a table of fixed-size entries had a size which was not a
+    /// multiple of its entry size. When ignored, the trailing partial entry is skipped.
+    InvalidEntrySize {
+        /// The section index
+        index: usize,
+        /// The size of each entry
+        entry_size: u64,
+        /// The size of the section
+        size: u64,
+    },
+    #[error("Invalid ELF Section Index {index}")]
+    /// A section index was out of range of the section header table
+    InvalidSectionIndex {
+        /// The section index
+        index: usize,
+    },
+    #[error("Invalid ELF section name string table index {index} for {count} sections")]
+    /// The `e_shstrndx` field of the ELF header, or the `sh_link` field of the first
+    /// section header when it is `SHN_XINDEX`, was out of range of the section header
+    /// table. When ignored, the names of the sections are left unresolved, and only
+    /// their indices into the missing string table are available.
+    InvalidShStrNdx {
+        /// The section name string table index
+        index: u64,
+        /// The number of entries in the section header table
+        count: u64,
+    },
+    #[error(
+        "ELF section header table of {size} bytes at {offset:#x} does not fit in the \
+         {length} byte file"
+    )]
+    /// The section header table declared by the ELF header extends past the end of the
+    /// file, which is checked before any of its entries are read. When ignored, the
+    /// section header table is treated as absent.
+    SectionHeaderTableOutOfBounds {
+        /// The offset of the section header table
+        offset: u64,
+        /// The declared size of the section header table
+        size: u64,
+        /// The length of the file
+        length: u64,
+    },
+    #[error("Invalid ELF Symbol Index {index} in symbol table {table}")]
+    /// A symbol index was out of range of the symbol table it refers to
+    InvalidSymbolIndex {
+        /// The index of the symbol table in the section header table
+        table: usize,
+        /// The symbol index
+        index: u32,
+    },
+    #[error("Invalid ELF Segment Index {index}")]
+    /// A segment index was out of range of the program header table
+    InvalidSegmentIndex {
+        /// The segment index
+        index: usize,
+    },
+    #[error("ELF Section {index} has no contents in the file")]
+    /// A section's contents were needed, but it occupies no space in the file or
+    /// extends past its end
+    SectionNotInFile {
+        /// The section index
+        index: usize,
+    },
+    #[error("New contents of ELF Section {index} do not fit in its {size} bytes")]
+    /// A section which cannot move was given contents larger than its existing space
+    SectionDataTooLarge {
+        /// The section index
+        index: usize,
+        /// The size of the section's existing space
+        size: u64,
+    },
+    #[error("ELF object of {size} bytes is too large for its class")]
+    /// A rewritten object would have offsets which do not fit in its class
+    FileTooLarge {
+        /// The size of the object
+        size: u64,
+    },
+    #[error("ELF address {address:#x} is too large for its class")]
+    /// A rewritten object would have an address which does not fit in its class
+    AddressTooLarge {
+        /// The address
+        address: u64,
+    },
+    #[error("ELF program header table has no space for another entry")]
+    /// A program header could not be added, because the space after the program header
+    /// table is in use
+    ProgramHeaderTableFull,
+    #[error("ELF Section {index} is compressed with unsupported type {compression}")]
+    /// A section was compressed with an algorithm which is not supported, or which
+    /// needs a feature which is not enabled
+    UnsupportedCompression {
+        /// The section index
+        index: usize,
+        /// The compression type
+        compression: u32,
+    },
+    #[error("Compressed data of ELF Section {index} is invalid")]
+    /// A compressed section's data could not be decompressed to its uncompressed size
+    InvalidCompressedData {
+        /// The section index
+        index: usize,
+    },
+    #[error("ELF Section {index} is not a string table")]
+    /// A section read as a string table had a different type
+    InvalidStringTableSection {
+        /// The section index
+        index: usize,
+    },
+    #[error("ELF Section {index} is not a relocation section of the expected type")]
+    /// A section read as a relocation section had a different type
+    InvalidRelocationSection {
+        /// The section index
+        index: usize,
+    },
+    #[error("ELF relocation at {offset:#x} is outside of the image")]
+    /// A relocation's location was outside of the memory image being relocated
+    InvalidRelocationOffset {
+        /// The location of the relocation
+        offset: u64,
+    },
+    #[error("Unsupported ELF relocation type {type} for {machine:?}")]
+    /// A relocation's type is not supported for the machine
+    UnsupportedRelocation {
+        /// The machine the relocation is for
+        machine: Option<ElfMachine<ELF_CLASS_DEFAULT, ELF_DATA_ENCODING_DEFAULT>>,
+        /// The type of the relocation
+        r#type: u32,
+    },
+    #[error("Reserved ELF Section Index {index:#x} has no defined meaning")]
+    /// A section index in the reserved range was not one of the defined special indices
+    InvalidReservedSectionIndex {
+        /// The reserved section index
+        index: u16,
+    },
+    #[error("Value {value:#x} does not fit in a usize on this host")]
+    /// A size or index read from an object is too large to be used on this host
+    UsizeOverflow {
+        /// The value which could not be converted
+        value: u64,
+    },
+    #[error("ELF build ID in the note sections does not match the one in the note segments")]
+    /// The GNU build ID note in the object's `SHT_NOTE` sections and the one in its
+    /// `PT_NOTE` segments hold different build IDs, which is a sign that one of them has
+    /// been corrupted or tampered with. When ignored, the build ID from the sections is
+    /// used.
+    BuildIdMismatch {
+        /// The build ID in the note sections
+        section: Vec<u8>,
+        /// The build ID in the note segments
+        segment: Vec<u8>,
+    },
+    #[error("Invalid ar archive magic")]
+    /// The data did not begin with the `!<arch>` magic of an ar archive
+    InvalidArchiveMagic,
+    #[error("Invalid ar archive member header at {offset:#x}")]
+    /// An ar archive member's header was malformed or its data extended past the end of
+    /// the archive
+    InvalidArchiveMember {
+        /// The offset of the member's header in the archive
+        offset: u64,
+    },
+    #[error("Unsupported .gdb_index version {version}")]
+    /// A `.gdb_index` section had a version other than 7 or 8, which are the versions
+    /// that record the kind of each symbol
+    UnsupportedGdbIndexVersion {
+        /// The version of the section
+        version: u32,
+    },
+    #[error("Chain of linked entries in section {section} is longer than {limit} entries")]
+    /// A chain of entries linked by their offsets, such as the version definitions of an
+    /// `SHT_GNU_verdef` section, had more entries than `Config::max_chain_length`
+    /// allows
+    MalformedVersionChain {
+        /// The index of the section holding the chain
+        section: usize,
+        /// The maximum number of entries in a chain
+        limit: usize,
+    },
+    #[error(
+        "ELF segment {index} is zero filled by {size:#x} bytes, more than the limit of {limit:#x}"
+    )]
+    /// A `PT_LOAD` segment's size in memory exceeds its size in the file by more than
+    /// `Config::max_zero_fill`, so its memory image is not built
+    SegmentZeroFillTooLarge {
+        /// The index of the segment in the program header table
+        index: usize,
+        /// The number of zero bytes the segment would be filled with
+        size: u64,
+        /// The maximum number of zero bytes a segment is filled with
+        limit: u64,
+    },
+    #[error(
+        "ELF segment {index} is smaller in memory ({memory_size:#x}) than in the file ({file_size:#x})"
+    )]
+    /// A `PT_LOAD` segment's size in memory is smaller than its size in the file, which
+    /// loaders reject. When ignored, the segment's contents are truncated to its size in
+    /// memory.
+    InvalidSegmentMemorySize {
+        /// The index of the segment in the program header table
+        index: usize,
+        /// The size of the segment in the file
+        file_size: u64,
+        /// The size of the segment in memory
+        memory_size: u64,
+    },
+    #[error("Entry at offset {offset:#x} of section {section} is linked to more than once")]
+    /// A chain of entries linked by their offsets linked back to an entry which was
+    /// already visited, which would make a walk of the chain loop forever
+    CyclicStructure {
+        /// The index of the section holding the chain
+        section: usize,
+        /// The offset in the section of the entry which was visited twice
+        offset: u64,
+    },
+}
+
+impl Error {
+    /// Whether decoding can continue past this error when it is ignored, so that it is
+    /// ignored by a `Config` built with `ignore_all_recoverable`. These are the errors
+    /// with a documented fallback: an invalid class and data encoding pair, a class and
+    /// data encoding which do not match the decoded type, an invalid version, an invalid
+    /// ELF header size, a read past the end of the file, an out of range section name string table index, an
+    /// entry size which does not divide its section, a section header table which does
+    /// not fit in the file, mismatched build IDs, and a segment which is smaller in
+    /// memory than in the file.
+    pub fn is_recoverable(&self) -> bool {
+        matches!(
+            self,
+            Error::InvalidClassEncodingPair { .. }
+                | Error::ClassEncodingMismatch { .. }
+                | Error::InvalidVersion { .. }
+                | Error::InvalidHeaderSize { .. }
+                | Error::Io {
+                    kind: crate::io::ErrorKind::UnexpectedEof,
+                    ..
+                }
+                | Error::InvalidShStrNdx { .. }
+                | Error::InvalidEntrySize { .. }
+                | Error::SectionHeaderTableOutOfBounds { .. }
+                | Error::BuildIdMismatch { .. }
+                | Error::InvalidSegmentMemorySize { .. }
+        )
+    }
+}
+
+impl From<crate::io::Error> for Error {
+    fn from(e: crate::io::Error) -> Self {
+        #[cfg(feature = "std")]
+        let errno = e.raw_os_error();
+        #[cfg(not(feature = "std"))]
+        let errno = None;
+
+        Error::Io {
+            kind: e.kind(),
+            errno,
+        }
+    }
+}
+
+/// Displays the OS error code of an I/O error, if there is one, as `std::io::Error`
+/// does
+struct OsError(Option<i32>);
+
+impl Display for OsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.0 {
+            Some(errno) => write!(f, " (os error {errno})"),
+            None => Ok(()),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialOrd, Ord, TypedBuilder)]
@@ -231,11 +599,12 @@ impl ErrorContext {
         R: Read + Seek,
     {
         let mut context = vec![0; size];
-        reader
-            .seek(std::io::SeekFrom::Start(offset))
-            .map_err(|e| Error::Io { kind: e.kind() })?;
         // Try to read exactly the size and if we fail read one less until we read nothing
-        while reader.read_exact(&mut context).is_err() {
+        loop {
+            reader.seek(SeekFrom::Start(offset))?;
+            if reader.read_exact(&mut context).is_ok() {
+                break;
+            }
             context.pop();
         }
         Ok(ErrorContext { offset, context })
@@ -247,16 +616,14 @@ impl ErrorContext {
     where
         R: Read + Seek,
     {
-        let offset = reader
-            .stream_position()
-            .map_err(|e| Error::Io { kind: e.kind() })?;
+        let offset = reader.stream_position()?;
         let begin = offset.saturating_sub(size as u64);
         Self::from_reader_at(reader, begin, size)
     }
 }
 
 impl Display for ErrorContext {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "offset: {:#x}, context: {:?}", self.offset, self.context)
     }
 }
@@ -267,8 +634,8 @@ impl PartialEq for ErrorContext {
     }
 }
 
-impl std::hash::Hash for ErrorContext {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+impl core::hash::Hash for ErrorContext {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         self.offset.hash(state);
     }
 }
@@ -0,0 +1,309 @@
+//! Build metadata embedded by the Go toolchain, which is held in the `.note.go.buildid`
+//! note and the `.go.buildinfo` section
+
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::{
+    error::Error,
+    io::{Read, Seek},
+    Elf,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// A Go module which was built into a binary
+pub struct GoModule {
+    /// The module path, for example `golang.org/x/sys`
+    pub path: String,
+    /// The version of the module, or `(devel)` for the main module of a binary built
+    /// from a working copy
+    pub version: String,
+    /// The checksum of the module, which is empty for the main module
+    pub sum: String,
+    /// The module which replaced this module with a `replace` directive, if any
+    pub replacement: Option<Box<GoModule>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+/// The build ID and build information embedded in a Go binary
+pub struct GoBuildInfo {
+    /// The build ID, which the Go toolchain uses to decide whether a binary is up to
+    /// date
+    pub build_id: Option<String>,
+    /// The version of the toolchain which built the binary, for example `go1.21.0`
+    pub version: Option<String>,
+    /// The import path of the main package
+    pub path: Option<String>,
+    /// The main module
+    pub main: Option<GoModule>,
+    /// The modules the main module depends on
+    pub dependencies: Vec<GoModule>,
+    /// The build settings, such as `GOOS` and `-ldflags`, which are recorded by Go 1.18
+    /// and later
+    pub settings: Vec<(String, String)>,
+}
+
+impl GoBuildInfo {
+    /// The name of the owner of the Go build ID note
+    pub const NOTE_NAME: &'static str = "Go";
+    /// The type of the Go build ID note (`NT_GO_BUILDID`)
+    pub const NOTE_TYPE_BUILD_ID: u32 = 4;
+    /// The name of the section holding the build information
+    pub const SECTION_NAME: &'static str = ".go.buildinfo";
+    /// The magic which begins the build information
+    const MAGIC: &'static [u8] = b"\xff Go buildinf:";
+    /// The size of the build information header, after which Go 1.18 and later place
+    /// the version and module information inline
+    const HEADER_SIZE: usize = 32;
+    /// The flag set in the header when pointers are big endian
+    const FLAG_BIG_ENDIAN: u8 = 1;
+    /// The flag set in the header when the version and module information are inline
+    const FLAG_INLINE: u8 = 2;
+    /// The size of the sentinels which surround the module information
+    const MODULE_INFO_SENTINEL_SIZE: usize = 16;
+
+    /// Decode the module information, which is a list of tab-separated lines, into
+    /// `self`
+    fn parse_module_info(&mut self, info: &[u8]) {
+        let size = info.len();
+        // The module information is surrounded by 16 byte sentinels, and ends with a
+        // newline before the final one
+        let info = if size > 2 * Self::MODULE_INFO_SENTINEL_SIZE
+            && info.get(size - Self::MODULE_INFO_SENTINEL_SIZE - 1) == Some(&b'\n')
+        {
+            info.get(Self::MODULE_INFO_SENTINEL_SIZE..size - Self::MODULE_INFO_SENTINEL_SIZE)
+                .unwrap_or(info)
+        } else {
+            info
+        };
+        let info = String::from_utf8_lossy(info);
+        let module = |fields: &[&str]| match fields {
+            [path, version, rest @ ..] => Some(GoModule {
+                path: path.to_string(),
+                version: version.to_string(),
+                sum: rest.first().map(|sum| sum.to_string()).unwrap_or_default(),
+                replacement: None,
+            }),
+            _ => None,
+        };
+
+        for line in info.lines() {
+            let fields = line.split('\t').collect::<Vec<_>>();
+
+            match fields.as_slice() {
+                ["path", path] => self.path = Some(path.to_string()),
+                ["mod", rest @ ..] => self.main = module(rest),
+                ["dep", rest @ ..] => self.dependencies.extend(module(rest)),
+                ["=>", rest @ ..] => {
+                    // A replacement applies to the module on the line before it
+                    let replaced = match self.dependencies.last_mut() {
+                        Some(dependency) => Some(dependency),
+                        None => self.main.as_mut(),
+                    };
+                    if let (Some(replaced), Some(replacement)) = (replaced, module(rest)) {
+                        replaced.replacement = Some(Box::new(replacement));
+                    }
+                }
+                ["build", setting] => {
+                    if let Some((key, value)) = setting.split_once('=') {
+                        self.settings.push((key.to_string(), value.to_string()));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Decode a pointer-sized unsigned integer from the start of `data`
+fn word(data: &[u8], size: usize, big_endian: bool) -> Option<u64> {
+    let bytes = data.get(..size)?;
+    let fold = |value: u64, byte: &u8| (value << 8) | *byte as u64;
+
+    match (size, big_endian) {
+        (4 | 8, true) => Some(bytes.iter().fold(0, fold)),
+        (4 | 8, false) => Some(bytes.iter().rev().fold(0, fold)),
+        _ => None,
+    }
+}
+
+/// Decode a string prefixed by its length as an unsigned LEB128 varint from the start of
+/// `data`, and return it with the rest of `data`
+fn varint_string(data: &[u8]) -> Option<(&[u8], &[u8])> {
+    let mut size = 0u64;
+    let mut shift = 0u32;
+    let mut rest = data;
+
+    loop {
+        let (byte, next) = rest.split_first()?;
+        rest = next;
+        size |= ((byte & 0x7f) as u64).checked_shl(shift)?;
+        shift += 7;
+
+        if byte & 0x80 == 0 {
+            break;
+        }
+    }
+
+    let size = usize::try_from(size).ok()?;
+    Some((rest.get(..size)?, rest.get(size..)?))
+}
+
+impl<const EC: u8, const ED: u8> Elf<EC, ED> {
+    /// Read the Go string whose header, a pointer to its data and its length, is at the
+    /// virtual address `address`
+    fn read_go_string<R>(
+        &self,
+        reader: &mut R,
+        address: u64,
+        pointer_size: usize,
+        big_endian: bool,
+    ) -> Result<Option<Vec<u8>>, Error>
+    where
+        R: Read + Seek,
+    {
+        let Some(header) = self.read_at_address(reader, address, 2 * pointer_size as u64)? else {
+            return Ok(None);
+        };
+        let (Some(data), Some(size)) = (
+            word(&header, pointer_size, big_endian),
+            header
+                .get(pointer_size..)
+                .and_then(|header| word(header, pointer_size, big_endian)),
+        ) else {
+            return Ok(None);
+        };
+
+        self.read_at_address(reader, data, size)
+    }
+
+    /// Read the build ID from the object's Go build ID note, and the toolchain version
+    /// and module information from its `.go.buildinfo` section. Both the inline layout
+    /// of Go 1.18 and later and the earlier layout, which points to the version and
+    /// module information elsewhere in the binary, are supported. `None` if the object
+    /// has neither, as is the case for binaries not built by the Go toolchain.
+    pub fn go_build_info<R>(&self, reader: &mut R) -> Result<Option<GoBuildInfo>, Error>
+    where
+        R: Read + Seek,
+    {
+        let mut info = GoBuildInfo {
+            build_id: self
+                .notes(reader)?
+                .into_iter()
+                .find(|note| {
+                    note.name == GoBuildInfo::NOTE_NAME
+                        && note.r#type == GoBuildInfo::NOTE_TYPE_BUILD_ID
+                })
+                .map(|note| String::from_utf8_lossy(&note.description).into_owned()),
+            ..Default::default()
+        };
+
+        let data = match self.section_by_name(GoBuildInfo::SECTION_NAME) {
+            Some((index, _)) => self.section_data(reader, index)?,
+            None => Vec::new(),
+        };
+
+        if let Some(&[pointer_size, flags, ref pointers @ ..]) =
+            data.strip_prefix(GoBuildInfo::MAGIC)
+        {
+            let pointer_size = pointer_size as usize;
+            let big_endian = flags & GoBuildInfo::FLAG_BIG_ENDIAN != 0;
+
+            let (version, module_info) = if flags & GoBuildInfo::FLAG_INLINE != 0 {
+                let strings = data.get(GoBuildInfo::HEADER_SIZE..).unwrap_or_default();
+                let version = varint_string(strings);
+                let module_info = version
+                    .and_then(|(_, rest)| varint_string(rest))
+                    .map(|(module_info, _)| module_info.to_vec());
+                (version.map(|(version, _)| version.to_vec()), module_info)
+            } else {
+                let mut string = |index: usize| match pointers
+                    .get(index * pointer_size..)
+                    .and_then(|pointer| word(pointer, pointer_size, big_endian))
+                {
+                    Some(address) => self.read_go_string(reader, address, pointer_size, big_endian),
+                    None => Ok(None),
+                };
+                (string(0)?, string(1)?)
+            };
+
+            info.version = version.map(|version| String::from_utf8_lossy(&version).into_owned());
+            if let Some(module_info) = module_info {
+                info.parse_module_info(&module_info);
+            }
+        }
+
+        Ok((info != GoBuildInfo::default()).then_some(info))
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(all(test, feature = "std"))]
+mod test {
+    use super::{varint_string, GoBuildInfo, GoModule};
+
+    #[test]
+    fn test_inline_build_info() {
+        let mut module_info = b"0w\xaf\x0c\x92t\x08\x02A\xe1\xc1\x07\xe6\xd6\x18\xe6".to_vec();
+        module_info.extend_from_slice(
+            b"path\texample.com/hello\n\
+            mod\texample.com/hello\t(devel)\t\n\
+            dep\tgolang.org/x/sys\tv0.1.0\th1:abc=\n\
+            =>\t../sys\t(devel)\t\n\
+            build\t-compiler=gc\n\
+            build\tGOOS=linux\n",
+        );
+        module_info.extend_from_slice(b"\xf92C1\x86\x18 r\x00\x82B\x10A\x16\xd8\xf2");
+        let mut data = vec![8];
+        data.extend_from_slice(b"go1.21.0");
+        let mut size = module_info.len();
+        while size >= 0x80 {
+            data.push(size as u8 | 0x80);
+            size >>= 7;
+        }
+        data.push(size as u8);
+        data.extend_from_slice(&module_info);
+
+        let (version, rest) = varint_string(&data).unwrap();
+        assert_eq!(version, b"go1.21.0");
+        let (module_info, rest) = varint_string(rest).unwrap();
+        assert!(rest.is_empty());
+
+        let mut info = GoBuildInfo::default();
+        info.parse_module_info(module_info);
+        assert_eq!(info.path.as_deref(), Some("example.com/hello"));
+        assert_eq!(
+            info.main,
+            Some(GoModule {
+                path: "example.com/hello".to_string(),
+                version: "(devel)".to_string(),
+                sum: String::new(),
+                replacement: None,
+            })
+        );
+        assert_eq!(
+            info.dependencies,
+            vec![GoModule {
+                path: "golang.org/x/sys".to_string(),
+                version: "v0.1.0".to_string(),
+                sum: "h1:abc=".to_string(),
+                replacement: Some(Box::new(GoModule {
+                    path: "../sys".to_string(),
+                    version: "(devel)".to_string(),
+                    sum: String::new(),
+                    replacement: None,
+                })),
+            }]
+        );
+        assert_eq!(
+            info.settings,
+            vec![
+                ("-compiler".to_string(), "gc".to_string()),
+                ("GOOS".to_string(), "linux".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,127 @@
+//! Section groups, which are held in `SHT_GROUP` sections and name sets of sections
+//! which the link editor must keep or discard together
+
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    base::ElfWord,
+    error::Error,
+    header::section::{ElfSectionHeaderType, ElfSectionIndex, SectionIndex},
+    io::{Cursor, Read, Seek},
+    symbol::{ElfSymbol, ElfSymbolType},
+    Config, Elf, FromReader,
+};
+
+/// The flag set in the first word of a section group when the group is a COMDAT group
+/// (`GRP_COMDAT`)
+pub const GRP_COMDAT: u32 = 0x1;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// A COMDAT group, of which the link editor keeps only the first copy it sees with a
+/// given signature, as reported by `Elf::comdat_groups`
+pub struct ComdatGroup {
+    /// The index of the `SHT_GROUP` section which defines the group
+    pub section: SectionIndex,
+    /// The signature of the group, which is the name of the symbol identified by the
+    /// group section's `sh_info`, or the name of the section it refers to if the
+    /// symbol is a section symbol
+    pub signature: String,
+    /// The names of the sections in the group
+    pub members: Vec<String>,
+}
+
+impl<const EC: u8, const ED: u8> Elf<EC, ED> {
+    /// Read the flags and the member section indices of the `SHT_GROUP` section at
+    /// `index`
+    fn section_group<R>(
+        &self,
+        reader: &mut R,
+        index: SectionIndex,
+    ) -> Result<(u32, Vec<u32>), Error>
+    where
+        R: Read + Seek,
+    {
+        let data = self.section_data(reader, index)?;
+        let count = data.len() / size_of::<u32>();
+        let mut config = Config::default();
+        let mut cursor = Cursor::new(data);
+
+        let mut words = (0..count)
+            .map(|_| ElfWord::<EC, ED>::from_reader_with(&mut cursor, &mut config).map(|w| w.0))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if words.is_empty() {
+            return Ok((0, words));
+        }
+
+        let flags = words.remove(0);
+
+        Ok((flags, words))
+    }
+
+    /// The name of `symbol` as a group signature, which is the name of its section if
+    /// it is a section symbol
+    fn signature_name(&self, symbol: &ElfSymbol<EC, ED>) -> String {
+        match (symbol.r#type(), symbol.section_index()) {
+            (Some(ElfSymbolType::Section), ElfSectionIndex::Index(index)) => self
+                .section(SectionIndex(index))
+                .map(|header| header.name().into())
+                .unwrap_or_default(),
+            _ => symbol.name().into(),
+        }
+    }
+
+    /// Read the COMDAT groups in the object, which are the `SHT_GROUP` sections with
+    /// the `GRP_COMDAT` flag. Each group's signature is resolved from the symbol table
+    /// linked to its section, and its members are named by their section names. Only
+    /// relocatable objects normally have groups, so no groups are returned for linked
+    /// objects.
+    pub fn comdat_groups<R>(&self, reader: &mut R) -> Result<Vec<ComdatGroup>, Error>
+    where
+        R: Read + Seek,
+    {
+        let mut symbol_tables: Vec<(SectionIndex, Vec<ElfSymbol<EC, ED>>)> = Vec::new();
+        let mut groups = Vec::new();
+
+        for (index, header) in self.indexed_sections() {
+            if header.r#type() != ElfSectionHeaderType::Group {
+                continue;
+            }
+
+            let (flags, members) = self.section_group(reader, index)?;
+
+            if flags & GRP_COMDAT == 0 {
+                continue;
+            }
+
+            let link = header.link();
+            let symbols = match symbol_tables.iter().position(|(table, _)| *table == link) {
+                Some(position) => position,
+                None => {
+                    symbol_tables.push((link, self.symbol_table(reader, link)?));
+                    symbol_tables.len() - 1
+                }
+            };
+            let signature = symbol_tables
+                .get(symbols)
+                .and_then(|(_, symbols)| symbols.get(header.info() as usize))
+                .map(|symbol| self.signature_name(symbol))
+                .unwrap_or_default();
+
+            groups.push(ComdatGroup {
+                section: index,
+                signature,
+                members: members
+                    .into_iter()
+                    .map(|member| {
+                        self.section(SectionIndex(member))
+                            .map(|header| header.name().into())
+                            .unwrap_or_default()
+                    })
+                    .collect(),
+            });
+        }
+
+        Ok(groups)
+    }
+}
@@ -2,21 +2,26 @@
 //! at the beginning of a section and specifies how the section data is to be
 //! decompressed.
 
+use alloc::vec::Vec;
+use core::mem::size_of;
 use num_traits::FromPrimitive;
-use std::{
-    io::{Read, Seek, Write},
-    mem::size_of,
-};
 
 use typed_builder::TypedBuilder;
 
 use crate::{
     base::{ElfByte, ElfExtendedWord, ElfWord},
     error::ErrorContext,
-    from_primitive, Config, FromReader, HasWrittenSize, ToWriter,
+    from_primitive,
+    io::{Read, Seek, Write},
+    Config, FromReader, HasWrittenSize, ToWriter,
 };
 use crate::{error::Error, header::elf::identification::ElfClass};
 
+/// The `ch_type` of a section compressed with zlib
+pub(crate) const ELFCOMPRESS_ZLIB: u32 = 1;
+/// The `ch_type` of a section compressed with Zstandard
+pub(crate) const ELFCOMPRESS_ZSTD: u32 = 2;
+
 from_primitive! {
     #[repr(u32)]
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -39,10 +44,15 @@ where
     type Error = Error;
 
     fn from_reader_with(reader: &mut R, config: &mut Config) -> Result<Self, Self::Error> {
-        ElfCompressionHeaderType::from_u32(ElfWord::<EC, ED>::from_reader_with(reader, config)?.0)
-            .ok_or(Error::InvalidCompressionHeaderType {
-                context: ErrorContext::from_reader_at(reader, 0, 4)?,
-            })
+        let r#type = ElfWord::<EC, ED>::from_reader_with(reader, config)?;
+
+        // The context is only read on error, as reading it moves the reader
+        match ElfCompressionHeaderType::from_u32(r#type.0) {
+            Some(r#type) => Ok(r#type),
+            None => Err(Error::InvalidCompressionHeaderType {
+                context: ErrorContext::from_reader(reader, size_of::<ElfWord<EC, ED>>())?,
+            }),
+        }
     }
 }
 
@@ -192,6 +202,7 @@ impl<const ED: u8> HasWrittenSize for Elf64CompressionHeader<ED> {
         + size_of::<ElfExtendedWord<{ ElfClass::Elf64 as u8 }, ED>>();
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
 /// ELF compression header for either 32-bit or 64-bit ELF files
 pub enum ElfCompressionHeader<const EC: u8, const ED: u8> {
     /// A 32-bit ELF compression header
@@ -200,6 +211,38 @@ pub enum ElfCompressionHeader<const EC: u8, const ED: u8> {
     Elf64(Elf64CompressionHeader<ED>),
 }
 
+impl<const EC: u8, const ED: u8> ElfCompressionHeader<EC, ED> {
+    /// The compression algorithm
+    pub fn r#type(&self) -> ElfCompressionHeaderType<EC, ED> {
+        let r#type = match self {
+            ElfCompressionHeader::Elf32(header) => header.r#type as u32,
+            ElfCompressionHeader::Elf64(header) => header.r#type as u32,
+        };
+
+        match r#type {
+            ELFCOMPRESS_ZLIB => ElfCompressionHeaderType::ZLib,
+            ELFCOMPRESS_ZSTD => ElfCompressionHeaderType::ZStd,
+            _ => ElfCompressionHeaderType::None,
+        }
+    }
+
+    /// The size in bytes of the uncompressed data
+    pub fn size(&self) -> u64 {
+        match self {
+            ElfCompressionHeader::Elf32(header) => header.size.0 as u64,
+            ElfCompressionHeader::Elf64(header) => header.size.0,
+        }
+    }
+
+    /// The required alignment of the uncompressed data
+    pub fn address_align(&self) -> u64 {
+        match self {
+            ElfCompressionHeader::Elf32(header) => header.address_align.0 as u64,
+            ElfCompressionHeader::Elf64(header) => header.address_align.0,
+        }
+    }
+}
+
 impl<R, const EC: u8, const ED: u8> FromReader<R> for ElfCompressionHeader<EC, ED>
 where
     R: Read + Seek,
@@ -242,3 +285,42 @@ impl<const EC: u8, const ED: u8> HasWrittenSize for ElfCompressionHeader<EC, ED>
         _ => panic!("Constant ELF Class must be valid"),
     };
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// How a section is compressed, which is known without decompressing it
+pub struct CompressionInfo {
+    /// The compression algorithm, as a `ch_type` value such as 1 for zlib or 2 for
+    /// Zstandard. Sections compressed with the GNU scheme are always compressed with
+    /// zlib.
+    pub r#type: u32,
+    /// The size in bytes of the uncompressed data
+    pub size: u64,
+    /// The required alignment of the uncompressed data. The GNU scheme does not record
+    /// it, so it is the alignment of the section for sections compressed with it.
+    pub address_align: u64,
+    /// Whether the section is a `.zdebug` section compressed with the older GNU
+    /// scheme, instead of an `SHF_COMPRESSED` section with a compression header
+    pub gnu: bool,
+}
+
+/// Decompress the zlib stream `data` of the section at `index`, which decompresses to
+/// `size` bytes
+#[cfg(feature = "compression")]
+pub(crate) fn inflate(index: usize, data: &[u8], size: u64) -> Result<Vec<u8>, Error> {
+    let size = usize::try_from(size).map_err(|_| Error::InvalidCompressedData { index })?;
+
+    miniz_oxide::inflate::decompress_to_vec_zlib_with_limit(data, size)
+        .ok()
+        .filter(|data| data.len() == size)
+        .ok_or(Error::InvalidCompressedData { index })
+}
+
+/// Decompress the zlib stream `data` of the section at `index`, which is unsupported
+/// without the `compression` feature
+#[cfg(not(feature = "compression"))]
+pub(crate) fn inflate(index: usize, _data: &[u8], _size: u64) -> Result<Vec<u8>, Error> {
+    Err(Error::UnsupportedCompression {
+        index,
+        compression: ELFCOMPRESS_ZLIB,
+    })
+}
@@ -1,16 +1,20 @@
 //! Implementation of the `ident` field of the ELF eader. This field is located
 //! at the beginning of an ELF object file and specifies how it is to be decoded.
 
-use std::{
-    io::{Read, Seek, Write},
-    mem::size_of,
-};
+use alloc::{format, vec::Vec};
+use core::mem::size_of;
 
 use num_derive::{FromPrimitive, ToPrimitive};
 use num_traits::FromPrimitive;
 use typed_builder::TypedBuilder;
 
-use crate::{base::ElfByte, error::Error, Config, FromReader, HasWrittenSize, ToWriter};
+use crate::{
+    base::ElfByte,
+    error::Error,
+    io::{Read, Seek, Write},
+    scan::ELF_MAGIC,
+    Config, FromReader, HasWrittenSize, ToWriter,
+};
 
 #[repr(u8)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, FromPrimitive, ToPrimitive)]
@@ -62,6 +66,12 @@ where
 
     fn from_reader_with(reader: &mut R, config: &mut Config) -> Result<Self, Self::Error> {
         let class = ElfByte::from_reader_with(reader, config)?;
+
+        // A forced class overrides the class byte, even if it is invalid
+        if let Some(class) = config.force_class {
+            return Ok(class);
+        }
+
         Self::from_u8(class.0).ok_or(Error::InvalidClass { class })
     }
 }
@@ -150,6 +160,12 @@ where
 
     fn from_reader_with(reader: &mut R, config: &mut Config) -> Result<Self, Self::Error> {
         let encoding = ElfByte::from_reader_with(reader, config)?;
+
+        // A forced data encoding overrides the data encoding byte, even if it is invalid
+        if let Some(encoding) = config.force_encoding {
+            return Ok(encoding);
+        }
+
         Self::from_u8(encoding.0).ok_or(Error::InvalidDataEncoding { encoding })
     }
 }
@@ -314,7 +330,13 @@ where
 
     fn from_reader_with(reader: &mut R, config: &mut Config) -> Result<Self, Self::Error> {
         let os_abi = ElfByte::from_reader_with(reader, config)?;
-        Self::from_u8(os_abi.0).ok_or(Error::InvalidOsAbi { os_abi })
+        let os_abi = Self::from_u8(os_abi.0).ok_or(Error::InvalidOsAbi { os_abi })?;
+
+        if config.auto_os_abi {
+            config.os_abi = Some(os_abi);
+        }
+
+        Ok(os_abi)
     }
 }
 
@@ -365,6 +387,15 @@ pub struct ElfHeaderIdentifier {
     pub pad: [ElfByte; 7],
 }
 
+impl ElfHeaderIdentifier {
+    /// The reserved bytes at the end of the identifier (`EI_PAD`), which should be
+    /// zero but which some producers use to store extra data, such as a brand or a
+    /// length. They are kept as read, so an identifier is written back unchanged.
+    pub fn padding(&self) -> [u8; 7] {
+        self.pad.map(|byte| byte.0)
+    }
+}
+
 impl<R> FromReader<R> for ElfHeaderIdentifier
 where
     R: Read + Seek,
@@ -378,6 +409,17 @@ where
             ElfByte::from_reader_with(reader, config)?,
             ElfByte::from_reader_with(reader, config)?,
         ];
+
+        if magic.map(|byte| byte.0) != *ELF_MAGIC {
+            let error = Error::InvalidMagic {
+                magic: magic.map(|byte| byte.0),
+            };
+
+            if !config.ignores(&error) {
+                return Err(error);
+            }
+        }
+
         let class = ElfClass::from_reader_with(reader, config)?;
         let data_encoding = ElfDataEncoding::from_reader_with(reader, config)?;
         let version = ElfIdentifierVersion::from_reader_with(reader, config)?;
@@ -436,8 +478,8 @@ impl HasWrittenSize for ElfHeaderIdentifier {
     const SIZE: usize = size_of::<ElfByte>() * 16;
 }
 
-impl std::fmt::Display for ElfHeaderIdentifier {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for ElfHeaderIdentifier {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         writeln!(
             f,
             "magic: {}",
@@ -478,7 +520,7 @@ impl std::fmt::Display for ElfHeaderIdentifier {
 }
 
 #[allow(clippy::unwrap_used)]
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod test {
     use super::*;
 
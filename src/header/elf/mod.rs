@@ -2,68 +2,165 @@
 //! of the ELF object file and is the only header type in the ELF specification
 //! which must be located at a specific offset (0) in the file.
 
-use num_traits::FromPrimitive;
-use std::{
-    io::{Read, Seek, Write},
-    mem::size_of,
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
 };
+use core::mem::size_of;
+use num_traits::FromPrimitive;
 use typed_builder::TypedBuilder;
 
+#[cfg(feature = "arch")]
+use crate::arch::{
+    arm32::{ElfHeaderFlagARM32, ElfHeaderFlagsARM32},
+    m68k::{ElfHeaderFlagM68K, ElfHeaderFlagsM68K},
+    mips::{ElfHeaderFlagMIPS, ElfHeaderFlagsMIPS},
+    parisc::{ElfHeaderFlagPARISC, ElfHeaderFlagsPARISC},
+    riscv::{ElfHeaderFlagRISCV, ElfHeaderFlagsRISCV},
+};
 use crate::{
-    arch::{
-        arm32::ElfHeaderFlagsARM32, m68k::ElfHeaderFlagsM68K, mips::ElfHeaderFlagsMIPS,
-        parisc::ElfHeaderFlagsPARISC, riscv::ElfHeaderFlagsRISCV,
-    },
     base::{ElfAddress, ElfByte, ElfHalfWord, ElfOffset, ElfWord},
     error::{Error, ErrorContext},
-    from_primitive, Config, FromReader, HasWrittenSize, ToWriter, TryFromWithConfig,
+    from_primitive,
+    header::section::ElfSectionHeader,
+    io::{Cursor, Read, Seek, SeekFrom, Write},
+    Config, Elf, FromReader, HasWrittenSize, ToWriter, TryFromWithConfig,
 };
 
-use self::identification::{ElfHeaderIdentifier, ELF_CLASS_DEFAULT, ELF_DATA_ENCODING_DEFAULT};
+use self::identification::{
+    ElfClass, ElfDataEncoding, ElfHeaderIdentifier, ELF_CLASS_DEFAULT, ELF_DATA_ENCODING_DEFAULT,
+};
 
 pub mod identification;
 
-from_primitive! {
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-    #[non_exhaustive]
-    /// The ELF object type
-    ///
-    /// The following operating systems define no additional values
-    /// for the e_type field:
-    ///
-    /// - Linux
-    ///
-    /// The following Processors define no additional values for
-    /// the e_type field:
-    ///
-    /// - ARM32
-    /// - AARCH64
-    /// - i386
-    /// - m68k
-    /// - MIPS
-    /// - PA-RISC
-    /// - PPC
-    /// - PPC64
-    /// - RISC-V
-    /// - S390
-    /// - S390X
-    /// - SPARC
-    /// - x86_64
-    ///
-    /// Therefore, it is possible to have an undefined flag, but is unlikely in a
-    /// well-formed ELF object file.  The OS-specific range of types is [0xfe00, 0xfeff]
-    /// and the processor-specific range of types is [0xff00, 0xffff].
-    enum ElfType<const EC: u8, const ED: u8> {
-        /// No file type
-        None = 0,
-        /// Relocatable file type
-        Relocatable = 1,
-        /// Executable file type
-        Executable = 2,
-        /// Shared object file type
-        Dynamic = 3,
-        /// Core file
-        Core = 4,
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+/// The ELF object type
+///
+/// The following operating systems define no additional values
+/// for the e_type field:
+///
+/// - Linux
+///
+/// The following Processors define no additional values for
+/// the e_type field:
+///
+/// - ARM32
+/// - AARCH64
+/// - i386
+/// - m68k
+/// - MIPS
+/// - PA-RISC
+/// - PPC
+/// - PPC64
+/// - RISC-V
+/// - S390
+/// - S390X
+/// - SPARC
+/// - x86_64
+///
+/// Therefore, it is possible to have an undefined flag, but is unlikely in a
+/// well-formed ELF object file.  The OS-specific range of types is [0xfe00, 0xfeff]
+/// and the processor-specific range of types is [0xff00, 0xffff]. Values in these
+/// ranges and other undefined values are kept as-is, so they are written unchanged.
+pub enum ElfType<const EC: u8, const ED: u8> {
+    /// No file type
+    None,
+    /// Relocatable file type
+    Relocatable,
+    /// Executable file type
+    Executable,
+    /// Shared object file type
+    Dynamic,
+    /// Core file
+    Core,
+    /// OS-specific file type
+    OsSpecific(u16),
+    /// Processor-specific file type
+    ProcessorSpecific(u16),
+    /// All others
+    Other(u16),
+}
+
+impl<const EC: u8, const ED: u8> ElfType<EC, ED> {
+    /// No file type
+    pub const NONE: u16 = 0;
+    /// Relocatable file type
+    pub const RELOCATABLE: u16 = 1;
+    /// Executable file type
+    pub const EXECUTABLE: u16 = 2;
+    /// Shared object file type
+    pub const DYNAMIC: u16 = 3;
+    /// Core file
+    pub const CORE: u16 = 4;
+    /// Values in this inclusive range are reserved for operating system-specific
+    /// semantics.
+    pub const LOW_OPERATING_SYSTEM: u16 = 0xfe00;
+    /// Values in this inclusive range are reserved for operating system-specific
+    /// semantics.
+    pub const HIGH_OPERATING_SYSTEM: u16 = 0xfeff;
+    /// Values in this inclusive range are reserved for processor-specific semantics.
+    pub const LOW_PROCESSOR_SPECIFIC: u16 = 0xff00;
+    /// Values in this inclusive range are reserved for processor-specific semantics.
+    pub const HIGH_PROCESSOR_SPECIFIC: u16 = 0xffff;
+
+    /// Whether the file has no file type (`ET_NONE`)
+    pub const fn is_none(&self) -> bool {
+        matches!(self, Self::None)
+    }
+
+    /// Whether the file is a relocatable object (`ET_REL`)
+    pub const fn is_relocatable(&self) -> bool {
+        matches!(self, Self::Relocatable)
+    }
+
+    /// Whether the file is an executable (`ET_EXEC`). Position independent executables
+    /// are shared objects, and are not executables by this definition.
+    pub const fn is_executable(&self) -> bool {
+        matches!(self, Self::Executable)
+    }
+
+    /// Whether the file is a shared object (`ET_DYN`), which includes position
+    /// independent executables
+    pub const fn is_shared_object(&self) -> bool {
+        matches!(self, Self::Dynamic)
+    }
+
+    /// Whether the file is a core file (`ET_CORE`)
+    pub const fn is_core(&self) -> bool {
+        matches!(self, Self::Core)
+    }
+}
+
+impl<const EC: u8, const ED: u8> From<u16> for ElfType<EC, ED> {
+    fn from(value: u16) -> Self {
+        match value {
+            Self::NONE => Self::None,
+            Self::RELOCATABLE => Self::Relocatable,
+            Self::EXECUTABLE => Self::Executable,
+            Self::DYNAMIC => Self::Dynamic,
+            Self::CORE => Self::Core,
+            Self::LOW_OPERATING_SYSTEM..=Self::HIGH_OPERATING_SYSTEM => Self::OsSpecific(value),
+            Self::LOW_PROCESSOR_SPECIFIC..=Self::HIGH_PROCESSOR_SPECIFIC => {
+                Self::ProcessorSpecific(value)
+            }
+            other => Self::Other(other),
+        }
+    }
+}
+
+impl<const EC: u8, const ED: u8> From<ElfType<EC, ED>> for u16 {
+    fn from(value: ElfType<EC, ED>) -> Self {
+        match value {
+            ElfType::None => ElfType::<EC, ED>::NONE,
+            ElfType::Relocatable => ElfType::<EC, ED>::RELOCATABLE,
+            ElfType::Executable => ElfType::<EC, ED>::EXECUTABLE,
+            ElfType::Dynamic => ElfType::<EC, ED>::DYNAMIC,
+            ElfType::Core => ElfType::<EC, ED>::CORE,
+            ElfType::OsSpecific(value)
+            | ElfType::ProcessorSpecific(value)
+            | ElfType::Other(value) => value,
+        }
     }
 }
 
@@ -76,14 +173,7 @@ where
     fn from_reader_with(reader: &mut R, config: &mut Config) -> Result<Self, Self::Error> {
         let ty = ElfHalfWord::<EC, ED>::from_reader_with(reader, config)?;
 
-        if let Some(ty) = Self::from_u16(ty.0) {
-            Ok(ty)
-        } else {
-            Err(Error::InvalidType {
-                context: ErrorContext::from_reader(reader, size_of::<ElfHalfWord<EC, ED>>())
-                    .map_err(Error::from)?,
-            })
-        }
+        Ok(Self::from(ty.0))
     }
 }
 
@@ -94,7 +184,7 @@ where
     type Error = Error;
 
     fn to_writer(&self, writer: &mut W) -> Result<(), Self::Error> {
-        ElfHalfWord::<EC, ED>((*self as u16).to_le()).to_writer(writer)
+        ElfHalfWord::<EC, ED>(u16::from(*self)).to_writer(writer)
     }
 }
 
@@ -487,6 +577,24 @@ from_primitive! {
         /// LoongArch
         LOONGARCH = 258,
     }
+    /// A machine which is not otherwise defined, such as one assigned after this crate
+    /// was released
+    other Other(u16);
+}
+
+impl<const EC: u8, const ED: u8> ElfMachine<EC, ED> {
+    /// The usual size of a page of memory on the machine, which is the largest page
+    /// size its common operating systems are configured with, so that segments laid out
+    /// for it can be mapped on any of them
+    pub fn default_page_size(&self) -> u64 {
+        match self {
+            ElfMachine::AARCH64 | ElfMachine::PPC64 | ElfMachine::IA_64 => 0x10000,
+            ElfMachine::MIPS | ElfMachine::MIPS_RS3_LE => 0x10000,
+            ElfMachine::LOONGARCH => 0x4000,
+            ElfMachine::SPARCV9 | ElfMachine::ALPHA => 0x2000,
+            _ => 0x1000,
+        }
+    }
 }
 
 impl<R, const EC: u8, const ED: u8> FromReader<R> for ElfMachine<EC, ED>
@@ -498,17 +606,14 @@ where
     fn from_reader_with(reader: &mut R, config: &mut Config) -> Result<Self, Self::Error> {
         let machine = ElfHalfWord::<EC, ED>::from_reader_with(reader, config)?;
 
-        if let Some(e_machine) = Self::from_u16(machine.0) {
-            config.machine =
-                ElfMachine::<ELF_CLASS_DEFAULT, ELF_DATA_ENCODING_DEFAULT>::from_u16(machine.0);
+        let e_machine = Self::from(machine.0);
 
-            Ok(e_machine)
-        } else {
-            Err(Error::InvalidMachine {
-                context: ErrorContext::from_reader(reader, size_of::<ElfHalfWord<EC, ED>>())
-                    .map_err(Error::from)?,
-            })
+        if config.auto_machine {
+            config.machine =
+                Some(ElfMachine::<ELF_CLASS_DEFAULT, ELF_DATA_ENCODING_DEFAULT>::from(machine.0));
         }
+
+        Ok(e_machine)
     }
 }
 
@@ -519,7 +624,7 @@ where
     type Error = Error;
 
     fn to_writer(&self, writer: &mut W) -> Result<(), Self::Error> {
-        ElfHalfWord::<EC, ED>((*self as u16).to_le()).to_writer(writer)
+        ElfHalfWord::<EC, ED>(u16::from(*self)).to_writer(writer)
     }
 }
 
@@ -552,11 +657,10 @@ where
             Ok(version)
         } else {
             let err = Error::InvalidVersion {
-                context: ErrorContext::from_reader(reader, size_of::<ElfWord<EC, ED>>())
-                    .map_err(Error::from)?,
+                context: ErrorContext::from_reader(reader, size_of::<ElfWord<EC, ED>>())?,
             };
 
-            if config.ignore.contains(&err) {
+            if config.ignores(&err) {
                 Ok(Self::None)
             } else {
                 Err(err)
@@ -591,6 +695,7 @@ pub enum ElfHeaderFlags<const EC: u8, const ED: u8> {
     /// shall be zero
     AARCH64(ElfWord<EC, ED>),
     /// Platform-specific flags for ARM32
+    #[cfg(feature = "arch")]
     ARM32(ElfHeaderFlagsARM32<EC, ED>),
     /// Platform-specific flags for i386
     ///
@@ -601,16 +706,19 @@ pub enum ElfHeaderFlags<const EC: u8, const ED: u8> {
     ///
     /// m68k defines no processor-specific flags but does not specify the value
     /// of this field
+    #[cfg(feature = "arch")]
     M68K(ElfHeaderFlagsM68K<EC, ED>),
     /// Platform-specific flags for MIPS
     ///
     /// MIPS defines no processor-specific flags but does not specify the value
     /// of this field
+    #[cfg(feature = "arch")]
     MIPS(ElfHeaderFlagsMIPS<EC, ED>),
     /// Platform-specific flags for PA-RISC
     ///
     /// PA-RISC defines no processor-specific flags but does not specify the value
     /// of this field
+    #[cfg(feature = "arch")]
     PARISC(ElfHeaderFlagsPARISC<EC, ED>),
     /// Platform-specific flags for PPC
     ///
@@ -626,6 +734,7 @@ pub enum ElfHeaderFlags<const EC: u8, const ED: u8> {
     ///
     /// RISC-V defines no processor-specific flags but does not specify the value
     /// of this field
+    #[cfg(feature = "arch")]
     RISCV(ElfHeaderFlagsRISCV<EC, ED>),
     /// Platform-specific flags for S390
     ///
@@ -656,6 +765,65 @@ pub enum ElfHeaderFlags<const EC: u8, const ED: u8> {
     Other(ElfWord<EC, ED>),
 }
 
+#[cfg(feature = "arch")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+/// The flags decoded from an ELF header's flags field, for the machines which have a
+/// flag decoder
+pub enum DecodedFlags<'a> {
+    /// Decoded flags for ARM32
+    ARM32(&'a [ElfHeaderFlagARM32]),
+    /// Decoded flags for m68k
+    M68K(&'a [ElfHeaderFlagM68K]),
+    /// Decoded flags for MIPS
+    MIPS(&'a [ElfHeaderFlagMIPS]),
+    /// Decoded flags for PA-RISC
+    PARISC(&'a [ElfHeaderFlagPARISC]),
+    /// Decoded flags for RISC-V
+    RISCV(&'a [ElfHeaderFlagRISCV]),
+}
+
+impl<const EC: u8, const ED: u8> ElfHeaderFlags<EC, ED> {
+    /// The raw value of the flags field
+    pub fn value(&self) -> u32 {
+        match self {
+            #[cfg(feature = "arch")]
+            Self::ARM32(flags) => flags.value(),
+            #[cfg(feature = "arch")]
+            Self::M68K(flags) => flags.value(),
+            #[cfg(feature = "arch")]
+            Self::MIPS(flags) => flags.value(),
+            #[cfg(feature = "arch")]
+            Self::PARISC(flags) => flags.value(),
+            #[cfg(feature = "arch")]
+            Self::RISCV(flags) => flags.value(),
+            Self::AARCH64(flags)
+            | Self::I386(flags)
+            | Self::PPC(flags)
+            | Self::PPC64(flags)
+            | Self::S390(flags)
+            | Self::S390X(flags)
+            | Self::SPARC(flags)
+            | Self::SH(flags)
+            | Self::X86_64(flags)
+            | Self::Other(flags) => flags.0,
+        }
+    }
+
+    /// The decoded flags, or `None` if the machine has no flag decoder
+    #[cfg(feature = "arch")]
+    pub fn decoded(&self) -> Option<DecodedFlags<'_>> {
+        match self {
+            Self::ARM32(flags) => Some(DecodedFlags::ARM32(flags.flags())),
+            Self::M68K(flags) => Some(DecodedFlags::M68K(flags.flags())),
+            Self::MIPS(flags) => Some(DecodedFlags::MIPS(flags.flags())),
+            Self::PARISC(flags) => Some(DecodedFlags::PARISC(flags.flags())),
+            Self::RISCV(flags) => Some(DecodedFlags::RISCV(flags.flags())),
+            _ => None,
+        }
+    }
+}
+
 impl<const EC: u8, const ED: u8> TryFromWithConfig<ElfWord<EC, ED>> for ElfHeaderFlags<EC, ED> {
     type Error = Error;
 
@@ -664,24 +832,29 @@ impl<const EC: u8, const ED: u8> TryFromWithConfig<ElfWord<EC, ED>> for ElfHeade
         // because there isn't actually a type for all of them (some define no flags)
         match config.machine {
             Some(ElfMachine::AARCH64) => Ok(Self::AARCH64(value)),
+            #[cfg(feature = "arch")]
             Some(ElfMachine::ARM) => Ok(Self::ARM32(ElfHeaderFlagsARM32::try_from_with(
                 value, config,
             )?)),
             Some(ElfMachine::I386) => Ok(Self::I386(value)),
             // NOTE: ColdFire is the same family according to Wikipedia
+            #[cfg(feature = "arch")]
             Some(ElfMachine::M68K) | Some(ElfMachine::COLDFIRE) => Ok(Self::M68K(
                 ElfHeaderFlagsM68K::try_from_with(value, config)?,
             )),
+            #[cfg(feature = "arch")]
             Some(ElfMachine::MIPS) | Some(ElfMachine::MIPS_RS3_LE) | Some(ElfMachine::MIPS_X) => {
                 Ok(Self::MIPS(ElfHeaderFlagsMIPS::try_from_with(
                     value, config,
                 )?))
             }
+            #[cfg(feature = "arch")]
             Some(ElfMachine::PARISC) => Ok(Self::PARISC(ElfHeaderFlagsPARISC::try_from_with(
                 value, config,
             )?)),
             Some(ElfMachine::PPC) => Ok(Self::PPC(value)),
             Some(ElfMachine::PPC64) => Ok(Self::PPC64(value)),
+            #[cfg(feature = "arch")]
             Some(ElfMachine::Riscv) => Ok(Self::RISCV(ElfHeaderFlagsRISCV::try_from_with(
                 value, config,
             )?)),
@@ -705,13 +878,18 @@ where
     fn to_writer(&self, writer: &mut W) -> Result<(), Self::Error> {
         match self {
             Self::AARCH64(flags) => flags.to_writer(writer),
+            #[cfg(feature = "arch")]
             Self::ARM32(flags) => flags.to_writer(writer),
             Self::I386(flags) => flags.to_writer(writer),
+            #[cfg(feature = "arch")]
             Self::M68K(flags) => flags.to_writer(writer),
+            #[cfg(feature = "arch")]
             Self::MIPS(flags) => flags.to_writer(writer),
+            #[cfg(feature = "arch")]
             Self::PARISC(flags) => flags.to_writer(writer),
             Self::PPC(flags) => flags.to_writer(writer),
             Self::PPC64(flags) => flags.to_writer(writer),
+            #[cfg(feature = "arch")]
             Self::RISCV(flags) => flags.to_writer(writer),
             Self::S390(flags) => flags.to_writer(writer),
             Self::S390X(flags) => flags.to_writer(writer),
@@ -727,6 +905,42 @@ impl<const EC: u8, const ED: u8> HasWrittenSize for ElfHeaderFlags<EC, ED> {
     const SIZE: usize = size_of::<ElfWord<EC, ED>>();
 }
 
+impl<const EC: u8, const ED: u8> core::fmt::Display for ElfHeaderFlags<EC, ED> {
+    /// Formats the flags like the `Flags:` line printed by `readelf -h`: the raw value,
+    /// followed by the decoded flags for machines which define any
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            #[cfg(feature = "arch")]
+            Self::ARM32(flags) => flags.fmt(f),
+            #[cfg(feature = "arch")]
+            Self::M68K(flags) => flags.fmt(f),
+            #[cfg(feature = "arch")]
+            Self::MIPS(flags) => flags.fmt(f),
+            #[cfg(feature = "arch")]
+            Self::PARISC(flags) => flags.fmt(f),
+            #[cfg(feature = "arch")]
+            Self::RISCV(flags) => flags.fmt(f),
+            Self::PPC64(flags) => {
+                write!(f, "{:#x}", flags.0)?;
+                // The low two bits hold the version of the ABI (`EF_PPC64_ABI`)
+                match flags.0 & 0x3 {
+                    0 => Ok(()),
+                    version => write!(f, ", abiv{version}"),
+                }
+            }
+            Self::AARCH64(flags)
+            | Self::I386(flags)
+            | Self::PPC(flags)
+            | Self::S390(flags)
+            | Self::S390X(flags)
+            | Self::SPARC(flags)
+            | Self::SH(flags)
+            | Self::X86_64(flags)
+            | Self::Other(flags) => write!(f, "{:#x}", flags.0),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, TypedBuilder)]
 /// The header for an ELF object. Resides at the beginning and holds a ``road map''
 /// describing the file's organization
@@ -800,6 +1014,79 @@ impl<const EC: u8, const ED: u8> ElfHeader<EC, ED> {
         + ElfOffset::<EC, ED>::SIZE
         + ElfHeaderFlags::<EC, ED>::SIZE
         + (ElfHalfWord::<EC, ED>::SIZE * 6);
+
+    /// The largest amount of extra data after the standard fields which is accepted in a
+    /// header. No ABI places more than a few bytes there, so a larger `header_size` is
+    /// invalid rather than a reason to read a large amount of extra data.
+    pub const MAX_DATA_SIZE: usize = 0x100;
+
+    /// The encoded bytes of the header, including the extra data after the standard
+    /// fields, so their length is `header_size` for a header which was read from an
+    /// object
+    pub fn raw_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut writer = Cursor::new(Vec::with_capacity(Self::SIZE + self.data.len()));
+        self.to_writer(&mut writer)?;
+        Ok(writer.into_inner())
+    }
+
+    /// Set the machine type and OS ABI in `config` from this header, as decoding the
+    /// header does, for decoding the rest of an object whose header was decoded with a
+    /// different configuration
+    pub(crate) fn configure(&self, config: &mut Config) {
+        if config.auto_machine {
+            config.machine = Some(
+                ElfMachine::<ELF_CLASS_DEFAULT, ELF_DATA_ENCODING_DEFAULT>::from(u16::from(
+                    self.machine,
+                )),
+            );
+        }
+
+        if config.auto_os_abi {
+            config.os_abi = Some(self.identifier.os_abi);
+        }
+    }
+
+    /// Check that the section header table declared by this header fits in the file in
+    /// `reader`, using the length of the stream and without reading or allocating any of
+    /// its entries. When the entry count is held in the first section header, only the
+    /// first entry is checked. Decoding an object makes the same check, so this is useful
+    /// to reject a file with a forged `e_shnum` before decoding it.
+    pub fn check_section_header_table_bounds<R>(&self, reader: &mut R) -> Result<(), Error>
+    where
+        R: Read + Seek,
+    {
+        let offset = match self.section_header_offset {
+            Some(offset) if offset.0 != 0 => offset.0,
+            _ => return Ok(()),
+        };
+        let length = reader.seek(SeekFrom::End(0))?;
+
+        ElfSectionHeader::<EC, ED>::check_table_bounds(
+            offset,
+            self.section_header_entry_count.0.max(1) as u64,
+            self.section_header_entry_size.0 as u64,
+            length,
+        )
+    }
+}
+
+impl<const EC: u8, const ED: u8> Elf<EC, ED> {
+    /// The object's processor-specific flags, formatted like the `Flags:` line printed by
+    /// `readelf -h`. The decoded flags, such as the MIPS ABI and ISA, the ARM EABI
+    /// version and float ABI, the RISC-V extensions and float ABI, and the PPC64 ABI
+    /// version, follow the raw value, which is all that is shown for machines without a
+    /// flag decoder.
+    pub fn format_header_flags(&self) -> String {
+        self.header.flags.to_string()
+    }
+
+    /// The raw value of the object's processor-specific flags, and the flags decoded
+    /// from it if the machine has a flag decoder, so that the raw value can always be
+    /// shown without knowing which machines are supported
+    #[cfg(feature = "arch")]
+    pub fn header_flags(&self) -> (u32, Option<DecodedFlags<'_>>) {
+        (self.header.flags.value(), self.header.flags.decoded())
+    }
 }
 
 impl<R, const EC: u8, const ED: u8> FromReader<R> for ElfHeader<EC, ED>
@@ -810,6 +1097,28 @@ where
 
     fn from_reader_with(reader: &mut R, config: &mut Config) -> Result<Self, Self::Error> {
         let identifier = ElfHeaderIdentifier::from_reader_with(reader, config)?;
+        let expected_class = ElfClass::const_from_u8(EC);
+        let expected_encoding = ElfDataEncoding::const_from_u8(ED);
+
+        // An identifier with no class or data encoding is reported when the kind of the
+        // object is decided, so only a valid class or data encoding which differs is an
+        // error here
+        if (!matches!(identifier.class, ElfClass::None) && identifier.class != expected_class)
+            || (!matches!(identifier.data_encoding, ElfDataEncoding::None)
+                && identifier.data_encoding != expected_encoding)
+        {
+            let err = Error::ClassEncodingMismatch {
+                class: identifier.class,
+                encoding: identifier.data_encoding,
+                expected_class,
+                expected_encoding,
+            };
+
+            if !config.ignores(&err) {
+                return Err(err);
+            }
+        }
+
         let r#type = ElfType::<EC, ED>::from_reader_with(reader, config)?;
         let machine = ElfMachine::<EC, ED>::from_reader_with(reader, config)?;
         let version = ElfVersion::<EC, ED>::from_reader_with(reader, config)?;
@@ -819,6 +1128,30 @@ where
         let flags_raw = ElfWord::<EC, ED>::from_reader_with(reader, config)?;
         let flags = ElfHeaderFlags::<EC, ED>::try_from_with(flags_raw, config)?;
         let header_size = ElfHalfWord::<EC, ED>::from_reader_with(reader, config)?;
+        let header_size_offset = reader
+            .stream_position()?
+            .saturating_sub(ElfHalfWord::<EC, ED>::SIZE as u64);
+
+        // The header size must be at least the size of the fields of the header. If this error
+        // is ignored, the header is treated as having no extra data.
+        let data_size = match header_size.as_usize()?.checked_sub(Self::SIZE) {
+            Some(data_size) => data_size,
+            None => {
+                let err = Error::InvalidHeaderSize {
+                    context: ErrorContext::from_reader_at(
+                        reader,
+                        header_size_offset,
+                        ElfHalfWord::<EC, ED>::SIZE,
+                    )?,
+                };
+
+                if config.ignores(&err) {
+                    0
+                } else {
+                    return Err(err);
+                }
+            }
+        };
         let program_header_entry_size = ElfHalfWord::<EC, ED>::from_reader_with(reader, config)?;
         let program_header_entry_count = ElfHalfWord::<EC, ED>::from_reader_with(reader, config)?;
         let section_header_entry_size = ElfHalfWord::<EC, ED>::from_reader_with(reader, config)?;
@@ -826,11 +1159,52 @@ where
         let section_name_string_table_index =
             ElfHalfWord::<EC, ED>::from_reader_with(reader, config)?;
 
+        // The header size must not leave more than `MAX_DATA_SIZE` bytes of extra data. If
+        // this error is ignored, only the first `MAX_DATA_SIZE` bytes of extra data are kept.
+        let data_size = match data_size > Self::MAX_DATA_SIZE {
+            true => {
+                let err = Error::InvalidHeaderSize {
+                    context: ErrorContext::from_reader_at(
+                        reader,
+                        header_size_offset,
+                        ElfHalfWord::<EC, ED>::SIZE,
+                    )?,
+                };
+
+                if !config.ignores(&err) {
+                    return Err(err);
+                }
+
+                Self::MAX_DATA_SIZE
+            }
+            false => data_size,
+        };
+
+        // The extra data is read only as far as the end of the reader, so a header size
+        // which runs past the end of a small file does not cause a large allocation. If the extra data is cut short, the header
+        // size is invalid. If this error is ignored, the extra data that could be read is kept.
         let data = {
-            let mut data = vec![ElfByte(0); (header_size.0 as usize).saturating_sub(Self::SIZE)];
-            data.iter_mut()
-                .try_for_each(|b| ElfByte::from_reader_with(reader, config).map(|r| *b = r))?;
-            data
+            let mut data = Vec::new();
+            reader.take(data_size as u64).read_to_end(&mut data)?;
+
+            if data.len() < data_size {
+                let position = reader.stream_position()?;
+                let err = Error::InvalidHeaderSize {
+                    context: ErrorContext::from_reader_at(
+                        reader,
+                        header_size_offset,
+                        ElfHalfWord::<EC, ED>::SIZE,
+                    )?,
+                };
+
+                if !config.ignores(&err) {
+                    return Err(err);
+                }
+
+                reader.seek(SeekFrom::Start(position))?;
+            }
+
+            data.into_iter().map(ElfByte).collect()
         };
 
         Ok(Self {
@@ -886,12 +1260,15 @@ where
         self.section_header_entry_size.to_writer(writer)?;
         self.section_header_entry_count.to_writer(writer)?;
         self.section_name_string_table_index.to_writer(writer)?;
+        for byte in &self.data {
+            byte.to_writer(writer)?;
+        }
         Ok(())
     }
 }
 
 #[allow(clippy::unwrap_used)]
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod test {
     use super::*;
     use identification::*;
@@ -910,6 +1287,11 @@ mod test {
         assert_eq!(be32t, ElfType::Relocatable);
         assert_eq!(le64t, ElfType::Relocatable);
         assert_eq!(be64t, ElfType::Relocatable);
+        assert!(le32t.is_relocatable());
+        assert!(!le32t.is_executable());
+        assert!(!le32t.is_shared_object());
+        assert!(!le32t.is_core());
+        assert!(!le32t.is_none());
 
         let mut le32t_out = Vec::new();
         le32t.to_writer(&mut le32t_out).unwrap();
@@ -925,6 +1307,29 @@ mod test {
         assert_eq!(be64t_out, bytes_be);
     }
 
+    #[test]
+    fn test_elf_type_reserved() {
+        for (bytes, expected) in [
+            ([0x00, 0xfe], ElfType::OsSpecific(0xfe00)),
+            ([0x34, 0xff], ElfType::ProcessorSpecific(0xff34)),
+            ([0x05, 0x00], ElfType::Other(0x0005)),
+        ] {
+            let ty = ElfType::<{ ElfClass::Elf64 as u8 }, { ElfDataEncoding::LittleEndian as u8 }>::from_reader(&mut std::io::Cursor::new(&bytes)).unwrap();
+            assert_eq!(ty, expected);
+            assert!(
+                !(ty.is_none()
+                    || ty.is_relocatable()
+                    || ty.is_executable()
+                    || ty.is_shared_object()
+                    || ty.is_core())
+            );
+
+            let mut out = Vec::new();
+            ty.to_writer(&mut out).unwrap();
+            assert_eq!(out, bytes);
+        }
+    }
+
     #[test]
     fn test_elf_machine() {
         let mut bytes_le = &[0x03, 0x00];
@@ -953,6 +1358,21 @@ mod test {
         be64m.to_writer(&mut be64m_out).unwrap();
     }
 
+    #[test]
+    fn test_elf_machine_other() {
+        let bytes = [0x39, 0x30];
+        let machine = ElfMachine::<
+            { ElfClass::Elf64 as u8 },
+            { ElfDataEncoding::LittleEndian as u8 },
+        >::from_reader(&mut std::io::Cursor::new(&bytes))
+        .unwrap();
+        assert_eq!(machine, ElfMachine::Other(0x3039));
+
+        let mut out = Vec::new();
+        machine.to_writer(&mut out).unwrap();
+        assert_eq!(out, bytes);
+    }
+
     #[test]
     fn test_elf_version() {
         let mut bytes_le = &[0x01, 0x00, 0x00, 0x00];
@@ -1 +1,1321 @@
 //! Implementation of the ELF program header
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bitflags::bitflags;
+use core::mem::size_of;
+use num_traits::FromPrimitive;
+use typed_builder::TypedBuilder;
+
+#[cfg(feature = "arch")]
+use crate::arch::{
+    aarch64::ElfProgramHeaderTypeAARCH64, arm32::ElfProgramHeaderTypeARM32,
+    mips::ElfProgramHeaderTypeMIPS, riscv::ElfProgramHeaderTypeRISCV,
+};
+use crate::{
+    base::{usize_from, ElfAddress, ElfByte, ElfExtendedWord, ElfOffset, ElfWord},
+    error::Error,
+    io::{Cursor, ErrorKind, Read, Seek, SeekFrom, Write},
+    os::{
+        gnu::ElfProgramHeaderTypeGNU, openbsd::ElfProgramHeaderTypeOpenBSD,
+        sun::ElfProgramHeaderTypeSUN,
+    },
+    Config, Elf, FromReader, HasWrittenSize, ToWriter, TryFromWithConfig,
+};
+
+#[cfg(feature = "arch")]
+use super::elf::ElfMachine;
+use super::{
+    elf::{
+        identification::{ElfClass, ElfOSABI},
+        ElfHeader,
+    },
+    section::{
+        string_at, ElfSectionHeader, ElfSectionHeaderFlags, ElfSectionHeaderType, SectionIndex,
+    },
+};
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+/// The kind of segment an ELF program header describes
+pub enum ElfProgramHeaderType<const EC: u8, const ED: u8> {
+    /// The array element is unused; other members' values are undefined. This type
+    /// lets the program header table have ignored entries.
+    Null = 0,
+    /// The array element specifies a loadable segment, described by `p_filesz` and
+    /// `p_memsz`. The bytes from the file are mapped to the beginning of the memory
+    /// segment. If the segment's memory size is larger than the file size, the extra
+    /// bytes are defined to hold the value 0 and to follow the segment's initialized
+    /// area.
+    Load = 1,
+    /// The array element specifies dynamic linking information
+    Dynamic = 2,
+    /// The array element specifies the location and size of a null-terminated path name
+    /// to invoke as an interpreter. This segment type is meaningful only for executable
+    /// files, and it may not occur more than once in a file.
+    Interpreter = 3,
+    /// The array element specifies the location and size of auxiliary information
+    Note = 4,
+    /// This segment type is reserved but has unspecified semantics
+    SharedLibrary = 5,
+    /// The array element, if present, specifies the location and size of the program
+    /// header table itself, both in the file and in the memory image of the program.
+    /// This segment type may not occur more than once in a file, and may only occur if
+    /// the program header table is part of the memory image of the program.
+    ProgramHeader = 6,
+    /// The array element specifies the Thread-Local Storage template
+    ThreadLocalStorage = 7,
+    /// AARCH64-specific program header types
+    #[cfg(feature = "arch")]
+    AARCH64(ElfProgramHeaderTypeAARCH64),
+    /// ARM-specific program header types
+    #[cfg(feature = "arch")]
+    Arm(ElfProgramHeaderTypeARM32),
+    /// MIPS-specific program header types
+    #[cfg(feature = "arch")]
+    Mips(ElfProgramHeaderTypeMIPS),
+    /// RISC-V-specific program header types
+    #[cfg(feature = "arch")]
+    Riscv(ElfProgramHeaderTypeRISCV),
+    /// A processor-specific program header type which is not known
+    OtherProcessorSpecific(ElfWord<EC, ED>),
+    /// GNU-specific program header types
+    Gnu(ElfProgramHeaderTypeGNU),
+    /// OpenBSD-specific program header types
+    OpenBsd(ElfProgramHeaderTypeOpenBSD),
+    /// SUN-specific program header types
+    Sun(ElfProgramHeaderTypeSUN),
+    /// An operating system-specific program header type which is not known
+    OtherOperatingSystemSpecific(ElfWord<EC, ED>),
+    /// A program header type which is not known
+    Other(ElfWord<EC, ED>),
+}
+
+impl<const EC: u8, const ED: u8> ElfProgramHeaderType<EC, ED> {
+    /// Constant value for [ElfProgramHeaderType::Null]
+    pub const NULL: u32 = 0;
+    /// Constant value for [ElfProgramHeaderType::Load]
+    pub const LOAD: u32 = 1;
+    /// Constant value for [ElfProgramHeaderType::Dynamic]
+    pub const DYNAMIC: u32 = 2;
+    /// Constant value for [ElfProgramHeaderType::Interpreter]
+    pub const INTERPRETER: u32 = 3;
+    /// Constant value for [ElfProgramHeaderType::Note]
+    pub const NOTE: u32 = 4;
+    /// Constant value for [ElfProgramHeaderType::SharedLibrary]
+    pub const SHARED_LIBRARY: u32 = 5;
+    /// Constant value for [ElfProgramHeaderType::ProgramHeader]
+    pub const PROGRAM_HEADER: u32 = 6;
+    /// Constant value for [ElfProgramHeaderType::ThreadLocalStorage]
+    pub const THREAD_LOCAL_STORAGE: u32 = 7;
+    /// Values in this inclusive range are reserved for operating system-specific
+    /// semantics.
+    pub const LOW_OPERATING_SYSTEM: u32 = 0x60000000;
+    /// Values in this inclusive range are reserved for operating system-specific
+    /// semantics.
+    pub const HIGH_OPERATING_SYSTEM: u32 = 0x6fffffff;
+    /// Values in this inclusive range are reserved for processor-specific semantics.
+    pub const LOW_PROCESSOR_SPECIFIC: u32 = 0x70000000;
+    /// Values in this inclusive range are reserved for processor-specific semantics.
+    pub const HIGH_PROCESSOR_SPECIFIC: u32 = 0x7fffffff;
+
+    /// The raw value of the program header type
+    pub fn value(&self) -> u32 {
+        ElfWord::<EC, ED>::from(self).0
+    }
+
+    /// Convert this program header type to the same program header type for another
+    /// ELF class. The value of a program header type is the same size for both classes.
+    pub(crate) fn with_class<const TC: u8>(self) -> ElfProgramHeaderType<TC, ED> {
+        match self {
+            Self::Null => ElfProgramHeaderType::Null,
+            Self::Load => ElfProgramHeaderType::Load,
+            Self::Dynamic => ElfProgramHeaderType::Dynamic,
+            Self::Interpreter => ElfProgramHeaderType::Interpreter,
+            Self::Note => ElfProgramHeaderType::Note,
+            Self::SharedLibrary => ElfProgramHeaderType::SharedLibrary,
+            Self::ProgramHeader => ElfProgramHeaderType::ProgramHeader,
+            Self::ThreadLocalStorage => ElfProgramHeaderType::ThreadLocalStorage,
+            #[cfg(feature = "arch")]
+            Self::AARCH64(value) => ElfProgramHeaderType::AARCH64(value),
+            #[cfg(feature = "arch")]
+            Self::Arm(value) => ElfProgramHeaderType::Arm(value),
+            #[cfg(feature = "arch")]
+            Self::Mips(value) => ElfProgramHeaderType::Mips(value),
+            #[cfg(feature = "arch")]
+            Self::Riscv(value) => ElfProgramHeaderType::Riscv(value),
+            Self::OtherProcessorSpecific(value) => {
+                ElfProgramHeaderType::OtherProcessorSpecific(ElfWord(value.0))
+            }
+            Self::Gnu(value) => ElfProgramHeaderType::Gnu(value),
+            Self::OpenBsd(value) => ElfProgramHeaderType::OpenBsd(value),
+            Self::Sun(value) => ElfProgramHeaderType::Sun(value),
+            Self::OtherOperatingSystemSpecific(value) => {
+                ElfProgramHeaderType::OtherOperatingSystemSpecific(ElfWord(value.0))
+            }
+            Self::Other(value) => ElfProgramHeaderType::Other(ElfWord(value.0)),
+        }
+    }
+}
+
+impl<R, const EC: u8, const ED: u8> FromReader<R> for ElfProgramHeaderType<EC, ED>
+where
+    R: Read + Seek,
+{
+    type Error = Error;
+
+    fn from_reader_with(reader: &mut R, config: &mut Config) -> Result<Self, Self::Error> {
+        let r#type = ElfWord::<EC, ED>::from_reader_with(reader, config)?;
+
+        match r#type.0 {
+            Self::NULL => Ok(Self::Null),
+            Self::LOAD => Ok(Self::Load),
+            Self::DYNAMIC => Ok(Self::Dynamic),
+            Self::INTERPRETER => Ok(Self::Interpreter),
+            Self::NOTE => Ok(Self::Note),
+            Self::SHARED_LIBRARY => Ok(Self::SharedLibrary),
+            Self::PROGRAM_HEADER => Ok(Self::ProgramHeader),
+            Self::THREAD_LOCAL_STORAGE => Ok(Self::ThreadLocalStorage),
+            other => {
+                if (Self::LOW_OPERATING_SYSTEM..=Self::HIGH_OPERATING_SYSTEM).contains(&other) {
+                    // The table for the object's OS ABI is tried first, falling back to
+                    // the others, as with section header types
+                    let sun = |config: &mut Config| {
+                        ElfProgramHeaderTypeSUN::try_from_with(r#type, config).map(Self::Sun)
+                    };
+                    let others = |config: &mut Config| {
+                        ElfProgramHeaderTypeGNU::try_from_with(r#type, config)
+                            .map(Self::Gnu)
+                            .or_else(|_| {
+                                ElfProgramHeaderTypeOpenBSD::try_from_with(r#type, config)
+                                    .map(Self::OpenBsd)
+                            })
+                    };
+
+                    match config.os_abi {
+                        Some(ElfOSABI::Solaris) => sun(config).or_else(|_| others(config)),
+                        _ => others(config).or_else(|_| sun(config)),
+                    }
+                    .or(Ok(Self::OtherOperatingSystemSpecific(r#type)))
+                } else if (Self::LOW_PROCESSOR_SPECIFIC..=Self::HIGH_PROCESSOR_SPECIFIC)
+                    .contains(&other)
+                {
+                    match config.machine {
+                        #[cfg(feature = "arch")]
+                        Some(ElfMachine::AARCH64) => {
+                            ElfProgramHeaderTypeAARCH64::try_from_with(r#type, config)
+                                .map(Self::AARCH64)
+                        }
+                        #[cfg(feature = "arch")]
+                        Some(ElfMachine::ARM) => {
+                            ElfProgramHeaderTypeARM32::try_from_with(r#type, config).map(Self::Arm)
+                        }
+                        #[cfg(feature = "arch")]
+                        Some(ElfMachine::MIPS) => {
+                            ElfProgramHeaderTypeMIPS::try_from_with(r#type, config).map(Self::Mips)
+                        }
+                        #[cfg(feature = "arch")]
+                        Some(ElfMachine::Riscv) => {
+                            ElfProgramHeaderTypeRISCV::try_from_with(r#type, config)
+                                .map(Self::Riscv)
+                        }
+                        _ => Ok::<_, Error>(Self::OtherProcessorSpecific(r#type)),
+                    }
+                    .or(Ok(Self::OtherProcessorSpecific(r#type)))
+                } else {
+                    Ok(Self::Other(r#type))
+                }
+            }
+        }
+    }
+}
+
+impl<const EC: u8, const ED: u8> From<&ElfProgramHeaderType<EC, ED>> for ElfWord<EC, ED> {
+    fn from(value: &ElfProgramHeaderType<EC, ED>) -> Self {
+        match value {
+            ElfProgramHeaderType::Null => ElfWord(ElfProgramHeaderType::<EC, ED>::NULL),
+            ElfProgramHeaderType::Load => ElfWord(ElfProgramHeaderType::<EC, ED>::LOAD),
+            ElfProgramHeaderType::Dynamic => ElfWord(ElfProgramHeaderType::<EC, ED>::DYNAMIC),
+            ElfProgramHeaderType::Interpreter => {
+                ElfWord(ElfProgramHeaderType::<EC, ED>::INTERPRETER)
+            }
+            ElfProgramHeaderType::Note => ElfWord(ElfProgramHeaderType::<EC, ED>::NOTE),
+            ElfProgramHeaderType::SharedLibrary => {
+                ElfWord(ElfProgramHeaderType::<EC, ED>::SHARED_LIBRARY)
+            }
+            ElfProgramHeaderType::ProgramHeader => {
+                ElfWord(ElfProgramHeaderType::<EC, ED>::PROGRAM_HEADER)
+            }
+            ElfProgramHeaderType::ThreadLocalStorage => {
+                ElfWord(ElfProgramHeaderType::<EC, ED>::THREAD_LOCAL_STORAGE)
+            }
+            #[cfg(feature = "arch")]
+            ElfProgramHeaderType::AARCH64(value) => ElfWord::from(value),
+            #[cfg(feature = "arch")]
+            ElfProgramHeaderType::Arm(value) => ElfWord::from(value),
+            #[cfg(feature = "arch")]
+            ElfProgramHeaderType::Mips(value) => ElfWord::from(value),
+            #[cfg(feature = "arch")]
+            ElfProgramHeaderType::Riscv(value) => ElfWord::from(value),
+            ElfProgramHeaderType::OtherProcessorSpecific(value) => *value,
+            ElfProgramHeaderType::Gnu(value) => ElfWord::from(value),
+            ElfProgramHeaderType::OpenBsd(value) => ElfWord::from(value),
+            ElfProgramHeaderType::Sun(value) => ElfWord::from(value),
+            ElfProgramHeaderType::OtherOperatingSystemSpecific(value) => *value,
+            ElfProgramHeaderType::Other(value) => *value,
+        }
+    }
+}
+
+impl<W, const EC: u8, const ED: u8> ToWriter<W> for ElfProgramHeaderType<EC, ED>
+where
+    W: Write,
+{
+    type Error = Error;
+
+    fn to_writer(&self, writer: &mut W) -> Result<(), Self::Error> {
+        ElfWord::<EC, ED>::from(self).to_writer(writer)
+    }
+}
+
+impl<const EC: u8, const ED: u8> core::fmt::Display for ElfProgramHeaderType<EC, ED> {
+    /// Display the program header type as its name in `readelf` output
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Null => f.write_str("NULL"),
+            Self::Load => f.write_str("LOAD"),
+            Self::Dynamic => f.write_str("DYNAMIC"),
+            Self::Interpreter => f.write_str("INTERP"),
+            Self::Note => f.write_str("NOTE"),
+            Self::SharedLibrary => f.write_str("SHLIB"),
+            Self::ProgramHeader => f.write_str("PHDR"),
+            Self::ThreadLocalStorage => f.write_str("TLS"),
+            #[cfg(feature = "arch")]
+            Self::AARCH64(r#type) => r#type.fmt(f),
+            #[cfg(feature = "arch")]
+            Self::Arm(r#type) => r#type.fmt(f),
+            #[cfg(feature = "arch")]
+            Self::Mips(r#type) => r#type.fmt(f),
+            #[cfg(feature = "arch")]
+            Self::Riscv(r#type) => r#type.fmt(f),
+            Self::OtherProcessorSpecific(r#type) => {
+                write!(f, "LOPROC+{:#x}", r#type.0 - Self::LOW_PROCESSOR_SPECIFIC)
+            }
+            Self::Gnu(r#type) => r#type.fmt(f),
+            Self::OpenBsd(r#type) => r#type.fmt(f),
+            Self::Sun(r#type) => r#type.fmt(f),
+            Self::OtherOperatingSystemSpecific(r#type) => {
+                write!(f, "LOOS+{:#x}", r#type.0 - Self::LOW_OPERATING_SYSTEM)
+            }
+            Self::Other(r#type) => write!(f, "<unknown>: {:x}", r#type.0),
+        }
+    }
+}
+
+impl<const EC: u8, const ED: u8> HasWrittenSize for ElfProgramHeaderType<EC, ED> {
+    const SIZE: usize = size_of::<ElfWord<EC, ED>>();
+}
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    /// The permissions of an ELF segment. A segment is not required to be loaded with
+    /// exactly these permissions, but it will have at least these permissions.
+    pub struct ElfProgramHeaderFlags: u32 {
+        /// The segment is executable
+        const EXECUTE = 0x1;
+        /// The segment is writable
+        const WRITE = 0x2;
+        /// The segment is readable
+        const READ = 0x4;
+        /// All bits included in this mask are reserved for operating system-specific
+        /// semantics.
+        const MASK_OPERATING_SYSTEM = 0x0ff00000;
+        /// All bits included in this mask are reserved for processor-specific semantics.
+        const MASK_PROCESSOR = 0xf0000000;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, TypedBuilder)]
+/// ELF 32-bit Program Header
+pub struct Elf32ProgramHeader<const ED: u8> {
+    /// The kind of segment this element describes or how to interpret the element's
+    /// information
+    pub r#type: ElfProgramHeaderType<{ ElfClass::Elf32 as u8 }, ED>,
+    /// The offset from the beginning of the file at which the first byte of the segment
+    /// resides
+    pub offset: ElfOffset<{ ElfClass::Elf32 as u8 }, ED>,
+    /// The virtual address at which the first byte of the segment resides in memory
+    pub virtual_address: ElfAddress<{ ElfClass::Elf32 as u8 }, ED>,
+    /// On systems for which physical addressing is relevant, this member is reserved for
+    /// the segment's physical address
+    pub physical_address: ElfAddress<{ ElfClass::Elf32 as u8 }, ED>,
+    /// The number of bytes in the file image of the segment; it may be zero
+    pub file_size: ElfWord<{ ElfClass::Elf32 as u8 }, ED>,
+    /// The number of bytes in the memory image of the segment; it may be zero
+    pub memory_size: ElfWord<{ ElfClass::Elf32 as u8 }, ED>,
+    /// Flags relevant to the segment
+    pub flags: ElfProgramHeaderFlags,
+    /// The value to which the segments are aligned in memory and in the file. Values 0
+    /// and 1 mean no alignment is required. Otherwise, `align` should be a positive,
+    /// integral power of 2, and `virtual_address` should equal `offset`, modulo
+    /// `align`.
+    pub align: ElfWord<{ ElfClass::Elf32 as u8 }, ED>,
+}
+
+impl<R, const ED: u8> FromReader<R> for Elf32ProgramHeader<ED>
+where
+    R: Read + Seek,
+{
+    type Error = Error;
+
+    fn from_reader_with(reader: &mut R, config: &mut Config) -> Result<Self, Self::Error> {
+        let r#type = ElfProgramHeaderType::<{ ElfClass::Elf32 as u8 }, ED>::from_reader_with(
+            reader, config,
+        )?;
+        let offset = ElfOffset::<{ ElfClass::Elf32 as u8 }, ED>::from_reader_with(reader, config)?;
+        let virtual_address =
+            ElfAddress::<{ ElfClass::Elf32 as u8 }, ED>::from_reader_with(reader, config)?;
+        let physical_address =
+            ElfAddress::<{ ElfClass::Elf32 as u8 }, ED>::from_reader_with(reader, config)?;
+        let file_size = ElfWord::<{ ElfClass::Elf32 as u8 }, ED>::from_reader_with(reader, config)?;
+        let memory_size =
+            ElfWord::<{ ElfClass::Elf32 as u8 }, ED>::from_reader_with(reader, config)?;
+        let flags = ElfProgramHeaderFlags::from_bits_retain(
+            ElfWord::<{ ElfClass::Elf32 as u8 }, ED>::from_reader_with(reader, config)?.0,
+        );
+        let align = ElfWord::<{ ElfClass::Elf32 as u8 }, ED>::from_reader_with(reader, config)?;
+
+        Ok(Self {
+            r#type,
+            offset,
+            virtual_address,
+            physical_address,
+            file_size,
+            memory_size,
+            flags,
+            align,
+        })
+    }
+}
+
+impl<W, const ED: u8> ToWriter<W> for Elf32ProgramHeader<ED>
+where
+    W: Write,
+{
+    type Error = Error;
+
+    fn to_writer(&self, writer: &mut W) -> Result<(), Self::Error> {
+        self.r#type.to_writer(writer)?;
+        self.offset.to_writer(writer)?;
+        self.virtual_address.to_writer(writer)?;
+        self.physical_address.to_writer(writer)?;
+        self.file_size.to_writer(writer)?;
+        self.memory_size.to_writer(writer)?;
+        ElfWord::<{ ElfClass::Elf32 as u8 }, ED>(self.flags.bits()).to_writer(writer)?;
+        self.align.to_writer(writer)?;
+        Ok(())
+    }
+}
+
+impl<const ED: u8> HasWrittenSize for Elf32ProgramHeader<ED> {
+    const SIZE: usize = ElfProgramHeaderType::<{ ElfClass::Elf32 as u8 }, ED>::SIZE
+        + ElfOffset::<{ ElfClass::Elf32 as u8 }, ED>::SIZE
+        + (ElfAddress::<{ ElfClass::Elf32 as u8 }, ED>::SIZE * 2)
+        + (ElfWord::<{ ElfClass::Elf32 as u8 }, ED>::SIZE * 4);
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, TypedBuilder)]
+/// ELF 64-bit Program Header
+pub struct Elf64ProgramHeader<const ED: u8> {
+    /// The kind of segment this element describes or how to interpret the element's
+    /// information
+    pub r#type: ElfProgramHeaderType<{ ElfClass::Elf64 as u8 }, ED>,
+    /// Flags relevant to the segment
+    pub flags: ElfProgramHeaderFlags,
+    /// The offset from the beginning of the file at which the first byte of the segment
+    /// resides
+    pub offset: ElfOffset<{ ElfClass::Elf64 as u8 }, ED>,
+    /// The virtual address at which the first byte of the segment resides in memory
+    pub virtual_address: ElfAddress<{ ElfClass::Elf64 as u8 }, ED>,
+    /// On systems for which physical addressing is relevant, this member is reserved for
+    /// the segment's physical address
+    pub physical_address: ElfAddress<{ ElfClass::Elf64 as u8 }, ED>,
+    /// The number of bytes in the file image of the segment; it may be zero
+    pub file_size: ElfExtendedWord<{ ElfClass::Elf64 as u8 }, ED>,
+    /// The number of bytes in the memory image of the segment; it may be zero
+    pub memory_size: ElfExtendedWord<{ ElfClass::Elf64 as u8 }, ED>,
+    /// The value to which the segments are aligned in memory and in the file. Values 0
+    /// and 1 mean no alignment is required. Otherwise, `align` should be a positive,
+    /// integral power of 2, and `virtual_address` should equal `offset`, modulo
+    /// `align`.
+    pub align: ElfExtendedWord<{ ElfClass::Elf64 as u8 }, ED>,
+}
+
+impl<R, const ED: u8> FromReader<R> for Elf64ProgramHeader<ED>
+where
+    R: Read + Seek,
+{
+    type Error = Error;
+
+    fn from_reader_with(reader: &mut R, config: &mut Config) -> Result<Self, Self::Error> {
+        let r#type = ElfProgramHeaderType::<{ ElfClass::Elf64 as u8 }, ED>::from_reader_with(
+            reader, config,
+        )?;
+        let flags = ElfProgramHeaderFlags::from_bits_retain(
+            ElfWord::<{ ElfClass::Elf64 as u8 }, ED>::from_reader_with(reader, config)?.0,
+        );
+        let offset = ElfOffset::<{ ElfClass::Elf64 as u8 }, ED>::from_reader_with(reader, config)?;
+        let virtual_address =
+            ElfAddress::<{ ElfClass::Elf64 as u8 }, ED>::from_reader_with(reader, config)?;
+        let physical_address =
+            ElfAddress::<{ ElfClass::Elf64 as u8 }, ED>::from_reader_with(reader, config)?;
+        let file_size =
+            ElfExtendedWord::<{ ElfClass::Elf64 as u8 }, ED>::from_reader_with(reader, config)?;
+        let memory_size =
+            ElfExtendedWord::<{ ElfClass::Elf64 as u8 }, ED>::from_reader_with(reader, config)?;
+        let align =
+            ElfExtendedWord::<{ ElfClass::Elf64 as u8 }, ED>::from_reader_with(reader, config)?;
+
+        Ok(Self {
+            r#type,
+            flags,
+            offset,
+            virtual_address,
+            physical_address,
+            file_size,
+            memory_size,
+            align,
+        })
+    }
+}
+
+impl<W, const ED: u8> ToWriter<W> for Elf64ProgramHeader<ED>
+where
+    W: Write,
+{
+    type Error = Error;
+
+    fn to_writer(&self, writer: &mut W) -> Result<(), Self::Error> {
+        self.r#type.to_writer(writer)?;
+        ElfWord::<{ ElfClass::Elf64 as u8 }, ED>(self.flags.bits()).to_writer(writer)?;
+        self.offset.to_writer(writer)?;
+        self.virtual_address.to_writer(writer)?;
+        self.physical_address.to_writer(writer)?;
+        self.file_size.to_writer(writer)?;
+        self.memory_size.to_writer(writer)?;
+        self.align.to_writer(writer)?;
+        Ok(())
+    }
+}
+
+impl<const ED: u8> HasWrittenSize for Elf64ProgramHeader<ED> {
+    const SIZE: usize = ElfProgramHeaderType::<{ ElfClass::Elf64 as u8 }, ED>::SIZE
+        + ElfWord::<{ ElfClass::Elf64 as u8 }, ED>::SIZE
+        + ElfOffset::<{ ElfClass::Elf64 as u8 }, ED>::SIZE
+        + (ElfAddress::<{ ElfClass::Elf64 as u8 }, ED>::SIZE * 2)
+        + (ElfExtendedWord::<{ ElfClass::Elf64 as u8 }, ED>::SIZE * 3);
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// An ELF program header, which describes a segment or other information the system
+/// needs to prepare the program for execution
+pub enum ElfProgramHeader<const EC: u8, const ED: u8> {
+    /// A 32-bit ELF program header
+    Elf32(Elf32ProgramHeader<ED>),
+    /// A 64-bit ELF program header
+    Elf64(Elf64ProgramHeader<ED>),
+}
+
+impl<const EC: u8, const ED: u8> ElfProgramHeader<EC, ED> {
+    /// The kind of segment this program header describes
+    pub fn r#type(&self) -> ElfProgramHeaderType<EC, ED> {
+        match self {
+            ElfProgramHeader::Elf32(header) => header.r#type.with_class(),
+            ElfProgramHeader::Elf64(header) => header.r#type.with_class(),
+        }
+    }
+
+    /// The segment's permissions
+    pub fn flags(&self) -> ElfProgramHeaderFlags {
+        match self {
+            ElfProgramHeader::Elf32(header) => header.flags,
+            ElfProgramHeader::Elf64(header) => header.flags,
+        }
+    }
+
+    /// The offset of the segment's first byte in the file
+    pub fn offset(&self) -> u64 {
+        match self {
+            ElfProgramHeader::Elf32(header) => header.offset.0,
+            ElfProgramHeader::Elf64(header) => header.offset.0,
+        }
+    }
+
+    /// The virtual address of the segment's first byte in memory
+    pub fn virtual_address(&self) -> u64 {
+        match self {
+            ElfProgramHeader::Elf32(header) => header.virtual_address.0,
+            ElfProgramHeader::Elf64(header) => header.virtual_address.0,
+        }
+    }
+
+    /// The physical address of the segment's first byte, on systems for which physical
+    /// addressing is relevant
+    pub fn physical_address(&self) -> u64 {
+        match self {
+            ElfProgramHeader::Elf32(header) => header.physical_address.0,
+            ElfProgramHeader::Elf64(header) => header.physical_address.0,
+        }
+    }
+
+    /// The number of bytes in the file image of the segment
+    pub fn file_size(&self) -> u64 {
+        match self {
+            ElfProgramHeader::Elf32(header) => header.file_size.0 as u64,
+            ElfProgramHeader::Elf64(header) => header.file_size.0,
+        }
+    }
+
+    /// The number of bytes in the memory image of the segment
+    pub fn memory_size(&self) -> u64 {
+        match self {
+            ElfProgramHeader::Elf32(header) => header.memory_size.0 as u64,
+            ElfProgramHeader::Elf64(header) => header.memory_size.0,
+        }
+    }
+
+    /// The alignment of the segment in memory and in the file
+    pub fn align(&self) -> u64 {
+        match self {
+            ElfProgramHeader::Elf32(header) => header.align.0 as u64,
+            ElfProgramHeader::Elf64(header) => header.align.0,
+        }
+    }
+
+    /// A `PT_LOAD` program header for `size` bytes at `offset` in the file, loaded at
+    /// `address`, whose values must fit in the class
+    pub(crate) fn load(
+        offset: u64,
+        address: u64,
+        size: u64,
+        flags: ElfProgramHeaderFlags,
+        align: u64,
+    ) -> Result<Self, Error> {
+        match ElfClass::from_u8(EC).ok_or(Error::InvalidClass { class: ElfByte(EC) })? {
+            ElfClass::Elf32 => Ok(ElfProgramHeader::Elf32(Elf32ProgramHeader {
+                r#type: ElfProgramHeaderType::Load,
+                offset: ElfOffset(offset),
+                virtual_address: ElfAddress(address),
+                physical_address: ElfAddress(address),
+                file_size: ElfWord(size as u32),
+                memory_size: ElfWord(size as u32),
+                flags,
+                align: ElfWord(align as u32),
+            })),
+            ElfClass::Elf64 => Ok(ElfProgramHeader::Elf64(Elf64ProgramHeader {
+                r#type: ElfProgramHeaderType::Load,
+                flags,
+                offset: ElfOffset(offset),
+                virtual_address: ElfAddress(address),
+                physical_address: ElfAddress(address),
+                file_size: ElfExtendedWord(size),
+                memory_size: ElfExtendedWord(size),
+                align: ElfExtendedWord(align),
+            })),
+            ElfClass::None => Err(Error::InvalidClass { class: ElfByte(EC) }),
+        }
+    }
+
+    /// Set the file offset of the segment, which must fit in the class
+    pub(crate) fn set_offset(&mut self, offset: u64) {
+        match self {
+            ElfProgramHeader::Elf32(header) => header.offset.0 = offset,
+            ElfProgramHeader::Elf64(header) => header.offset.0 = offset,
+        }
+    }
+
+    /// Set the file and memory sizes of the segment, which must fit in the class
+    pub(crate) fn set_sizes(&mut self, file_size: u64, memory_size: u64) {
+        match self {
+            ElfProgramHeader::Elf32(header) => {
+                header.file_size.0 = file_size as u32;
+                header.memory_size.0 = memory_size as u32;
+            }
+            ElfProgramHeader::Elf64(header) => {
+                header.file_size.0 = file_size;
+                header.memory_size.0 = memory_size;
+            }
+        }
+    }
+
+    /// Read the program header table described by an ELF header. When the number of
+    /// program headers does not fit in the ELF header, the count is read from the
+    /// `sh_info` field of the first section header.
+    ///
+    /// A program header table which does not fit in the file is treated as absent rather
+    /// than as an error, unless `config` enables partial decoding, in which case the
+    /// entries which fit are kept.
+    pub(crate) fn table_from_reader_with<R>(
+        reader: &mut R,
+        header: &ElfHeader<EC, ED>,
+        section_headers: &[ElfSectionHeader<EC, ED>],
+        config: &mut Config,
+    ) -> Result<Vec<Self>, Error>
+    where
+        R: Read + Seek,
+    {
+        let offset = match header.program_header_offset {
+            Some(offset) if offset.0 != 0 => offset.0,
+            _ => return Ok(Vec::new()),
+        };
+        let entry_size = match header.program_header_entry_size.0 {
+            0 => return Ok(Vec::new()),
+            entry_size => entry_size as u64,
+        };
+        let count = match header.program_header_entry_count.0 {
+            Self::EXTENDED_COUNT => section_headers
+                .first()
+                .map_or(Self::EXTENDED_COUNT as u64, |first| first.info() as u64),
+            count => count as u64,
+        };
+        let length = reader.seek(SeekFrom::End(0))?;
+
+        let count = match offset.checked_add(count.saturating_mul(entry_size)) {
+            Some(end) if end <= length => count,
+            // In partial mode, keep the entries which fit before the end of the file
+            _ if config.partial => length.saturating_sub(offset) / entry_size,
+            _ => 0,
+        };
+
+        (0..count)
+            .map(|index| {
+                reader.seek(SeekFrom::Start(offset + index * entry_size))?;
+                Self::from_reader_with(reader, config)
+            })
+            .collect()
+    }
+
+    /// Read the contents of the segment described by this header from the file
+    pub(crate) fn data_from_reader<R>(&self, reader: &mut R) -> Result<Vec<u8>, Error>
+    where
+        R: Read + Seek,
+    {
+        reader.seek(SeekFrom::Start(self.offset()))?;
+
+        let mut data = Vec::new();
+        reader.take(self.file_size()).read_to_end(&mut data)?;
+
+        if (data.len() as u64) < self.file_size() {
+            return Err(Error::Io {
+                kind: ErrorKind::UnexpectedEof,
+                errno: None,
+            });
+        }
+
+        Ok(data)
+    }
+
+    /// Whether `section` is part of this segment, using the same rules as `readelf` and
+    /// the GNU linker. Thread-local `SHT_NOBITS` sections occupy space only in the
+    /// `PT_TLS` segment.
+    pub fn contains_section(&self, section: &ElfSectionHeader<EC, ED>) -> bool {
+        let r#type = self.r#type();
+        let flags = section.flags();
+        let tls = flags.contains(ElfSectionHeaderFlags::THREAD_LOCAL_STORAGE);
+        let allocated = flags.contains(ElfSectionHeaderFlags::ALLOCATED);
+        let no_bits = matches!(section.r#type(), ElfSectionHeaderType::NoBits);
+        let is_tls_segment = matches!(r#type, ElfProgramHeaderType::ThreadLocalStorage);
+        let size = if !tls || !no_bits || is_tls_segment {
+            section.size()
+        } else {
+            0
+        };
+
+        // The .tbss section occupies no space outside of the TLS segment
+        if tls && no_bits && !is_tls_segment {
+            return false;
+        }
+
+        // Only PT_LOAD, PT_GNU_RELRO and PT_TLS segments can contain TLS sections, and
+        // PT_TLS segments contain only TLS sections. PT_PHDR segments contain no sections.
+        let tls_ok = if tls {
+            matches!(
+                r#type,
+                ElfProgramHeaderType::ThreadLocalStorage
+                    | ElfProgramHeaderType::Load
+                    | ElfProgramHeaderType::Gnu(ElfProgramHeaderTypeGNU::Relro)
+            )
+        } else {
+            !matches!(
+                r#type,
+                ElfProgramHeaderType::ThreadLocalStorage | ElfProgramHeaderType::ProgramHeader
+            )
+        };
+
+        // Loadable segments only contain allocated sections
+        let alloc_ok = allocated
+            || !(matches!(
+                r#type,
+                ElfProgramHeaderType::Load
+                    | ElfProgramHeaderType::Dynamic
+                    | ElfProgramHeaderType::Gnu(
+                        ElfProgramHeaderTypeGNU::EhFrame
+                            | ElfProgramHeaderTypeGNU::Stack
+                            | ElfProgramHeaderTypeGNU::Relro
+                            | ElfProgramHeaderTypeGNU::SFrame
+                    )
+            ) || (Self::LOW_GNU_MBIND..=Self::HIGH_GNU_MBIND).contains(&r#type.value()));
+
+        // Sections other than SHT_NOBITS sections must be within the segment in the file
+        let offset_ok = no_bits
+            || (section.offset() >= self.offset()
+                && section.offset() - self.offset() <= self.file_size().wrapping_sub(1)
+                && (section.offset() - self.offset()).saturating_add(size) <= self.file_size());
+
+        // Allocated sections must be within the segment in memory
+        let address_ok = !allocated
+            || (section.address() >= self.virtual_address()
+                && section.address() - self.virtual_address()
+                    <= self.memory_size().wrapping_sub(1)
+                && (section.address() - self.virtual_address()).saturating_add(size)
+                    <= self.memory_size());
+
+        // Empty sections may not be at the start or end of PT_DYNAMIC and PT_NOTE segments
+        let empty_ok = !matches!(
+            r#type,
+            ElfProgramHeaderType::Dynamic | ElfProgramHeaderType::Note
+        ) || section.size() != 0
+            || self.memory_size() == 0
+            || ((no_bits
+                || (section.offset() > self.offset()
+                    && section.offset() - self.offset() < self.file_size()))
+                && (!allocated
+                    || (section.address() > self.virtual_address()
+                        && section.address() - self.virtual_address() < self.memory_size())));
+
+        tls_ok && alloc_ok && offset_ok && address_ok && empty_ok
+    }
+
+    /// The value of `e_phnum` indicating that the number of program headers is held in
+    /// the `sh_info` field of the first section header (`PN_XNUM`)
+    pub const EXTENDED_COUNT: u16 = 0xffff;
+    /// The first of the GNU program header types reserved for `SHF_GNU_MBIND` sections
+    const LOW_GNU_MBIND: u32 = 0x6474e555;
+    /// The last of the GNU program header types reserved for `SHF_GNU_MBIND` sections
+    const HIGH_GNU_MBIND: u32 = 0x6474f554;
+}
+
+impl<R, const EC: u8, const ED: u8> FromReader<R> for ElfProgramHeader<EC, ED>
+where
+    R: Read + Seek,
+{
+    type Error = Error;
+
+    fn from_reader_with(reader: &mut R, config: &mut Config) -> Result<Self, Self::Error> {
+        Ok(
+            match ElfClass::from_u8(EC).ok_or(Error::InvalidClass { class: ElfByte(EC) })? {
+                ElfClass::None => return Err(Error::InvalidClass { class: ElfByte(EC) }),
+                ElfClass::Elf32 => {
+                    ElfProgramHeader::Elf32(Elf32ProgramHeader::from_reader_with(reader, config)?)
+                }
+                ElfClass::Elf64 => {
+                    ElfProgramHeader::Elf64(Elf64ProgramHeader::from_reader_with(reader, config)?)
+                }
+            },
+        )
+    }
+}
+
+impl<W, const EC: u8, const ED: u8> ToWriter<W> for ElfProgramHeader<EC, ED>
+where
+    W: Write,
+{
+    type Error = Error;
+
+    fn to_writer(&self, writer: &mut W) -> Result<(), Self::Error> {
+        match self {
+            ElfProgramHeader::Elf32(header) => header.to_writer(writer),
+            ElfProgramHeader::Elf64(header) => header.to_writer(writer),
+        }
+    }
+}
+
+impl<const EC: u8, const ED: u8> HasWrittenSize for ElfProgramHeader<EC, ED> {
+    const SIZE: usize = match EC {
+        ElfClass::ELF_CLASS_64 => Elf64ProgramHeader::<ED>::SIZE,
+        _ => Elf32ProgramHeader::<ED>::SIZE,
+    };
+}
+
+#[derive(Debug, Clone)]
+/// The program header table of an object, read on its own from the location described
+/// by an ELF header which was decoded separately
+pub struct ProgramHeaderTable<const EC: u8, const ED: u8> {
+    /// The entries of the table, in order
+    pub headers: Vec<ElfProgramHeader<EC, ED>>,
+}
+
+impl<const EC: u8, const ED: u8> ProgramHeaderTable<EC, ED> {
+    /// Read the program header table described by `header`, without reading the ELF
+    /// header again
+    pub fn from_reader<R>(reader: &mut R, header: &ElfHeader<EC, ED>) -> Result<Self, Error>
+    where
+        R: Read + Seek,
+    {
+        Self::from_reader_with(reader, header, &mut Config::default())
+    }
+
+    /// Read the program header table described by `header` with `config`, without
+    /// reading the ELF header again. The machine type and OS ABI are taken from `header`
+    /// and the table is read as they are when decoding an `Elf`, so a table which does
+    /// not fit in the file is empty. When the number of program headers does not fit in
+    /// the ELF header, only the first section header is read to find it.
+    pub fn from_reader_with<R>(
+        reader: &mut R,
+        header: &ElfHeader<EC, ED>,
+        config: &mut Config,
+    ) -> Result<Self, Error>
+    where
+        R: Read + Seek,
+    {
+        header.configure(config);
+
+        let first = match header.program_header_entry_count.0 {
+            ElfProgramHeader::<EC, ED>::EXTENDED_COUNT => {
+                ElfSectionHeader::first_from_reader_with(reader, header, config)?
+            }
+            _ => None,
+        };
+
+        Ok(Self {
+            headers: ElfProgramHeader::table_from_reader_with(
+                reader,
+                header,
+                first.as_slice(),
+                config,
+            )?,
+        })
+    }
+
+    /// Whether the object is dynamically linked, as with `Elf::is_dynamically_linked`.
+    /// Only the ELF header and the program header table need to be read, so this is
+    /// cheap enough to classify many objects.
+    pub fn is_dynamically_linked(&self) -> bool {
+        is_dynamically_linked(&self.headers)
+    }
+}
+
+/// Whether a program header table has a `PT_INTERP` or `PT_DYNAMIC` segment
+fn is_dynamically_linked<const EC: u8, const ED: u8>(headers: &[ElfProgramHeader<EC, ED>]) -> bool {
+    headers.iter().any(|header| {
+        matches!(
+            header.r#type(),
+            ElfProgramHeaderType::Interpreter | ElfProgramHeaderType::Dynamic
+        )
+    })
+}
+
+impl<const EC: u8, const ED: u8> From<ProgramHeaderTable<EC, ED>>
+    for Vec<ElfProgramHeader<EC, ED>>
+{
+    fn from(value: ProgramHeaderTable<EC, ED>) -> Self {
+        value.headers
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// The memory image of a `PT_LOAD` segment, which is what a loader maps into the address
+/// space of a process
+pub struct LoadRegion {
+    /// The virtual address of the first byte of the segment in memory
+    pub address: u64,
+    /// The size of the segment in memory
+    pub memory_size: u64,
+    /// The permissions of the segment in memory
+    pub flags: ElfProgramHeaderFlags,
+    /// The contents of the segment in memory, which are its contents in the file
+    /// followed by zeroes up to its size in memory
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+/// A summary of the memory an object occupies once its `PT_LOAD` segments are mapped,
+/// as reported by `Elf::memory_footprint`
+pub struct MemoryFootprint {
+    /// The sum of the sizes in memory of the segments
+    pub memory_size: u64,
+    /// The sum of the sizes in the file of the segments, which is the part of their
+    /// contents backed by the file rather than zero filled
+    pub file_size: u64,
+    /// The size of the address space the segments are mapped into, from the image base
+    /// to the end of the highest segment rounded up to the page size. Unlike
+    /// `memory_size`, this includes the gaps between segments left by their alignment.
+    pub span: u64,
+    /// The address one past the last byte of the highest segment
+    pub end_address: u64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// The index of a segment in the program header table. Segment indices are a distinct
+/// type from section indices, so that one cannot be passed where the other is expected.
+pub struct SegmentIndex(pub u32);
+
+impl SegmentIndex {
+    /// The index as a `usize`, for indexing the program header table
+    pub fn get(self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl From<u32> for SegmentIndex {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl core::fmt::Display for SegmentIndex {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<const EC: u8, const ED: u8> Elf<EC, ED> {
+    /// Read the contents of the segment at `index` in the program header table
+    pub fn segment_data<R>(&self, reader: &mut R, index: SegmentIndex) -> Result<Vec<u8>, Error>
+    where
+        R: Read + Seek,
+    {
+        self.program_headers
+            .get(index.get())
+            .ok_or(Error::InvalidSegmentIndex { index: index.get() })?
+            .data_from_reader(reader)
+    }
+
+    /// The indices of the sections in the section header table which are part of the
+    /// segment at `index` in the program header table
+    pub fn segment_sections(&self, index: SegmentIndex) -> Result<Vec<SectionIndex>, Error> {
+        let segment = self
+            .program_headers
+            .get(index.get())
+            .ok_or(Error::InvalidSegmentIndex { index: index.get() })?;
+
+        Ok(self
+            .section_headers
+            .iter()
+            .enumerate()
+            .skip(1)
+            .filter(|(_, section)| segment.contains_section(section))
+            .map(|(index, _)| SectionIndex(index as u32))
+            .collect())
+    }
+
+    /// The memory images of the object's `PT_LOAD` segments, in the order of the program
+    /// header table. The file contents of each segment are read from `reader` and zero
+    /// filled up to its size in memory, as a loader would do for its `.bss`. A segment
+    /// whose size in memory is smaller than its size in the file, or which is zero
+    /// filled by more than `Config::max_zero_fill` bytes, is an error.
+    pub fn load_segments<R>(&self, reader: &mut R) -> Result<Vec<LoadRegion>, Error>
+    where
+        R: Read + Seek,
+    {
+        self.load_segments_with(reader, &Config::default())
+    }
+
+    /// The memory images of the object's `PT_LOAD` segments, as with `load_segments`,
+    /// recovering from the errors ignored by `config` and filling each segment with at
+    /// most `config`'s `max_zero_fill` zero bytes
+    pub fn load_segments_with<R>(
+        &self,
+        reader: &mut R,
+        config: &Config,
+    ) -> Result<Vec<LoadRegion>, Error>
+    where
+        R: Read + Seek,
+    {
+        self.program_headers
+            .iter()
+            .enumerate()
+            .filter(|(_, header)| matches!(header.r#type(), ElfProgramHeaderType::Load))
+            .map(|(index, header)| {
+                let (file_size, memory_size) = (header.file_size(), header.memory_size());
+
+                if memory_size < file_size {
+                    let err = Error::InvalidSegmentMemorySize {
+                        index,
+                        file_size,
+                        memory_size,
+                    };
+
+                    if !config.ignores(&err) {
+                        return Err(err);
+                    }
+                } else if memory_size - file_size > config.max_zero_fill {
+                    return Err(Error::SegmentZeroFillTooLarge {
+                        index,
+                        size: memory_size - file_size,
+                        limit: config.max_zero_fill,
+                    });
+                }
+
+                let mut data = header.data_from_reader(reader)?;
+                data.resize(usize_from(memory_size)?, 0);
+
+                Ok(LoadRegion {
+                    address: header.virtual_address(),
+                    memory_size: header.memory_size(),
+                    flags: header.flags(),
+                    data,
+                })
+            })
+            .collect()
+    }
+
+    /// The image base of the object, which is the lowest virtual address of its
+    /// `PT_LOAD` segments rounded down to the page size, because loaders map whole
+    /// pages. The load bias of a position-independent executable or shared
+    /// object is the address it was loaded at minus its image base, and subtracting it
+    /// from a runtime address gives the virtual address in the file. `None` if there are
+    /// no `PT_LOAD` segments.
+    pub fn load_base(&self) -> Option<u64> {
+        self.program_headers
+            .iter()
+            .filter(|header| matches!(header.r#type(), ElfProgramHeaderType::Load))
+            .map(|header| match self.page_size {
+                page_size if page_size.is_power_of_two() => {
+                    header.virtual_address() & !(page_size - 1)
+                }
+                _ => header.virtual_address(),
+            })
+            .min()
+    }
+
+    /// Summarize the memory occupied by the object's `PT_LOAD` segments: the total of
+    /// their sizes in memory and in the file, and the span of address space a loader
+    /// must reserve to map them. An object with no `PT_LOAD` segments, such as a
+    /// relocatable object, occupies no memory.
+    pub fn memory_footprint(&self) -> MemoryFootprint {
+        let mut footprint = MemoryFootprint::default();
+
+        for header in self
+            .program_headers
+            .iter()
+            .filter(|header| matches!(header.r#type(), ElfProgramHeaderType::Load))
+        {
+            footprint.memory_size = footprint.memory_size.saturating_add(header.memory_size());
+            footprint.file_size = footprint.file_size.saturating_add(header.file_size());
+            footprint.end_address = footprint.end_address.max(
+                header
+                    .virtual_address()
+                    .saturating_add(header.memory_size()),
+            );
+        }
+
+        if let Some(base) = self.load_base() {
+            let end = match self.page_size {
+                page_size if page_size.is_power_of_two() => footprint
+                    .end_address
+                    .checked_next_multiple_of(page_size)
+                    .unwrap_or(footprint.end_address),
+                _ => footprint.end_address,
+            };
+            footprint.span = end.saturating_sub(base);
+        }
+
+        footprint
+    }
+
+    /// The offset in the file of the `size` bytes at the virtual address `address`, if
+    /// they are all in the file contents of one `PT_LOAD` segment
+    pub(crate) fn address_offset(&self, address: u64, size: u64) -> Option<u64> {
+        self.program_headers
+            .iter()
+            .filter(|header| matches!(header.r#type(), ElfProgramHeaderType::Load))
+            .find_map(|header| {
+                let start = address.checked_sub(header.virtual_address())?;
+                (start.checked_add(size)? <= header.file_size())
+                    .then(|| header.offset().checked_add(start))
+                    .flatten()
+            })
+    }
+
+    /// Read the `size` bytes at the virtual address `address` from `reader`, or `None`
+    /// if they are not all in the file contents of one loadable segment
+    pub(crate) fn read_at_address<R>(
+        &self,
+        reader: &mut R,
+        address: u64,
+        size: u64,
+    ) -> Result<Option<Vec<u8>>, Error>
+    where
+        R: Read + Seek,
+    {
+        let Some(offset) = self.address_offset(address, size) else {
+            return Ok(None);
+        };
+
+        reader.seek(SeekFrom::Start(offset))?;
+
+        let mut data = Vec::new();
+        reader.take(size).read_to_end(&mut data)?;
+
+        Ok((data.len() as u64 == size).then_some(data))
+    }
+
+    /// Read the 32-bit unsigned integer at the virtual address `address` from `reader`,
+    /// in the data encoding of the object. `None` if its 4 bytes are not all in the file
+    /// contents of one `PT_LOAD` segment, such as when the address is in `.bss`.
+    pub fn read_u32_at_vaddr<R>(&self, reader: &mut R, address: u64) -> Result<Option<u32>, Error>
+    where
+        R: Read + Seek,
+    {
+        self.read_at_address(reader, address, size_of::<u32>() as u64)?
+            .map(|data| {
+                ElfWord::<EC, ED>::from_reader_with(&mut Cursor::new(data), &mut Config::default())
+                    .map(|value| value.0)
+            })
+            .transpose()
+    }
+
+    /// Read the 64-bit unsigned integer at the virtual address `address` from `reader`,
+    /// in the data encoding of the object. `None` if its 8 bytes are not all in the file
+    /// contents of one `PT_LOAD` segment, such as when the address is in `.bss`.
+    pub fn read_u64_at_vaddr<R>(&self, reader: &mut R, address: u64) -> Result<Option<u64>, Error>
+    where
+        R: Read + Seek,
+    {
+        self.read_at_address(reader, address, size_of::<u64>() as u64)?
+            .map(|data| {
+                ElfExtendedWord::<EC, ED>::from_reader_with(
+                    &mut Cursor::new(data),
+                    &mut Config::default(),
+                )
+                .map(|value| value.0)
+            })
+            .transpose()
+    }
+
+    /// Whether the object is dynamically linked, which is when it has a `PT_INTERP`
+    /// segment naming a program interpreter or a `PT_DYNAMIC` segment for the dynamic
+    /// linker. Relocatable objects have no segments, so they are never dynamically
+    /// linked, and static position-independent executables have a `PT_DYNAMIC` segment
+    /// for their self-relocation, so they are. To classify many objects without decoding
+    /// their section header tables, use `ProgramHeaderTable::is_dynamically_linked`.
+    pub fn is_dynamically_linked(&self) -> bool {
+        is_dynamically_linked(&self.program_headers)
+    }
+
+    /// Whether the object requests an executable stack, from the execute flag of its
+    /// `PT_GNU_STACK` segment. `None` if there is no `PT_GNU_STACK` segment, in which case
+    /// the loader's default for the machine applies, which is an executable stack on many
+    /// older architectures.
+    pub fn executable_stack(&self) -> Option<bool> {
+        self.program_headers
+            .iter()
+            .find(|header| {
+                matches!(
+                    header.r#type(),
+                    ElfProgramHeaderType::Gnu(ElfProgramHeaderTypeGNU::Stack)
+                )
+            })
+            .map(|header| header.flags().contains(ElfProgramHeaderFlags::EXECUTE))
+    }
+
+    /// Format the program header table and the mapping of sections to segments in the
+    /// same layout as `readelf -l --wide`. The path of the program interpreter is read
+    /// from `reader`.
+    pub fn format_program_headers<R>(&self, reader: &mut R) -> Result<String, Error>
+    where
+        R: Read + Seek,
+    {
+        if self.program_headers.is_empty() {
+            return Ok("There are no program headers in this file.\n".to_string());
+        }
+
+        let elf32 = matches!(ElfClass::from_u8(EC), Some(ElfClass::Elf32));
+        let mut table = "Program Headers:\n".to_string();
+
+        table += if elf32 {
+            "  Type           Offset   VirtAddr   PhysAddr   FileSiz MemSiz  Flg Align\n"
+        } else {
+            "  Type           Offset   VirtAddr           PhysAddr           FileSiz  MemSiz   Flg Align\n"
+        };
+
+        for header in self.program_headers.iter() {
+            let flags = header.flags();
+            let flags = [
+                (ElfProgramHeaderFlags::READ, 'R'),
+                (ElfProgramHeaderFlags::WRITE, 'W'),
+                (ElfProgramHeaderFlags::EXECUTE, 'E'),
+            ]
+            .iter()
+            .map(|(flag, letter)| if flags.contains(*flag) { *letter } else { ' ' })
+            .collect::<String>();
+            // Like printf's `%#x`, readelf prints an alignment of 0 without a prefix
+            let align = match header.align() {
+                0 => "0".to_string(),
+                align => format!("{:#x}", align),
+            };
+
+            table += &if elf32 {
+                format!(
+                    "  {:<14.14} {:#08x} {:#010x} {:#010x} {:#07x} {:#07x} {} {}\n",
+                    header.r#type().to_string(),
+                    header.offset(),
+                    header.virtual_address(),
+                    header.physical_address(),
+                    header.file_size(),
+                    header.memory_size(),
+                    flags,
+                    align,
+                )
+            } else {
+                format!(
+                    "  {:<14.14} {:#08x} {:#018x} {:#018x} {:#08x} {:#08x} {} {}\n",
+                    header.r#type().to_string(),
+                    header.offset(),
+                    header.virtual_address(),
+                    header.physical_address(),
+                    header.file_size(),
+                    header.memory_size(),
+                    flags,
+                    align,
+                )
+            };
+
+            if matches!(header.r#type(), ElfProgramHeaderType::Interpreter) {
+                if let Some(interpreter) = header
+                    .data_from_reader(reader)
+                    .ok()
+                    .and_then(|data| string_at(&data, 0))
+                    .filter(|interpreter| !interpreter.is_empty())
+                {
+                    table += &format!("      [Requesting program interpreter: {}]\n", interpreter);
+                }
+            }
+        }
+
+        if !self.section_headers.is_empty() {
+            table += "\n Section to Segment mapping:\n  Segment Sections...\n";
+
+            for index in 0..self.program_headers.len() {
+                table += &format!("   {:02}     ", index);
+
+                for section in self
+                    .segment_sections(SegmentIndex(index as u32))?
+                    .into_iter()
+                    .filter_map(|section| self.section(section))
+                {
+                    table += &format!("{} ", section.name());
+                }
+
+                table += "\n";
+            }
+        }
+
+        Ok(table)
+    }
+}
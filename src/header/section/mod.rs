@@ -1,27 +1,48 @@
 //! Implementation of the ELF section header
 
-use num_traits::FromPrimitive;
-use std::{
-    io::{Read, Seek, Write},
-    mem::size_of,
+use alloc::{
+    borrow::Cow,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
 };
+use bitflags::bitflags;
+use core::{mem::size_of, ops::Range};
+use num_traits::FromPrimitive;
 use typed_builder::TypedBuilder;
 
+#[cfg(feature = "arch")]
+use crate::arch::{
+    aarch64::ElfSectionHeaderTypeAARCH64, arm32::ElfSectionHeaderTypeARM32,
+    i386::ElfSectionHeaderTypeI386, mips::ElfSectionHeaderTypeMIPS,
+    parisc::ElfSectionHeaderTypePARISC, ppc::ElfSectionHeaderTypePPC,
+    riscv::ElfSectionHeaderTypeRISCV, x86_64::ElfSectionHeaderTypeX86_64,
+};
 use crate::{
-    arch::{
-        aarch64::ElfSectionHeaderTypeAARCH64, arm32::ElfSectionHeaderTypeARM32,
-        i386::ElfSectionHeaderTypeI386, mips::ElfSectionHeaderTypeMIPS,
-        parisc::ElfSectionHeaderTypePARISC, ppc::ElfSectionHeaderTypePPC,
-        riscv::ElfSectionHeaderTypeRISCV, x86_64::ElfSectionHeaderTypeX86_64,
+    base::{ElfAddress, ElfByte, ElfExtendedWord, ElfOffset, ElfSection, ElfWord},
+    error::Error,
+    io::{Cursor, ErrorKind, Read, Seek, SeekFrom, Write},
+    os::{
+        gnu::ElfSectionHeaderTypeGNU, llvm::ElfSectionHeaderTypeLLVM, sun::ElfSectionHeaderTypeSUN,
+    },
+    Config, Elf, FromReader, HasWrittenSize, ToWriter, TryFromWithConfig,
+};
+
+use super::{
+    compression::{
+        inflate, CompressionInfo, ElfCompressionHeader, ElfCompressionHeaderType, ELFCOMPRESS_ZLIB,
     },
-    base::{ElfAddress, ElfExtendedWord, ElfOffset, ElfWord},
-    error::{Error, ErrorContext},
-    from_primitive,
-    os::{gnu::ElfSectionHeaderTypeGNU, sun::ElfSectionHeaderTypeSUN},
-    Config, FromReader, HasWrittenSize, ToWriter, TryFromWithConfig,
+    elf::{
+        identification::{ElfClass, ElfOSABI, ELF_CLASS_DEFAULT, ELF_DATA_ENCODING_DEFAULT},
+        ElfHeader, ElfMachine,
+    },
+    program::{ElfProgramHeader, ElfProgramHeaderFlags, ElfProgramHeaderType},
 };
 
-use super::elf::{identification::ElfClass, ElfMachine};
+/// The magic which begins a `.zdebug` section compressed with the GNU scheme, which is
+/// followed by the uncompressed size as a 64-bit big endian integer and a zlib stream
+const ZDEBUG_MAGIC: &[u8] = b"ZLIB";
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 /// The name of an ELF section
@@ -33,11 +54,42 @@ pub struct ElfSectionHeaderName<const ED: u8> {
     pub value: ElfWord<{ ElfClass::Elf32 as u8 }, ED>,
 }
 
+impl<R, const ED: u8> FromReader<R> for ElfSectionHeaderName<ED>
+where
+    R: Read + Seek,
+{
+    type Error = Error;
+
+    /// Read the raw section header name. The name itself is resolved once the section
+    /// header string table has been read.
+    fn from_reader_with(reader: &mut R, config: &mut Config) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: String::new(),
+            value: ElfWord::<{ ElfClass::Elf32 as u8 }, ED>::from_reader_with(reader, config)?,
+        })
+    }
+}
+
+impl<W, const ED: u8> ToWriter<W> for ElfSectionHeaderName<ED>
+where
+    W: Write,
+{
+    type Error = Error;
+
+    fn to_writer(&self, writer: &mut W) -> Result<(), Self::Error> {
+        self.value.to_writer(writer)
+    }
+}
+
+impl<const ED: u8> HasWrittenSize for ElfSectionHeaderName<ED> {
+    const SIZE: usize = ElfWord::<{ ElfClass::Elf32 as u8 }, ED>::SIZE;
+}
+
 #[repr(u32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[non_exhaustive]
 /// The type of an ELF section
-enum ElfSectionHeaderType<const EC: u8, const ED: u8> {
+pub enum ElfSectionHeaderType<const EC: u8, const ED: u8> {
     /// Marks the section header as inactive; it does not have an associated
     /// section. Other members of the section header have undefined values.
     NullUndefined = 0,
@@ -144,25 +196,35 @@ enum ElfSectionHeaderType<const EC: u8, const ED: u8> {
     // // /// Values in this inclusive range are resserved for application programs
     // // HighUserDefined = 0xffffffff
     /// AARCH64-specific
+    #[cfg(feature = "arch")]
     AARCH64(ElfSectionHeaderTypeAARCH64),
     /// ARM-specific
+    #[cfg(feature = "arch")]
     Arm(ElfSectionHeaderTypeARM32),
     /// I386-specific
+    #[cfg(feature = "arch")]
     I386(ElfSectionHeaderTypeI386),
     /// MIPS-specific
+    #[cfg(feature = "arch")]
     Mips(ElfSectionHeaderTypeMIPS),
     /// PA-RISC-specific
+    #[cfg(feature = "arch")]
     PaRisc(ElfSectionHeaderTypePARISC),
     /// PPC-specific
+    #[cfg(feature = "arch")]
     Ppc(ElfSectionHeaderTypePPC),
     /// RISC-V-specific
+    #[cfg(feature = "arch")]
     Riscv(ElfSectionHeaderTypeRISCV),
     /// X86_64-Specific
+    #[cfg(feature = "arch")]
     X86_64(ElfSectionHeaderTypeX86_64),
     /// Other processor-specific
     OtherProcessorSpecific(ElfWord<EC, ED>),
     /// GNU-Specific
     Gnu(ElfSectionHeaderTypeGNU),
+    /// LLVM-Specific
+    Llvm(ElfSectionHeaderTypeLLVM),
     /// SUN-Specific
     Sun(ElfSectionHeaderTypeSUN),
     /// Other OS-specific
@@ -269,6 +331,64 @@ impl<const EC: u8, const ED: u8> ElfSectionHeaderType<EC, ED> {
     pub const LOW_PROCESSOR_SPECIFIC: u32 = 0x70000000;
     /// High bound for processor-specific semantics
     pub const HIGH_PROCESSOR_SPECIFIC: u32 = 0x7fffffff;
+
+    /// The raw value of the section header type
+    pub fn value(&self) -> u32 {
+        ElfWord::<EC, ED>::from(self).0
+    }
+
+    /// Convert this section header type to the same section header type for another
+    /// ELF class. The value of a section header type is the same size for both classes.
+    pub(crate) fn with_class<const TC: u8>(self) -> ElfSectionHeaderType<TC, ED> {
+        match self {
+            Self::NullUndefined => ElfSectionHeaderType::NullUndefined,
+            Self::ProgramBits => ElfSectionHeaderType::ProgramBits,
+            Self::SymbolTable => ElfSectionHeaderType::SymbolTable,
+            Self::StringTable => ElfSectionHeaderType::StringTable,
+            Self::RelocationExplicit => ElfSectionHeaderType::RelocationExplicit,
+            Self::Hash => ElfSectionHeaderType::Hash,
+            Self::Dynamic => ElfSectionHeaderType::Dynamic,
+            Self::Note => ElfSectionHeaderType::Note,
+            Self::NoBits => ElfSectionHeaderType::NoBits,
+            Self::RelocationImplicit => ElfSectionHeaderType::RelocationImplicit,
+            Self::SectionHeaderLibrary => ElfSectionHeaderType::SectionHeaderLibrary,
+            Self::DynamicSymbol => ElfSectionHeaderType::DynamicSymbol,
+            Self::InitializerArray => ElfSectionHeaderType::InitializerArray,
+            Self::FinalizerArray => ElfSectionHeaderType::FinalizerArray,
+            Self::PreInitializerArray => ElfSectionHeaderType::PreInitializerArray,
+            Self::Group => ElfSectionHeaderType::Group,
+            Self::SymbolTableSectionHeaderIndex => {
+                ElfSectionHeaderType::SymbolTableSectionHeaderIndex
+            }
+            Self::RelR => ElfSectionHeaderType::RelR,
+            #[cfg(feature = "arch")]
+            Self::AARCH64(value) => ElfSectionHeaderType::AARCH64(value),
+            #[cfg(feature = "arch")]
+            Self::Arm(value) => ElfSectionHeaderType::Arm(value),
+            #[cfg(feature = "arch")]
+            Self::I386(value) => ElfSectionHeaderType::I386(value),
+            #[cfg(feature = "arch")]
+            Self::Mips(value) => ElfSectionHeaderType::Mips(value),
+            #[cfg(feature = "arch")]
+            Self::PaRisc(value) => ElfSectionHeaderType::PaRisc(value),
+            #[cfg(feature = "arch")]
+            Self::Ppc(value) => ElfSectionHeaderType::Ppc(value),
+            #[cfg(feature = "arch")]
+            Self::Riscv(value) => ElfSectionHeaderType::Riscv(value),
+            #[cfg(feature = "arch")]
+            Self::X86_64(value) => ElfSectionHeaderType::X86_64(value),
+            Self::OtherProcessorSpecific(value) => {
+                ElfSectionHeaderType::OtherProcessorSpecific(ElfWord(value.0))
+            }
+            Self::Gnu(value) => ElfSectionHeaderType::Gnu(value),
+            Self::Llvm(value) => ElfSectionHeaderType::Llvm(value),
+            Self::Sun(value) => ElfSectionHeaderType::Sun(value),
+            Self::OtherOperatingSystemSpecific(value) => {
+                ElfSectionHeaderType::OtherOperatingSystemSpecific(ElfWord(value.0))
+            }
+            Self::Other(value) => ElfSectionHeaderType::Other(ElfWord(value.0)),
+        }
+    }
 }
 
 impl<R, const EC: u8, const ED: u8> FromReader<R> for ElfSectionHeaderType<EC, ED>
@@ -300,47 +420,69 @@ where
             Self::SYMBOL_TABLE_SECTION_HEADER_INDEX => Ok(Self::SymbolTableSectionHeaderIndex),
             Self::REL_R => Ok(Self::RelR),
             other => {
-                if (Self::LOW_OPERATING_SYSTEM..Self::HIGH_OPERATING_SYSTEM).contains(&other) {
-                    ElfSectionHeaderTypeGNU::try_from_with(r#type, config)
-                        .map(Self::Gnu)
-                        .or_else(|_| {
-                            ElfSectionHeaderTypeSUN::try_from_with(r#type, config).map(Self::Sun)
-                        })
-                        .or(Ok(Self::OtherOperatingSystemSpecific(r#type)))
-                } else if (Self::LOW_PROCESSOR_SPECIFIC..Self::HIGH_PROCESSOR_SPECIFIC)
+                if (Self::LOW_OPERATING_SYSTEM..=Self::HIGH_OPERATING_SYSTEM).contains(&other) {
+                    // Some SUN and GNU types share a value, so the table for the object's
+                    // OS ABI is tried first, falling back to the other
+                    let sun = |config: &mut Config| {
+                        ElfSectionHeaderTypeSUN::try_from_with(r#type, config).map(Self::Sun)
+                    };
+                    let gnu = |config: &mut Config| {
+                        ElfSectionHeaderTypeGNU::try_from_with(r#type, config)
+                            .map(Self::Gnu)
+                            .or_else(|_| {
+                                ElfSectionHeaderTypeLLVM::try_from_with(r#type, config)
+                                    .map(Self::Llvm)
+                            })
+                    };
+
+                    match config.os_abi {
+                        Some(ElfOSABI::Solaris) => sun(config).or_else(|_| gnu(config)),
+                        _ => gnu(config).or_else(|_| sun(config)),
+                    }
+                    .or(Ok(Self::OtherOperatingSystemSpecific(r#type)))
+                } else if (Self::LOW_PROCESSOR_SPECIFIC..=Self::HIGH_PROCESSOR_SPECIFIC)
                     .contains(&other)
                 {
                     match config.machine {
+                        #[cfg(feature = "arch")]
                         Some(ElfMachine::AARCH64) => {
                             ElfSectionHeaderTypeAARCH64::try_from_with(r#type, config)
                                 .map(Self::AARCH64)
                         }
+                        #[cfg(feature = "arch")]
                         Some(ElfMachine::ARM) => {
                             ElfSectionHeaderTypeARM32::try_from_with(r#type, config).map(Self::Arm)
                         }
+                        #[cfg(feature = "arch")]
                         Some(ElfMachine::I386) => {
                             ElfSectionHeaderTypeI386::try_from_with(r#type, config).map(Self::I386)
                         }
+                        #[cfg(feature = "arch")]
                         Some(ElfMachine::MIPS) => {
                             ElfSectionHeaderTypeMIPS::try_from_with(r#type, config).map(Self::Mips)
                         }
+                        #[cfg(feature = "arch")]
                         Some(ElfMachine::PARISC) => {
                             ElfSectionHeaderTypePARISC::try_from_with(r#type, config)
                                 .map(Self::PaRisc)
                         }
+                        #[cfg(feature = "arch")]
                         Some(ElfMachine::PPC) => {
                             ElfSectionHeaderTypePPC::try_from_with(r#type, config).map(Self::Ppc)
                         }
+                        #[cfg(feature = "arch")]
                         Some(ElfMachine::Riscv) => {
                             ElfSectionHeaderTypeRISCV::try_from_with(r#type, config)
                                 .map(Self::Riscv)
                         }
+                        #[cfg(feature = "arch")]
                         Some(ElfMachine::X86_64) => {
                             ElfSectionHeaderTypeX86_64::try_from_with(r#type, config)
                                 .map(Self::X86_64)
                         }
-                        _ => Ok(Self::OtherProcessorSpecific(r#type)),
+                        _ => Ok::<_, Error>(Self::OtherProcessorSpecific(r#type)),
                     }
+                    .or(Ok(Self::OtherProcessorSpecific(r#type)))
                 } else {
                     Ok(Self::Other(r#type))
                 }
@@ -349,212 +491,165 @@ where
     }
 }
 
-impl<W, const EC: u8, const ED: u8> ToWriter<W> for ElfSectionHeaderType<EC, ED>
-where
-    W: Write,
-{
-    type Error = Error;
-
-    fn to_writer(&self, writer: &mut W) -> Result<(), Self::Error> {
-        match self {
+impl<const EC: u8, const ED: u8> From<&ElfSectionHeaderType<EC, ED>> for ElfWord<EC, ED> {
+    fn from(value: &ElfSectionHeaderType<EC, ED>) -> Self {
+        match value {
             ElfSectionHeaderType::NullUndefined => {
-                ElfWord::<EC, ED>(Self::NULL_UNDEFINED).to_writer(writer)
+                ElfWord(ElfSectionHeaderType::<EC, ED>::NULL_UNDEFINED)
             }
             ElfSectionHeaderType::ProgramBits => {
-                ElfWord::<EC, ED>(Self::PROGRAM_BITS).to_writer(writer)
+                ElfWord(ElfSectionHeaderType::<EC, ED>::PROGRAM_BITS)
             }
             ElfSectionHeaderType::SymbolTable => {
-                ElfWord::<EC, ED>(Self::SYMBOL_TABLE).to_writer(writer)
+                ElfWord(ElfSectionHeaderType::<EC, ED>::SYMBOL_TABLE)
             }
             ElfSectionHeaderType::StringTable => {
-                ElfWord::<EC, ED>(Self::STRING_TABLE).to_writer(writer)
+                ElfWord(ElfSectionHeaderType::<EC, ED>::STRING_TABLE)
             }
             ElfSectionHeaderType::RelocationExplicit => {
-                ElfWord::<EC, ED>(Self::RELOCATION_EXPLICIT).to_writer(writer)
+                ElfWord(ElfSectionHeaderType::<EC, ED>::RELOCATION_EXPLICIT)
             }
-            ElfSectionHeaderType::Hash => ElfWord::<EC, ED>(Self::HASH).to_writer(writer),
-            ElfSectionHeaderType::Dynamic => ElfWord::<EC, ED>(Self::DYNAMIC).to_writer(writer),
-            ElfSectionHeaderType::Note => ElfWord::<EC, ED>(Self::NOTE).to_writer(writer),
-            ElfSectionHeaderType::NoBits => ElfWord::<EC, ED>(Self::NO_BITS).to_writer(writer),
+            ElfSectionHeaderType::Hash => ElfWord(ElfSectionHeaderType::<EC, ED>::HASH),
+            ElfSectionHeaderType::Dynamic => ElfWord(ElfSectionHeaderType::<EC, ED>::DYNAMIC),
+            ElfSectionHeaderType::Note => ElfWord(ElfSectionHeaderType::<EC, ED>::NOTE),
+            ElfSectionHeaderType::NoBits => ElfWord(ElfSectionHeaderType::<EC, ED>::NO_BITS),
             ElfSectionHeaderType::RelocationImplicit => {
-                ElfWord::<EC, ED>(Self::RELOCATION_IMPLICIT).to_writer(writer)
+                ElfWord(ElfSectionHeaderType::<EC, ED>::RELOCATION_IMPLICIT)
             }
             ElfSectionHeaderType::SectionHeaderLibrary => {
-                ElfWord::<EC, ED>(Self::SECTION_HEADER_LIBRARY).to_writer(writer)
+                ElfWord(ElfSectionHeaderType::<EC, ED>::SECTION_HEADER_LIBRARY)
             }
             ElfSectionHeaderType::DynamicSymbol => {
-                ElfWord::<EC, ED>(Self::DYNAMIC_SYMBOL).to_writer(writer)
+                ElfWord(ElfSectionHeaderType::<EC, ED>::DYNAMIC_SYMBOL)
             }
             ElfSectionHeaderType::InitializerArray => {
-                ElfWord::<EC, ED>(Self::INITIALIZER_ARRAY).to_writer(writer)
+                ElfWord(ElfSectionHeaderType::<EC, ED>::INITIALIZER_ARRAY)
             }
             ElfSectionHeaderType::FinalizerArray => {
-                ElfWord::<EC, ED>(Self::FINALIZER_ARRAY).to_writer(writer)
+                ElfWord(ElfSectionHeaderType::<EC, ED>::FINALIZER_ARRAY)
             }
             ElfSectionHeaderType::PreInitializerArray => {
-                ElfWord::<EC, ED>(Self::PRE_INITIALIZER_ARRAY).to_writer(writer)
+                ElfWord(ElfSectionHeaderType::<EC, ED>::PRE_INITIALIZER_ARRAY)
             }
-            ElfSectionHeaderType::Group => ElfWord::<EC, ED>(Self::GROUP).to_writer(writer),
+            ElfSectionHeaderType::Group => ElfWord(ElfSectionHeaderType::<EC, ED>::GROUP),
             ElfSectionHeaderType::SymbolTableSectionHeaderIndex => {
-                ElfWord::<EC, ED>(Self::SYMBOL_TABLE_SECTION_HEADER_INDEX).to_writer(writer)
+                ElfWord(ElfSectionHeaderType::<EC, ED>::SYMBOL_TABLE_SECTION_HEADER_INDEX)
             }
-            ElfSectionHeaderType::RelR => ElfWord::<EC, ED>(Self::REL_R).to_writer(writer),
-            ElfSectionHeaderType::AARCH64(value) => {
-                ElfWord::<EC, ED>::from(value).to_writer(writer)
-            }
-            ElfSectionHeaderType::Arm(value) => ElfWord::<EC, ED>::from(value).to_writer(writer),
-            ElfSectionHeaderType::I386(value) => ElfWord::<EC, ED>::from(value).to_writer(writer),
-            ElfSectionHeaderType::Mips(value) => ElfWord::<EC, ED>::from(value).to_writer(writer),
-            ElfSectionHeaderType::PaRisc(value) => ElfWord::<EC, ED>::from(value).to_writer(writer),
-            ElfSectionHeaderType::Ppc(value) => ElfWord::<EC, ED>::from(value).to_writer(writer),
-            ElfSectionHeaderType::Riscv(value) => ElfWord::<EC, ED>::from(value).to_writer(writer),
-            ElfSectionHeaderType::X86_64(value) => ElfWord::<EC, ED>::from(value).to_writer(writer),
-            ElfSectionHeaderType::OtherProcessorSpecific(value) => value.to_writer(writer),
-            ElfSectionHeaderType::Gnu(value) => ElfWord::<EC, ED>::from(value).to_writer(writer),
-            ElfSectionHeaderType::Sun(value) => ElfWord::<EC, ED>::from(value).to_writer(writer),
-            ElfSectionHeaderType::OtherOperatingSystemSpecific(value) => value.to_writer(writer),
-            ElfSectionHeaderType::Other(value) => value.to_writer(writer),
+            ElfSectionHeaderType::RelR => ElfWord(ElfSectionHeaderType::<EC, ED>::REL_R),
+            #[cfg(feature = "arch")]
+            ElfSectionHeaderType::AARCH64(value) => ElfWord::from(value),
+            #[cfg(feature = "arch")]
+            ElfSectionHeaderType::Arm(value) => ElfWord::from(value),
+            #[cfg(feature = "arch")]
+            ElfSectionHeaderType::I386(value) => ElfWord::from(value),
+            #[cfg(feature = "arch")]
+            ElfSectionHeaderType::Mips(value) => ElfWord::from(value),
+            #[cfg(feature = "arch")]
+            ElfSectionHeaderType::PaRisc(value) => ElfWord::from(value),
+            #[cfg(feature = "arch")]
+            ElfSectionHeaderType::Ppc(value) => ElfWord::from(value),
+            #[cfg(feature = "arch")]
+            ElfSectionHeaderType::Riscv(value) => ElfWord::from(value),
+            #[cfg(feature = "arch")]
+            ElfSectionHeaderType::X86_64(value) => ElfWord::from(value),
+            ElfSectionHeaderType::OtherProcessorSpecific(value) => *value,
+            ElfSectionHeaderType::Gnu(value) => ElfWord::from(value),
+            ElfSectionHeaderType::Llvm(value) => ElfWord::from(value),
+            ElfSectionHeaderType::Sun(value) => ElfWord::from(value),
+            ElfSectionHeaderType::OtherOperatingSystemSpecific(value) => *value,
+            ElfSectionHeaderType::Other(value) => *value,
         }
     }
 }
 
-impl<const EC: u8, const ED: u8> HasWrittenSize for ElfSectionHeaderType<EC, ED> {
-    const SIZE: usize = size_of::<ElfWord<EC, ED>>();
-}
-
-from_primitive! {
-    #[repr(u32)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-    #[non_exhaustive]
-    /// The flags of an ELF section
-    enum Elf32SectionHeaderFlags<const ED: u8> {
-        /// The section contains data that should be writable during process execution
-        Write = 0x1,
-        /// The section occupies memory during process execution.  Some control sections
-        /// do not reside in the memory image of an object file; this attribute is off
-        /// for those sections.
-        Allocated = 0x2,
-        /// Contains executable machine instructions
-        ExecutableInstructions = 0x4,
-        /// The data in the section may be merged to eliminate duplication.  Unless the
-        /// SHF_STRINGS flag is also set, the data elements in the section are of a
-        /// uniform size. The size of each element is specified in the section header's
-        /// sh_entsize field. If the SHF_STRINGS flag is also set, the data elements
-        /// consist of null-terminated character strings. The size of each character is
-        /// specified in the section header's sh_entsize field.  Each element in the
-        /// section is compared against other elements in sections with the same name,
-        /// type and flags. Elements that would have identical values at program
-        /// run-time may be merged.  Relocations referencing elements of such sections
-        /// must be resolved to the merged locations of the referenced values. Note that
-        /// any relocatable values, including values that would result in run-time
-        /// relocations, must be analyzed to determine whether the run-time values would
-        /// actually be identical. An ABI-conforming object file may not depend on
-        /// specific elements being merged, and an ABI- conforming link editor may
-        /// choose not to merge specific elements.
-        Merge = 0x10,
-        /// The data elements in the section consist of null-terminated character
-        /// strings. The size of each character is specified in the section header's
-        /// sh_entsize field.
-        Strings = 0x20,
-        /// The sh_info field of this section header holds a section header table
-        /// index.
-        InfoLink = 0x40,
-        /// This flag adds special ordering requirements for link editors. The
-        /// requirements apply if the sh_link field of this section's header references
-        /// another section (the linked-to section). If this section is combined with
-        /// other sections in the output file, it must appear in the same relative order
-        /// with respect to those sections, as the linked-to section appears with
-        /// respect to sections the linked-to section is combined with.
-        ///
-        /// A typical use of this flag is to build a table that references text
-        /// or data sections in address order.
-        LinkOrder = 0x80,
-        /// This section requires special OS-specific processing (beyond the standard
-        /// linking rules) to avoid incorrect behavior. If this section has either an
-        /// sh_type value or contains sh_flags bits in the OS-specific ranges for those
-        /// fields, and a link editor processing this section does not recognize those
-        /// values, then the link editor should reject the object file containing this
-        /// section with an error.
-        OsNonConforming = 0x100,
-        /// This section is a member (perhaps the only one) of a section group.  The
-        /// section must be referenced by a section of type SHT_GROUP. The SHF_GROUP flag
-        /// may be set only for sections contained in relocatable objects (objects with
-        /// the ELF header e_type member set to ET_REL). See below for further details.
-        Group = 0x200,
-        /// This section holds Thread-Local Storage, meaning that each separate
-        /// execution flow has its own distinct instance of this data.  Implementations
-        /// need not support this flag.
-        ThreadLocalStorage = 0x400,
-        /// This flag identifies a section containing compressed data.
-        /// SHF_COMPRESSED applies only to non-allocable sections, and cannot
-        /// be used in conjunction with SHF_ALLOC. In addition,
-        /// SHF_COMPRESSED cannot be applied to sections of type SHT_NOBITS.
-        /// All relocations to a compressed section specifiy oﬀsets to the
-        /// uncompressed section data. It is therefore necessary to decompress
-        /// the section data before relocations can be applied. Each compressed
-        /// section specifies the algorithm independently. It is permissible for
-        /// diﬀerent sections in a given ELF object to employ diﬀerent
-        /// compression algorithms.
-        /// Compressed sections begin with a compression header structure that
-        /// identifies the compression algorithm.
-        Compressed = 0x800,
-        // Maskos = 0x0ff00000
-        // Maskproc = 0xf0000000
-    }
-}
-
-impl<R, const ED: u8> FromReader<R> for Elf32SectionHeaderFlags<ED>
+impl<W, const EC: u8, const ED: u8> ToWriter<W> for ElfSectionHeaderType<EC, ED>
 where
-    R: Read + Seek,
+    W: Write,
 {
     type Error = Error;
 
-    fn from_reader_with(reader: &mut R, config: &mut Config) -> Result<Self, Self::Error> {
-        let flags = ElfWord::<{ ElfClass::Elf32 as u8 }, ED>::from_reader_with(reader, config)?;
-
-        if let Some(flags) = Self::from_u32(flags.0) {
-            Ok(flags)
-        } else {
-            Err(Error::InvalidElfSectionHeaderFlags {
-                context: ErrorContext::from_reader(
-                    reader,
-                    size_of::<ElfWord<{ ElfClass::Elf32 as u8 }, ED>>(),
-                )
-                .map_err(Error::from)?,
-            })
-        }
+    fn to_writer(&self, writer: &mut W) -> Result<(), Self::Error> {
+        ElfWord::<EC, ED>::from(self).to_writer(writer)
     }
 }
 
-impl<W, const ED: u8> ToWriter<W> for Elf32SectionHeaderFlags<ED>
-where
-    W: Write,
-{
-    type Error = Error;
-
-    fn to_writer(&self, writer: &mut W) -> Result<(), Self::Error> {
-        ElfWord::<{ ElfClass::Elf32 as u8 }, ED>((*self as u32).to_le()).to_writer(writer)
+impl<const EC: u8, const ED: u8> core::fmt::Display for ElfSectionHeaderType<EC, ED> {
+    /// Display the section header type as its name in `readelf` output
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NullUndefined => f.write_str("NULL"),
+            Self::ProgramBits => f.write_str("PROGBITS"),
+            Self::SymbolTable => f.write_str("SYMTAB"),
+            Self::StringTable => f.write_str("STRTAB"),
+            Self::RelocationExplicit => f.write_str("RELA"),
+            Self::Hash => f.write_str("HASH"),
+            Self::Dynamic => f.write_str("DYNAMIC"),
+            Self::Note => f.write_str("NOTE"),
+            Self::NoBits => f.write_str("NOBITS"),
+            Self::RelocationImplicit => f.write_str("REL"),
+            Self::SectionHeaderLibrary => f.write_str("SHLIB"),
+            Self::DynamicSymbol => f.write_str("DYNSYM"),
+            Self::InitializerArray => f.write_str("INIT_ARRAY"),
+            Self::FinalizerArray => f.write_str("FINI_ARRAY"),
+            Self::PreInitializerArray => f.write_str("PREINIT_ARRAY"),
+            Self::Group => f.write_str("GROUP"),
+            Self::SymbolTableSectionHeaderIndex => f.write_str("SYMTAB SECTION INDICES"),
+            Self::RelR => f.write_str("RELR"),
+            #[cfg(feature = "arch")]
+            Self::AARCH64(r#type) => r#type.fmt(f),
+            #[cfg(feature = "arch")]
+            Self::Arm(r#type) => r#type.fmt(f),
+            #[cfg(feature = "arch")]
+            Self::I386(r#type) => r#type.fmt(f),
+            #[cfg(feature = "arch")]
+            Self::Mips(r#type) => r#type.fmt(f),
+            #[cfg(feature = "arch")]
+            Self::PaRisc(r#type) => r#type.fmt(f),
+            #[cfg(feature = "arch")]
+            Self::Ppc(r#type) => r#type.fmt(f),
+            #[cfg(feature = "arch")]
+            Self::Riscv(r#type) => r#type.fmt(f),
+            #[cfg(feature = "arch")]
+            Self::X86_64(r#type) => r#type.fmt(f),
+            Self::OtherProcessorSpecific(r#type) => {
+                write!(f, "LOPROC+{:#x}", r#type.0 - Self::LOW_PROCESSOR_SPECIFIC)
+            }
+            Self::Gnu(r#type) => r#type.fmt(f),
+            Self::Llvm(r#type) => r#type.fmt(f),
+            Self::Sun(r#type) => r#type.fmt(f),
+            Self::OtherOperatingSystemSpecific(r#type) => {
+                write!(f, "LOOS+{:#x}", r#type.0 - Self::LOW_OPERATING_SYSTEM)
+            }
+            Self::Other(r#type) if r#type.0 > Self::HIGH_PROCESSOR_SPECIFIC => {
+                write!(
+                    f,
+                    "LOUSER+{:#x}",
+                    r#type.0 - (Self::HIGH_PROCESSOR_SPECIFIC + 1)
+                )
+            }
+            Self::Other(r#type) => write!(f, "<unknown>: {:x}", r#type.0),
+        }
     }
 }
 
-impl<const ED: u8> HasWrittenSize for Elf32SectionHeaderFlags<ED> {
-    const SIZE: usize = size_of::<ElfWord<{ ElfClass::Elf32 as u8 }, ED>>();
+impl<const EC: u8, const ED: u8> HasWrittenSize for ElfSectionHeaderType<EC, ED> {
+    const SIZE: usize = size_of::<ElfWord<EC, ED>>();
 }
 
-from_primitive! {
-    #[repr(u64)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-    #[non_exhaustive]
-    /// The flags of an ELF section
-    enum Elf64SectionHeaderFlags<const ED: u8> {
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    /// The flags of an ELF section. The flags are stored as a 32-bit word in 32-bit
+    /// files and as a 64-bit extended word in 64-bit files, and any combination of
+    /// flags may be set.
+    pub struct ElfSectionHeaderFlags: u64 {
         /// The section contains data that should be writable during process execution
-        Write = 0x1,
+        const WRITE = 0x1;
         /// The section occupies memory during process execution.  Some control sections
         /// do not reside in the memory image of an object file; this attribute is off
         /// for those sections.
-        Allocated = 0x2,
+        const ALLOCATED = 0x2;
         /// Contains executable machine instructions
-        ExecutableInstructions = 0x4,
+        const EXECUTABLE_INSTRUCTIONS = 0x4;
         /// The data in the section may be merged to eliminate duplication.  Unless the
         /// SHF_STRINGS flag is also set, the data elements in the section are of a
         /// uniform size. The size of each element is specified in the section header's
@@ -570,14 +665,14 @@ from_primitive! {
         /// actually be identical. An ABI-conforming object file may not depend on
         /// specific elements being merged, and an ABI- conforming link editor may
         /// choose not to merge specific elements.
-        Merge = 0x10,
+        const MERGE = 0x10;
         /// The data elements in the section consist of null-terminated character
         /// strings. The size of each character is specified in the section header's
         /// sh_entsize field.
-        Strings = 0x20,
+        const STRINGS = 0x20;
         /// The sh_info field of this section header holds a section header table
         /// index.
-        InfoLink = 0x40,
+        const INFO_LINK = 0x40;
         /// This flag adds special ordering requirements for link editors. The
         /// requirements apply if the sh_link field of this section's header references
         /// another section (the linked-to section). If this section is combined with
@@ -587,23 +682,23 @@ from_primitive! {
         ///
         /// A typical use of this flag is to build a table that references text
         /// or data sections in address order.
-        LinkOrder = 0x80,
+        const LINK_ORDER = 0x80;
         /// This section requires special OS-specific processing (beyond the standard
         /// linking rules) to avoid incorrect behavior. If this section has either an
         /// sh_type value or contains sh_flags bits in the OS-specific ranges for those
         /// fields, and a link editor processing this section does not recognize those
         /// values, then the link editor should reject the object file containing this
         /// section with an error.
-        OsNonConforming = 0x100,
+        const OS_NONCONFORMING = 0x100;
         /// This section is a member (perhaps the only one) of a section group.  The
         /// section must be referenced by a section of type SHT_GROUP. The SHF_GROUP flag
         /// may be set only for sections contained in relocatable objects (objects with
         /// the ELF header e_type member set to ET_REL). See below for further details.
-        Group = 0x200,
+        const GROUP = 0x200;
         /// This section holds Thread-Local Storage, meaning that each separate
         /// execution flow has its own distinct instance of this data.  Implementations
         /// need not support this flag.
-        ThreadLocalStorage = 0x400,
+        const THREAD_LOCAL_STORAGE = 0x400;
         /// This flag identifies a section containing compressed data.
         /// SHF_COMPRESSED applies only to non-allocable sections, and cannot
         /// be used in conjunction with SHF_ALLOC. In addition,
@@ -616,75 +711,38 @@ from_primitive! {
         /// compression algorithms.
         /// Compressed sections begin with a compression header structure that
         /// identifies the compression algorithm.
-        Compressed = 0x800,
-        // Maskos = 0x0ff00000
-        // Maskproc = 0xf0000000
-    }
-}
-
-impl<R, const ED: u8> FromReader<R> for Elf64SectionHeaderFlags<ED>
-where
-    R: Read + Seek,
-{
-    type Error = Error;
-
-    fn from_reader_with(reader: &mut R, config: &mut Config) -> Result<Self, Self::Error> {
-        let flags =
-            ElfExtendedWord::<{ ElfClass::Elf64 as u8 }, ED>::from_reader_with(reader, config)?;
-
-        if let Some(flags) = Self::from_u64(flags.0) {
-            Ok(flags)
-        } else {
-            Err(Error::InvalidElfSectionHeaderFlags {
-                context: ErrorContext::from_reader(
-                    reader,
-                    size_of::<ElfExtendedWord<{ ElfClass::Elf64 as u8 }, ED>>(),
-                )
-                .map_err(Error::from)?,
-            })
-        }
-    }
-}
-
-impl<W, const ED: u8> ToWriter<W> for Elf64SectionHeaderFlags<ED>
-where
-    W: Write,
-{
-    type Error = Error;
-
-    fn to_writer(&self, writer: &mut W) -> Result<(), Self::Error> {
-        ElfExtendedWord::<{ ElfClass::Elf64 as u8 }, ED>((*self as u64).to_le()).to_writer(writer)
+        const COMPRESSED = 0x800;
+        /// All bits included in this mask are reserved for operating system-specific
+        /// semantics.
+        const MASK_OPERATING_SYSTEM = 0x0ff00000;
+        /// All bits included in this mask are reserved for processor-specific semantics.
+        const MASK_PROCESSOR = 0xf0000000;
     }
 }
 
-impl<const ED: u8> HasWrittenSize for Elf64SectionHeaderFlags<ED> {
-    const SIZE: usize = size_of::<ElfExtendedWord<{ ElfClass::Elf64 as u8 }, ED>>();
-}
-
-#[repr(C)]
 #[derive(Debug, Clone, PartialEq, Eq, TypedBuilder)]
-/// ELF Section Header
+/// ELF 32-bit Section Header
 pub struct Elf32SectionHeader<const ED: u8> {
     /// The name of the section. Its value is an index into the section header string
     /// table section giving the location of a null-terminated string
-    name: ElfSectionHeaderName<ED>,
+    pub name: ElfSectionHeaderName<ED>,
     /// The section's contents and semantics
-    r#type: ElfSectionHeaderType<{ ElfClass::Elf32 as u8 }, ED>,
+    pub r#type: ElfSectionHeaderType<{ ElfClass::Elf32 as u8 }, ED>,
     /// Bit-flags that describe miscellaneous attributes
-    flags: Elf32SectionHeaderFlags<ED>,
+    pub flags: ElfSectionHeaderFlags,
     /// If the section will appear in the memory image of a process, this member gives
     /// the address at which the section's first byte should reside. Otherwise, the
     /// member contains 0.
-    address: Option<ElfAddress<{ ElfClass::Elf32 as u8 }, ED>>,
+    pub address: ElfAddress<{ ElfClass::Elf32 as u8 }, ED>,
     /// This member's value gives the byte offset from the beginning of the file to the
     /// first byte in the section. One section type, SHT_NOBITS described below, occupies
     /// no space in the file, and its sh_offset member locates the conceptual placement in
     /// the file.
-    offset: ElfOffset<{ ElfClass::Elf32 as u8 }, ED>,
+    pub offset: ElfOffset<{ ElfClass::Elf32 as u8 }, ED>,
     /// This member gives the section's size in bytes. Unless the section type is
     /// SHT_NOBITS, the section occupies sh_size bytes in the file. A section of type
     /// SHT_NOBITS may have a non-zero size, but it occupies no space in the file.
-    size: ElfWord<{ ElfClass::Elf32 as u8 }, ED>,
+    pub size: ElfWord<{ ElfClass::Elf32 as u8 }, ED>,
     /// This member holds a section header table index link, whose interpretation
     /// depends on the section type. A table below describes the values.
     ///
@@ -712,48 +770,47 @@ pub struct Elf32SectionHeader<const ED: u8> {
     /// * SHT_SYMTAB_SHNDX:
     ///     * link: The section header index of the associated symbol table section
     ///     * info: 0
-    link: ElfWord<{ ElfClass::Elf32 as u8 }, ED>,
+    pub link: ElfWord<{ ElfClass::Elf32 as u8 }, ED>,
     /// This member holds extra information, whose interpretation depends on the section
     /// type. See `link` for the table describing meanings. If the sh_flags field for
     /// this section header includes the attribute SHF_INFO_LINK, then this member
     /// represents a section header table index.
-    info: ElfWord<{ ElfClass::Elf32 as u8 }, ED>,
+    pub info: ElfWord<{ ElfClass::Elf32 as u8 }, ED>,
     /// Some sections have address alignment constraints. For example, if a section
     /// holds a doubleword, the system must ensure doubleword alignment for the entire
     /// section. The value of sh_addr must be congruent to 0, modulo the value of
     /// sh_addralign. Currently, only 0 and positive integral powers of two are allowed.
     /// Values 0 and 1 mean the section has no alignment constraints.
-    address_align: ElfWord<{ ElfClass::Elf32 as u8 }, ED>,
+    pub address_align: ElfWord<{ ElfClass::Elf32 as u8 }, ED>,
     /// Some sections hold a table of fixed-size entries, such as a symbol table.  For
     /// such a section, this member gives the size in bytes of each entry.  The member
     /// contains 0 if the section does not hold a table of fixed- size entries
-    entry_size: ElfWord<{ ElfClass::Elf32 as u8 }, ED>,
+    pub entry_size: ElfWord<{ ElfClass::Elf32 as u8 }, ED>,
 }
 
-#[repr(C)]
 #[derive(Debug, Clone, PartialEq, Eq, TypedBuilder)]
-/// ELF Section Header
+/// ELF 64-bit Section Header
 pub struct Elf64SectionHeader<const ED: u8> {
     /// The name of the section. Its value is an index into the section header string
     /// table section giving the location of a null-terminated string
-    name: ElfWord<{ ElfClass::Elf64 as u8 }, ED>,
+    pub name: ElfSectionHeaderName<ED>,
     /// The section's contents and semantics
-    r#type: ElfSectionHeaderType<{ ElfClass::Elf64 as u8 }, ED>,
+    pub r#type: ElfSectionHeaderType<{ ElfClass::Elf64 as u8 }, ED>,
     /// Bit-flags that describe miscellaneous attributes
-    flags: Elf64SectionHeaderFlags<ED>,
+    pub flags: ElfSectionHeaderFlags,
     /// If the section will appear in the memory image of a process, this member gives
     /// the address at which the section's first byte should reside. Otherwise, the
     /// member contains 0.
-    address: ElfAddress<{ ElfClass::Elf64 as u8 }, ED>,
+    pub address: ElfAddress<{ ElfClass::Elf64 as u8 }, ED>,
     /// This member's value gives the byte offset from the beginning of the file to the
     /// ﬁrst byte in the section. One section type, SHT_NOBITS described below, occupies
     /// no space in the file, and its sh_offset member locates the conceptual placement in
     /// the file.
-    offset: ElfOffset<{ ElfClass::Elf64 as u8 }, ED>,
+    pub offset: ElfOffset<{ ElfClass::Elf64 as u8 }, ED>,
     /// This member gives the section's size in bytes. Unless the section type is
     /// SHT_NOBITS, the section occupies sh_size bytes in the file. A section of type
     /// SHT_NOBITS may have a non-zero size, but it occupies no space in the file.
-    size: ElfExtendedWord<{ ElfClass::Elf64 as u8 }, ED>,
+    pub size: ElfExtendedWord<{ ElfClass::Elf64 as u8 }, ED>,
     /// This member holds a section header table index link, whose interpretation
     /// depends on the section type. A table below describes the values.
     ///
@@ -781,20 +838,1631 @@ pub struct Elf64SectionHeader<const ED: u8> {
     /// * SHT_SYMTAB_SHNDX:
     ///     * link: The section header index of the associated symbol table section
     ///     * info: 0
-    link: ElfWord<{ ElfClass::Elf64 as u8 }, ED>,
+    pub link: ElfWord<{ ElfClass::Elf64 as u8 }, ED>,
     /// This member holds extra information, whose interpretation depends on the section
     /// type. See `link` for the table describing meanings. If the sh_flags field for
     /// this section header includes the attribute SHF_INFO_LINK, then this member
     /// represents a section header table index.
-    info: ElfWord<{ ElfClass::Elf64 as u8 }, ED>,
+    pub info: ElfWord<{ ElfClass::Elf64 as u8 }, ED>,
     /// Some sections have address alignment constraints. For example, if a section
     /// holds a doubleword, the system must ensure doubleword alignment for the entire
     /// section. The value of sh_addr must be congruent to 0, modulo the value of
     /// sh_addralign. Currently, only 0 and positive integral powers of two are allowed.
     /// Values 0 and 1 mean the section has no alignment constraints.
-    address_align: ElfExtendedWord<{ ElfClass::Elf64 as u8 }, ED>,
+    pub address_align: ElfExtendedWord<{ ElfClass::Elf64 as u8 }, ED>,
     /// Some sections hold a table of fixed-size entries, such as a symbol table.  For
     /// such a section, this member gives the size in bytes of each entry.  The member
     /// contains 0 if the section does not hold a table of fixed- size entries
-    entry_size: ElfExtendedWord<{ ElfClass::Elf64 as u8 }, ED>,
+    pub entry_size: ElfExtendedWord<{ ElfClass::Elf64 as u8 }, ED>,
+}
+
+impl<R, const ED: u8> FromReader<R> for Elf32SectionHeader<ED>
+where
+    R: Read + Seek,
+{
+    type Error = Error;
+
+    fn from_reader_with(reader: &mut R, config: &mut Config) -> Result<Self, Self::Error> {
+        let name = ElfSectionHeaderName::<ED>::from_reader_with(reader, config)?;
+        let r#type = ElfSectionHeaderType::<{ ElfClass::Elf32 as u8 }, ED>::from_reader_with(
+            reader, config,
+        )?;
+        let flags = ElfSectionHeaderFlags::from_bits_retain(
+            ElfWord::<{ ElfClass::Elf32 as u8 }, ED>::from_reader_with(reader, config)?.0 as u64,
+        );
+        let address =
+            ElfAddress::<{ ElfClass::Elf32 as u8 }, ED>::from_reader_with(reader, config)?;
+        let offset = ElfOffset::<{ ElfClass::Elf32 as u8 }, ED>::from_reader_with(reader, config)?;
+        let size = ElfWord::<{ ElfClass::Elf32 as u8 }, ED>::from_reader_with(reader, config)?;
+        let link = ElfWord::<{ ElfClass::Elf32 as u8 }, ED>::from_reader_with(reader, config)?;
+        let info = ElfWord::<{ ElfClass::Elf32 as u8 }, ED>::from_reader_with(reader, config)?;
+        let address_align =
+            ElfWord::<{ ElfClass::Elf32 as u8 }, ED>::from_reader_with(reader, config)?;
+        let entry_size =
+            ElfWord::<{ ElfClass::Elf32 as u8 }, ED>::from_reader_with(reader, config)?;
+
+        Ok(Self {
+            name,
+            r#type,
+            flags,
+            address,
+            offset,
+            size,
+            link,
+            info,
+            address_align,
+            entry_size,
+        })
+    }
+}
+
+impl<W, const ED: u8> ToWriter<W> for Elf32SectionHeader<ED>
+where
+    W: Write,
+{
+    type Error = Error;
+
+    fn to_writer(&self, writer: &mut W) -> Result<(), Self::Error> {
+        self.name.to_writer(writer)?;
+        self.r#type.to_writer(writer)?;
+        ElfWord::<{ ElfClass::Elf32 as u8 }, ED>(self.flags.bits() as u32).to_writer(writer)?;
+        self.address.to_writer(writer)?;
+        self.offset.to_writer(writer)?;
+        self.size.to_writer(writer)?;
+        self.link.to_writer(writer)?;
+        self.info.to_writer(writer)?;
+        self.address_align.to_writer(writer)?;
+        self.entry_size.to_writer(writer)?;
+        Ok(())
+    }
+}
+
+impl<const ED: u8> HasWrittenSize for Elf32SectionHeader<ED> {
+    const SIZE: usize = ElfSectionHeaderName::<ED>::SIZE
+        + ElfSectionHeaderType::<{ ElfClass::Elf32 as u8 }, ED>::SIZE
+        + ElfWord::<{ ElfClass::Elf32 as u8 }, ED>::SIZE
+        + ElfAddress::<{ ElfClass::Elf32 as u8 }, ED>::SIZE
+        + ElfOffset::<{ ElfClass::Elf32 as u8 }, ED>::SIZE
+        + (ElfWord::<{ ElfClass::Elf32 as u8 }, ED>::SIZE * 5);
+}
+
+impl<R, const ED: u8> FromReader<R> for Elf64SectionHeader<ED>
+where
+    R: Read + Seek,
+{
+    type Error = Error;
+
+    fn from_reader_with(reader: &mut R, config: &mut Config) -> Result<Self, Self::Error> {
+        let name = ElfSectionHeaderName::<ED>::from_reader_with(reader, config)?;
+        let r#type = ElfSectionHeaderType::<{ ElfClass::Elf64 as u8 }, ED>::from_reader_with(
+            reader, config,
+        )?;
+        let flags = ElfSectionHeaderFlags::from_bits_retain(
+            ElfExtendedWord::<{ ElfClass::Elf64 as u8 }, ED>::from_reader_with(reader, config)?.0,
+        );
+        let address =
+            ElfAddress::<{ ElfClass::Elf64 as u8 }, ED>::from_reader_with(reader, config)?;
+        let offset = ElfOffset::<{ ElfClass::Elf64 as u8 }, ED>::from_reader_with(reader, config)?;
+        let size =
+            ElfExtendedWord::<{ ElfClass::Elf64 as u8 }, ED>::from_reader_with(reader, config)?;
+        let link = ElfWord::<{ ElfClass::Elf64 as u8 }, ED>::from_reader_with(reader, config)?;
+        let info = ElfWord::<{ ElfClass::Elf64 as u8 }, ED>::from_reader_with(reader, config)?;
+        let address_align =
+            ElfExtendedWord::<{ ElfClass::Elf64 as u8 }, ED>::from_reader_with(reader, config)?;
+        let entry_size =
+            ElfExtendedWord::<{ ElfClass::Elf64 as u8 }, ED>::from_reader_with(reader, config)?;
+
+        Ok(Self {
+            name,
+            r#type,
+            flags,
+            address,
+            offset,
+            size,
+            link,
+            info,
+            address_align,
+            entry_size,
+        })
+    }
+}
+
+impl<W, const ED: u8> ToWriter<W> for Elf64SectionHeader<ED>
+where
+    W: Write,
+{
+    type Error = Error;
+
+    fn to_writer(&self, writer: &mut W) -> Result<(), Self::Error> {
+        self.name.to_writer(writer)?;
+        self.r#type.to_writer(writer)?;
+        ElfExtendedWord::<{ ElfClass::Elf64 as u8 }, ED>(self.flags.bits()).to_writer(writer)?;
+        self.address.to_writer(writer)?;
+        self.offset.to_writer(writer)?;
+        self.size.to_writer(writer)?;
+        self.link.to_writer(writer)?;
+        self.info.to_writer(writer)?;
+        self.address_align.to_writer(writer)?;
+        self.entry_size.to_writer(writer)?;
+        Ok(())
+    }
+}
+
+impl<const ED: u8> HasWrittenSize for Elf64SectionHeader<ED> {
+    const SIZE: usize = ElfSectionHeaderName::<ED>::SIZE
+        + ElfSectionHeaderType::<{ ElfClass::Elf64 as u8 }, ED>::SIZE
+        + ElfExtendedWord::<{ ElfClass::Elf64 as u8 }, ED>::SIZE
+        + ElfAddress::<{ ElfClass::Elf64 as u8 }, ED>::SIZE
+        + ElfOffset::<{ ElfClass::Elf64 as u8 }, ED>::SIZE
+        + ElfExtendedWord::<{ ElfClass::Elf64 as u8 }, ED>::SIZE
+        + (ElfWord::<{ ElfClass::Elf64 as u8 }, ED>::SIZE * 2)
+        + (ElfExtendedWord::<{ ElfClass::Elf64 as u8 }, ED>::SIZE * 2);
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// ELF section header for either 32-bit or 64-bit ELF files
+pub enum ElfSectionHeader<const EC: u8, const ED: u8> {
+    /// A 32-bit ELF section header
+    Elf32(Elf32SectionHeader<ED>),
+    /// A 64-bit ELF section header
+    Elf64(Elf64SectionHeader<ED>),
+}
+
+impl<const EC: u8, const ED: u8> ElfSectionHeader<EC, ED> {
+    /// The name of the section, or an empty string if the name could not be resolved
+    pub fn name(&self) -> &str {
+        match self {
+            ElfSectionHeader::Elf32(header) => &header.name.name,
+            ElfSectionHeader::Elf64(header) => &header.name.name,
+        }
+    }
+
+    /// The index of the section's name in the section header string table
+    pub fn name_index(&self) -> u32 {
+        match self {
+            ElfSectionHeader::Elf32(header) => header.name.value.0,
+            ElfSectionHeader::Elf64(header) => header.name.value.0,
+        }
+    }
+
+    /// The section's contents and semantics
+    pub fn r#type(&self) -> ElfSectionHeaderType<EC, ED> {
+        match self {
+            ElfSectionHeader::Elf32(header) => header.r#type.with_class(),
+            ElfSectionHeader::Elf64(header) => header.r#type.with_class(),
+        }
+    }
+
+    /// Bit-flags that describe miscellaneous attributes
+    pub fn flags(&self) -> ElfSectionHeaderFlags {
+        match self {
+            ElfSectionHeader::Elf32(header) => header.flags,
+            ElfSectionHeader::Elf64(header) => header.flags,
+        }
+    }
+
+    /// The address at which the section's first byte resides in the memory image of a
+    /// process, or 0
+    pub fn address(&self) -> u64 {
+        match self {
+            ElfSectionHeader::Elf32(header) => header.address.0,
+            ElfSectionHeader::Elf64(header) => header.address.0,
+        }
+    }
+
+    /// The byte offset from the beginning of the file to the first byte in the section
+    pub fn offset(&self) -> u64 {
+        match self {
+            ElfSectionHeader::Elf32(header) => header.offset.0,
+            ElfSectionHeader::Elf64(header) => header.offset.0,
+        }
+    }
+
+    /// The section's size in bytes
+    pub fn size(&self) -> u64 {
+        match self {
+            ElfSectionHeader::Elf32(header) => header.size.0 as u64,
+            ElfSectionHeader::Elf64(header) => header.size.0,
+        }
+    }
+
+    /// The section header table index link, whose interpretation depends on the
+    /// section type
+    pub fn link(&self) -> SectionIndex {
+        match self {
+            ElfSectionHeader::Elf32(header) => SectionIndex(header.link.0),
+            ElfSectionHeader::Elf64(header) => SectionIndex(header.link.0),
+        }
+    }
+
+    /// Extra information, whose interpretation depends on the section type
+    pub fn info(&self) -> u32 {
+        match self {
+            ElfSectionHeader::Elf32(header) => header.info.0,
+            ElfSectionHeader::Elf64(header) => header.info.0,
+        }
+    }
+
+    /// The section referenced by `sh_info`, which it is when the section has the
+    /// `SHF_INFO_LINK` flag, as relocation sections do for the section they apply to
+    pub fn info_section(&self) -> Option<SectionIndex> {
+        self.flags()
+            .contains(ElfSectionHeaderFlags::INFO_LINK)
+            .then(|| SectionIndex(self.info()))
+    }
+
+    /// The section's address alignment constraint
+    pub fn address_align(&self) -> u64 {
+        match self {
+            ElfSectionHeader::Elf32(header) => header.address_align.0 as u64,
+            ElfSectionHeader::Elf64(header) => header.address_align.0,
+        }
+    }
+
+    /// The size in bytes of each entry, for sections holding a table of fixed-size
+    /// entries
+    pub fn entry_size(&self) -> u64 {
+        match self {
+            ElfSectionHeader::Elf32(header) => header.entry_size.0 as u64,
+            ElfSectionHeader::Elf64(header) => header.entry_size.0,
+        }
+    }
+
+    /// The size in bytes of each entry which the specification defines for the
+    /// section's type, or `None` if sections of the type do not hold a table of
+    /// fixed-size entries
+    pub fn default_entry_size(&self) -> Option<u64> {
+        let word_size = ElfAddress::<EC, ED>::SIZE as u64;
+
+        match self.r#type() {
+            // Symbols have a different layout in 64-bit objects, which is not simply
+            // wider
+            ElfSectionHeaderType::SymbolTable | ElfSectionHeaderType::DynamicSymbol => {
+                Some(if word_size == 8 { 24 } else { 16 })
+            }
+            ElfSectionHeaderType::RelocationImplicit | ElfSectionHeaderType::Dynamic => {
+                Some(2 * word_size)
+            }
+            ElfSectionHeaderType::RelocationExplicit => Some(3 * word_size),
+            ElfSectionHeaderType::InitializerArray
+            | ElfSectionHeaderType::FinalizerArray
+            | ElfSectionHeaderType::PreInitializerArray
+            | ElfSectionHeaderType::RelR => Some(word_size),
+            ElfSectionHeaderType::Hash
+            | ElfSectionHeaderType::Group
+            | ElfSectionHeaderType::SymbolTableSectionHeaderIndex => Some(4),
+            ElfSectionHeaderType::Gnu(ElfSectionHeaderTypeGNU::VerSym) => Some(2),
+            _ => None,
+        }
+    }
+
+    /// The size of each entry of the section at `index`, whose data is `size` bytes, and
+    /// the number of entries it holds. An entry size of 0 is replaced by the entry size
+    /// defined for the section's type, and a section with neither holds no entries.
+    pub(crate) fn entry_count(
+        &self,
+        index: usize,
+        size: u64,
+        config: &Config,
+    ) -> Result<(u64, u64), Error> {
+        let entry_size = match self.entry_size() {
+            0 => self.default_entry_size().unwrap_or(0),
+            entry_size => entry_size,
+        };
+
+        if entry_size == 0 {
+            return Ok((0, 0));
+        }
+
+        if !size.is_multiple_of(entry_size) {
+            let err = Error::InvalidEntrySize {
+                index,
+                entry_size,
+                size,
+            };
+
+            if !config.ignores(&err) {
+                return Err(err);
+            }
+        }
+
+        Ok((entry_size, size / entry_size))
+    }
+
+    pub(crate) fn set_name(&mut self, name: String) {
+        match self {
+            ElfSectionHeader::Elf32(header) => header.name.name = name,
+            ElfSectionHeader::Elf64(header) => header.name.name = name,
+        }
+    }
+
+    /// Set the file offset of the section, which must fit in the class
+    pub(crate) fn set_offset(&mut self, offset: u64) {
+        match self {
+            ElfSectionHeader::Elf32(header) => header.offset.0 = offset,
+            ElfSectionHeader::Elf64(header) => header.offset.0 = offset,
+        }
+    }
+
+    /// Set the size of the section, which must fit in the class
+    pub(crate) fn set_size(&mut self, size: u64) {
+        match self {
+            ElfSectionHeader::Elf32(header) => header.size.0 = size as u32,
+            ElfSectionHeader::Elf64(header) => header.size.0 = size,
+        }
+    }
+
+    /// Check that a section header table of `count` entries of `entry_size` bytes at
+    /// `offset` fits in a file of `length` bytes, without reading any of its entries
+    pub(crate) fn check_table_bounds(
+        offset: u64,
+        count: u64,
+        entry_size: u64,
+        length: u64,
+    ) -> Result<(), Error> {
+        let size = count.saturating_mul(entry_size);
+
+        match offset.checked_add(size) {
+            Some(end) if end <= length => Ok(()),
+            _ => Err(Error::SectionHeaderTableOutOfBounds {
+                offset,
+                size,
+                length,
+            }),
+        }
+    }
+
+    /// Read the first entry of the section header table described by an ELF header,
+    /// which holds the extended counts and indices of the ELF header, or `None` if the
+    /// table is absent. An entry which does not fit in the file is an error, unless it
+    /// is ignored or `config` enables partial decoding, in which case it is absent.
+    pub(crate) fn first_from_reader_with<R>(
+        reader: &mut R,
+        header: &ElfHeader<EC, ED>,
+        config: &mut Config,
+    ) -> Result<Option<Self>, Error>
+    where
+        R: Read + Seek,
+    {
+        let offset = match header.section_header_offset {
+            Some(offset) if offset.0 != 0 => offset.0,
+            _ => return Ok(None),
+        };
+        let entry_size = match header.section_header_entry_size.0 {
+            0 => return Ok(None),
+            entry_size => entry_size as u64,
+        };
+        let length = reader.seek(SeekFrom::End(0))?;
+
+        if let Err(err) = Self::check_table_bounds(offset, 1, entry_size, length) {
+            return match config.partial || config.ignores(&err) {
+                true => Ok(None),
+                false => Err(err),
+            };
+        }
+
+        reader.seek(SeekFrom::Start(offset))?;
+
+        Self::from_reader_with(reader, config).map(Some)
+    }
+
+    /// Read the section header table described by an ELF header. When the number of
+    /// section headers does not fit in the ELF header, the count is read from the
+    /// `sh_size` field of the first section header, and when the section header string
+    /// table index does not fit, it is read from the `sh_link` field of the first
+    /// section header. Section names are resolved from the section header string table
+    /// if it is present.
+    ///
+    /// A section header table which does not fit in the file is an error. The table's
+    /// bounds are checked before any entries are read, so a forged entry count never
+    /// causes a large allocation. When the error is ignored, the table is treated as
+    /// absent like `readelf` does, so the rest of the file can still be decoded, and if
+    /// `config` enables partial decoding, the entries which fit are kept instead.
+    pub(crate) fn table_from_reader_with<R>(
+        reader: &mut R,
+        header: &ElfHeader<EC, ED>,
+        config: &mut Config,
+    ) -> Result<Vec<Self>, Error>
+    where
+        R: Read + Seek,
+    {
+        let Some(first) = Self::first_from_reader_with(reader, header, config)? else {
+            return Ok(Vec::new());
+        };
+        let offset = header.section_header_offset.map_or(0, |offset| offset.0);
+        let entry_size = header.section_header_entry_size.0 as u64;
+        let length = reader.seek(SeekFrom::End(0))?;
+        let fits = |count: u64| Self::check_table_bounds(offset, count, entry_size, length).is_ok();
+
+        let count = match header.section_header_entry_count.0 {
+            0 => first.size(),
+            count => count as u64,
+        };
+
+        let string_table_index = match header.section_name_string_table_index.0 {
+            ElfSectionIndex::XINDEX => first.link().0 as u64,
+            index => index as u64,
+        };
+
+        if count != 0 && string_table_index >= count && fits(count) {
+            let err = Error::InvalidShStrNdx {
+                index: string_table_index,
+                count,
+            };
+
+            if !config.ignores(&err) {
+                return Err(err);
+            }
+        }
+
+        // In partial mode, keep the entries which fit before the end of the file
+        let count = match Self::check_table_bounds(offset, count, entry_size, length) {
+            Ok(()) => count,
+            Err(_) if config.partial => (length - offset) / entry_size,
+            Err(err) if config.ignores(&err) => 0,
+            Err(err) => return Err(err),
+        };
+
+        if count == 0 {
+            return Ok(Vec::new());
+        }
+
+        let mut headers = vec![first];
+
+        for index in 1..count {
+            reader.seek(SeekFrom::Start(
+                offset.saturating_add(index.saturating_mul(entry_size)),
+            ))?;
+            headers.push(Self::from_reader_with(reader, config)?);
+        }
+
+        if let Some(string_table) = usize::try_from(string_table_index)
+            .ok()
+            .filter(|index| *index != 0)
+            .and_then(|index| headers.get(index))
+        {
+            if let Ok(strings) = string_table.data_from_reader(reader) {
+                headers.iter_mut().for_each(|header| {
+                    if let Some(name) = string_at(&strings, header.name_index() as usize) {
+                        header.set_name(name);
+                    }
+                });
+            }
+        }
+
+        Ok(headers)
+    }
+
+    /// Read the contents of the section described by this header. Sections of type
+    /// `SHT_NOBITS` occupy no space in the file and have no contents.
+    pub(crate) fn data_from_reader<R>(&self, reader: &mut R) -> Result<Vec<u8>, Error>
+    where
+        R: Read + Seek,
+    {
+        if matches!(self.r#type(), ElfSectionHeaderType::NoBits) {
+            return Ok(Vec::new());
+        }
+
+        reader.seek(SeekFrom::Start(self.offset()))?;
+
+        let mut data = Vec::new();
+
+        reader.take(self.size()).read_to_end(&mut data)?;
+
+        if data.len() as u64 != self.size() {
+            return Err(Error::Io {
+                kind: ErrorKind::UnexpectedEof,
+                errno: None,
+            });
+        }
+
+        Ok(data)
+    }
+}
+
+impl<R, const EC: u8, const ED: u8> FromReader<R> for ElfSectionHeader<EC, ED>
+where
+    R: Read + Seek,
+{
+    type Error = Error;
+
+    fn from_reader_with(reader: &mut R, config: &mut Config) -> Result<Self, Self::Error> {
+        Ok(
+            match ElfClass::from_u8(EC).ok_or(Error::InvalidClass { class: ElfByte(EC) })? {
+                ElfClass::None => return Err(Error::InvalidClass { class: ElfByte(EC) }),
+                ElfClass::Elf32 => {
+                    ElfSectionHeader::Elf32(Elf32SectionHeader::from_reader_with(reader, config)?)
+                }
+                ElfClass::Elf64 => {
+                    ElfSectionHeader::Elf64(Elf64SectionHeader::from_reader_with(reader, config)?)
+                }
+            },
+        )
+    }
+}
+
+impl<W, const EC: u8, const ED: u8> ToWriter<W> for ElfSectionHeader<EC, ED>
+where
+    W: Write,
+{
+    type Error = Error;
+
+    fn to_writer(&self, writer: &mut W) -> Result<(), Self::Error> {
+        match self {
+            ElfSectionHeader::Elf32(header) => header.to_writer(writer),
+            ElfSectionHeader::Elf64(header) => header.to_writer(writer),
+        }
+    }
+}
+
+impl<const EC: u8, const ED: u8> HasWrittenSize for ElfSectionHeader<EC, ED> {
+    const SIZE: usize = match EC {
+        ElfClass::ELF_CLASS_64 => Elf64SectionHeader::<ED>::SIZE,
+        _ => Elf32SectionHeader::<ED>::SIZE,
+    };
+}
+
+/// The `readelf` flag letters for a set of section flags, such as `WA` for a writable,
+/// allocated section, with bits in ascending order. Flags whose meaning depends on the
+/// machine or OS ABI are not known without an object, so any OS-specific flags are shown
+/// as one `o` and any processor-specific flags as one `p`, except that `SHF_EXCLUDE` is
+/// shown as `E` when it is the only processor-specific flag.
+pub fn section_flags_string(flags: ElfSectionHeaderFlags) -> String {
+    flag_letters::<ELF_CLASS_DEFAULT, ELF_DATA_ENCODING_DEFAULT>(flags, None, None)
+}
+
+/// The `readelf` flag letters for a set of section flags, with bits in ascending order.
+/// Like `readelf`, unrecognized OS-specific and processor-specific flags are each shown
+/// as a single letter.
+fn flag_letters<const EC: u8, const ED: u8>(
+    flags: ElfSectionHeaderFlags,
+    machine: Option<ElfMachine<EC, ED>>,
+    os_abi: Option<ElfOSABI>,
+) -> String {
+    let mut remaining = flags.bits();
+    let mut letters = String::new();
+
+    for bit in (0..u64::BITS).map(|bit| 1u64 << bit) {
+        if remaining & bit == 0 {
+            continue;
+        }
+
+        letters.push(match ElfSectionHeaderFlags::from_bits_retain(bit) {
+            ElfSectionHeaderFlags::WRITE => 'W',
+            ElfSectionHeaderFlags::ALLOCATED => 'A',
+            ElfSectionHeaderFlags::EXECUTABLE_INSTRUCTIONS => 'X',
+            ElfSectionHeaderFlags::MERGE => 'M',
+            ElfSectionHeaderFlags::STRINGS => 'S',
+            ElfSectionHeaderFlags::INFO_LINK => 'I',
+            ElfSectionHeaderFlags::LINK_ORDER => 'L',
+            ElfSectionHeaderFlags::OS_NONCONFORMING => 'O',
+            ElfSectionHeaderFlags::GROUP => 'G',
+            ElfSectionHeaderFlags::THREAD_LOCAL_STORAGE => 'T',
+            ElfSectionHeaderFlags::COMPRESSED => 'C',
+            _ => match (bit, machine, os_abi) {
+                // SHF_GNU_RETAIN
+                (0x200000, _, Some(ElfOSABI::GnuLinux | ElfOSABI::FreeBSD)) => 'R',
+                // SHF_GNU_MBIND
+                (
+                    0x1000000,
+                    _,
+                    Some(ElfOSABI::GnuLinux | ElfOSABI::FreeBSD | ElfOSABI::NoneSystemV),
+                ) => 'D',
+                // SHF_X86_64_LARGE
+                (0x10000000, Some(ElfMachine::X86_64), _) => 'l',
+                // SHF_PPC_VLE
+                (0x10000000, Some(ElfMachine::PPC), _) => 'v',
+                // SHF_ARM_PURECODE
+                (0x20000000, Some(ElfMachine::ARM), _) => 'y',
+                // SHF_EXCLUDE
+                (0x80000000, _, _) => 'E',
+                _ if ElfSectionHeaderFlags::MASK_OPERATING_SYSTEM.bits() & bit != 0 => {
+                    remaining &= !ElfSectionHeaderFlags::MASK_OPERATING_SYSTEM.bits();
+                    'o'
+                }
+                _ if ElfSectionHeaderFlags::MASK_PROCESSOR.bits() & bit != 0 => {
+                    remaining &= !ElfSectionHeaderFlags::MASK_PROCESSOR.bits();
+                    'p'
+                }
+                _ => 'x',
+            },
+        });
+    }
+
+    letters
+}
+
+/// Read the null-terminated string at `offset` in a string table
+pub(crate) fn string_at(strings: &[u8], offset: usize) -> Option<String> {
+    let string = strings.get(offset..)?.split(|b| *b == 0).next()?;
+    Some(String::from_utf8_lossy(string).into_owned())
+}
+
+impl<const EC: u8, const ED: u8> Elf<EC, ED> {
+    /// The real section count, section name string table index, and program header
+    /// count held by the first section header for objects whose ELF header holds escape
+    /// values in their place. The first section header is still the null section, and
+    /// is included in the section header table as such.
+    pub fn extended_header_info(&self) -> ExtendedHeaderInfo {
+        let Some(first) = self.section_headers.first() else {
+            return ExtendedHeaderInfo::default();
+        };
+
+        ExtendedHeaderInfo {
+            section_count: (self.header.section_header_entry_count.0 == 0).then(|| first.size()),
+            section_name_string_table_index: (self.header.section_name_string_table_index.0
+                == ElfSectionIndex::XINDEX)
+                .then(|| first.link()),
+            program_header_count: (self.header.program_header_entry_count.0
+                == ElfProgramHeader::<EC, ED>::EXTENDED_COUNT)
+                .then(|| first.info()),
+        }
+    }
+
+    /// Each section in the section header table with its index
+    pub(crate) fn indexed_sections(
+        &self,
+    ) -> impl Iterator<Item = (SectionIndex, &ElfSectionHeader<EC, ED>)> {
+        self.section_headers
+            .iter()
+            .enumerate()
+            .map(|(index, section)| (SectionIndex(index as u32), section))
+    }
+
+    /// The index of the first section in the section header table for which `predicate`
+    /// holds
+    pub(crate) fn section_position(
+        &self,
+        predicate: impl Fn(&ElfSectionHeader<EC, ED>) -> bool,
+    ) -> Option<SectionIndex> {
+        self.indexed_sections()
+            .find(|(_, section)| predicate(section))
+            .map(|(index, _)| index)
+    }
+
+    /// The section at `index` in the section header table. Indices are stable, unlike
+    /// names, which may be empty or shared by several sections.
+    pub fn section(&self, index: SectionIndex) -> Option<&ElfSectionHeader<EC, ED>> {
+        self.section_headers.get(index.get())
+    }
+
+    /// The index and header of the first section in the section header table named
+    /// `name`. When several sections share a name, as is common for `.text` and `.data`
+    /// in relocatable objects compiled with `-ffunction-sections`, the one with the
+    /// lowest index is returned. Use `sections_by_name` to find all of them.
+    pub fn section_by_name(&self, name: &str) -> Option<(SectionIndex, &ElfSectionHeader<EC, ED>)> {
+        self.indexed_sections()
+            .find(|(_, section)| section.name() == name)
+    }
+
+    /// The indices and headers of every section in the section header table named
+    /// `name`, in the order of the table. The null section at index 0 and any other
+    /// sections without a name are named by the empty string.
+    pub fn sections_by_name(&self, name: &str) -> Vec<(SectionIndex, &ElfSectionHeader<EC, ED>)> {
+        self.indexed_sections()
+            .filter(|(_, section)| section.name() == name)
+            .collect()
+    }
+
+    /// The sections whose contents differ between this object, read from `reader`, and
+    /// `other`, read from `other_reader`, such as two builds of the same program.
+    /// Sections are matched by name, with the n-th section of a name in one object
+    /// matched with the n-th section of that name in the other, and sections without a
+    /// name are not compared. Removed and modified sections are reported in the order
+    /// of this object's section header table, followed by added sections in the order
+    /// of `other`'s. `SHT_NOBITS` sections have no contents, so they never differ.
+    pub fn content_diff<R, S>(
+        &self,
+        reader: &mut R,
+        other: &Self,
+        other_reader: &mut S,
+    ) -> Result<Vec<SectionDiff>, Error>
+    where
+        R: Read + Seek,
+        S: Read + Seek,
+    {
+        // Each named section with the number of sections of the same name before it
+        fn named<const EC: u8, const ED: u8>(
+            elf: &Elf<EC, ED>,
+        ) -> Vec<(&str, usize, SectionIndex)> {
+            let mut named = Vec::<(&str, usize, SectionIndex)>::new();
+            for (index, header) in elf.indexed_sections() {
+                let name = header.name();
+                if !name.is_empty() {
+                    let occurrence = named.iter().filter(|(seen, _, _)| *seen == name).count();
+                    named.push((name, occurrence, index));
+                }
+            }
+            named
+        }
+
+        let ours = named(self);
+        let theirs = named(other);
+        let mut diffs = Vec::new();
+
+        for (name, occurrence, index) in &ours {
+            let Some((_, _, other_index)) =
+                theirs.iter().find(|(other_name, other_occurrence, _)| {
+                    other_name == name && other_occurrence == occurrence
+                })
+            else {
+                diffs.push(SectionDiff::Removed {
+                    name: name.to_string(),
+                });
+                continue;
+            };
+
+            let data = self.section_data(reader, *index)?;
+            let other_data = other.section_data(other_reader, *other_index)?;
+
+            if data != other_data {
+                let offset = data
+                    .iter()
+                    .zip(&other_data)
+                    .position(|(a, b)| a != b)
+                    .unwrap_or(data.len().min(other_data.len()));
+                diffs.push(SectionDiff::Modified {
+                    name: name.to_string(),
+                    offset: offset as u64,
+                });
+            }
+        }
+
+        diffs.extend(
+            theirs
+                .iter()
+                .filter(|(name, occurrence, _)| {
+                    !ours.iter().any(|(our_name, our_occurrence, _)| {
+                        our_name == name && our_occurrence == occurrence
+                    })
+                })
+                .map(|(name, _, _)| SectionDiff::Added {
+                    name: name.to_string(),
+                }),
+        );
+
+        Ok(diffs)
+    }
+
+    /// Read the contents of the section at `index` in the section header table
+    pub fn section_data<R>(&self, reader: &mut R, index: SectionIndex) -> Result<Vec<u8>, Error>
+    where
+        R: Read + Seek,
+    {
+        self.section(index)
+            .ok_or(Error::InvalidSectionIndex { index: index.get() })?
+            .data_from_reader(reader)
+    }
+
+    /// The contents of the section at `index` in the section header table, from `file`,
+    /// which holds the whole object, such as a memory-mapped file. Compressed sections
+    /// are decompressed, both `SHF_COMPRESSED` sections and `.zdebug` sections
+    /// compressed with the older GNU scheme, in which case the contents are owned.
+    /// Otherwise, they are borrowed from `file` without a copy. Decompressing zlib
+    /// sections requires the `compression` feature, and other algorithms are an
+    /// `Error::UnsupportedCompression`.
+    pub fn section_data_cow<'a>(
+        &self,
+        file: &'a [u8],
+        index: SectionIndex,
+    ) -> Result<Cow<'a, [u8]>, Error> {
+        let header = self
+            .section(index)
+            .ok_or(Error::InvalidSectionIndex { index: index.get() })?;
+
+        if matches!(header.r#type(), ElfSectionHeaderType::NoBits) {
+            return Ok(Cow::Borrowed(&[]));
+        }
+
+        let data = header
+            .offset()
+            .checked_add(header.size())
+            .and_then(|end| {
+                file.get(usize::try_from(header.offset()).ok()?..usize::try_from(end).ok()?)
+            })
+            .ok_or(Error::Io {
+                kind: ErrorKind::UnexpectedEof,
+                errno: None,
+            })?;
+
+        if header.flags().contains(ElfSectionHeaderFlags::COMPRESSED) {
+            let compression = ElfCompressionHeader::<EC, ED>::from_reader(&mut Cursor::new(data))?;
+            let compressed = data
+                .get(ElfCompressionHeader::<EC, ED>::SIZE..)
+                .unwrap_or_default();
+
+            match compression.r#type() {
+                ElfCompressionHeaderType::ZLib => {
+                    inflate(index.get(), compressed, compression.size()).map(Cow::Owned)
+                }
+                r#type => Err(Error::UnsupportedCompression {
+                    index: index.get(),
+                    compression: r#type as u32,
+                }),
+            }
+        } else if let Some(compressed) = data
+            .strip_prefix(ZDEBUG_MAGIC)
+            .filter(|_| header.name().starts_with(".zdebug"))
+        {
+            let (size, compressed) = compressed.split_at_checked(8).ok_or(Error::Io {
+                kind: ErrorKind::UnexpectedEof,
+                errno: None,
+            })?;
+            let size = size.iter().fold(0, |size, byte| (size << 8) | *byte as u64);
+            inflate(index.get(), compressed, size).map(Cow::Owned)
+        } else {
+            Ok(Cow::Borrowed(data))
+        }
+    }
+
+    /// How the section at `index` in the section header table is compressed, from its
+    /// compression header if it is an `SHF_COMPRESSED` section, or from the GNU header
+    /// if it is a `.zdebug` section compressed with the older GNU scheme. Only the
+    /// header is read, so the algorithm can be inspected before deciding to decompress
+    /// the section. `None` if the section is not compressed.
+    pub fn section_compression<R>(
+        &self,
+        reader: &mut R,
+        index: SectionIndex,
+    ) -> Result<Option<CompressionInfo>, Error>
+    where
+        R: Read + Seek,
+    {
+        self.section_compression_with(reader, index, &Config::default())
+    }
+
+    /// How the section at `index` in the section header table is compressed, recovering
+    /// from the errors ignored by `config`
+    pub fn section_compression_with<R>(
+        &self,
+        reader: &mut R,
+        index: SectionIndex,
+        config: &Config,
+    ) -> Result<Option<CompressionInfo>, Error>
+    where
+        R: Read + Seek,
+    {
+        let header = self
+            .section(index)
+            .ok_or(Error::InvalidSectionIndex { index: index.get() })?;
+
+        if matches!(header.r#type(), ElfSectionHeaderType::NoBits) {
+            return Ok(None);
+        }
+
+        reader.seek(SeekFrom::Start(header.offset()))?;
+
+        if header.flags().contains(ElfSectionHeaderFlags::COMPRESSED) {
+            let mut config = config.clone();
+            let compression =
+                ElfCompressionHeader::<EC, ED>::from_reader_with(reader, &mut config)?;
+
+            Ok(Some(CompressionInfo {
+                r#type: compression.r#type() as u32,
+                size: compression.size(),
+                address_align: compression.address_align(),
+                gnu: false,
+            }))
+        } else if header.name().starts_with(".zdebug") {
+            // The magic is followed by the uncompressed size as a 64-bit big endian
+            // integer
+            let mut data = Vec::new();
+            reader
+                .take(header.size().min(ZDEBUG_MAGIC.len() as u64 + 8))
+                .read_to_end(&mut data)?;
+
+            let Some(size) = data
+                .strip_prefix(ZDEBUG_MAGIC)
+                .filter(|size| size.len() == 8)
+            else {
+                return Ok(None);
+            };
+
+            Ok(Some(CompressionInfo {
+                r#type: ELFCOMPRESS_ZLIB,
+                size: size.iter().fold(0, |size, byte| (size << 8) | *byte as u64),
+                address_align: header.address_align(),
+                gnu: true,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Read each string in the `SHT_STRTAB` section at `index` in the section header
+    /// table, with its offset in the section. The leading empty string at offset 0 is
+    /// included, but other empty strings, such as padding, are skipped. A final string
+    /// which is missing its null terminator is included.
+    pub fn strings_in<R>(
+        &self,
+        reader: &mut R,
+        index: SectionIndex,
+    ) -> Result<Vec<(u32, String)>, Error>
+    where
+        R: Read + Seek,
+    {
+        let header = self
+            .section(index)
+            .ok_or(Error::InvalidSectionIndex { index: index.get() })?;
+
+        if !matches!(header.r#type(), ElfSectionHeaderType::StringTable) {
+            return Err(Error::InvalidStringTableSection { index: index.get() });
+        }
+
+        let data = self.section_data(reader, index)?;
+        let mut offset = 0u32;
+
+        Ok(data
+            .strip_suffix(&[0])
+            .unwrap_or(&data)
+            .split(|b| *b == 0)
+            .filter_map(|string| {
+                let start = offset;
+                offset = offset.saturating_add(string.len() as u32).saturating_add(1);
+                (start == 0 || !string.is_empty())
+                    .then(|| (start, String::from_utf8_lossy(string).into_owned()))
+            })
+            .collect())
+    }
+
+    /// The indices of the sections in the section header table whose contents in the
+    /// file intersect the byte range `range` of the file. `SHT_NOBITS` sections and
+    /// empty sections occupy no bytes of the file, so they never intersect a range.
+    pub fn sections_overlapping(&self, range: Range<u64>) -> Vec<SectionIndex> {
+        self.indexed_sections()
+            .filter(|(_, section)| {
+                !matches!(section.r#type(), ElfSectionHeaderType::NoBits) && section.size() != 0
+            })
+            .filter(|(_, section)| {
+                let end = section.offset().saturating_add(section.size());
+                section.offset() < range.end && range.start < end
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// The indices of the sections in the section header table whose contents in memory
+    /// intersect the virtual address range `range`. Only allocated sections occupy
+    /// memory, and thread-local `SHT_NOBITS` sections are excluded, because they occupy
+    /// no memory outside of the thread-local storage template. Empty sections occupy no
+    /// memory, so they never intersect a range.
+    pub fn sections_overlapping_address(&self, range: Range<u64>) -> Vec<SectionIndex> {
+        self.indexed_sections()
+            .filter(|(_, section)| {
+                let flags = section.flags();
+                section.size() != 0
+                    && flags.contains(ElfSectionHeaderFlags::ALLOCATED)
+                    && !(flags.contains(ElfSectionHeaderFlags::THREAD_LOCAL_STORAGE)
+                        && matches!(section.r#type(), ElfSectionHeaderType::NoBits))
+            })
+            .filter(|(_, section)| {
+                let end = section.address().saturating_add(section.size());
+                section.address() < range.end && range.start < end
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// The region of the first section named `name`, or of the first `PT_LOAD` segment
+    /// whose flags satisfy `segment` when there is no such section
+    fn conventional_region(
+        &self,
+        name: &str,
+        segment: impl Fn(&ElfProgramHeader<EC, ED>) -> Option<ElfRegion>,
+    ) -> Option<ElfRegion> {
+        match self.section_by_name(name) {
+            Some((_, section)) => {
+                let file_size = match section.r#type() {
+                    ElfSectionHeaderType::NoBits => 0,
+                    _ => section.size(),
+                };
+                Some(ElfRegion {
+                    file: section.offset()..section.offset().saturating_add(file_size),
+                    memory: section.address()..section.address().saturating_add(section.size()),
+                })
+            }
+            None => self
+                .program_headers
+                .iter()
+                .filter(|header| matches!(header.r#type(), ElfProgramHeaderType::Load))
+                .find_map(segment),
+        }
+    }
+
+    /// The region of the code, from the `.text` section, or from the first executable
+    /// `PT_LOAD` segment if there is no `.text` section, as is the case for stripped
+    /// objects without a section header table.
+    ///
+    /// This is a heuristic based on the conventional section name and the segment
+    /// permissions, not a guarantee: code may be spread across other sections, such as
+    /// `.init` and `.plt`, and an executable segment usually holds read-only data as
+    /// well.
+    pub fn text_range(&self) -> Option<ElfRegion> {
+        self.conventional_region(".text", |header| {
+            header
+                .flags()
+                .contains(ElfProgramHeaderFlags::EXECUTE)
+                .then(|| ElfRegion {
+                    file: header.offset()..header.offset().saturating_add(header.file_size()),
+                    memory: header.virtual_address()
+                        ..header
+                            .virtual_address()
+                            .saturating_add(header.memory_size()),
+                })
+        })
+    }
+
+    /// The region of the initialized writable data, from the `.data` section, or from
+    /// the file contents of the first writable `PT_LOAD` segment if there is no `.data`
+    /// section.
+    ///
+    /// This is a heuristic based on the conventional section name and the segment
+    /// permissions, not a guarantee: writable data may be spread across other
+    /// sections, such as `.got` and `.init_array`.
+    pub fn data_range(&self) -> Option<ElfRegion> {
+        self.conventional_region(".data", |header| {
+            header
+                .flags()
+                .contains(ElfProgramHeaderFlags::WRITE)
+                .then(|| ElfRegion {
+                    file: header.offset()..header.offset().saturating_add(header.file_size()),
+                    memory: header.virtual_address()
+                        ..header.virtual_address().saturating_add(header.file_size()),
+                })
+        })
+    }
+
+    /// The region of the zero-initialized data, from the `.bss` section, or from the
+    /// part of the first writable `PT_LOAD` segment which is beyond its file contents if
+    /// there is no `.bss` section. The file range is empty, and starts where the region
+    /// would be in the file.
+    ///
+    /// This is a heuristic based on the conventional section name and the segment
+    /// permissions, not a guarantee: the zero-filled part of a segment may hold other
+    /// sections, such as `.tbss` or `COMMON` symbols placed by the linker.
+    pub fn bss_range(&self) -> Option<ElfRegion> {
+        self.conventional_region(".bss", |header| {
+            (header.flags().contains(ElfProgramHeaderFlags::WRITE)
+                && header.memory_size() > header.file_size())
+            .then(|| {
+                let file_end = header.offset().saturating_add(header.file_size());
+                ElfRegion {
+                    file: file_end..file_end,
+                    memory: header.virtual_address().saturating_add(header.file_size())
+                        ..header
+                            .virtual_address()
+                            .saturating_add(header.memory_size()),
+                }
+            })
+        })
+    }
+
+    /// The indices of the sections holding the initialized part of the thread-local
+    /// storage template, such as `.tdata`, which is copied from the file for each thread
+    pub fn tls_data_sections(&self) -> Vec<SectionIndex> {
+        self.tls_sections(false)
+    }
+
+    /// The indices of the sections holding the zero-initialized part of the
+    /// thread-local storage template, such as `.tbss`, which occupies memory in each
+    /// thread but no space in the file
+    pub fn tls_bss_sections(&self) -> Vec<SectionIndex> {
+        self.tls_sections(true)
+    }
+
+    /// The indices of the `SHF_ALLOC` sections, which occupy memory at runtime, in load
+    /// order, sorted by `sh_addr`. Allocated sections at address 0 have not been placed
+    /// in memory, as in relocatable objects, so they are excluded. Sections at the same
+    /// address, such as an empty section followed by the one it precedes, are ordered
+    /// by their index in the section header table.
+    pub fn allocated_sections(&self) -> Vec<SectionIndex> {
+        let mut sections = self
+            .indexed_sections()
+            .filter(|(_, header)| {
+                header.flags().contains(ElfSectionHeaderFlags::ALLOCATED) && header.address() != 0
+            })
+            .map(|(index, header)| (header.address(), index))
+            .collect::<Vec<_>>();
+
+        sections.sort_unstable();
+
+        sections.into_iter().map(|(_, index)| index).collect()
+    }
+
+    /// The indices of the `SHF_TLS` sections which are or are not `SHT_NOBITS`
+    fn tls_sections(&self, no_bits: bool) -> Vec<SectionIndex> {
+        self.indexed_sections()
+            .filter(|(_, header)| {
+                header
+                    .flags()
+                    .contains(ElfSectionHeaderFlags::THREAD_LOCAL_STORAGE)
+                    && matches!(header.r#type(), ElfSectionHeaderType::NoBits) == no_bits
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Format the section header table in the same layout as `readelf -S --wide`
+    pub fn format_section_table(&self) -> String {
+        let address_width = match ElfClass::from_u8(EC) {
+            Some(ElfClass::Elf32) => 8,
+            _ => 16,
+        };
+        if self.section_headers.is_empty() {
+            return "There are no sections in this file.\n".to_string();
+        }
+
+        let mut table = format!(
+            "There are {} section headers, starting at offset {:#x}:\n\nSection Headers:\n",
+            self.section_headers.len(),
+            self.header
+                .section_header_offset
+                .map_or(0, |offset| offset.0),
+        );
+
+        table += &format!(
+            "  [Nr] Name              Type            {:<address_width$} Off    Size   ES Flg Lk Inf Al\n",
+            if address_width == 8 { "Addr" } else { "Address" },
+        );
+
+        for (index, header) in self.section_headers.iter().enumerate() {
+            table += &format!(
+                "  [{:>2}] {:<17} {:<15} {:0address_width$x} {:06x} {:06x} {:02x} {:>3} {:>2} {:>3} {:>2}\n",
+                index,
+                header
+                    .name()
+                    .chars()
+                    .map(|c| match c {
+                        // Control characters are shown in caret notation
+                        '\0'..='\x1f' => format!("^{}", char::from(c as u8 + 0x40)),
+                        c => c.to_string(),
+                    })
+                    .collect::<String>(),
+                header.r#type().to_string(),
+                header.address(),
+                header.offset(),
+                header.size(),
+                header.entry_size(),
+                flag_letters(
+                    header.flags(),
+                    Some(self.header.machine),
+                    Some(self.header.identifier.os_abi),
+                ),
+                header.link(),
+                header.info(),
+                header.address_align(),
+            );
+        }
+
+        table += "Key to Flags:\n";
+        table += "  W (write), A (alloc), X (execute), M (merge), S (strings), I (info),\n";
+        table += "  L (link order), O (extra OS processing required), G (group), T (TLS),\n";
+        table += "  C (compressed), x (unknown), o (OS specific), E (exclude),\n";
+        table += "  ";
+        if matches!(
+            self.header.identifier.os_abi,
+            ElfOSABI::GnuLinux | ElfOSABI::FreeBSD
+        ) {
+            table += "R (retain), ";
+        }
+        if matches!(
+            self.header.identifier.os_abi,
+            ElfOSABI::GnuLinux | ElfOSABI::FreeBSD | ElfOSABI::NoneSystemV
+        ) {
+            table += "D (mbind), ";
+        }
+        table += match self.header.machine {
+            ElfMachine::X86_64 => "l (large), ",
+            ElfMachine::ARM => "y (purecode), ",
+            ElfMachine::PPC => "v (VLE), ",
+            _ => "",
+        };
+        table += "p (processor specific)\n";
+
+        table
+    }
+}
+
+#[derive(Debug, Clone)]
+/// The section header table of an object, read on its own from the location described
+/// by an ELF header which was decoded separately
+pub struct SectionHeaderTable<const EC: u8, const ED: u8> {
+    /// The entries of the table, in order
+    pub headers: Vec<ElfSectionHeader<EC, ED>>,
+}
+
+impl<const EC: u8, const ED: u8> SectionHeaderTable<EC, ED> {
+    /// Read the section header table described by `header`, without reading the ELF
+    /// header again
+    pub fn from_reader<R>(reader: &mut R, header: &ElfHeader<EC, ED>) -> Result<Self, Error>
+    where
+        R: Read + Seek,
+    {
+        Self::from_reader_with(reader, header, &mut Config::default())
+    }
+
+    /// Read the section header table described by `header` with `config`, without
+    /// reading the ELF header again. The machine type and OS ABI are taken from `header`
+    /// and the table is read as they are when decoding an `Elf`, so a table which does
+    /// not fit in the file is empty, and section names are resolved from the section
+    /// header string table.
+    pub fn from_reader_with<R>(
+        reader: &mut R,
+        header: &ElfHeader<EC, ED>,
+        config: &mut Config,
+    ) -> Result<Self, Error>
+    where
+        R: Read + Seek,
+    {
+        header.configure(config);
+
+        Ok(Self {
+            headers: ElfSectionHeader::table_from_reader_with(reader, header, config)?,
+        })
+    }
+}
+
+impl<const EC: u8, const ED: u8> From<SectionHeaderTable<EC, ED>>
+    for Vec<ElfSectionHeader<EC, ED>>
+{
+    fn from(value: SectionHeaderTable<EC, ED>) -> Self {
+        value.headers
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// A region of the object, as the byte range it occupies in the file and the virtual
+/// address range it occupies in memory. Regions which are zero-filled at load time, like
+/// `.bss`, have an empty file range.
+pub struct ElfRegion {
+    /// The range of offsets of the region in the file
+    pub file: Range<u64>,
+    /// The range of virtual addresses of the region in memory
+    pub memory: Range<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// A difference in the contents of a section between two objects, as reported by
+/// `Elf::content_diff`
+pub enum SectionDiff {
+    /// The section is only in the second object
+    Added {
+        /// The name of the section
+        name: String,
+    },
+    /// The section is only in the first object
+    Removed {
+        /// The name of the section
+        name: String,
+    },
+    /// The section is in both objects, but its contents differ
+    Modified {
+        /// The name of the section
+        name: String,
+        /// The offset in the section of the first byte which differs. If the contents
+        /// of one section are a prefix of the other's, this is the size of the smaller
+        /// section.
+        offset: u64,
+    },
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// The index of a section in the section header table. Section indices are a distinct
+/// type from segment indices and other table indices, so that one cannot be passed
+/// where the other is expected.
+pub struct SectionIndex(pub u32);
+
+impl SectionIndex {
+    /// The index as a `usize`, for indexing the section header table
+    pub fn get(self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl From<u32> for SectionIndex {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl core::fmt::Display for SectionIndex {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+/// The values held by the null section header at index 0 of the section header table in
+/// place of ELF header fields which are too small to hold them. Each value is `None`
+/// unless the ELF header field holds its escape value and the section header table has
+/// a first entry to hold the real value.
+pub struct ExtendedHeaderInfo {
+    /// The number of entries in the section header table, from the `sh_size` field,
+    /// when `e_shnum` is 0 because the count is `SHN_LORESERVE` (0xff00) or greater
+    pub section_count: Option<u64>,
+    /// The index of the section name string table, from the `sh_link` field, when
+    /// `e_shstrndx` is `SHN_XINDEX` (0xffff)
+    pub section_name_string_table_index: Option<SectionIndex>,
+    /// The number of entries in the program header table, from the `sh_info` field,
+    /// when `e_phnum` is `PN_XNUM` (0xffff)
+    pub program_header_count: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// A section header table index, as referenced by a symbol's `st_shndx` field. Some
+/// index values are reserved and must not be used to index the section header table.
+pub enum ElfSectionIndex {
+    /// Marks an undefined, missing, irrelevant, or otherwise meaningless section
+    /// reference. A symbol "defined" relative to this index is an undefined symbol.
+    Undefined,
+    /// Specifies absolute values for the corresponding reference. Symbols defined
+    /// relative to this index have absolute values and are not affected by relocation.
+    Absolute,
+    /// Symbols defined relative to this index are common symbols, such as FORTRAN
+    /// COMMON or unallocated C external variables.
+    Common,
+    /// An escape value indicating that the actual section header index is too large to
+    /// fit in the containing field and is to be found in another location (specific to
+    /// the structure where it appears).
+    Xindex,
+    /// A value in the reserved range which is not one of the special indices above.
+    /// This includes the processor-specific and operating system-specific ranges.
+    Reserved(u16),
+    /// An ordinary index into the section header table. Indices resolved through an
+    /// `SHT_SYMTAB_SHNDX` section may exceed the range of the 16-bit `st_shndx` field.
+    Index(u32),
+}
+
+impl ElfSectionIndex {
+    /// An undefined, missing, irrelevant, or otherwise meaningless section reference
+    pub const UNDEFINED: u16 = 0;
+    /// The lower bound of the range of reserved indexes
+    pub const LOW_RESERVE: u16 = 0xff00;
+    /// Low bound for processor-specific semantics
+    pub const LOW_PROCESSOR_SPECIFIC: u16 = 0xff00;
+    /// High bound for processor-specific semantics
+    pub const HIGH_PROCESSOR_SPECIFIC: u16 = 0xff1f;
+    /// Low bound for operating system-specific semantics
+    pub const LOW_OPERATING_SYSTEM: u16 = 0xff20;
+    /// High bound for operating system-specific semantics
+    pub const HIGH_OPERATING_SYSTEM: u16 = 0xff3f;
+    /// Absolute values for the corresponding reference
+    pub const ABSOLUTE: u16 = 0xfff1;
+    /// Common symbols
+    pub const COMMON: u16 = 0xfff2;
+    /// Escape value indicating the actual index is stored elsewhere
+    pub const XINDEX: u16 = 0xffff;
+    /// The upper bound of the range of reserved indexes
+    pub const HIGH_RESERVE: u16 = 0xffff;
+
+    /// Returns the index into the section header table, if this is an ordinary index
+    pub fn index(&self) -> Option<SectionIndex> {
+        match self {
+            Self::Index(index) => Some(SectionIndex(*index)),
+            _ => None,
+        }
+    }
+}
+
+impl From<u16> for ElfSectionIndex {
+    fn from(value: u16) -> Self {
+        match value {
+            Self::UNDEFINED => Self::Undefined,
+            Self::ABSOLUTE => Self::Absolute,
+            Self::COMMON => Self::Common,
+            Self::XINDEX => Self::Xindex,
+            Self::LOW_RESERVE..=Self::HIGH_RESERVE => Self::Reserved(value),
+            index => Self::Index(index as u32),
+        }
+    }
+}
+
+impl<const EC: u8, const ED: u8> From<ElfSection<EC, ED>> for ElfSectionIndex {
+    fn from(value: ElfSection<EC, ED>) -> Self {
+        Self::from(value.0)
+    }
+}
+
+impl From<ElfSectionIndex> for u32 {
+    fn from(value: ElfSectionIndex) -> Self {
+        match value {
+            ElfSectionIndex::Undefined => ElfSectionIndex::UNDEFINED as u32,
+            ElfSectionIndex::Absolute => ElfSectionIndex::ABSOLUTE as u32,
+            ElfSectionIndex::Common => ElfSectionIndex::COMMON as u32,
+            ElfSectionIndex::Xindex => ElfSectionIndex::XINDEX as u32,
+            ElfSectionIndex::Reserved(index) => index as u32,
+            ElfSectionIndex::Index(index) => index,
+        }
+    }
+}
+
+impl<const EC: u8, const ED: u8> TryFromWithConfig<ElfSection<EC, ED>> for ElfSectionIndex {
+    type Error = Error;
+
+    /// Convert a raw section index, rejecting reserved values which are neither one of the
+    /// special indices nor in the processor-specific or operating system-specific ranges.
+    /// Ignoring `InvalidReservedSectionIndex` yields `Reserved` for such values instead.
+    fn try_from_with(value: ElfSection<EC, ED>, config: &mut Config) -> Result<Self, Self::Error> {
+        match Self::from(value) {
+            Self::Reserved(index)
+                if !(Self::LOW_PROCESSOR_SPECIFIC..=Self::HIGH_OPERATING_SYSTEM)
+                    .contains(&index) =>
+            {
+                let err = Error::InvalidReservedSectionIndex { index };
+
+                if config.ignores(&err) {
+                    Ok(Self::Reserved(index))
+                } else {
+                    Err(err)
+                }
+            }
+            index => Ok(index),
+        }
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(all(test, feature = "std"))]
+mod test {
+    use std::collections::HashSet;
+
+    use crate::{
+        base::{ElfSection, ElfWord},
+        error::Error,
+        header::elf::identification::{ElfClass, ElfDataEncoding, ElfOSABI},
+        os::{
+            gnu::ElfSectionHeaderTypeGNU, llvm::ElfSectionHeaderTypeLLVM,
+            sun::ElfSectionHeaderTypeSUN,
+        },
+        Config, FromReader, TryFromWithConfig,
+    };
+
+    use super::{
+        section_flags_string, ElfSectionHeaderFlags, ElfSectionHeaderType, ElfSectionIndex,
+        SectionIndex,
+    };
+
+    fn section(
+        index: u16,
+    ) -> ElfSection<{ ElfClass::Elf32 as u8 }, { ElfDataEncoding::LittleEndian as u8 }> {
+        ElfSection(index)
+    }
+
+    #[test]
+    fn test_section_flags_string() {
+        assert_eq!(section_flags_string(ElfSectionHeaderFlags::empty()), "");
+        assert_eq!(
+            section_flags_string(ElfSectionHeaderFlags::ALLOCATED | ElfSectionHeaderFlags::WRITE),
+            "WA"
+        );
+        assert_eq!(
+            section_flags_string(
+                ElfSectionHeaderFlags::MERGE
+                    | ElfSectionHeaderFlags::STRINGS
+                    | ElfSectionHeaderFlags::ALLOCATED
+            ),
+            "AMS"
+        );
+        assert_eq!(
+            section_flags_string(
+                ElfSectionHeaderFlags::GROUP
+                    | ElfSectionHeaderFlags::THREAD_LOCAL_STORAGE
+                    | ElfSectionHeaderFlags::COMPRESSED
+                    | ElfSectionHeaderFlags::INFO_LINK
+                    | ElfSectionHeaderFlags::LINK_ORDER
+                    | ElfSectionHeaderFlags::OS_NONCONFORMING
+                    | ElfSectionHeaderFlags::EXECUTABLE_INSTRUCTIONS
+            ),
+            "XILOGTC"
+        );
+        // OS-specific and processor-specific flags are one letter each
+        assert_eq!(
+            section_flags_string(ElfSectionHeaderFlags::from_bits_retain(
+                0x0030_0000 | 0x3000_0000 | 0x8000_0000
+            )),
+            "op"
+        );
+        assert_eq!(
+            section_flags_string(
+                ElfSectionHeaderFlags::ALLOCATED
+                    | ElfSectionHeaderFlags::from_bits_retain(0x8000_0000)
+            ),
+            "AE"
+        );
+    }
+
+    #[test]
+    fn test_section_index() {
+        assert_eq!(ElfSectionIndex::from(0), ElfSectionIndex::Undefined);
+        assert_eq!(ElfSectionIndex::from(1), ElfSectionIndex::Index(1));
+        assert_eq!(
+            ElfSectionIndex::from(0xfeff),
+            ElfSectionIndex::Index(0xfeff)
+        );
+        assert_eq!(
+            ElfSectionIndex::from(0xff00),
+            ElfSectionIndex::Reserved(0xff00)
+        );
+        assert_eq!(ElfSectionIndex::from(0xfff1), ElfSectionIndex::Absolute);
+        assert_eq!(ElfSectionIndex::from(0xfff2), ElfSectionIndex::Common);
+        assert_eq!(ElfSectionIndex::from(0xffff), ElfSectionIndex::Xindex);
+        assert_eq!(ElfSectionIndex::Absolute.index(), None);
+        assert_eq!(ElfSectionIndex::Index(3).index(), Some(SectionIndex(3)));
+
+        for value in [0, 1, 0xff00, 0xff3f, 0xfff1, 0xfff2, 0xfff5, 0xffff] {
+            assert_eq!(u32::from(ElfSectionIndex::from(value)), value as u32);
+        }
+    }
+
+    #[test]
+    fn test_section_index_reserved_with_config() {
+        let mut config = Config::default();
+        assert_eq!(
+            ElfSectionIndex::try_from_with(section(0xff02), &mut config).unwrap(),
+            ElfSectionIndex::Reserved(0xff02)
+        );
+        assert_eq!(
+            ElfSectionIndex::try_from_with(section(0xfff5), &mut config),
+            Err(Error::InvalidReservedSectionIndex { index: 0xfff5 })
+        );
+
+        let mut config = Config::builder()
+            .ignore(HashSet::from([Error::InvalidReservedSectionIndex {
+                index: 0xfff5,
+            }]))
+            .build();
+        assert_eq!(
+            ElfSectionIndex::try_from_with(section(0xfff5), &mut config).unwrap(),
+            ElfSectionIndex::Reserved(0xfff5)
+        );
+    }
+
+    #[test]
+    fn test_section_header_type_sun() {
+        let r#type = |value: u32, os_abi| {
+            ElfSectionHeaderType::<
+                { ElfClass::Elf32 as u8 },
+                { ElfDataEncoding::LittleEndian as u8 },
+            >::from_reader_with(
+                &mut std::io::Cursor::new(value.to_le_bytes()),
+                &mut Config::builder().ignore([]).os_abi(os_abi).build(),
+            )
+            .unwrap()
+        };
+
+        assert_eq!(
+            r#type(0x6ffffff3, ElfOSABI::Solaris),
+            ElfSectionHeaderType::Sun(ElfSectionHeaderTypeSUN::LocalDynamicSymbol)
+        );
+        assert_eq!(
+            r#type(0x6ffffffb, ElfOSABI::Solaris),
+            ElfSectionHeaderType::Sun(ElfSectionHeaderTypeSUN::Comdat)
+        );
+        // SHT_SUNW_cap and SHT_GNU_ATTRIBUTES share a value
+        assert_eq!(
+            r#type(0x6ffffff5, ElfOSABI::Solaris),
+            ElfSectionHeaderType::Sun(ElfSectionHeaderTypeSUN::Cap)
+        );
+        assert_eq!(
+            r#type(0x6ffffff5, ElfOSABI::GnuLinux),
+            ElfSectionHeaderType::Gnu(ElfSectionHeaderTypeGNU::Attributes)
+        );
+        // LLVM types are decoded whatever the OS ABI
+        assert_eq!(
+            r#type(0x6fff4c03, ElfOSABI::Solaris),
+            ElfSectionHeaderType::Llvm(ElfSectionHeaderTypeLLVM::Addrsig)
+        );
+        assert_eq!(
+            r#type(0x6fff4c03, ElfOSABI::FreeBSD),
+            ElfSectionHeaderType::Llvm(ElfSectionHeaderTypeLLVM::Addrsig)
+        );
+        assert_eq!(
+            r#type(0x6fff4c0a, ElfOSABI::OpenBSD),
+            ElfSectionHeaderType::Llvm(ElfSectionHeaderTypeLLVM::BbAddrMap)
+        );
+        assert_eq!(
+            r#type(0x6fff4c02, ElfOSABI::GnuLinux),
+            ElfSectionHeaderType::OtherOperatingSystemSpecific(ElfWord(0x6fff4c02))
+        );
+    }
 }
@@ -0,0 +1,455 @@
+//! I/O traits used to decode and encode ELF objects
+//!
+//! With the `std` feature, these are the traits and types from `std::io`. Without it,
+//! a minimal equivalent is provided which is implemented for in-memory buffers, so
+//! ELF objects can be decoded from a `&[u8]` with only `alloc`.
+
+#[cfg(feature = "std")]
+pub use std::io::{Cursor, Error, ErrorKind, Read, Result, Seek, SeekFrom, Take, Write};
+
+#[cfg(not(feature = "std"))]
+pub use self::no_std::*;
+
+#[derive(Debug, Clone)]
+/// A reader over the part of `inner` which begins at `base`, for an ELF object which is
+/// embedded partway through a larger stream, such as a firmware image. Positions are
+/// relative to `base`, so seeking to the start seeks to `base` in `inner`.
+pub struct OffsetReader<R> {
+    inner: R,
+    base: u64,
+}
+
+impl<R> OffsetReader<R> {
+    /// Read the part of `inner` which begins at `base`
+    pub fn new(inner: R, base: u64) -> Self {
+        Self { inner, base }
+    }
+
+    /// The offset in the underlying reader of the start of this reader
+    pub fn base(&self) -> u64 {
+        self.base
+    }
+
+    /// Unwrap the underlying reader
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R> Read for OffsetReader<R>
+where
+    R: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        self.inner.read(buf)
+    }
+}
+
+impl<R> Seek for OffsetReader<R>
+where
+    R: Seek,
+{
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        let pos = match pos {
+            SeekFrom::Start(position) => SeekFrom::Start(
+                position
+                    .checked_add(self.base)
+                    .ok_or(Error::from(ErrorKind::InvalidInput))?,
+            ),
+            pos => pos,
+        };
+
+        // A position before the base is outside of this reader
+        self.inner
+            .seek(pos)?
+            .checked_sub(self.base)
+            .ok_or(Error::from(ErrorKind::InvalidInput))
+    }
+}
+
+#[cfg(not(feature = "std"))]
+mod no_std {
+    use alloc::vec::Vec;
+    use core::fmt::Display;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    #[non_exhaustive]
+    /// The kind of an I/O error, a subset of `std::io::ErrorKind`
+    pub enum ErrorKind {
+        /// A parameter was incorrect, for example a seek to a negative position
+        InvalidInput,
+        /// The end of the buffer was reached before the operation completed
+        UnexpectedEof,
+        /// A write returned `Ok(0)`
+        WriteZero,
+        /// Any other I/O error
+        Other,
+    }
+
+    impl Display for ErrorKind {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.write_str(match self {
+                Self::InvalidInput => "invalid input parameter",
+                Self::UnexpectedEof => "unexpected end of file",
+                Self::WriteZero => "write zero",
+                Self::Other => "other error",
+            })
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    /// An I/O error, which only carries its kind
+    pub struct Error {
+        kind: ErrorKind,
+    }
+
+    impl Error {
+        /// The kind of this error
+        pub fn kind(&self) -> ErrorKind {
+            self.kind
+        }
+    }
+
+    impl From<ErrorKind> for Error {
+        fn from(kind: ErrorKind) -> Self {
+            Self { kind }
+        }
+    }
+
+    /// The result of an I/O operation
+    pub type Result<T> = core::result::Result<T, Error>;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    /// A position to seek to, relative to the start, end, or current position
+    pub enum SeekFrom {
+        /// An offset from the start of the stream
+        Start(u64),
+        /// An offset from the end of the stream
+        End(i64),
+        /// An offset from the current position in the stream
+        Current(i64),
+    }
+
+    /// A source of bytes
+    pub trait Read {
+        /// Read bytes into `buf`, returning how many bytes were read
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize>;
+
+        /// Read exactly enough bytes to fill `buf`
+        fn read_exact(&mut self, mut buf: &mut [u8]) -> Result<()> {
+            while !buf.is_empty() {
+                match self.read(buf)? {
+                    0 => return Err(ErrorKind::UnexpectedEof.into()),
+                    read => buf = buf.get_mut(read..).unwrap_or_default(),
+                }
+            }
+            Ok(())
+        }
+
+        /// Read all bytes until the end of the source into `buf`, returning how many
+        /// bytes were read
+        fn read_to_end(&mut self, buf: &mut Vec<u8>) -> Result<usize> {
+            let start = buf.len();
+            let mut chunk = [0; 512];
+            loop {
+                match self.read(&mut chunk)? {
+                    0 => return Ok(buf.len() - start),
+                    read => buf.extend_from_slice(chunk.get(..read).unwrap_or_default()),
+                }
+            }
+        }
+
+        /// An adapter which reads at most `limit` bytes from this source
+        fn take(self, limit: u64) -> Take<Self>
+        where
+            Self: Sized,
+        {
+            Take { inner: self, limit }
+        }
+    }
+
+    impl<R> Read for &mut R
+    where
+        R: Read + ?Sized,
+    {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+            (**self).read(buf)
+        }
+    }
+
+    /// A source of bytes with a cursor which can be moved
+    pub trait Seek {
+        /// Seek to a position, returning the new position from the start of the stream
+        fn seek(&mut self, pos: SeekFrom) -> Result<u64>;
+
+        /// The current position from the start of the stream
+        fn stream_position(&mut self) -> Result<u64> {
+            self.seek(SeekFrom::Current(0))
+        }
+    }
+
+    impl<S> Seek for &mut S
+    where
+        S: Seek + ?Sized,
+    {
+        fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+            (**self).seek(pos)
+        }
+    }
+
+    /// A sink for bytes
+    pub trait Write {
+        /// Write bytes from `buf`, returning how many bytes were written
+        fn write(&mut self, buf: &[u8]) -> Result<usize>;
+
+        /// Flush any buffered bytes
+        fn flush(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        /// Write all bytes from `buf`
+        fn write_all(&mut self, mut buf: &[u8]) -> Result<()> {
+            while !buf.is_empty() {
+                match self.write(buf)? {
+                    0 => return Err(ErrorKind::WriteZero.into()),
+                    written => buf = buf.get(written..).unwrap_or_default(),
+                }
+            }
+            Ok(())
+        }
+    }
+
+    impl<W> Write for &mut W
+    where
+        W: Write + ?Sized,
+    {
+        fn write(&mut self, buf: &[u8]) -> Result<usize> {
+            (**self).write(buf)
+        }
+    }
+
+    impl Write for Vec<u8> {
+        fn write(&mut self, buf: &[u8]) -> Result<usize> {
+            self.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+    }
+
+    #[derive(Debug)]
+    /// A reader which reads at most a limited number of bytes from another reader
+    pub struct Take<R> {
+        inner: R,
+        limit: u64,
+    }
+
+    impl<R> Read for Take<R>
+    where
+        R: Read,
+    {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+            let size = usize::try_from(self.limit)
+                .unwrap_or(usize::MAX)
+                .min(buf.len());
+            let read = self.inner.read(buf.get_mut(..size).unwrap_or_default())?;
+            self.limit = self.limit.saturating_sub(read as u64);
+            Ok(read)
+        }
+    }
+
+    #[derive(Debug, Clone, Default, PartialEq, Eq)]
+    /// An in-memory buffer with a cursor which can be read from and written to
+    pub struct Cursor<T> {
+        inner: T,
+        position: u64,
+    }
+
+    impl<T> Cursor<T> {
+        /// Create a cursor at the start of `inner`
+        pub fn new(inner: T) -> Self {
+            Self { inner, position: 0 }
+        }
+
+        /// Consume the cursor and return the underlying buffer
+        pub fn into_inner(self) -> T {
+            self.inner
+        }
+
+        /// The underlying buffer
+        pub fn get_ref(&self) -> &T {
+            &self.inner
+        }
+
+        /// The current position of the cursor
+        pub fn position(&self) -> u64 {
+            self.position
+        }
+
+        /// Move the cursor to `position`
+        pub fn set_position(&mut self, position: u64) {
+            self.position = position;
+        }
+    }
+
+    impl<T> Read for Cursor<T>
+    where
+        T: AsRef<[u8]>,
+    {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+            let start = usize::try_from(self.position).unwrap_or(usize::MAX);
+            let size = copy(buf, self.inner.as_ref().get(start..).unwrap_or_default());
+            self.position = self.position.saturating_add(size as u64);
+            Ok(size)
+        }
+    }
+
+    impl<T> Seek for Cursor<T>
+    where
+        T: AsRef<[u8]>,
+    {
+        fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+            let (base, offset) = match pos {
+                SeekFrom::Start(position) => {
+                    self.position = position;
+                    return Ok(position);
+                }
+                SeekFrom::End(offset) => (self.inner.as_ref().len() as u64, offset),
+                SeekFrom::Current(offset) => (self.position, offset),
+            };
+            self.position = base
+                .checked_add_signed(offset)
+                .ok_or(Error::from(ErrorKind::InvalidInput))?;
+            Ok(self.position)
+        }
+    }
+
+    impl Write for Cursor<&mut [u8]> {
+        fn write(&mut self, buf: &[u8]) -> Result<usize> {
+            let start = usize::try_from(self.position).unwrap_or(usize::MAX);
+            let size = copy(self.inner.get_mut(start..).unwrap_or_default(), buf);
+            self.position = self.position.saturating_add(size as u64);
+            Ok(size)
+        }
+    }
+
+    impl Write for Cursor<Vec<u8>> {
+        fn write(&mut self, buf: &[u8]) -> Result<usize> {
+            let start = usize::try_from(self.position).map_err(|_| ErrorKind::InvalidInput)?;
+            let end = start
+                .checked_add(buf.len())
+                .ok_or(Error::from(ErrorKind::InvalidInput))?;
+            if self.inner.len() < end {
+                self.inner.resize(end, 0);
+            }
+            copy(self.inner.get_mut(start..).unwrap_or_default(), buf);
+            self.position = end as u64;
+            Ok(buf.len())
+        }
+    }
+
+    /// Copy as many bytes as fit from `from` to `to`, returning how many were copied
+    fn copy(to: &mut [u8], from: &[u8]) -> usize {
+        to.iter_mut()
+            .zip(from)
+            .map(|(to, from)| *to = *from)
+            .count()
+    }
+
+    #[allow(clippy::unwrap_used)]
+    #[cfg(test)]
+    mod test {
+        use alloc::vec::Vec;
+
+        use super::{Cursor, ErrorKind, Read, Seek, SeekFrom, Write};
+
+        #[test]
+        fn test_cursor_seek() {
+            let mut cursor = Cursor::new([0u8, 1, 2, 3, 4, 5, 6, 7]);
+
+            assert_eq!(cursor.seek(SeekFrom::End(-2)).unwrap(), 6);
+            assert_eq!(cursor.seek(SeekFrom::Current(-4)).unwrap(), 2);
+            assert_eq!(cursor.stream_position().unwrap(), 2);
+            // Seeking past the end is allowed, but seeking before the start is not
+            assert_eq!(cursor.seek(SeekFrom::End(8)).unwrap(), 16);
+            assert_eq!(
+                cursor.seek(SeekFrom::Current(-17)).unwrap_err().kind(),
+                ErrorKind::InvalidInput
+            );
+            assert_eq!(cursor.position(), 16);
+            assert_eq!(cursor.seek(SeekFrom::Start(3)).unwrap(), 3);
+
+            let mut byte = [0; 1];
+            cursor.read_exact(&mut byte).unwrap();
+            assert_eq!(byte, [3]);
+        }
+
+        #[test]
+        fn test_cursor_read_past_end() {
+            let mut cursor = Cursor::new([0u8, 1, 2, 3]);
+            let mut buf = [0xff; 3];
+
+            cursor.seek(SeekFrom::Start(2)).unwrap();
+            assert_eq!(cursor.read(&mut buf).unwrap(), 2);
+            assert_eq!(buf, [2, 3, 0xff]);
+            assert_eq!(cursor.read(&mut buf).unwrap(), 0);
+
+            cursor.seek(SeekFrom::Start(100)).unwrap();
+            assert_eq!(cursor.read(&mut buf).unwrap(), 0);
+
+            cursor.seek(SeekFrom::Start(1)).unwrap();
+            assert_eq!(
+                cursor.read_exact(&mut [0; 4]).unwrap_err().kind(),
+                ErrorKind::UnexpectedEof
+            );
+
+            cursor.seek(SeekFrom::Start(1)).unwrap();
+            let mut rest = Vec::new();
+            assert_eq!(cursor.read_to_end(&mut rest).unwrap(), 3);
+            assert_eq!(rest, [1, 2, 3]);
+        }
+
+        #[test]
+        fn test_cursor_write() {
+            // A vector grows to fit writes past its end, zero filling any gap
+            let mut cursor = Cursor::new(Vec::from([1u8, 2]));
+            cursor.seek(SeekFrom::Start(1)).unwrap();
+            cursor.write_all(&[3, 4]).unwrap();
+            cursor.seek(SeekFrom::End(2)).unwrap();
+            cursor.write_all(&[5]).unwrap();
+            assert_eq!(cursor.position(), 6);
+            assert_eq!(cursor.into_inner(), [1, 3, 4, 0, 0, 5]);
+
+            // A slice does not grow, so a write past its end is short
+            let mut buf = [0u8; 3];
+            let mut cursor = Cursor::new(&mut buf[..]);
+            cursor.seek(SeekFrom::Start(2)).unwrap();
+            assert_eq!(cursor.write(&[6, 7]).unwrap(), 1);
+            assert_eq!(cursor.write(&[8]).unwrap(), 0);
+            assert_eq!(
+                cursor.write_all(&[9]).unwrap_err().kind(),
+                ErrorKind::WriteZero
+            );
+            assert_eq!(buf, [0, 0, 6]);
+        }
+
+        #[test]
+        fn test_take() {
+            let mut cursor = Cursor::new([0u8, 1, 2, 3, 4, 5]);
+            let mut data = Vec::new();
+
+            (&mut cursor).take(4).read_to_end(&mut data).unwrap();
+            assert_eq!(data, [0, 1, 2, 3]);
+            assert_eq!(cursor.position(), 4);
+
+            // The limit is only an upper bound on what the inner reader holds
+            data.clear();
+            (&mut cursor).take(100).read_to_end(&mut data).unwrap();
+            assert_eq!(data, [4, 5]);
+
+            let mut take = Cursor::new([0u8; 8]).take(3);
+            let mut buf = [0xff; 8];
+            assert_eq!(take.read(&mut buf).unwrap(), 3);
+            assert_eq!(take.read(&mut buf).unwrap(), 0);
+            assert_eq!(buf[3..], [0xff; 5]);
+        }
+    }
+}
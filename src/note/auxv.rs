@@ -0,0 +1,180 @@
+//! The auxiliary vector, which the kernel passes to a new process on its stack and
+//! which is saved in the `NT_AUXV` note of the process's core files
+
+use alloc::vec::Vec;
+use num_derive::FromPrimitive;
+use num_traits::FromPrimitive as _;
+
+use crate::{
+    base::ElfAddress,
+    error::Error,
+    io::{Cursor, Read, Seek},
+    Config, Elf, FromReader,
+};
+
+use super::ElfNote;
+
+#[repr(u64)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, FromPrimitive)]
+#[non_exhaustive]
+/// The type of an auxiliary vector entry, which determines the interpretation of its
+/// value
+pub enum AuxvType {
+    /// Marks the end of the vector (`AT_NULL`)
+    Null = 0,
+    /// An entry to be ignored (`AT_IGNORE`)
+    Ignore = 1,
+    /// The file descriptor of the program, if the kernel did not map it (`AT_EXECFD`)
+    ExecFd = 2,
+    /// The address of the program's program header table in memory (`AT_PHDR`)
+    Phdr = 3,
+    /// The size of an entry of the program's program header table (`AT_PHENT`)
+    Phent = 4,
+    /// The number of entries in the program's program header table (`AT_PHNUM`)
+    Phnum = 5,
+    /// The system page size (`AT_PAGESZ`)
+    PageSize = 6,
+    /// The base address the program interpreter was loaded at (`AT_BASE`)
+    Base = 7,
+    /// Flags for the program interpreter (`AT_FLAGS`)
+    Flags = 8,
+    /// The address of the program's entry point (`AT_ENTRY`)
+    Entry = 9,
+    /// Set if the program is not an ELF object (`AT_NOTELF`)
+    NotElf = 10,
+    /// The real user ID of the process (`AT_UID`)
+    Uid = 11,
+    /// The effective user ID of the process (`AT_EUID`)
+    Euid = 12,
+    /// The real group ID of the process (`AT_GID`)
+    Gid = 13,
+    /// The effective group ID of the process (`AT_EGID`)
+    Egid = 14,
+    /// The address of a string naming the platform (`AT_PLATFORM`)
+    Platform = 15,
+    /// The processor's capabilities, as a machine-specific bit mask (`AT_HWCAP`)
+    Hwcap = 16,
+    /// The frequency at which `times` counts (`AT_CLKTCK`)
+    ClockTick = 17,
+    /// Set if the program runs with elevated privileges, such as setuid (`AT_SECURE`)
+    Secure = 23,
+    /// The address of a string naming the base platform (`AT_BASE_PLATFORM`)
+    BasePlatform = 24,
+    /// The address of 16 random bytes, used to seed stack protectors (`AT_RANDOM`)
+    Random = 25,
+    /// More of the processor's capabilities (`AT_HWCAP2`)
+    Hwcap2 = 26,
+    /// The address of the path the program was executed by (`AT_EXECFN`)
+    ExecFn = 31,
+    /// The address of the vDSO's ELF header (`AT_SYSINFO_EHDR`)
+    SysinfoEhdr = 33,
+    /// The minimal stack size for a signal handler (`AT_MINSIGSTKSZ`)
+    MinSigStackSize = 51,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// An entry of an auxiliary vector
+pub struct AuxvEntry {
+    /// The raw type of the entry
+    pub r#type: u64,
+    /// The value of the entry, which is an address or an integer depending on its type
+    pub value: u64,
+}
+
+impl AuxvEntry {
+    /// The type of the entry, or `None` if it is not a known type
+    pub fn auxv_type(&self) -> Option<AuxvType> {
+        AuxvType::from_u64(self.r#type)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// The auxiliary vector of a process, as reported by `Elf::auxv`
+pub struct Auxv {
+    /// The entries of the vector before its `AT_NULL` entry, in order
+    pub entries: Vec<AuxvEntry>,
+}
+
+impl Auxv {
+    /// The value of the first entry of type `type`, or `None` if there is no such entry
+    pub fn get(&self, r#type: AuxvType) -> Option<u64> {
+        self.entries
+            .iter()
+            .find(|entry| entry.r#type == r#type as u64)
+            .map(|entry| entry.value)
+    }
+
+    /// The address of the program's program header table in memory (`AT_PHDR`), which
+    /// locates the program's image in the process's address space
+    pub fn phdr(&self) -> Option<u64> {
+        self.get(AuxvType::Phdr)
+    }
+
+    /// The address of the program's entry point (`AT_ENTRY`)
+    pub fn entry(&self) -> Option<u64> {
+        self.get(AuxvType::Entry)
+    }
+
+    /// The base address of the program interpreter (`AT_BASE`), which is 0 for a
+    /// program without one
+    pub fn base(&self) -> Option<u64> {
+        self.get(AuxvType::Base)
+    }
+
+    /// The address of the 16 random bytes given to the process (`AT_RANDOM`)
+    pub fn random(&self) -> Option<u64> {
+        self.get(AuxvType::Random)
+    }
+
+    /// The processor's capabilities (`AT_HWCAP`)
+    pub fn hwcap(&self) -> Option<u64> {
+        self.get(AuxvType::Hwcap)
+    }
+}
+
+impl ElfNote {
+    /// The name of the owner of core file notes
+    pub const CORE_NAME: &'static str = "CORE";
+    /// The type of the core file note holding the auxiliary vector (`NT_AUXV`)
+    pub const CORE_AUXV: u32 = 6;
+}
+
+impl<const EC: u8, const ED: u8> Elf<EC, ED> {
+    /// Read the auxiliary vector of the process a core file was dumped from, from its
+    /// `NT_AUXV` note. Each entry is a pair of words the size of an address in the
+    /// object's class, and the vector ends at its `AT_NULL` entry or at the end of the
+    /// note. Objects other than core files have no such note, in which case `None` is
+    /// returned.
+    pub fn auxv<R>(&self, reader: &mut R) -> Result<Option<Auxv>, Error>
+    where
+        R: Read + Seek,
+    {
+        let Some(note) = self
+            .notes(reader)?
+            .into_iter()
+            .find(|note| note.name == ElfNote::CORE_NAME && note.r#type == ElfNote::CORE_AUXV)
+        else {
+            return Ok(None);
+        };
+
+        let mut config = Config::default();
+        let mut cursor = Cursor::new(note.description);
+        let mut auxv = Auxv::default();
+
+        while let (Ok(r#type), Ok(value)) = (
+            ElfAddress::<EC, ED>::from_reader_with(&mut cursor, &mut config),
+            ElfAddress::<EC, ED>::from_reader_with(&mut cursor, &mut config),
+        ) {
+            if r#type.0 == AuxvType::Null as u64 {
+                break;
+            }
+
+            auxv.entries.push(AuxvEntry {
+                r#type: r#type.0,
+                value: value.0,
+            });
+        }
+
+        Ok(Some(auxv))
+    }
+}
@@ -0,0 +1,248 @@
+//! Implementation of ELF notes, which are held in `SHT_NOTE` sections and `PT_NOTE`
+//! segments
+
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    base::ElfWord,
+    error::Error,
+    header::{
+        program::{ElfProgramHeaderType, SegmentIndex},
+        section::ElfSectionHeaderType,
+    },
+    io::{Cursor, Read, Seek},
+    Config, Elf, FromReader,
+};
+
+pub mod auxv;
+pub mod stapsdt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// An ELF note, which holds vendor-specific information for consumers of the object
+pub struct ElfNote {
+    /// The name of the owner of the note, such as `GNU`
+    pub name: String,
+    /// The type of the note, whose interpretation depends on the owner
+    pub r#type: u32,
+    /// The descriptor of the note, whose interpretation depends on the owner and type
+    pub description: Vec<u8>,
+}
+
+impl ElfNote {
+    /// The size of the `namesz`, `descsz`, and `type` words which begin each note
+    const HEADER_SIZE: u64 = 12;
+    /// The name of the owner of GNU notes
+    pub const GNU_NAME: &'static str = "GNU";
+    /// The type of the GNU build ID note (`NT_GNU_BUILD_ID`)
+    pub const GNU_BUILD_ID: u32 = 3;
+}
+
+#[derive(Debug, Clone)]
+/// An iterator over the notes in the contents of a note section or segment, which
+/// decodes each note as it is reached. Each note's descriptor and the following note
+/// begin at a multiple of the alignment, which is 8 for notes in sections or segments
+/// aligned to 8 and 4 otherwise. Iteration stops at the end of the contents, or at the
+/// first note which does not fit in them.
+pub struct ElfNoteIterator<const EC: u8, const ED: u8> {
+    data: Vec<u8>,
+    align: u64,
+    offset: Option<u64>,
+}
+
+impl<const EC: u8, const ED: u8> ElfNoteIterator<EC, ED> {
+    /// Iterate over the notes in `data`, the contents of a note section or segment with
+    /// alignment `align`
+    pub(crate) fn new(data: Vec<u8>, align: u64) -> Self {
+        Self {
+            data,
+            align: if align == 8 { 8 } else { 4 },
+            offset: Some(0),
+        }
+    }
+
+    /// Decode the note at `offset`, and return it with the offset of the note after it
+    fn note_at(&self, offset: u64) -> Result<Option<(ElfNote, Option<u64>)>, Error> {
+        let align_up = |value: u64| value.checked_next_multiple_of(self.align);
+        let mut config = Config::default();
+
+        let Some(mut header) = slice(&self.data, offset, ElfNote::HEADER_SIZE).map(Cursor::new)
+        else {
+            return Ok(None);
+        };
+        let name_size = ElfWord::<EC, ED>::from_reader_with(&mut header, &mut config)?.0;
+        let description_size = ElfWord::<EC, ED>::from_reader_with(&mut header, &mut config)?.0;
+        let r#type = ElfWord::<EC, ED>::from_reader_with(&mut header, &mut config)?.0;
+
+        let name_start = offset + ElfNote::HEADER_SIZE;
+        let Some(description_start) = align_up(name_start + name_size as u64) else {
+            return Ok(None);
+        };
+        let description_end = description_start + description_size as u64;
+        let (Some(name), Some(description)) = (
+            slice(&self.data, name_start, name_size as u64),
+            slice(&self.data, description_start, description_size as u64),
+        ) else {
+            return Ok(None);
+        };
+
+        let note = ElfNote {
+            name: String::from_utf8_lossy(name.split(|b| *b == 0).next().unwrap_or(name))
+                .into_owned(),
+            r#type,
+            description: description.to_vec(),
+        };
+
+        Ok(Some((note, align_up(description_end))))
+    }
+}
+
+impl<const EC: u8, const ED: u8> Iterator for ElfNoteIterator<EC, ED> {
+    type Item = Result<ElfNote, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.offset.take()?;
+
+        match self.note_at(offset) {
+            Ok(Some((note, next))) => {
+                self.offset = next;
+                Some(Ok(note))
+            }
+            Ok(None) => None,
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+/// The `size` bytes of `data` at `offset`, if they are all in `data`
+fn slice(data: &[u8], offset: u64, size: u64) -> Option<&[u8]> {
+    let start = usize::try_from(offset).ok()?;
+    let end = start.checked_add(usize::try_from(size).ok()?)?;
+    data.get(start..end)
+}
+
+impl<const EC: u8, const ED: u8> Elf<EC, ED> {
+    /// Read the notes in the object's `SHT_NOTE` sections
+    fn section_notes<R>(&self, reader: &mut R) -> Result<Vec<ElfNote>, Error>
+    where
+        R: Read + Seek,
+    {
+        let mut notes = Vec::new();
+
+        for (index, header) in self
+            .indexed_sections()
+            .filter(|(_, header)| matches!(header.r#type(), ElfSectionHeaderType::Note))
+        {
+            let data = self.section_data(reader, index)?;
+            for note in ElfNoteIterator::<EC, ED>::new(data, header.address_align()) {
+                notes.push(note?);
+            }
+        }
+
+        Ok(notes)
+    }
+
+    /// Read the notes in the object's `PT_NOTE` segments
+    fn segment_notes<R>(&self, reader: &mut R) -> Result<Vec<ElfNote>, Error>
+    where
+        R: Read + Seek,
+    {
+        let mut notes = Vec::new();
+
+        for header in self
+            .program_headers
+            .iter()
+            .filter(|header| matches!(header.r#type(), ElfProgramHeaderType::Note))
+        {
+            let data = header.data_from_reader(reader)?;
+            for note in ElfNoteIterator::<EC, ED>::new(data, header.align()) {
+                notes.push(note?);
+            }
+        }
+
+        Ok(notes)
+    }
+
+    /// Read the notes in the object's `SHT_NOTE` sections. If the object has no section
+    /// header table, as is the case for core files, the notes in its `PT_NOTE` segments
+    /// are read instead.
+    pub fn notes<R>(&self, reader: &mut R) -> Result<Vec<ElfNote>, Error>
+    where
+        R: Read + Seek,
+    {
+        if self.section_headers.is_empty() {
+            self.segment_notes(reader)
+        } else {
+            self.section_notes(reader)
+        }
+    }
+
+    /// Read the build ID from the object's GNU build ID note, which is usually held in
+    /// the `.note.gnu.build-id` section. `None` if the object has no build ID note.
+    pub fn build_id<R>(&self, reader: &mut R) -> Result<Option<Vec<u8>>, Error>
+    where
+        R: Read + Seek,
+    {
+        self.build_id_with(reader, &Config::default())
+    }
+
+    /// Read the build ID from the object's GNU build ID note, as with `build_id`,
+    /// recovering from the errors ignored by `config`. The note is looked for in both
+    /// the `SHT_NOTE` sections and the `PT_NOTE` segments, so the build ID of an object
+    /// whose section header table has been stripped is still found. When both have a
+    /// build ID, they must agree.
+    pub fn build_id_with<R>(
+        &self,
+        reader: &mut R,
+        config: &Config,
+    ) -> Result<Option<Vec<u8>>, Error>
+    where
+        R: Read + Seek,
+    {
+        let build_id = |notes: Vec<ElfNote>| {
+            notes
+                .into_iter()
+                .find(|note| note.name == ElfNote::GNU_NAME && note.r#type == ElfNote::GNU_BUILD_ID)
+                .map(|note| note.description)
+        };
+        let section = build_id(self.section_notes(reader)?);
+        let segment = build_id(self.segment_notes(reader)?);
+
+        match (section, segment) {
+            (Some(section), Some(segment)) if section != segment => {
+                let err = Error::BuildIdMismatch {
+                    section: section.clone(),
+                    segment,
+                };
+
+                match config.ignores(&err) {
+                    true => Ok(Some(section)),
+                    false => Err(err),
+                }
+            }
+            (section, segment) => Ok(section.or(segment)),
+        }
+    }
+
+    /// Iterate over the notes in the segment at `index` in the program header table,
+    /// which is usually a `PT_NOTE` segment. Core files pack many notes back to back in
+    /// a single `PT_NOTE` segment, and they are decoded one at a time as the iterator is
+    /// advanced, stopping at the end of the segment.
+    pub fn notes_in_segment<R>(
+        &self,
+        reader: &mut R,
+        index: SegmentIndex,
+    ) -> Result<ElfNoteIterator<EC, ED>, Error>
+    where
+        R: Read + Seek,
+    {
+        let header = self
+            .program_headers
+            .get(index.get())
+            .ok_or(Error::InvalidSegmentIndex { index: index.get() })?;
+
+        Ok(ElfNoteIterator::new(
+            header.data_from_reader(reader)?,
+            header.align(),
+        ))
+    }
+}
@@ -0,0 +1,90 @@
+//! SystemTap statically defined tracing (USDT) probe notes, which are held in the
+//! `.note.stapsdt` section
+
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    base::ElfAddress,
+    error::Error,
+    header::section::string_at,
+    io::{Cursor, Read, Seek},
+    Config, Elf, FromReader,
+};
+
+use super::ElfNote;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A user-space statically defined tracing probe, as enumerated by SystemTap, DTrace,
+/// and bpftrace
+pub struct UsdtProbe {
+    /// The provider of the probe, for example `libc`
+    pub provider: String,
+    /// The name of the probe
+    pub name: String,
+    /// The address of the probe's instruction
+    pub location: u64,
+    /// The link-time address of the `.stapsdt.base` section. If the object is loaded or
+    /// prelinked at a different address, the difference must be applied to `location`
+    /// and `semaphore`.
+    pub base: u64,
+    /// The address of the probe's semaphore, or 0 if it has none
+    pub semaphore: u64,
+    /// The description of the probe's arguments, for example `-4@$0 8@%rbx`
+    pub arguments: String,
+}
+
+impl UsdtProbe {
+    /// The name of the owner of SystemTap probe notes
+    pub const NOTE_NAME: &'static str = "stapsdt";
+    /// The type of SystemTap probe notes (`NT_STAPSDT`)
+    pub const NOTE_TYPE: u32 = 3;
+
+    /// Decode a probe from the descriptor of a SystemTap probe note, which holds three
+    /// addresses followed by three null-terminated strings
+    fn from_note<const EC: u8, const ED: u8>(note: &ElfNote) -> Option<Self> {
+        let mut description = Cursor::new(note.description.as_slice());
+        let mut config = Config::default();
+        let mut address = || {
+            ElfAddress::<EC, ED>::from_reader_with(&mut description, &mut config)
+                .map(|address| address.0)
+                .ok()
+        };
+        let (Some(location), Some(base), Some(semaphore)) = (address(), address(), address())
+        else {
+            return None;
+        };
+        let strings = note
+            .description
+            .get(usize::try_from(description.position()).ok()?..)?;
+        let mut strings = strings.split(|b| *b == 0);
+        let mut string = || strings.next().and_then(|string| string_at(string, 0));
+        let (Some(provider), Some(name), Some(arguments)) = (string(), string(), string()) else {
+            return None;
+        };
+
+        Some(Self {
+            provider,
+            name,
+            location,
+            base,
+            semaphore,
+            arguments,
+        })
+    }
+}
+
+impl<const EC: u8, const ED: u8> Elf<EC, ED> {
+    /// Read the SystemTap probes described by the object's notes. Probe notes whose
+    /// descriptors are too short to hold a probe are skipped.
+    pub fn usdt_probes<R>(&self, reader: &mut R) -> Result<Vec<UsdtProbe>, Error>
+    where
+        R: Read + Seek,
+    {
+        Ok(self
+            .notes(reader)?
+            .iter()
+            .filter(|note| note.name == UsdtProbe::NOTE_NAME && note.r#type == UsdtProbe::NOTE_TYPE)
+            .filter_map(UsdtProbe::from_note::<EC, ED>)
+            .collect())
+    }
+}
@@ -3,6 +3,7 @@
 use crate::{
     base::ElfWord, error::Error, header::elf::identification::ElfOSABI, TryFromWithConfig,
 };
+use alloc::vec;
 
 #[repr(u32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -42,6 +43,20 @@ impl ElfSectionHeaderTypeGNU {
     pub const VERSYM: u32 = 0x6fffffff;
 }
 
+impl core::fmt::Display for ElfSectionHeaderTypeGNU {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::IncrementalInputs => "GNU_INCREMENTAL_INPUTS",
+            Self::Attributes => "GNU_ATTRIBUTES",
+            Self::Hash => "GNU_HASH",
+            Self::LibList => "GNU_LIBLIST",
+            Self::VerDef => "VERDEF",
+            Self::VerNeed => "VERNEED",
+            Self::VerSym => "VERSYM",
+        })
+    }
+}
+
 impl<const EC: u8, const ED: u8> From<ElfSectionHeaderTypeGNU> for ElfWord<EC, ED> {
     fn from(value: ElfSectionHeaderTypeGNU) -> Self {
         Self(value as u32)
@@ -61,10 +76,19 @@ impl<const EC: u8, const ED: u8> TryFromWithConfig<ElfWord<EC, ED>> for ElfSecti
         value: ElfWord<EC, ED>,
         config: &mut crate::Config,
     ) -> Result<Self, Self::Error> {
-        if !matches!(config.os_abi, Some(ElfOSABI::GnuLinux)) {
+        // GNU toolchains usually leave EI_OSABI as ELFOSABI_NONE unless GNU-specific
+        // features such as IFUNCs are used, and are also used to build FreeBSD objects
+        if !matches!(
+            config.os_abi,
+            Some(ElfOSABI::GnuLinux | ElfOSABI::NoneSystemV | ElfOSABI::FreeBSD)
+        ) {
             return Err(Error::InvalidOsAbiForSectionHeaderType {
                 os_abi: config.os_abi,
-                expected_os_abis: vec![ElfOSABI::GnuLinux],
+                expected_os_abis: vec![
+                    ElfOSABI::GnuLinux,
+                    ElfOSABI::NoneSystemV,
+                    ElfOSABI::FreeBSD,
+                ],
                 value: value.0,
             });
         }
@@ -83,3 +107,99 @@ impl<const EC: u8, const ED: u8> TryFromWithConfig<ElfWord<EC, ED>> for ElfSecti
         }
     }
 }
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+/// Program Header Types
+pub enum ElfProgramHeaderTypeGNU {
+    /// Exception handling frame header
+    EhFrame = Self::EH_FRAME,
+    /// Stack executability, given by the segment flags
+    Stack = Self::STACK,
+    /// Read-only after relocation
+    Relro = Self::RELRO,
+    /// GNU property notes for the linker and run-time loader
+    Property = Self::PROPERTY,
+    /// Stack trace information
+    SFrame = Self::SFRAME,
+}
+
+impl ElfProgramHeaderTypeGNU {
+    /// Constant value for [ElfProgramHeaderTypeGNU::EhFrame]
+    pub const EH_FRAME: u32 = 0x6474e550;
+    /// Constant value for [ElfProgramHeaderTypeGNU::Stack]
+    pub const STACK: u32 = 0x6474e551;
+    /// Constant value for [ElfProgramHeaderTypeGNU::Relro]
+    pub const RELRO: u32 = 0x6474e552;
+    /// Constant value for [ElfProgramHeaderTypeGNU::Property]
+    pub const PROPERTY: u32 = 0x6474e553;
+    /// Constant value for [ElfProgramHeaderTypeGNU::SFrame]
+    pub const SFRAME: u32 = 0x6474e554;
+}
+
+impl core::fmt::Display for ElfProgramHeaderTypeGNU {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::EhFrame => "GNU_EH_FRAME",
+            Self::Stack => "GNU_STACK",
+            Self::Relro => "GNU_RELRO",
+            Self::Property => "GNU_PROPERTY",
+            Self::SFrame => "GNU_SFRAME",
+        })
+    }
+}
+
+impl<const EC: u8, const ED: u8> From<ElfProgramHeaderTypeGNU> for ElfWord<EC, ED> {
+    fn from(value: ElfProgramHeaderTypeGNU) -> Self {
+        Self(value as u32)
+    }
+}
+
+impl<const EC: u8, const ED: u8> From<&ElfProgramHeaderTypeGNU> for ElfWord<EC, ED> {
+    fn from(value: &ElfProgramHeaderTypeGNU) -> Self {
+        Self(*value as u32)
+    }
+}
+
+impl<const EC: u8, const ED: u8> TryFromWithConfig<ElfWord<EC, ED>> for ElfProgramHeaderTypeGNU {
+    type Error = Error;
+
+    fn try_from_with(
+        value: ElfWord<EC, ED>,
+        config: &mut crate::Config,
+    ) -> Result<Self, Self::Error> {
+        // GNU toolchains usually leave EI_OSABI as ELFOSABI_NONE unless GNU-specific
+        // features such as IFUNCs are used, and are also used to build FreeBSD objects
+        // and Solaris objects, which GNU ld gives segments such as `PT_GNU_STACK`
+        if !matches!(
+            config.os_abi,
+            Some(
+                ElfOSABI::GnuLinux | ElfOSABI::NoneSystemV | ElfOSABI::FreeBSD | ElfOSABI::Solaris
+            )
+        ) {
+            return Err(Error::InvalidOsAbiForProgramHeaderType {
+                os_abi: config.os_abi,
+                expected_os_abis: vec![
+                    ElfOSABI::GnuLinux,
+                    ElfOSABI::NoneSystemV,
+                    ElfOSABI::FreeBSD,
+                    ElfOSABI::Solaris,
+                ],
+                value: value.0,
+            });
+        }
+
+        match value.0 {
+            Self::EH_FRAME => Ok(Self::EhFrame),
+            Self::STACK => Ok(Self::Stack),
+            Self::RELRO => Ok(Self::Relro),
+            Self::PROPERTY => Ok(Self::Property),
+            Self::SFRAME => Ok(Self::SFrame),
+            _ => Err(Error::InvalidProgramHeaderType {
+                machine: config.machine,
+                value: value.0,
+            }),
+        }
+    }
+}
@@ -0,0 +1,116 @@
+//! Linux-specific definitions, for the sections of loadable kernel modules
+
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+
+use crate::{
+    base::ElfAddress,
+    error::Error,
+    io::{Cursor, Read, Seek},
+    Config, Elf, FromReader,
+};
+
+/// The size of an entry of the `__versions` section of a kernel module, which is a
+/// `struct modversion_info`
+pub const MODVERSION_INFO_SIZE: usize = 64;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// The CRC of a kernel symbol a module was built against, from an entry of the
+/// module's `__versions` section
+pub struct ModuleVersion {
+    /// The CRC of the symbol's type, which the kernel checks when loading the module
+    pub crc: u32,
+    /// The name of the symbol
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// The information of a Linux kernel module, as reported by `Elf::kernel_module_info`
+pub struct KernelModuleInfo {
+    /// The `key=value` pairs of the `.modinfo` section, in which a key can be given
+    /// more than once, as `alias` and `parm` commonly are
+    pub modinfo: BTreeMap<String, Vec<String>>,
+    /// The symbol versions of the `__versions` section, which is only present in
+    /// modules built with `CONFIG_MODVERSIONS`
+    pub versions: Vec<ModuleVersion>,
+}
+
+impl KernelModuleInfo {
+    /// The first value given for `key` in `.modinfo`
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.modinfo
+            .get(key)
+            .and_then(|values| values.first())
+            .map(String::as_str)
+    }
+
+    /// The name of the module
+    pub fn name(&self) -> Option<&str> {
+        self.get("name")
+    }
+
+    /// The license of the module, which decides whether it may use GPL-only symbols
+    pub fn license(&self) -> Option<&str> {
+        self.get("license")
+    }
+
+    /// The names of the modules this module depends on, which are given as a
+    /// comma-separated list
+    pub fn dependencies(&self) -> Vec<&str> {
+        self.get("depends")
+            .map(|depends| depends.split(',').filter(|d| !d.is_empty()).collect())
+            .unwrap_or_default()
+    }
+}
+
+impl<const EC: u8, const ED: u8> Elf<EC, ED> {
+    /// Read the information of a Linux kernel module from its `.modinfo` section,
+    /// which holds null-terminated `key=value` strings, and its `__versions` section,
+    /// which holds the CRC of each kernel symbol the module uses in a fixed-size entry.
+    /// Strings without a `=` are skipped, as is the padding between them. Objects
+    /// which are not kernel modules have no `.modinfo` section, in which case `None`
+    /// is returned.
+    pub fn kernel_module_info<R>(&self, reader: &mut R) -> Result<Option<KernelModuleInfo>, Error>
+    where
+        R: Read + Seek,
+    {
+        let Some((index, _)) = self.section_by_name(".modinfo") else {
+            return Ok(None);
+        };
+
+        let mut info = KernelModuleInfo::default();
+
+        for string in self.section_data(reader, index)?.split(|b| *b == 0) {
+            let string = String::from_utf8_lossy(string);
+
+            if let Some((key, value)) = string.split_once('=') {
+                info.modinfo
+                    .entry(key.into())
+                    .or_default()
+                    .push(value.into());
+            }
+        }
+
+        if let Some((index, _)) = self.section_by_name("__versions") {
+            let mut config = Config::default();
+
+            for entry in self
+                .section_data(reader, index)?
+                .chunks_exact(MODVERSION_INFO_SIZE)
+            {
+                let mut cursor = Cursor::new(entry);
+                let crc = ElfAddress::<EC, ED>::from_reader_with(&mut cursor, &mut config)?.0;
+                let name = entry
+                    .get(cursor.position() as usize..)
+                    .and_then(|name| name.split(|b| *b == 0).next())
+                    .unwrap_or_default();
+
+                info.versions.push(ModuleVersion {
+                    crc: crc as u32,
+                    name: String::from_utf8_lossy(name).into_owned(),
+                });
+            }
+        }
+
+        Ok(Some(info))
+    }
+}
@@ -0,0 +1,168 @@
+//! LLVM-specific definitions, which are emitted by Clang and LLD on any OS
+
+use alloc::vec::Vec;
+
+use crate::{
+    base::ElfWord,
+    error::Error,
+    header::section::ElfSectionHeaderType,
+    io::{Read, Seek},
+    Elf, TryFromWithConfig,
+};
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+/// Section Header Types
+pub enum ElfSectionHeaderTypeLLVM {
+    /// The ODR table of an LTO module
+    OdrTab = Self::ODRTAB,
+    /// Options for the linker
+    LinkerOptions = Self::LINKER_OPTIONS,
+    /// Indices of the address-significant symbols, which identical code folding must
+    /// not merge
+    Addrsig = Self::ADDRSIG,
+    /// Names of libraries the object depends on
+    DependentLibraries = Self::DEPENDENT_LIBRARIES,
+    /// Symbol partition specification
+    SymPart = Self::SYMPART,
+    /// The ELF header of a loadable partition
+    PartEhdr = Self::PART_EHDR,
+    /// The program header table of a loadable partition
+    PartPhdr = Self::PART_PHDR,
+    /// Basic block address map, in its original version
+    BbAddrMapV0 = Self::BB_ADDR_MAP_V0,
+    /// Call graph profile, the weights of the edges between functions
+    CallGraphProfile = Self::CALL_GRAPH_PROFILE,
+    /// Basic block address map
+    BbAddrMap = Self::BB_ADDR_MAP,
+    /// Offloading data for a device, such as a GPU
+    Offloading = Self::OFFLOADING,
+    /// LLVM bitcode for LTO
+    Lto = Self::LTO,
+}
+
+impl ElfSectionHeaderTypeLLVM {
+    /// Constant value for [ElfSectionHeaderTypeLLVM::OdrTab]
+    pub const ODRTAB: u32 = 0x6fff4c00;
+    /// Constant value for [ElfSectionHeaderTypeLLVM::LinkerOptions]
+    pub const LINKER_OPTIONS: u32 = 0x6fff4c01;
+    /// Constant value for [ElfSectionHeaderTypeLLVM::Addrsig]
+    pub const ADDRSIG: u32 = 0x6fff4c03;
+    /// Constant value for [ElfSectionHeaderTypeLLVM::DependentLibraries]
+    pub const DEPENDENT_LIBRARIES: u32 = 0x6fff4c04;
+    /// Constant value for [ElfSectionHeaderTypeLLVM::SymPart]
+    pub const SYMPART: u32 = 0x6fff4c05;
+    /// Constant value for [ElfSectionHeaderTypeLLVM::PartEhdr]
+    pub const PART_EHDR: u32 = 0x6fff4c06;
+    /// Constant value for [ElfSectionHeaderTypeLLVM::PartPhdr]
+    pub const PART_PHDR: u32 = 0x6fff4c07;
+    /// Constant value for [ElfSectionHeaderTypeLLVM::BbAddrMapV0]
+    pub const BB_ADDR_MAP_V0: u32 = 0x6fff4c08;
+    /// Constant value for [ElfSectionHeaderTypeLLVM::CallGraphProfile]
+    pub const CALL_GRAPH_PROFILE: u32 = 0x6fff4c09;
+    /// Constant value for [ElfSectionHeaderTypeLLVM::BbAddrMap]
+    pub const BB_ADDR_MAP: u32 = 0x6fff4c0a;
+    /// Constant value for [ElfSectionHeaderTypeLLVM::Offloading]
+    pub const OFFLOADING: u32 = 0x6fff4c0b;
+    /// Constant value for [ElfSectionHeaderTypeLLVM::Lto]
+    pub const LTO: u32 = 0x6fff4c0c;
+}
+
+impl core::fmt::Display for ElfSectionHeaderTypeLLVM {
+    /// Display the section header type as its name in `llvm-readelf` output
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::OdrTab => "LLVM_ODRTAB",
+            Self::LinkerOptions => "LLVM_LINKER_OPTIONS",
+            Self::Addrsig => "LLVM_ADDRSIG",
+            Self::DependentLibraries => "LLVM_DEPENDENT_LIBRARIES",
+            Self::SymPart => "LLVM_SYMPART",
+            Self::PartEhdr => "LLVM_PART_EHDR",
+            Self::PartPhdr => "LLVM_PART_PHDR",
+            Self::BbAddrMapV0 => "LLVM_BB_ADDR_MAP_V0",
+            Self::CallGraphProfile => "LLVM_CALL_GRAPH_PROFILE",
+            Self::BbAddrMap => "LLVM_BB_ADDR_MAP",
+            Self::Offloading => "LLVM_OFFLOADING",
+            Self::Lto => "LLVM_LTO",
+        })
+    }
+}
+
+impl<const EC: u8, const ED: u8> From<ElfSectionHeaderTypeLLVM> for ElfWord<EC, ED> {
+    fn from(value: ElfSectionHeaderTypeLLVM) -> Self {
+        Self(value as u32)
+    }
+}
+
+impl<const EC: u8, const ED: u8> From<&ElfSectionHeaderTypeLLVM> for ElfWord<EC, ED> {
+    fn from(value: &ElfSectionHeaderTypeLLVM) -> Self {
+        Self(*value as u32)
+    }
+}
+
+impl<const EC: u8, const ED: u8> TryFromWithConfig<ElfWord<EC, ED>> for ElfSectionHeaderTypeLLVM {
+    type Error = Error;
+
+    /// Decode an LLVM section header type. Unlike GNU types, these are not checked
+    /// against the OS ABI, because LLVM emits them for every OS it targets.
+    fn try_from_with(
+        value: ElfWord<EC, ED>,
+        config: &mut crate::Config,
+    ) -> Result<Self, Self::Error> {
+        match value.0 {
+            Self::ODRTAB => Ok(Self::OdrTab),
+            Self::LINKER_OPTIONS => Ok(Self::LinkerOptions),
+            Self::ADDRSIG => Ok(Self::Addrsig),
+            Self::DEPENDENT_LIBRARIES => Ok(Self::DependentLibraries),
+            Self::SYMPART => Ok(Self::SymPart),
+            Self::PART_EHDR => Ok(Self::PartEhdr),
+            Self::PART_PHDR => Ok(Self::PartPhdr),
+            Self::BB_ADDR_MAP_V0 => Ok(Self::BbAddrMapV0),
+            Self::CALL_GRAPH_PROFILE => Ok(Self::CallGraphProfile),
+            Self::BB_ADDR_MAP => Ok(Self::BbAddrMap),
+            Self::OFFLOADING => Ok(Self::Offloading),
+            Self::LTO => Ok(Self::Lto),
+            _ => Err(Error::InvalidSectionHeaderType {
+                machine: config.machine,
+                value: value.0,
+            }),
+        }
+    }
+}
+
+impl<const EC: u8, const ED: u8> Elf<EC, ED> {
+    /// Read the indices of the address-significant symbols from the first
+    /// `SHT_LLVM_ADDRSIG` section, which index the symbol table linked to the section.
+    /// These are the symbols whose addresses are taken, so identical code folding must
+    /// not merge them. The indices are held as a sequence of ULEB128 values, and a value
+    /// truncated by the end of the section is dropped. Objects not built by LLVM have no
+    /// such section, in which case no indices are returned.
+    pub fn address_significant_symbols<R>(&self, reader: &mut R) -> Result<Vec<u64>, Error>
+    where
+        R: Read + Seek,
+    {
+        let Some(index) = self.section_position(|header| {
+            header.r#type() == ElfSectionHeaderType::Llvm(ElfSectionHeaderTypeLLVM::Addrsig)
+        }) else {
+            return Ok(Vec::new());
+        };
+
+        let mut indices = Vec::new();
+        let mut value = 0u64;
+        let mut shift = 0u32;
+
+        for byte in self.section_data(reader, index)? {
+            value |= ((byte & 0x7f) as u64).checked_shl(shift).unwrap_or(0);
+            shift = shift.saturating_add(7);
+
+            if byte & 0x80 == 0 {
+                indices.push(value);
+                value = 0;
+                shift = 0;
+            }
+        }
+
+        Ok(indices)
+    }
+}
@@ -1,4 +1,7 @@
 //! OS-specific definitions
 
 pub mod gnu;
+pub mod linux;
+pub mod llvm;
+pub mod openbsd;
 pub mod sun;
@@ -0,0 +1,101 @@
+//! OpenBSD-specific definitions
+
+use crate::{
+    base::ElfWord, error::Error, header::elf::identification::ElfOSABI, TryFromWithConfig,
+};
+use alloc::vec;
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+/// Program Header Types
+pub enum ElfProgramHeaderTypeOpenBSD {
+    /// Data which the loader makes immutable only after the program has initialized it
+    Mutable = Self::MUTABLE,
+    /// Data which the loader fills with random bytes
+    Randomize = Self::RANDOMIZE,
+    /// The program needs writable and executable mappings
+    WxNeeded = Self::WXNEEDED,
+    /// The program does not enforce branch target control flow integrity
+    NoBtCfi = Self::NOBTCFI,
+    /// The table of system call entry points the program is allowed to use
+    Syscalls = Self::SYSCALLS,
+    /// Data passed to the program by the boot loader
+    BootData = Self::BOOTDATA,
+}
+
+impl ElfProgramHeaderTypeOpenBSD {
+    /// Constant value for [ElfProgramHeaderTypeOpenBSD::Mutable]
+    pub const MUTABLE: u32 = 0x65a3dbe5;
+    /// Constant value for [ElfProgramHeaderTypeOpenBSD::Randomize]
+    pub const RANDOMIZE: u32 = 0x65a3dbe6;
+    /// Constant value for [ElfProgramHeaderTypeOpenBSD::WxNeeded]
+    pub const WXNEEDED: u32 = 0x65a3dbe7;
+    /// Constant value for [ElfProgramHeaderTypeOpenBSD::NoBtCfi]
+    pub const NOBTCFI: u32 = 0x65a3dbe8;
+    /// Constant value for [ElfProgramHeaderTypeOpenBSD::Syscalls]
+    pub const SYSCALLS: u32 = 0x65a3dbe9;
+    /// Constant value for [ElfProgramHeaderTypeOpenBSD::BootData]
+    pub const BOOTDATA: u32 = 0x65a41be6;
+}
+
+impl core::fmt::Display for ElfProgramHeaderTypeOpenBSD {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Mutable => "OPENBSD_MUTABLE",
+            Self::Randomize => "OPENBSD_RANDOMIZE",
+            Self::WxNeeded => "OPENBSD_WXNEEDED",
+            Self::NoBtCfi => "OPENBSD_NOBTCFI",
+            Self::Syscalls => "OPENBSD_SYSCALLS",
+            Self::BootData => "OPENBSD_BOOTDATA",
+        })
+    }
+}
+
+impl<const EC: u8, const ED: u8> From<ElfProgramHeaderTypeOpenBSD> for ElfWord<EC, ED> {
+    fn from(value: ElfProgramHeaderTypeOpenBSD) -> Self {
+        Self(value as u32)
+    }
+}
+
+impl<const EC: u8, const ED: u8> From<&ElfProgramHeaderTypeOpenBSD> for ElfWord<EC, ED> {
+    fn from(value: &ElfProgramHeaderTypeOpenBSD) -> Self {
+        Self(*value as u32)
+    }
+}
+
+impl<const EC: u8, const ED: u8> TryFromWithConfig<ElfWord<EC, ED>>
+    for ElfProgramHeaderTypeOpenBSD
+{
+    type Error = Error;
+
+    fn try_from_with(
+        value: ElfWord<EC, ED>,
+        config: &mut crate::Config,
+    ) -> Result<Self, Self::Error> {
+        // The OpenBSD toolchain leaves EI_OSABI as ELFOSABI_NONE
+        if !matches!(
+            config.os_abi,
+            Some(ElfOSABI::OpenBSD | ElfOSABI::NoneSystemV)
+        ) {
+            return Err(Error::InvalidOsAbiForProgramHeaderType {
+                os_abi: config.os_abi,
+                expected_os_abis: vec![ElfOSABI::OpenBSD, ElfOSABI::NoneSystemV],
+                value: value.0,
+            });
+        }
+
+        match value.0 {
+            Self::MUTABLE => Ok(Self::Mutable),
+            Self::RANDOMIZE => Ok(Self::Randomize),
+            Self::WXNEEDED => Ok(Self::WxNeeded),
+            Self::NOBTCFI => Ok(Self::NoBtCfi),
+            Self::SYSCALLS => Ok(Self::Syscalls),
+            Self::BOOTDATA => Ok(Self::BootData),
+            _ => Err(Error::InvalidProgramHeaderType {
+                machine: config.machine,
+                value: value.0,
+            }),
+        }
+    }
+}
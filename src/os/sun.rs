@@ -3,12 +3,35 @@
 use crate::{
     base::ElfWord, error::Error, header::elf::identification::ElfOSABI, TryFromWithConfig,
 };
+use alloc::vec;
 
 #[repr(u32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[non_exhaustive]
 /// Section Header Types
 pub enum ElfSectionHeaderTypeSUN {
+    /// Ancillary information for capabilities and symbols
+    Ancillary = Self::ANCILLARY,
+    /// Capability chain for symbol capabilities
+    CapChain = Self::CAPCHAIN,
+    /// Capability information for symbol capabilities
+    CapInfo = Self::CAPINFO,
+    /// Sorted array of indices of regular symbols in the symbol tables
+    SymSort = Self::SYMSORT,
+    /// Sorted array of indices of thread local symbols in the symbol tables
+    TlsSort = Self::TLSSORT,
+    /// Local symbols which augment the dynamic symbol table
+    LocalDynamicSymbol = Self::LDYNSYM,
+    /// DTrace object format data
+    Dof = Self::DOF,
+    /// Hardware and software capability requirements
+    Cap = Self::CAP,
+    /// Move entries for initializing partially initialized data
+    Move = Self::MOVE,
+    /// COMDAT section, of which only one copy is kept when linking
+    Comdat = Self::COMDAT,
+    /// Additional symbol information
+    SymInfo = Self::SYMINFO,
     /// Versions defined by file
     VerDef = Self::VERDEF,
     /// Versions needed by file
@@ -18,6 +41,28 @@ pub enum ElfSectionHeaderTypeSUN {
 }
 
 impl ElfSectionHeaderTypeSUN {
+    /// Ancillary information for capabilities and symbols
+    pub const ANCILLARY: u32 = 0x6fffffee;
+    /// Capability chain for symbol capabilities
+    pub const CAPCHAIN: u32 = 0x6fffffef;
+    /// Capability information for symbol capabilities
+    pub const CAPINFO: u32 = 0x6ffffff0;
+    /// Sorted array of indices of regular symbols in the symbol tables
+    pub const SYMSORT: u32 = 0x6ffffff1;
+    /// Sorted array of indices of thread local symbols in the symbol tables
+    pub const TLSSORT: u32 = 0x6ffffff2;
+    /// Local symbols which augment the dynamic symbol table
+    pub const LDYNSYM: u32 = 0x6ffffff3;
+    /// DTrace object format data
+    pub const DOF: u32 = 0x6ffffff4;
+    /// Hardware and software capability requirements
+    pub const CAP: u32 = 0x6ffffff5;
+    /// Move entries for initializing partially initialized data
+    pub const MOVE: u32 = 0x6ffffffa;
+    /// COMDAT section, of which only one copy is kept when linking
+    pub const COMDAT: u32 = 0x6ffffffb;
+    /// Additional symbol information
+    pub const SYMINFO: u32 = 0x6ffffffc;
     /// Versions defined by file
     pub const VERDEF: u32 = 0x6ffffffd;
     /// Versions needed by file
@@ -26,6 +71,27 @@ impl ElfSectionHeaderTypeSUN {
     pub const VERSYM: u32 = 0x6fffffff;
 }
 
+impl core::fmt::Display for ElfSectionHeaderTypeSUN {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Ancillary => "SUNW_ancillary",
+            Self::CapChain => "SUNW_capchain",
+            Self::CapInfo => "SUNW_capinfo",
+            Self::SymSort => "SUNW_symsort",
+            Self::TlsSort => "SUNW_tlssort",
+            Self::LocalDynamicSymbol => "SUNW_LDYNSYM",
+            Self::Dof => "SUNW_dof",
+            Self::Cap => "SUNW_cap",
+            Self::Move => "SUNW_move",
+            Self::Comdat => "SUNW_COMDAT",
+            Self::SymInfo => "SUNW_syminfo",
+            Self::VerDef => "SUNW_verdef",
+            Self::VerNeed => "SUNW_verneed",
+            Self::VerSym => "SUNW_versym",
+        })
+    }
+}
+
 impl<const EC: u8, const ED: u8> From<ElfSectionHeaderTypeSUN> for ElfWord<EC, ED> {
     fn from(value: ElfSectionHeaderTypeSUN) -> Self {
         Self(value as u32)
@@ -54,6 +120,17 @@ impl<const EC: u8, const ED: u8> TryFromWithConfig<ElfWord<EC, ED>> for ElfSecti
         }
 
         match value.0 {
+            Self::ANCILLARY => Ok(Self::Ancillary),
+            Self::CAPCHAIN => Ok(Self::CapChain),
+            Self::CAPINFO => Ok(Self::CapInfo),
+            Self::SYMSORT => Ok(Self::SymSort),
+            Self::TLSSORT => Ok(Self::TlsSort),
+            Self::LDYNSYM => Ok(Self::LocalDynamicSymbol),
+            Self::DOF => Ok(Self::Dof),
+            Self::CAP => Ok(Self::Cap),
+            Self::MOVE => Ok(Self::Move),
+            Self::COMDAT => Ok(Self::Comdat),
+            Self::SYMINFO => Ok(Self::SymInfo),
             Self::VERDEF => Ok(Self::VerDef),
             Self::VERNEED => Ok(Self::VerNeed),
             Self::VERSYM => Ok(Self::VerSym),
@@ -64,3 +141,68 @@ impl<const EC: u8, const ED: u8> TryFromWithConfig<ElfWord<EC, ED>> for ElfSecti
         }
     }
 }
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+/// Program Header Types
+pub enum ElfProgramHeaderTypeSUN {
+    /// Sun-specific BSS segment
+    Bss = Self::BSS,
+    /// Stack segment
+    Stack = Self::STACK,
+}
+
+impl ElfProgramHeaderTypeSUN {
+    /// Constant value for [ElfProgramHeaderTypeSUN::Bss]
+    pub const BSS: u32 = 0x6ffffffa;
+    /// Constant value for [ElfProgramHeaderTypeSUN::Stack]
+    pub const STACK: u32 = 0x6ffffffb;
+}
+
+impl core::fmt::Display for ElfProgramHeaderTypeSUN {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Bss => "SUNWBSS",
+            Self::Stack => "SUNWSTACK",
+        })
+    }
+}
+
+impl<const EC: u8, const ED: u8> From<ElfProgramHeaderTypeSUN> for ElfWord<EC, ED> {
+    fn from(value: ElfProgramHeaderTypeSUN) -> Self {
+        Self(value as u32)
+    }
+}
+
+impl<const EC: u8, const ED: u8> From<&ElfProgramHeaderTypeSUN> for ElfWord<EC, ED> {
+    fn from(value: &ElfProgramHeaderTypeSUN) -> Self {
+        Self(*value as u32)
+    }
+}
+
+impl<const EC: u8, const ED: u8> TryFromWithConfig<ElfWord<EC, ED>> for ElfProgramHeaderTypeSUN {
+    type Error = Error;
+
+    fn try_from_with(
+        value: ElfWord<EC, ED>,
+        config: &mut crate::Config,
+    ) -> Result<Self, Self::Error> {
+        if !matches!(config.os_abi, Some(ElfOSABI::Solaris)) {
+            return Err(Error::InvalidOsAbiForProgramHeaderType {
+                os_abi: config.os_abi,
+                expected_os_abis: vec![ElfOSABI::Solaris],
+                value: value.0,
+            });
+        }
+
+        match value.0 {
+            Self::BSS => Ok(Self::Bss),
+            Self::STACK => Ok(Self::Stack),
+            _ => Err(Error::InvalidProgramHeaderType {
+                machine: config.machine,
+                value: value.0,
+            }),
+        }
+    }
+}
@@ -0,0 +1,179 @@
+//! Inference of the toolchain which produced an ELF object from the names of its
+//! sections, its notes, and the mangling of its symbols
+
+use alloc::string::{String, ToString};
+
+use crate::{
+    error::Error,
+    go::GoBuildInfo,
+    io::{Read, Seek},
+    Elf,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// The part of an object which identified the toolchain that produced it
+pub enum ProducerEvidence {
+    /// A section with this name
+    Section(String),
+    /// A note with this owner and type
+    Note {
+        /// The name of the owner of the note
+        name: String,
+        /// The type of the note
+        r#type: u32,
+    },
+    /// A symbol with this name
+    Symbol(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// The toolchain which produced an object, along with the evidence it was inferred from
+pub enum Producer {
+    /// The Go toolchain, either `gc` or `gccgo`
+    Go(ProducerEvidence),
+    /// The Rust compiler
+    Rust(ProducerEvidence),
+    /// The Swift compiler
+    Swift(ProducerEvidence),
+    /// A C++ compiler using the Itanium C++ ABI mangling
+    Cpp(ProducerEvidence),
+    /// No toolchain could be identified, as is the case for C objects and stripped
+    /// objects without identifying sections
+    Unknown,
+}
+
+impl Producer {
+    /// The evidence the producer was inferred from, if one was identified
+    pub fn evidence(&self) -> Option<&ProducerEvidence> {
+        match self {
+            Producer::Go(evidence)
+            | Producer::Rust(evidence)
+            | Producer::Swift(evidence)
+            | Producer::Cpp(evidence) => Some(evidence),
+            Producer::Unknown => None,
+        }
+    }
+}
+
+/// Whether `name` is a symbol mangled with the legacy Rust scheme, which is the Itanium
+/// C++ scheme with a trailing `17h<hash>E` path component holding a 16 digit hash
+pub(crate) fn is_rust_legacy_symbol(name: &str) -> bool {
+    // Symbols of local functions which LLVM has internalized have a `.llvm.<hash>` suffix
+    let name = name.split('.').next().unwrap_or(name);
+
+    name.starts_with("_ZN")
+        && name
+            .strip_suffix('E')
+            .and_then(|name| name.get(name.len().checked_sub(19)?..))
+            .and_then(|hash| hash.strip_prefix("17h"))
+            .is_some_and(|hash| hash.bytes().all(|b| b.is_ascii_hexdigit()))
+}
+
+/// Whether `name` is a symbol mangled with the Rust v0 scheme
+pub(crate) fn is_rust_v0_symbol(name: &str) -> bool {
+    name.strip_prefix("_R")
+        .and_then(|name| name.bytes().next())
+        .is_some_and(|b| b.is_ascii_uppercase())
+}
+
+/// Whether `name` is a symbol mangled with the Itanium C++ scheme
+pub(crate) fn is_cpp_symbol(name: &str) -> bool {
+    name.strip_prefix("_Z")
+        .and_then(|name| name.bytes().next())
+        .is_some_and(|b| b.is_ascii_alphanumeric())
+}
+
+impl<const EC: u8, const ED: u8> Elf<EC, ED> {
+    /// Infer the toolchain which produced the object. Go is identified by its
+    /// `.gopclntab`, `.go.buildinfo`, `.go_export`, or `.note.go.buildid` sections or by
+    /// its build ID note, Swift by its `swift` metadata sections, Rust by its `.rustc`
+    /// section or by Rust-mangled symbols, and C++ by Itanium-mangled symbols. The
+    /// checks are made in that order, because Go, Swift, and Rust objects frequently
+    /// link C++ code, and the legacy Rust mangling is a form of the C++ mangling.
+    pub fn detect_producer<R>(&self, reader: &mut R) -> Result<Producer, Error>
+    where
+        R: Read + Seek,
+    {
+        let section = |matches: fn(&str) -> bool| {
+            self.section_headers
+                .iter()
+                .map(|header| header.name())
+                .find(|name| matches(name))
+                .map(|name| ProducerEvidence::Section(name.to_string()))
+        };
+
+        if let Some(evidence) = section(|name| {
+            matches!(
+                name,
+                ".gopclntab" | ".go.buildinfo" | ".go_export" | ".note.go.buildid"
+            )
+        }) {
+            return Ok(Producer::Go(evidence));
+        }
+
+        if let Some(note) = self.notes(reader)?.into_iter().find(|note| {
+            note.name == GoBuildInfo::NOTE_NAME && note.r#type == GoBuildInfo::NOTE_TYPE_BUILD_ID
+        }) {
+            return Ok(Producer::Go(ProducerEvidence::Note {
+                name: note.name,
+                r#type: note.r#type,
+            }));
+        }
+
+        if let Some(evidence) = section(|name| {
+            name.trim_start_matches('.').starts_with("swift")
+                || name.trim_start_matches('.').starts_with("__swift")
+        }) {
+            return Ok(Producer::Swift(evidence));
+        }
+
+        if let Some(evidence) = section(|name| name == ".rustc") {
+            return Ok(Producer::Rust(evidence));
+        }
+
+        let mut symbols = self.symbols(reader)?;
+        symbols.extend(self.dynamic_symbols(reader)?);
+
+        let symbol = |matches: fn(&str) -> bool| {
+            symbols
+                .iter()
+                .map(|symbol| symbol.name())
+                .find(|name| matches(name))
+                .map(|name| ProducerEvidence::Symbol(name.to_string()))
+        };
+
+        if let Some(evidence) =
+            symbol(|name| is_rust_legacy_symbol(name) || is_rust_v0_symbol(name))
+        {
+            return Ok(Producer::Rust(evidence));
+        }
+
+        if let Some(evidence) = symbol(is_cpp_symbol) {
+            return Ok(Producer::Cpp(evidence));
+        }
+
+        Ok(Producer::Unknown)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{is_cpp_symbol, is_rust_legacy_symbol, is_rust_v0_symbol};
+
+    #[test]
+    fn test_symbol_manglings() {
+        assert!(is_rust_legacy_symbol(
+            "_ZN8compound4main17he5c7eb201f3a6692E"
+        ));
+        assert!(is_rust_legacy_symbol(
+            "_ZN4core3fmt5write17h0123456789abcdefE.llvm.1234"
+        ));
+        assert!(!is_rust_legacy_symbol("_ZN3foo3barEv"));
+        assert!(!is_rust_legacy_symbol("_ZN3foo17hxyzE"));
+        assert!(is_rust_v0_symbol("_RNvCs1234_7mycrate3foo"));
+        assert!(!is_rust_v0_symbol("_Rfoo"));
+        assert!(is_cpp_symbol("_ZNSt8ios_base4InitC1Ev"));
+        assert!(!is_cpp_symbol("_Z"));
+        assert!(!is_cpp_symbol("main"));
+    }
+}
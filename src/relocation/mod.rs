@@ -0,0 +1,921 @@
+//! Implementation of ELF relocation entries, which are held in `SHT_REL`, `SHT_RELA`,
+//! and `SHT_RELR` sections
+
+use alloc::{string::String, vec::Vec};
+use num_traits::FromPrimitive;
+use typed_builder::TypedBuilder;
+
+#[cfg(feature = "arch")]
+use crate::{
+    arch::{
+        aarch64::ElfRelocationTypeAARCH64, arm32::ElfRelocationTypeARM32,
+        x86_64::ElfRelocationTypeX86_64,
+    },
+    TryFromWithConfig,
+};
+use crate::{
+    base::{ElfAddress, ElfByte, ElfExtendedWord, ElfSignedExtendedWord, ElfSignedWord, ElfWord},
+    dynamic::ElfDynamicTag,
+    error::Error,
+    header::{
+        elf::{
+            identification::{
+                ElfClass, ElfDataEncoding, ELF_CLASS_DEFAULT, ELF_DATA_ENCODING_DEFAULT,
+            },
+            ElfMachine,
+        },
+        program::ElfProgramHeaderType,
+        section::{ElfSectionHeaderFlags, ElfSectionHeaderType, ElfSectionIndex, SectionIndex},
+    },
+    io::{Cursor, Read, Seek, SeekFrom, Write},
+    symbol::{ElfSymbol, ElfSymbolType},
+    Config, Elf, FromReader, HasWrittenSize, ToWriter,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, TypedBuilder)]
+/// ELF 32-bit Relocation Entry
+pub struct Elf32Relocation<const ED: u8> {
+    /// The location at which to apply the relocation. For a relocatable file, this is
+    /// the offset from the beginning of the section to the storage unit affected by
+    /// the relocation. For an executable or shared object, this is the virtual address
+    /// of the storage unit affected by the relocation.
+    pub offset: ElfAddress<{ ElfClass::Elf32 as u8 }, ED>,
+    /// The symbol table index with respect to which the relocation must be made, and
+    /// the type of relocation to apply
+    pub info: ElfWord<{ ElfClass::Elf32 as u8 }, ED>,
+    #[builder(default)]
+    /// The constant addend used to compute the value to be stored into the relocatable
+    /// field. This is only present for entries in `SHT_RELA` sections; entries in
+    /// `SHT_REL` sections hold the addend in the location to be modified.
+    pub addend: Option<ElfSignedWord<{ ElfClass::Elf32 as u8 }, ED>>,
+}
+
+impl<R, const ED: u8> FromReader<R> for Elf32Relocation<ED>
+where
+    R: Read + Seek,
+{
+    type Error = Error;
+
+    /// Read a relocation entry without an addend. The addend of an entry in a
+    /// `SHT_RELA` section is read separately.
+    fn from_reader_with(reader: &mut R, config: &mut Config) -> Result<Self, Self::Error> {
+        let offset = ElfAddress::<{ ElfClass::Elf32 as u8 }, ED>::from_reader_with(reader, config)?;
+        let info = ElfWord::<{ ElfClass::Elf32 as u8 }, ED>::from_reader_with(reader, config)?;
+
+        Ok(Self {
+            offset,
+            info,
+            addend: None,
+        })
+    }
+}
+
+impl<W, const ED: u8> ToWriter<W> for Elf32Relocation<ED>
+where
+    W: Write,
+{
+    type Error = Error;
+
+    /// Write the relocation entry, followed by its addend if it has one
+    fn to_writer(&self, writer: &mut W) -> Result<(), Self::Error> {
+        self.offset.to_writer(writer)?;
+        self.info.to_writer(writer)?;
+        if let Some(addend) = self.addend {
+            addend.to_writer(writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<const ED: u8> HasWrittenSize for Elf32Relocation<ED> {
+    /// The size of an entry without an addend, as held in a `SHT_REL` section. An entry
+    /// with an addend, as held in a `SHT_RELA` section, is followed by a signed word.
+    const SIZE: usize = ElfAddress::<{ ElfClass::Elf32 as u8 }, ED>::SIZE
+        + ElfWord::<{ ElfClass::Elf32 as u8 }, ED>::SIZE;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, TypedBuilder)]
+/// ELF 64-bit Relocation Entry
+pub struct Elf64Relocation<const ED: u8> {
+    /// The location at which to apply the relocation. For a relocatable file, this is
+    /// the offset from the beginning of the section to the storage unit affected by
+    /// the relocation. For an executable or shared object, this is the virtual address
+    /// of the storage unit affected by the relocation.
+    pub offset: ElfAddress<{ ElfClass::Elf64 as u8 }, ED>,
+    /// The symbol table index with respect to which the relocation must be made, and
+    /// the type of relocation to apply. For MIPS64 entries, this is held in the big
+    /// endian layout regardless of the data encoding, which places the symbol index in
+    /// the upper 32 bits as for other machines.
+    pub info: ElfExtendedWord<{ ElfClass::Elf64 as u8 }, ED>,
+    #[builder(default)]
+    /// The constant addend used to compute the value to be stored into the relocatable
+    /// field. This is only present for entries in `SHT_RELA` sections; entries in
+    /// `SHT_REL` sections hold the addend in the location to be modified.
+    pub addend: Option<ElfSignedExtendedWord<{ ElfClass::Elf64 as u8 }, ED>>,
+    #[builder(default)]
+    /// Whether `info` holds a MIPS64 symbol index, special symbol, and three relocation
+    /// types. This is set when the entry is read with the machine configured as MIPS,
+    /// and is not part of the relocation entry itself.
+    pub mips64: bool,
+}
+
+impl<R, const ED: u8> FromReader<R> for Elf64Relocation<ED>
+where
+    R: Read + Seek,
+{
+    type Error = Error;
+
+    /// Read a relocation entry without an addend. The addend of an entry in a
+    /// `SHT_RELA` section is read separately.
+    fn from_reader_with(reader: &mut R, config: &mut Config) -> Result<Self, Self::Error> {
+        let offset = ElfAddress::<{ ElfClass::Elf64 as u8 }, ED>::from_reader_with(reader, config)?;
+        let mut info =
+            ElfExtendedWord::<{ ElfClass::Elf64 as u8 }, ED>::from_reader_with(reader, config)?;
+        let mips64 = matches!(config.machine, Some(ElfMachine::MIPS));
+
+        // MIPS64 stores r_info as a 32-bit symbol index followed by four bytes, so in
+        // little endian objects the bytes following the symbol index are reversed
+        if mips64 && ED == ElfDataEncoding::ELF_DATA_ENCODING_LITTLE_ENDIAN {
+            info.0 = info.0 << 32 | ((info.0 >> 32) as u32).swap_bytes() as u64;
+        }
+
+        Ok(Self {
+            offset,
+            info,
+            addend: None,
+            mips64,
+        })
+    }
+}
+
+impl<W, const ED: u8> ToWriter<W> for Elf64Relocation<ED>
+where
+    W: Write,
+{
+    type Error = Error;
+
+    /// Write the relocation entry, followed by its addend if it has one
+    fn to_writer(&self, writer: &mut W) -> Result<(), Self::Error> {
+        let mut info = self.info;
+
+        // Reverse the reordering of the bytes following the symbol index when the
+        // entry was read
+        if self.mips64 && ED == ElfDataEncoding::ELF_DATA_ENCODING_LITTLE_ENDIAN {
+            info.0 = ((info.0 as u32).swap_bytes() as u64) << 32 | info.0 >> 32;
+        }
+
+        self.offset.to_writer(writer)?;
+        info.to_writer(writer)?;
+        if let Some(addend) = self.addend {
+            addend.to_writer(writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<const ED: u8> HasWrittenSize for Elf64Relocation<ED> {
+    /// The size of an entry without an addend, as held in a `SHT_REL` section. An entry
+    /// with an addend, as held in a `SHT_RELA` section, is followed by a signed extended
+    /// word.
+    const SIZE: usize = ElfAddress::<{ ElfClass::Elf64 as u8 }, ED>::SIZE
+        + ElfExtendedWord::<{ ElfClass::Elf64 as u8 }, ED>::SIZE;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// The fields of the `r_info` member of a MIPS64 relocation entry. Each entry describes
+/// up to three relocation operations, which are applied in order to the same location,
+/// each using the result of the previous operation as its addend.
+pub struct Mips64RelocationInfo {
+    /// The index of the symbol the relocation is made with respect to
+    pub symbol_index: u32,
+    /// The special symbol used by the second or third relocation type (`RSS_*`)
+    pub special_symbol: u8,
+    /// The first relocation type
+    pub r#type: u8,
+    /// The second relocation type, or `R_MIPS_NONE`
+    pub type2: u8,
+    /// The third relocation type, or `R_MIPS_NONE`
+    pub type3: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// ELF relocation entry for either 32-bit or 64-bit ELF files
+pub enum ElfRelocation<const EC: u8, const ED: u8> {
+    /// A 32-bit ELF relocation entry
+    Elf32(Elf32Relocation<ED>),
+    /// A 64-bit ELF relocation entry
+    Elf64(Elf64Relocation<ED>),
+}
+
+impl<const EC: u8, const ED: u8> ElfRelocation<EC, ED> {
+    /// The location at which to apply the relocation
+    pub fn offset(&self) -> u64 {
+        match self {
+            ElfRelocation::Elf32(relocation) => relocation.offset.0,
+            ElfRelocation::Elf64(relocation) => relocation.offset.0,
+        }
+    }
+
+    /// The raw symbol table index and type of the relocation
+    pub fn info(&self) -> u64 {
+        match self {
+            ElfRelocation::Elf32(relocation) => relocation.info.0 as u64,
+            ElfRelocation::Elf64(relocation) => relocation.info.0,
+        }
+    }
+
+    /// The index in the associated symbol table of the symbol the relocation is made
+    /// with respect to, or 0 if the relocation uses no symbol
+    pub fn symbol_index(&self) -> u32 {
+        match self {
+            ElfRelocation::Elf32(relocation) => relocation.info.0 >> 8,
+            ElfRelocation::Elf64(relocation) => (relocation.info.0 >> 32) as u32,
+        }
+    }
+
+    /// The processor-specific type of the relocation. For MIPS64 entries, this is the
+    /// first of the three relocation types.
+    pub fn r#type(&self) -> u32 {
+        match self {
+            ElfRelocation::Elf32(relocation) => relocation.info.0 & 0xff,
+            ElfRelocation::Elf64(relocation) if relocation.mips64 => {
+                relocation.info.0 as u32 & 0xff
+            }
+            ElfRelocation::Elf64(relocation) => relocation.info.0 as u32,
+        }
+    }
+
+    /// The type of the relocation, decoded for the machine configured in `config`. The
+    /// type is kept as its raw value for machines without named relocation types.
+    #[cfg(feature = "arch")]
+    pub fn decoded_type(&self, config: &mut Config) -> Result<ElfRelocationType, Error> {
+        ElfRelocationType::try_from_with(self.r#type(), config)
+    }
+
+    /// The fields of a MIPS64 relocation entry's `r_info`, or `None` if the entry was
+    /// not read as a MIPS64 entry
+    pub fn mips64_info(&self) -> Option<Mips64RelocationInfo> {
+        match self {
+            ElfRelocation::Elf64(relocation) if relocation.mips64 => {
+                let [_, _, _, _, special_symbol, type3, type2, r#type] =
+                    relocation.info.0.to_be_bytes();
+                Some(Mips64RelocationInfo {
+                    symbol_index: (relocation.info.0 >> 32) as u32,
+                    special_symbol,
+                    r#type,
+                    type2,
+                    type3,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// The explicit addend of the relocation, or `None` if the relocation is from a
+    /// `SHT_REL` section and its addend is held in the location to be modified
+    pub fn addend(&self) -> Option<i64> {
+        match self {
+            ElfRelocation::Elf32(relocation) => relocation.addend.map(|addend| addend.0 as i64),
+            ElfRelocation::Elf64(relocation) => relocation.addend.map(|addend| addend.0),
+        }
+    }
+
+    /// Read a relocation entry, and its addend if `explicit` is set
+    fn from_reader_with<R>(
+        reader: &mut R,
+        config: &mut Config,
+        explicit: bool,
+    ) -> Result<Self, Error>
+    where
+        R: Read + Seek,
+    {
+        Ok(
+            match ElfClass::from_u8(EC).ok_or(Error::InvalidClass { class: ElfByte(EC) })? {
+                ElfClass::None => return Err(Error::InvalidClass { class: ElfByte(EC) }),
+                ElfClass::Elf32 => {
+                    let mut relocation = Elf32Relocation::from_reader_with(reader, config)?;
+                    if explicit {
+                        relocation.addend = Some(ElfSignedWord::from_reader_with(reader, config)?);
+                    }
+                    ElfRelocation::Elf32(relocation)
+                }
+                ElfClass::Elf64 => {
+                    let mut relocation = Elf64Relocation::from_reader_with(reader, config)?;
+                    if explicit {
+                        relocation.addend =
+                            Some(ElfSignedExtendedWord::from_reader_with(reader, config)?);
+                    }
+                    ElfRelocation::Elf64(relocation)
+                }
+            },
+        )
+    }
+}
+
+impl<W, const EC: u8, const ED: u8> ToWriter<W> for ElfRelocation<EC, ED>
+where
+    W: Write,
+{
+    type Error = Error;
+
+    fn to_writer(&self, writer: &mut W) -> Result<(), Self::Error> {
+        match self {
+            ElfRelocation::Elf32(relocation) => relocation.to_writer(writer),
+            ElfRelocation::Elf64(relocation) => relocation.to_writer(writer),
+        }
+    }
+}
+
+impl<const EC: u8, const ED: u8> HasWrittenSize for ElfRelocation<EC, ED> {
+    /// The size of an entry without an addend, as held in a `SHT_REL` section
+    const SIZE: usize = match EC {
+        ElfClass::ELF_CLASS_64 => Elf64Relocation::<ED>::SIZE,
+        _ => Elf32Relocation::<ED>::SIZE,
+    };
+}
+
+#[cfg(feature = "arch")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+/// The type of a relocation entry, decoded for the machine the object is for
+pub enum ElfRelocationType {
+    /// A relocation type for AARCH64
+    AARCH64(ElfRelocationTypeAARCH64),
+    /// A relocation type for ARM32
+    ARM32(ElfRelocationTypeARM32),
+    /// A relocation type for x86_64
+    X86_64(ElfRelocationTypeX86_64),
+    /// The raw relocation type for a machine without named relocation types
+    Other(u32),
+}
+
+#[cfg(feature = "arch")]
+impl core::fmt::Display for ElfRelocationType {
+    /// Display the relocation type as its name in the ABI, or as its raw value for a
+    /// machine without named relocation types
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::AARCH64(r#type) => r#type.fmt(f),
+            Self::ARM32(r#type) => r#type.fmt(f),
+            Self::X86_64(r#type) => r#type.fmt(f),
+            Self::Other(r#type) => write!(f, "{type:#x}"),
+        }
+    }
+}
+
+#[cfg(feature = "arch")]
+impl From<ElfRelocationType> for u32 {
+    fn from(value: ElfRelocationType) -> Self {
+        match value {
+            ElfRelocationType::AARCH64(r#type) => r#type.into(),
+            ElfRelocationType::ARM32(r#type) => r#type.into(),
+            ElfRelocationType::X86_64(r#type) => r#type.into(),
+            ElfRelocationType::Other(r#type) => r#type,
+        }
+    }
+}
+
+#[cfg(feature = "arch")]
+impl TryFromWithConfig<u32> for ElfRelocationType {
+    type Error = Error;
+
+    /// Decode the type of a relocation entry, as returned by `ElfRelocation::r#type`,
+    /// using the relocation types of the machine configured in `config`
+    fn try_from_with(value: u32, config: &mut Config) -> Result<Self, Self::Error> {
+        match config.machine {
+            Some(ElfMachine::AARCH64) => Ok(Self::AARCH64(
+                ElfRelocationTypeAARCH64::try_from_with(value, config)?,
+            )),
+            Some(ElfMachine::ARM) => Ok(Self::ARM32(ElfRelocationTypeARM32::try_from_with(
+                value, config,
+            )?)),
+            Some(ElfMachine::X86_64) => Ok(Self::X86_64(ElfRelocationTypeX86_64::try_from_with(
+                value, config,
+            )?)),
+            _ => Ok(Self::Other(value)),
+        }
+    }
+}
+
+/// The type of the relative relocation for `machine`, which adjusts an address by the
+/// load base of the object, or `None` if relative relocations are not supported for
+/// the machine. Type 0 is the null relocation on every supported machine.
+fn relative_type<const EC: u8, const ED: u8>(machine: ElfMachine<EC, ED>) -> Option<u32> {
+    match machine {
+        #[cfg(feature = "arch")]
+        ElfMachine::X86_64 => Some(ElfRelocationTypeX86_64::RELATIVE),
+        #[cfg(feature = "arch")]
+        ElfMachine::AARCH64 => Some(ElfRelocationTypeAARCH64::RELATIVE),
+        #[cfg(feature = "arch")]
+        ElfMachine::ARM => Some(ElfRelocationTypeARM32::RELATIVE),
+        // R_X86_64_RELATIVE, R_AARCH64_RELATIVE, and R_ARM_RELATIVE, whose named types
+        // are only available with the `arch` feature
+        #[cfg(not(feature = "arch"))]
+        ElfMachine::X86_64 => Some(8),
+        #[cfg(not(feature = "arch"))]
+        ElfMachine::AARCH64 => Some(1027),
+        #[cfg(not(feature = "arch"))]
+        ElfMachine::ARM => Some(23),
+        // R_386_RELATIVE
+        ElfMachine::I386 => Some(8),
+        // R_RISCV_RELATIVE
+        ElfMachine::Riscv => Some(3),
+        _ => None,
+    }
+}
+
+/// The sizes of the resolver stub at the start of the procedure linkage table and of
+/// each entry after it for `machine`, or `None` if the layout of the table is not known
+/// for the machine
+fn plt_layout<const EC: u8, const ED: u8>(machine: ElfMachine<EC, ED>) -> Option<(u64, u64)> {
+    match machine {
+        ElfMachine::X86_64 | ElfMachine::I386 => Some((16, 16)),
+        ElfMachine::AARCH64 | ElfMachine::Riscv => Some((32, 16)),
+        ElfMachine::ARM => Some((20, 12)),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// An entry of the procedure linkage table, which is the stub that calls through the
+/// global offset table to an imported function
+pub struct PltEntry {
+    /// The virtual address of the stub
+    pub address: u64,
+    /// The name of the imported symbol the stub calls, or `None` for stubs with no
+    /// symbol, such as those for `R_*_IRELATIVE` relocations
+    pub symbol_name: Option<String>,
+    /// The virtual address of the global offset table slot the stub jumps through, which
+    /// the stub's relocation fills with the address of the symbol
+    pub slot: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A relocation entry joined to the symbol it refers to, as listed by `readelf -r`
+pub struct ResolvedRelocation {
+    /// The location the relocation is applied to
+    pub offset: u64,
+    /// The machine-specific relocation type
+    pub r#type: u32,
+    /// The index of the symbol in the symbol table linked to the relocation section
+    pub symbol_index: u32,
+    /// The name of the symbol, or the name of the section for a section symbol.
+    /// `None` for relocations with no symbol, such as `R_*_RELATIVE` relocations, which
+    /// are relative to the load base instead.
+    pub symbol_name: Option<String>,
+    /// The type of the symbol, or `None` if the relocation has no symbol or the type is
+    /// OS or processor-specific
+    pub symbol_type: Option<ElfSymbolType>,
+    /// The value of the symbol, or 0 if the relocation has no symbol
+    pub symbol_value: u64,
+    /// The explicit addend of a `SHT_RELA` relocation, or `None` for a `SHT_REL`
+    /// relocation whose addend is held in the location to be modified
+    pub addend: Option<i64>,
+}
+
+#[cfg(feature = "arch")]
+impl ResolvedRelocation {
+    /// The type of the relocation, decoded for the machine configured in `config`. The
+    /// type is kept as its raw value for machines without named relocation types.
+    pub fn decoded_type(&self, config: &mut Config) -> Result<ElfRelocationType, Error> {
+        ElfRelocationType::try_from_with(self.r#type, config)
+    }
+}
+
+impl<const EC: u8, const ED: u8> Elf<EC, ED> {
+    /// Read the relocation entries in the `SHT_REL` or `SHT_RELA` section at `index` in
+    /// the section header table
+    pub fn relocations<R>(
+        &self,
+        reader: &mut R,
+        index: SectionIndex,
+    ) -> Result<Vec<ElfRelocation<EC, ED>>, Error>
+    where
+        R: Read + Seek,
+    {
+        self.relocations_with(reader, index, &Config::default())
+    }
+
+    /// Read the relocation entries in the `SHT_REL` or `SHT_RELA` section at `index` in
+    /// the section header table, recovering from the errors ignored by `config`
+    pub fn relocations_with<R>(
+        &self,
+        reader: &mut R,
+        index: SectionIndex,
+        config: &Config,
+    ) -> Result<Vec<ElfRelocation<EC, ED>>, Error>
+    where
+        R: Read + Seek,
+    {
+        let header = self
+            .section(index)
+            .ok_or(Error::InvalidSectionIndex { index: index.get() })?;
+        let explicit = match header.r#type() {
+            ElfSectionHeaderType::RelocationImplicit => false,
+            ElfSectionHeaderType::RelocationExplicit => true,
+            _ => return Err(Error::InvalidRelocationSection { index: index.get() }),
+        };
+        let data = self.section_data(reader, index)?;
+        let (entry_size, count) = header.entry_count(index.get(), data.len() as u64, config)?;
+        let mut config = config.clone();
+        let mut cursor = Cursor::new(data);
+
+        config.machine = ElfMachine::<ELF_CLASS_DEFAULT, ELF_DATA_ENCODING_DEFAULT>::from_u16(
+            u16::from(self.header.machine),
+        );
+
+        (0..count)
+            .map(|relocation| {
+                cursor.seek(SeekFrom::Start(relocation * entry_size))?;
+                ElfRelocation::from_reader_with(&mut cursor, &mut config, explicit)
+            })
+            .collect()
+    }
+
+    /// Read the symbol table which the relocations in the section at `index` refer to.
+    /// This is the section's linked symbol table, which is the full symbol table for
+    /// relocations in relocatable objects and the dynamic symbol table for dynamic
+    /// relocations. Sections without a link fall back to the dynamic symbol table, then
+    /// the full symbol table.
+    fn relocation_symbols<R>(
+        &self,
+        reader: &mut R,
+        index: SectionIndex,
+    ) -> Result<(SectionIndex, Vec<ElfSymbol<EC, ED>>), Error>
+    where
+        R: Read + Seek,
+    {
+        let header = self
+            .section(index)
+            .ok_or(Error::InvalidSectionIndex { index: index.get() })?;
+        let table = match header.link() {
+            SectionIndex(0) => self
+                .section_position(|header| {
+                    matches!(header.r#type(), ElfSectionHeaderType::DynamicSymbol)
+                })
+                .or_else(|| {
+                    self.section_position(|header| {
+                        matches!(header.r#type(), ElfSectionHeaderType::SymbolTable)
+                    })
+                }),
+            link => Some(link),
+        };
+
+        match table {
+            Some(table) => Ok((table, self.symbol_table(reader, table)?)),
+            None => Ok((SectionIndex(0), Vec::new())),
+        }
+    }
+
+    /// Join `relocation` to the symbol at its symbol index in `symbols`, the symbol
+    /// table at `table`
+    fn resolve_relocation_in(
+        &self,
+        relocation: &ElfRelocation<EC, ED>,
+        table: SectionIndex,
+        symbols: &[ElfSymbol<EC, ED>],
+    ) -> Result<ResolvedRelocation, Error> {
+        let symbol_index = relocation.symbol_index();
+        let symbol = match symbol_index {
+            0 => None,
+            index => Some(
+                symbols
+                    .get(index as usize)
+                    .ok_or(Error::InvalidSymbolIndex {
+                        table: table.get(),
+                        index,
+                    })?,
+            ),
+        };
+        let symbol_name =
+            symbol.map(
+                |symbol| match (symbol.r#type(), symbol.name(), symbol.section_index()) {
+                    (Some(ElfSymbolType::Section), "", ElfSectionIndex::Index(section)) => self
+                        .section(SectionIndex(section))
+                        .map(|header| header.name().into())
+                        .unwrap_or_default(),
+                    (_, name, _) => name.into(),
+                },
+            );
+
+        Ok(ResolvedRelocation {
+            offset: relocation.offset(),
+            r#type: relocation.r#type(),
+            symbol_index,
+            symbol_name,
+            symbol_type: symbol.and_then(|symbol| symbol.r#type()),
+            symbol_value: symbol.map(|symbol| symbol.value()).unwrap_or(0),
+            addend: relocation.addend(),
+        })
+    }
+
+    /// Join `relocation`, which was read from the `SHT_REL` or `SHT_RELA` section at
+    /// `index` in the section header table, to the symbol it refers to
+    pub fn resolve_relocation<R>(
+        &self,
+        reader: &mut R,
+        index: SectionIndex,
+        relocation: &ElfRelocation<EC, ED>,
+    ) -> Result<ResolvedRelocation, Error>
+    where
+        R: Read + Seek,
+    {
+        let (table, symbols) = self.relocation_symbols(reader, index)?;
+        self.resolve_relocation_in(relocation, table, &symbols)
+    }
+
+    /// Read the relocation entries in the `SHT_REL` or `SHT_RELA` section at `index` in
+    /// the section header table, and join each to the symbol it refers to
+    pub fn resolved_relocations<R>(
+        &self,
+        reader: &mut R,
+        index: SectionIndex,
+    ) -> Result<Vec<ResolvedRelocation>, Error>
+    where
+        R: Read + Seek,
+    {
+        let relocations = self.relocations(reader, index)?;
+        let (table, symbols) = self.relocation_symbols(reader, index)?;
+
+        relocations
+            .iter()
+            .map(|relocation| self.resolve_relocation_in(relocation, table, &symbols))
+            .collect()
+    }
+
+    /// Read the entries of the procedure linkage table, pairing each stub's address with
+    /// the imported symbol it calls. The `n`th relocation in the section at the address
+    /// given by `DT_JMPREL` belongs to the `n`th stub, which is in the `.plt.sec` section
+    /// if the object has one, or otherwise in the `.plt` section after the resolver stub
+    /// at its start. No entries are returned for objects without a procedure linkage
+    /// table, and for machines whose table layout is not known (x86_64, i386, AARCH64,
+    /// ARM, and RISC-V are supported).
+    pub fn plt_entries<R>(&self, reader: &mut R) -> Result<Vec<PltEntry>, Error>
+    where
+        R: Read + Seek,
+    {
+        let Some((header_size, entry_size)) = plt_layout(self.header.machine) else {
+            return Ok(Vec::new());
+        };
+        // The stubs in `.plt.sec` are split from the code in `.plt` which pushes the
+        // relocation index, so they do not follow a resolver stub
+        let (plt, header_size) = match (
+            self.section_by_name(".plt.sec"),
+            self.section_by_name(".plt"),
+        ) {
+            (Some((_, plt)), _) => (plt, 0),
+            (None, Some((_, plt))) => (plt, header_size),
+            (None, None) => return Ok(Vec::new()),
+        };
+        let Some(relocations) = self.dynamic_entry(reader, ElfDynamicTag::JumpRelocation)? else {
+            return Ok(Vec::new());
+        };
+        let Some(index) = self.section_position(|header| {
+            matches!(
+                header.r#type(),
+                ElfSectionHeaderType::RelocationImplicit | ElfSectionHeaderType::RelocationExplicit
+            ) && header.address() == relocations.value()
+        }) else {
+            return Ok(Vec::new());
+        };
+        let end = plt.address().saturating_add(plt.size());
+
+        Ok(self
+            .resolved_relocations(reader, index)?
+            .into_iter()
+            .zip(0u64..)
+            .map_while(|(relocation, entry)| {
+                let address = plt
+                    .address()
+                    .checked_add(header_size)?
+                    .checked_add(entry.checked_mul(entry_size)?)?;
+
+                (address.checked_add(entry_size)? <= end).then_some(PltEntry {
+                    address,
+                    symbol_name: relocation.symbol_name,
+                    slot: relocation.offset,
+                })
+            })
+            .collect())
+    }
+
+    /// Read the addresses relocated by the `SHT_RELR` section at `index` in the section
+    /// header table. Each is the location of a relative relocation whose addend is held
+    /// in the location to be modified.
+    pub fn relr_relocations<R>(
+        &self,
+        reader: &mut R,
+        index: SectionIndex,
+    ) -> Result<Vec<u64>, Error>
+    where
+        R: Read + Seek,
+    {
+        let header = self
+            .section(index)
+            .ok_or(Error::InvalidSectionIndex { index: index.get() })?;
+
+        if !matches!(header.r#type(), ElfSectionHeaderType::RelR) {
+            return Err(Error::InvalidRelocationSection { index: index.get() });
+        }
+
+        let data = self.section_data(reader, index)?;
+        let word_size = ElfAddress::<EC, ED>::SIZE as u64;
+        let word_bits = word_size * 8;
+        let mut config = Config::default();
+        let mut cursor = Cursor::new(data.as_slice());
+        let mut addresses = Vec::new();
+        let mut next = 0u64;
+
+        for _ in 0..data.len() as u64 / word_size {
+            let entry = ElfAddress::<EC, ED>::from_reader_with(&mut cursor, &mut config)?.0;
+
+            if entry & 1 == 0 {
+                // An address entry relocates the address and starts a run at the next word
+                addresses.push(entry);
+                next = entry.wrapping_add(word_size);
+            } else {
+                // A bitmap entry relocates the words of the run whose bits are set, after
+                // the least significant bit which marks the entry as a bitmap
+                addresses.extend(
+                    (1..word_bits)
+                        .filter(|bit| entry >> bit & 1 != 0)
+                        .map(|bit| next.wrapping_add((bit - 1) * word_size)),
+                );
+                next = next.wrapping_add((word_bits - 1) * word_size);
+            }
+        }
+
+        Ok(addresses)
+    }
+
+    /// Apply the object's relative relocations to `image`, a copy of the object's
+    /// loadable segments as they are laid out in memory. The first byte of `image` is
+    /// the lowest virtual address of any `PT_LOAD` segment, and `base` is the
+    /// difference between the address the object is loaded at and the address it was
+    /// linked at. Relocations are read from the allocated `SHT_REL`, `SHT_RELA`, and
+    /// `SHT_RELR` sections.
+    ///
+    /// Only the relative relocation type of each supported machine is applied, which
+    /// is enough for static position independent executables. Any other relocation
+    /// type, other than the null relocation, is an `Error::UnsupportedRelocation`.
+    pub fn apply_relocations<R>(
+        &self,
+        reader: &mut R,
+        image: &mut [u8],
+        base: u64,
+    ) -> Result<(), Error>
+    where
+        R: Read + Seek,
+    {
+        let unsupported = |r#type| Error::UnsupportedRelocation {
+            machine: ElfMachine::<ELF_CLASS_DEFAULT, ELF_DATA_ENCODING_DEFAULT>::from_u16(
+                u16::from(self.header.machine),
+            ),
+            r#type,
+        };
+        let start = self
+            .program_headers
+            .iter()
+            .filter(|header| matches!(header.r#type(), ElfProgramHeaderType::Load))
+            .map(|header| header.virtual_address())
+            .min()
+            .unwrap_or(0);
+        let mut config = Config::default();
+        // Add the base to the address at `offset`, or replace it with the base plus
+        // `addend` if the relocation has an explicit addend
+        let mut relocate = |offset: u64, addend: Option<i64>| {
+            let word = offset
+                .checked_sub(start)
+                .and_then(|offset| usize::try_from(offset).ok())
+                .and_then(|offset| {
+                    image.get_mut(offset..offset.checked_add(ElfAddress::<EC, ED>::SIZE)?)
+                })
+                .ok_or(Error::InvalidRelocationOffset { offset })?;
+            let addend = match addend {
+                Some(addend) => addend as u64,
+                None => {
+                    ElfAddress::<EC, ED>::from_reader_with(&mut Cursor::new(&*word), &mut config)?.0
+                }
+            };
+            ElfAddress::<EC, ED>(base.wrapping_add(addend) & ElfAddress::<EC, ED>::MAX)
+                .to_writer(&mut Cursor::new(word))
+        };
+
+        for (index, header) in self.indexed_sections() {
+            if !header.flags().contains(ElfSectionHeaderFlags::ALLOCATED) {
+                continue;
+            }
+
+            match header.r#type() {
+                ElfSectionHeaderType::RelocationImplicit
+                | ElfSectionHeaderType::RelocationExplicit => {
+                    for relocation in self.relocations(reader, index)? {
+                        match relocation.r#type() {
+                            0 => {}
+                            r#type if Some(r#type) == relative_type(self.header.machine) => {
+                                relocate(relocation.offset(), relocation.addend())?
+                            }
+                            r#type => return Err(unsupported(r#type)),
+                        }
+                    }
+                }
+                ElfSectionHeaderType::RelR => {
+                    for offset in self.relr_relocations(reader, index)? {
+                        relocate(offset, None)?;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(all(test, feature = "std"))]
+mod test {
+    use crate::{
+        header::{
+            elf::identification::{ElfClass, ElfDataEncoding},
+            section::SectionIndex,
+        },
+        Elf, FromReader,
+    };
+
+    /// A 64-bit little endian shared object with an allocated `SHT_RELR` section and no
+    /// segments
+    fn relr_object() -> Vec<u8> {
+        let mut object = Vec::new();
+        // ELF header
+        object.extend_from_slice(&[0x7f, b'E', b'L', b'F', 2, 1, 1, 0]);
+        object.extend_from_slice(&[0; 8]);
+        object.extend_from_slice(&3u16.to_le_bytes()); // e_type
+        object.extend_from_slice(&62u16.to_le_bytes()); // e_machine
+        object.extend_from_slice(&1u32.to_le_bytes()); // e_version
+        object.extend_from_slice(&0u64.to_le_bytes()); // e_entry
+        object.extend_from_slice(&0u64.to_le_bytes()); // e_phoff
+        object.extend_from_slice(&88u64.to_le_bytes()); // e_shoff
+        object.extend_from_slice(&0u32.to_le_bytes()); // e_flags
+        object.extend_from_slice(&64u16.to_le_bytes()); // e_ehsize
+        object.extend_from_slice(&0u16.to_le_bytes()); // e_phentsize
+        object.extend_from_slice(&0u16.to_le_bytes()); // e_phnum
+        object.extend_from_slice(&64u16.to_le_bytes()); // e_shentsize
+        object.extend_from_slice(&2u16.to_le_bytes()); // e_shnum
+        object.extend_from_slice(&0u16.to_le_bytes()); // e_shstrndx
+                                                       // RELR section: an address, a bitmap continuing its run, and a bitmap for the
+                                                       // following run
+        object.extend_from_slice(&0x1000u64.to_le_bytes());
+        object.extend_from_slice(&0b1011u64.to_le_bytes());
+        object.extend_from_slice(&0b11u64.to_le_bytes());
+        // Section header table
+        let section = |r#type: u32, flags: u64, offset: u64, size: u64, entry_size: u64| {
+            let mut section = Vec::new();
+            section.extend_from_slice(&0u32.to_le_bytes());
+            section.extend_from_slice(&r#type.to_le_bytes());
+            section.extend_from_slice(&flags.to_le_bytes());
+            section.extend_from_slice(&0u64.to_le_bytes());
+            section.extend_from_slice(&offset.to_le_bytes());
+            section.extend_from_slice(&size.to_le_bytes());
+            section.extend_from_slice(&0u32.to_le_bytes());
+            section.extend_from_slice(&0u32.to_le_bytes());
+            section.extend_from_slice(&8u64.to_le_bytes());
+            section.extend_from_slice(&entry_size.to_le_bytes());
+            section
+        };
+        object.extend(section(0, 0, 0, 0, 0));
+        object.extend(section(19, 2, 64, 24, 8));
+        object
+    }
+
+    #[test]
+    fn test_relr_relocations() {
+        let mut reader = std::io::Cursor::new(relr_object());
+        let elf =
+            Elf::<{ ElfClass::Elf64 as u8 }, { ElfDataEncoding::LittleEndian as u8 }>::from_reader(
+                &mut reader,
+            )
+            .unwrap();
+        assert_eq!(
+            elf.relr_relocations(&mut reader, SectionIndex(1)).unwrap(),
+            vec![0x1000, 0x1008, 0x1018, 0x1200]
+        );
+
+        let mut image = vec![0; 0x1208];
+        image[0x1000..0x1008].copy_from_slice(&0x10u64.to_le_bytes());
+        elf.apply_relocations(&mut reader, &mut image, 0x7f00_0000_0000)
+            .unwrap();
+        let word =
+            |offset: usize| u64::from_le_bytes(image[offset..offset + 8].try_into().unwrap());
+        assert_eq!(word(0x1000), 0x7f00_0000_0010);
+        assert_eq!(word(0x1008), 0x7f00_0000_0000);
+        assert_eq!(word(0x1010), 0);
+        assert_eq!(word(0x1200), 0x7f00_0000_0000);
+
+        let mut image = vec![0; 0x1200];
+        assert_eq!(
+            elf.apply_relocations(&mut reader, &mut image, 0),
+            Err(crate::error::Error::InvalidRelocationOffset { offset: 0x1200 })
+        );
+    }
+}
@@ -0,0 +1,50 @@
+//! Scanning of streams, such as firmware images and memory dumps, for ELF objects
+//! embedded in them
+
+use alloc::vec::Vec;
+
+use crate::{
+    error::Error,
+    io::{Read, Seek, SeekFrom},
+    Config, ElfKind, FromReader,
+};
+
+/// The magic which begins every ELF object
+pub const ELF_MAGIC: &[u8] = b"\x7fELF";
+
+/// Search the stream in `reader` for the ELF magic at each multiple of `alignment`,
+/// and return the offset of each candidate object in the stream. An alignment of 0 or
+/// 1 checks every offset. The magic is only four bytes, so it also occurs by chance in
+/// data which is not an object. If `validate` is set, a candidate is only returned if
+/// its headers can be decoded as an `ElfKind` with the default configuration. Each
+/// offset can be used as the `Config::base_offset` to decode the object at it.
+pub fn scan_for_elves<R>(reader: &mut R, alignment: u64, validate: bool) -> Result<Vec<u64>, Error>
+where
+    R: Read + Seek,
+{
+    let alignment = usize::try_from(alignment.max(1)).unwrap_or(usize::MAX);
+    let mut data = Vec::new();
+
+    reader.seek(SeekFrom::Start(0))?;
+    reader.read_to_end(&mut data)?;
+
+    let mut offsets = Vec::new();
+
+    for offset in (0..data.len()).step_by(alignment) {
+        if !data
+            .get(offset..)
+            .is_some_and(|data| data.starts_with(ELF_MAGIC))
+        {
+            continue;
+        }
+
+        let offset = offset as u64;
+        let mut config = Config::builder().ignore([]).base_offset(offset).build();
+
+        if !validate || ElfKind::from_reader_with(reader, &mut config).is_ok() {
+            offsets.push(offset);
+        }
+    }
+
+    Ok(offsets)
+}
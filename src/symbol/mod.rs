@@ -0,0 +1,1242 @@
+//! Implementation of the ELF symbol table entry
+
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::{String, ToString},
+    vec::Vec,
+};
+use num_derive::FromPrimitive;
+use num_traits::FromPrimitive as _;
+
+use typed_builder::TypedBuilder;
+
+#[cfg(feature = "demangle")]
+use crate::producer::{is_cpp_symbol, is_rust_legacy_symbol, is_rust_v0_symbol};
+use crate::{
+    base::{ElfAddress, ElfByte, ElfExtendedWord, ElfHalfWord, ElfSection, ElfWord},
+    error::Error,
+    header::{
+        elf::identification::ElfClass,
+        section::{string_at, ElfSectionHeaderType, ElfSectionIndex, SectionIndex},
+    },
+    io::{Cursor, Read, Seek, SeekFrom, Write},
+    os::{gnu::ElfSectionHeaderTypeGNU, sun::ElfSectionHeaderTypeSUN},
+    Config, Elf, FromReader, HasWrittenSize, ToWriter,
+};
+use alloc::format;
+
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FromPrimitive)]
+#[non_exhaustive]
+/// The binding of a symbol, which determines its linkage visibility and behavior
+pub enum ElfSymbolBinding {
+    /// Not visible outside the object file containing the symbol's definition
+    Local = Self::LOCAL,
+    /// Visible to all object files being combined
+    Global = Self::GLOBAL,
+    /// Like global symbols, but with lower precedence
+    Weak = Self::WEAK,
+}
+
+impl ElfSymbolBinding {
+    /// Not visible outside the object file containing the symbol's definition
+    pub const LOCAL: u8 = 0;
+    /// Visible to all object files being combined
+    pub const GLOBAL: u8 = 1;
+    /// Like global symbols, but with lower precedence
+    pub const WEAK: u8 = 2;
+}
+
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FromPrimitive)]
+#[non_exhaustive]
+/// The type of a symbol, which provides a general classification for the associated
+/// entity
+pub enum ElfSymbolType {
+    /// The symbol's type is not specified
+    NoType = Self::NOTYPE,
+    /// The symbol is associated with a data object, such as a variable or an array
+    Object = Self::OBJECT,
+    /// The symbol is associated with a function or other executable code
+    Function = Self::FUNC,
+    /// The symbol is associated with a section
+    Section = Self::SECTION,
+    /// The symbol's name gives the name of the source file associated with the object
+    File = Self::FILE,
+    /// The symbol labels an uninitialized common block
+    Common = Self::COMMON,
+    /// The symbol specifies a thread-local storage entity
+    ThreadLocalStorage = Self::TLS,
+}
+
+impl ElfSymbolType {
+    /// The symbol's type is not specified
+    pub const NOTYPE: u8 = 0;
+    /// The symbol is associated with a data object, such as a variable or an array
+    pub const OBJECT: u8 = 1;
+    /// The symbol is associated with a function or other executable code
+    pub const FUNC: u8 = 2;
+    /// The symbol is associated with a section
+    pub const SECTION: u8 = 3;
+    /// The symbol's name gives the name of the source file associated with the object
+    pub const FILE: u8 = 4;
+    /// The symbol labels an uninitialized common block
+    pub const COMMON: u8 = 5;
+    /// The symbol specifies a thread-local storage entity
+    pub const TLS: u8 = 6;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// The name of an ELF symbol
+pub struct ElfSymbolName<const EC: u8, const ED: u8> {
+    /// The name of the symbol, which is obtained by indexing into the string table
+    /// linked to the symbol table
+    pub name: String,
+    /// The raw symbol name
+    pub value: ElfWord<EC, ED>,
+}
+
+impl<R, const EC: u8, const ED: u8> FromReader<R> for ElfSymbolName<EC, ED>
+where
+    R: Read + Seek,
+{
+    type Error = Error;
+
+    /// Read the raw symbol name. The name itself is resolved once the symbol table's
+    /// string table has been read.
+    fn from_reader_with(reader: &mut R, config: &mut Config) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: String::new(),
+            value: ElfWord::<EC, ED>::from_reader_with(reader, config)?,
+        })
+    }
+}
+
+impl<W, const EC: u8, const ED: u8> ToWriter<W> for ElfSymbolName<EC, ED>
+where
+    W: Write,
+{
+    type Error = Error;
+
+    /// Write the raw symbol name. The name itself is held in the string table, which
+    /// is written separately.
+    fn to_writer(&self, writer: &mut W) -> Result<(), Self::Error> {
+        self.value.to_writer(writer)
+    }
+}
+
+impl<const EC: u8, const ED: u8> HasWrittenSize for ElfSymbolName<EC, ED> {
+    const SIZE: usize = ElfWord::<EC, ED>::SIZE;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, TypedBuilder)]
+/// ELF 32-bit Symbol Table Entry
+pub struct Elf32Symbol<const ED: u8> {
+    /// An index into the object file's symbol string table, which holds the character
+    /// representations of the symbol names. If the value is non-zero, it represents a
+    /// string table index that gives the symbol name. Otherwise, the symbol table entry
+    /// has no name.
+    pub name: ElfSymbolName<{ ElfClass::Elf32 as u8 }, ED>,
+    /// The value of the associated symbol. Depending on the context, this may be an
+    /// absolute value, an address, and so on
+    pub value: ElfAddress<{ ElfClass::Elf32 as u8 }, ED>,
+    /// Many symbols have associated sizes. For example, a data object's size is the
+    /// number of bytes contained in the object. This member holds 0 if the symbol has no
+    /// size or an unknown size.
+    pub size: ElfWord<{ ElfClass::Elf32 as u8 }, ED>,
+    /// The symbol's type and binding attributes
+    pub info: ElfByte,
+    /// The symbol's visibility
+    pub other: ElfByte,
+    /// Every symbol table entry is defined in relation to some section. This member
+    /// holds the relevant section header table index.
+    pub section_index: ElfSection<{ ElfClass::Elf32 as u8 }, ED>,
+    #[builder(default)]
+    /// The section header table index held by the associated `SHT_SYMTAB_SHNDX` section
+    /// for this symbol, if the symbol table has one. This is not part of the symbol
+    /// table entry itself.
+    pub extended_section_index: Option<ElfWord<{ ElfClass::Elf32 as u8 }, ED>>,
+}
+
+impl<R, const ED: u8> FromReader<R> for Elf32Symbol<ED>
+where
+    R: Read + Seek,
+{
+    type Error = Error;
+
+    fn from_reader_with(reader: &mut R, config: &mut Config) -> Result<Self, Self::Error> {
+        let name =
+            ElfSymbolName::<{ ElfClass::Elf32 as u8 }, ED>::from_reader_with(reader, config)?;
+        let value = ElfAddress::<{ ElfClass::Elf32 as u8 }, ED>::from_reader_with(reader, config)?;
+        let size = ElfWord::<{ ElfClass::Elf32 as u8 }, ED>::from_reader_with(reader, config)?;
+        let info = ElfByte::from_reader_with(reader, config)?;
+        let other = ElfByte::from_reader_with(reader, config)?;
+        let section_index =
+            ElfSection::<{ ElfClass::Elf32 as u8 }, ED>::from_reader_with(reader, config)?;
+
+        Ok(Self {
+            name,
+            value,
+            size,
+            info,
+            other,
+            section_index,
+            extended_section_index: None,
+        })
+    }
+}
+
+impl<W, const ED: u8> ToWriter<W> for Elf32Symbol<ED>
+where
+    W: Write,
+{
+    type Error = Error;
+
+    /// Write the symbol table entry. The extended section index is held in the
+    /// `SHT_SYMTAB_SHNDX` section, which is written separately.
+    fn to_writer(&self, writer: &mut W) -> Result<(), Self::Error> {
+        self.name.to_writer(writer)?;
+        self.value.to_writer(writer)?;
+        self.size.to_writer(writer)?;
+        self.info.to_writer(writer)?;
+        self.other.to_writer(writer)?;
+        self.section_index.to_writer(writer)?;
+        Ok(())
+    }
+}
+
+impl<const ED: u8> HasWrittenSize for Elf32Symbol<ED> {
+    const SIZE: usize = ElfSymbolName::<{ ElfClass::Elf32 as u8 }, ED>::SIZE
+        + ElfAddress::<{ ElfClass::Elf32 as u8 }, ED>::SIZE
+        + ElfWord::<{ ElfClass::Elf32 as u8 }, ED>::SIZE
+        + ElfByte::SIZE
+        + ElfByte::SIZE
+        + ElfSection::<{ ElfClass::Elf32 as u8 }, ED>::SIZE;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, TypedBuilder)]
+/// ELF 64-bit Symbol Table Entry
+pub struct Elf64Symbol<const ED: u8> {
+    /// An index into the object file's symbol string table, which holds the character
+    /// representations of the symbol names. If the value is non-zero, it represents a
+    /// string table index that gives the symbol name. Otherwise, the symbol table entry
+    /// has no name.
+    pub name: ElfSymbolName<{ ElfClass::Elf64 as u8 }, ED>,
+    /// The symbol's type and binding attributes
+    pub info: ElfByte,
+    /// The symbol's visibility
+    pub other: ElfByte,
+    /// Every symbol table entry is defined in relation to some section. This member
+    /// holds the relevant section header table index.
+    pub section_index: ElfSection<{ ElfClass::Elf64 as u8 }, ED>,
+    /// The value of the associated symbol. Depending on the context, this may be an
+    /// absolute value, an address, and so on
+    pub value: ElfAddress<{ ElfClass::Elf64 as u8 }, ED>,
+    /// Many symbols have associated sizes. For example, a data object's size is the
+    /// number of bytes contained in the object. This member holds 0 if the symbol has no
+    /// size or an unknown size.
+    pub size: ElfExtendedWord<{ ElfClass::Elf64 as u8 }, ED>,
+    #[builder(default)]
+    /// The section header table index held by the associated `SHT_SYMTAB_SHNDX` section
+    /// for this symbol, if the symbol table has one. This is not part of the symbol
+    /// table entry itself.
+    pub extended_section_index: Option<ElfWord<{ ElfClass::Elf64 as u8 }, ED>>,
+}
+
+impl<R, const ED: u8> FromReader<R> for Elf64Symbol<ED>
+where
+    R: Read + Seek,
+{
+    type Error = Error;
+
+    fn from_reader_with(reader: &mut R, config: &mut Config) -> Result<Self, Self::Error> {
+        let name =
+            ElfSymbolName::<{ ElfClass::Elf64 as u8 }, ED>::from_reader_with(reader, config)?;
+        let info = ElfByte::from_reader_with(reader, config)?;
+        let other = ElfByte::from_reader_with(reader, config)?;
+        let section_index =
+            ElfSection::<{ ElfClass::Elf64 as u8 }, ED>::from_reader_with(reader, config)?;
+        let value = ElfAddress::<{ ElfClass::Elf64 as u8 }, ED>::from_reader_with(reader, config)?;
+        let size =
+            ElfExtendedWord::<{ ElfClass::Elf64 as u8 }, ED>::from_reader_with(reader, config)?;
+
+        Ok(Self {
+            name,
+            info,
+            other,
+            section_index,
+            value,
+            size,
+            extended_section_index: None,
+        })
+    }
+}
+
+impl<W, const ED: u8> ToWriter<W> for Elf64Symbol<ED>
+where
+    W: Write,
+{
+    type Error = Error;
+
+    /// Write the symbol table entry. The extended section index is held in the
+    /// `SHT_SYMTAB_SHNDX` section, which is written separately.
+    fn to_writer(&self, writer: &mut W) -> Result<(), Self::Error> {
+        self.name.to_writer(writer)?;
+        self.info.to_writer(writer)?;
+        self.other.to_writer(writer)?;
+        self.section_index.to_writer(writer)?;
+        self.value.to_writer(writer)?;
+        self.size.to_writer(writer)?;
+        Ok(())
+    }
+}
+
+impl<const ED: u8> HasWrittenSize for Elf64Symbol<ED> {
+    const SIZE: usize = ElfSymbolName::<{ ElfClass::Elf64 as u8 }, ED>::SIZE
+        + ElfByte::SIZE
+        + ElfByte::SIZE
+        + ElfSection::<{ ElfClass::Elf64 as u8 }, ED>::SIZE
+        + ElfAddress::<{ ElfClass::Elf64 as u8 }, ED>::SIZE
+        + ElfExtendedWord::<{ ElfClass::Elf64 as u8 }, ED>::SIZE;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// The symbol table a symbol was read from, as reported by `Elf::all_symbols`
+pub enum SymbolSource {
+    /// The full symbol table, in the `SHT_SYMTAB` section
+    SymbolTable,
+    /// The dynamic symbol table, in the `SHT_DYNSYM` section
+    DynamicSymbolTable,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+/// How `Elf::symbolicate_with` treats symbols without a size, which assembly routines
+/// and the symbols of some stripped objects often are
+pub enum SymbolicationMode {
+    /// Only a symbol whose range of `st_size` bytes contains the address is used, so an
+    /// address covered only by symbols without a size is not symbolicated
+    Strict,
+    #[default]
+    /// If no symbol with a size contains the address, the nearest preceding symbol
+    /// without a size is used, with the offset of the address from it, as `addr2line`
+    /// and `nm` do
+    Nearest,
+}
+
+/// The names a symbol can be looked up by: its name, and for a versioned name such
+/// as `free@GLIBC_2.2.5` or `free@@GLIBC_2.2.5`, also the name without the version.
+/// Unnamed symbols cannot be looked up.
+fn lookup_names(name: &str) -> impl Iterator<Item = &str> {
+    let unversioned = name
+        .split_once('@')
+        .map(|(unversioned, _)| unversioned)
+        .filter(|unversioned| !unversioned.is_empty());
+
+    core::iter::once(name)
+        .filter(|name| !name.is_empty())
+        .chain(unversioned)
+}
+
+/// The bit of an `SHT_GNU_versym` entry which marks the symbol's version as hidden,
+/// so that it is not the default version of the symbol's name (`VERSYM_HIDDEN`)
+pub const VERSYM_HIDDEN: u16 = 0x8000;
+/// The version index of symbols which are global but not versioned (`VER_NDX_GLOBAL`)
+pub const VER_NDX_GLOBAL: u16 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// An index of the symbols of an object by name, as built by `Elf::build_symbol_index`,
+/// for resolving many names without reading and scanning the symbol tables for each
+/// of them. Names are held in a `BTreeMap` so the index needs no `std`.
+pub struct SymbolIndex<const EC: u8, const ED: u8> {
+    symbols: Vec<(SymbolSource, ElfSymbol<EC, ED>)>,
+    names: BTreeMap<String, Vec<usize>>,
+}
+
+impl<const EC: u8, const ED: u8> SymbolIndex<EC, ED> {
+    /// Index `symbols` by name. Each symbol is indexed by its name and, if the name is
+    /// versioned, by its name without the version. Unnamed symbols are not indexed.
+    pub fn new(symbols: Vec<(SymbolSource, ElfSymbol<EC, ED>)>) -> Self {
+        let mut names: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+
+        for (index, (_, symbol)) in symbols.iter().enumerate() {
+            for name in lookup_names(symbol.name()) {
+                names.entry(name.into()).or_default().push(index);
+            }
+        }
+
+        Self { symbols, names }
+    }
+
+    /// The indexed symbols, in the order they were indexed
+    pub fn symbols(&self) -> &[(SymbolSource, ElfSymbol<EC, ED>)] {
+        &self.symbols
+    }
+
+    /// The positions in `symbols` of every symbol named `name`, in the order they were
+    /// indexed
+    pub fn indices(&self, name: &str) -> &[usize] {
+        self.names.get(name).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// The first symbol named `name`, or `None` if there is no such symbol
+    pub fn symbol_by_name(&self, name: &str) -> Option<&(SymbolSource, ElfSymbol<EC, ED>)> {
+        self.indices(name)
+            .first()
+            .and_then(|index| self.symbols.get(*index))
+    }
+
+    /// Every symbol named `name`, such as a local symbol defined in several
+    /// translation units
+    pub fn symbols_by_name(&self, name: &str) -> Vec<&(SymbolSource, ElfSymbol<EC, ED>)> {
+        self.indices(name)
+            .iter()
+            .filter_map(|index| self.symbols.get(*index))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// ELF symbol table entry for either 32-bit or 64-bit ELF files
+pub enum ElfSymbol<const EC: u8, const ED: u8> {
+    /// A 32-bit ELF symbol table entry
+    Elf32(Elf32Symbol<ED>),
+    /// A 64-bit ELF symbol table entry
+    Elf64(Elf64Symbol<ED>),
+}
+
+impl<const EC: u8, const ED: u8> ElfSymbol<EC, ED> {
+    /// The name of the symbol. This is empty if the symbol has no name, or if the
+    /// symbol was not read as part of a symbol table with a string table.
+    pub fn name(&self) -> &str {
+        match self {
+            ElfSymbol::Elf32(symbol) => &symbol.name.name,
+            ElfSymbol::Elf64(symbol) => &symbol.name.name,
+        }
+    }
+
+    /// The index of the symbol's name in the associated string table
+    pub fn name_index(&self) -> u32 {
+        match self {
+            ElfSymbol::Elf32(symbol) => symbol.name.value.0,
+            ElfSymbol::Elf64(symbol) => symbol.name.value.0,
+        }
+    }
+
+    /// The demangled name of the symbol, or `None` if the name is not a Rust or C++
+    /// mangled name or cannot be demangled. Rust names, in either the legacy or v0
+    /// mangling, are demangled without their hash, and other names beginning with `_Z`
+    /// are demangled as C++ names.
+    #[cfg(feature = "demangle")]
+    pub fn demangled_name(&self) -> Option<String> {
+        let name = self.name();
+
+        if is_rust_legacy_symbol(name) || is_rust_v0_symbol(name) {
+            rustc_demangle::try_demangle(name)
+                .ok()
+                .map(|name| format!("{name:#}"))
+        } else if is_cpp_symbol(name) {
+            cpp_demangle::Symbol::new(name.as_bytes())
+                .ok()?
+                .demangle()
+                .ok()
+        } else {
+            None
+        }
+    }
+
+    /// The value of the symbol
+    pub fn value(&self) -> u64 {
+        match self {
+            ElfSymbol::Elf32(symbol) => symbol.value.0,
+            ElfSymbol::Elf64(symbol) => symbol.value.0,
+        }
+    }
+
+    /// The size of the symbol
+    pub fn size(&self) -> u64 {
+        match self {
+            ElfSymbol::Elf32(symbol) => symbol.size.0 as u64,
+            ElfSymbol::Elf64(symbol) => symbol.size.0,
+        }
+    }
+
+    /// The raw type and binding attributes of the symbol
+    pub fn info(&self) -> u8 {
+        match self {
+            ElfSymbol::Elf32(symbol) => symbol.info.0,
+            ElfSymbol::Elf64(symbol) => symbol.info.0,
+        }
+    }
+
+    /// The binding of the symbol, or `None` if the binding is OS or processor-specific
+    pub fn binding(&self) -> Option<ElfSymbolBinding> {
+        ElfSymbolBinding::from_u8(self.info() >> 4)
+    }
+
+    /// The type of the symbol, or `None` if the type is OS or processor-specific
+    pub fn r#type(&self) -> Option<ElfSymbolType> {
+        ElfSymbolType::from_u8(self.info() & 0xf)
+    }
+
+    /// The raw visibility of the symbol
+    pub fn other(&self) -> u8 {
+        match self {
+            ElfSymbol::Elf32(symbol) => symbol.other.0,
+            ElfSymbol::Elf64(symbol) => symbol.other.0,
+        }
+    }
+
+    /// The section the symbol is defined relative to. Special section indices such as
+    /// `SHN_ABS` and `SHN_COMMON` are distinguished from ordinary indices, so only
+    /// `ElfSectionIndex::Index` values may be used to index the section header table.
+    /// A `SHN_XINDEX` index is resolved through the symbol table's `SHT_SYMTAB_SHNDX`
+    /// section when one was read alongside the symbol.
+    pub fn section_index(&self) -> ElfSectionIndex {
+        let (section_index, extended_section_index) = match self {
+            ElfSymbol::Elf32(symbol) => (
+                ElfSectionIndex::from(symbol.section_index),
+                symbol.extended_section_index.map(u32::from),
+            ),
+            ElfSymbol::Elf64(symbol) => (
+                ElfSectionIndex::from(symbol.section_index),
+                symbol.extended_section_index.map(u32::from),
+            ),
+        };
+
+        match (section_index, extended_section_index) {
+            (ElfSectionIndex::Xindex, Some(0)) => ElfSectionIndex::Undefined,
+            (ElfSectionIndex::Xindex, Some(index)) => ElfSectionIndex::Index(index),
+            (section_index, _) => section_index,
+        }
+    }
+
+    fn set_name(&mut self, name: String) {
+        match self {
+            ElfSymbol::Elf32(symbol) => symbol.name.name = name,
+            ElfSymbol::Elf64(symbol) => symbol.name.name = name,
+        }
+    }
+
+    fn set_extended_section_index(&mut self, index: u32) {
+        match self {
+            ElfSymbol::Elf32(symbol) => symbol.extended_section_index = Some(ElfWord(index)),
+            ElfSymbol::Elf64(symbol) => symbol.extended_section_index = Some(ElfWord(index)),
+        }
+    }
+}
+
+impl<R, const EC: u8, const ED: u8> FromReader<R> for ElfSymbol<EC, ED>
+where
+    R: Read + Seek,
+{
+    type Error = Error;
+
+    fn from_reader_with(reader: &mut R, config: &mut Config) -> Result<Self, Self::Error> {
+        Ok(
+            match ElfClass::from_u8(EC).ok_or(Error::InvalidClass { class: ElfByte(EC) })? {
+                ElfClass::None => return Err(Error::InvalidClass { class: ElfByte(EC) }),
+                ElfClass::Elf32 => ElfSymbol::Elf32(Elf32Symbol::from_reader_with(reader, config)?),
+                ElfClass::Elf64 => ElfSymbol::Elf64(Elf64Symbol::from_reader_with(reader, config)?),
+            },
+        )
+    }
+}
+
+impl<W, const EC: u8, const ED: u8> ToWriter<W> for ElfSymbol<EC, ED>
+where
+    W: Write,
+{
+    type Error = Error;
+
+    fn to_writer(&self, writer: &mut W) -> Result<(), Self::Error> {
+        match self {
+            ElfSymbol::Elf32(symbol) => symbol.to_writer(writer),
+            ElfSymbol::Elf64(symbol) => symbol.to_writer(writer),
+        }
+    }
+}
+
+impl<const EC: u8, const ED: u8> HasWrittenSize for ElfSymbol<EC, ED> {
+    const SIZE: usize = match EC {
+        ElfClass::ELF_CLASS_64 => Elf64Symbol::<ED>::SIZE,
+        _ => Elf32Symbol::<ED>::SIZE,
+    };
+}
+
+impl<const EC: u8, const ED: u8> Elf<EC, ED> {
+    /// Read the symbol table in the section at `index` in the section header table.
+    /// Symbol names are resolved from the string table linked to the symbol table, if
+    /// it is present. Symbols whose section index is `SHN_XINDEX` are resolved using the
+    /// `SHT_SYMTAB_SHNDX` section linked to the symbol table, if there is one.
+    pub fn symbol_table<R>(
+        &self,
+        reader: &mut R,
+        index: SectionIndex,
+    ) -> Result<Vec<ElfSymbol<EC, ED>>, Error>
+    where
+        R: Read + Seek,
+    {
+        self.symbol_table_with(reader, index, &Config::default())
+    }
+
+    /// Read the symbol table in the section at `index` in the section header table, as
+    /// with `symbol_table`, recovering from the errors ignored by `config`
+    pub fn symbol_table_with<R>(
+        &self,
+        reader: &mut R,
+        index: SectionIndex,
+        config: &Config,
+    ) -> Result<Vec<ElfSymbol<EC, ED>>, Error>
+    where
+        R: Read + Seek,
+    {
+        let header = self
+            .section(index)
+            .ok_or(Error::InvalidSectionIndex { index: index.get() })?;
+        let string_table_index = header.link();
+        let data = self.section_data(reader, index)?;
+        let (entry_size, count) = header.entry_count(index.get(), data.len() as u64, config)?;
+        let mut config = config.clone();
+        let mut cursor = Cursor::new(data);
+
+        let mut symbols = (0..count)
+            .map(|symbol| {
+                cursor.seek(SeekFrom::Start(symbol * entry_size))?;
+                ElfSymbol::from_reader_with(&mut cursor, &mut config)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if string_table_index.0 != 0 {
+            if let Ok(strings) = self.section_data(reader, string_table_index) {
+                for symbol in symbols.iter_mut() {
+                    if let Some(name) = string_at(&strings, symbol.name_index() as usize) {
+                        symbol.set_name(name);
+                    }
+                }
+            }
+        }
+
+        if !symbols
+            .iter()
+            .any(|symbol| symbol.section_index() == ElfSectionIndex::Xindex)
+        {
+            return Ok(symbols);
+        }
+
+        let Some(extended_index) = self.section_position(|header| {
+            matches!(
+                header.r#type(),
+                ElfSectionHeaderType::SymbolTableSectionHeaderIndex
+            ) && header.link() == index
+        }) else {
+            return Ok(symbols);
+        };
+
+        let mut cursor = Cursor::new(self.section_data(reader, extended_index)?);
+
+        for symbol in symbols.iter_mut() {
+            let extended_section_index =
+                ElfWord::<EC, ED>::from_reader_with(&mut cursor, &mut config)?;
+
+            if symbol.section_index() == ElfSectionIndex::Xindex {
+                symbol.set_extended_section_index(extended_section_index.0);
+            }
+        }
+
+        Ok(symbols)
+    }
+
+    /// Read the symbol table of the first section with type `r#type`, or no symbols if
+    /// there is no such section
+    fn symbol_table_of_type<R>(
+        &self,
+        reader: &mut R,
+        r#type: ElfSectionHeaderType<EC, ED>,
+    ) -> Result<Vec<ElfSymbol<EC, ED>>, Error>
+    where
+        R: Read + Seek,
+    {
+        match self.section_position(|header| header.r#type() == r#type) {
+            Some(index) => self.symbol_table(reader, index),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Read the full symbol table from the `SHT_SYMTAB` section. This holds every symbol
+    /// the link editor knew about, including local symbols, and is usually a superset of
+    /// the dynamic symbol table. Stripped objects have no full symbol table, in which
+    /// case no symbols are returned.
+    pub fn symbols<R>(&self, reader: &mut R) -> Result<Vec<ElfSymbol<EC, ED>>, Error>
+    where
+        R: Read + Seek,
+    {
+        self.symbol_table_of_type(reader, ElfSectionHeaderType::SymbolTable)
+    }
+
+    /// Read the dynamic symbol table from the `SHT_DYNSYM` section. This holds the
+    /// minimal set of symbols needed for dynamic linking. Objects which are not
+    /// dynamically linked have no dynamic symbol table, in which case no symbols are
+    /// returned.
+    pub fn dynamic_symbols<R>(&self, reader: &mut R) -> Result<Vec<ElfSymbol<EC, ED>>, Error>
+    where
+        R: Read + Seek,
+    {
+        self.symbol_table_of_type(reader, ElfSectionHeaderType::DynamicSymbol)
+    }
+
+    /// The number of local symbols in the symbol table in the section at `index` in the
+    /// section header table, which is one greater than the index of its last local
+    /// symbol and is held in its `sh_info` field. Link editors place every local symbol
+    /// before every global symbol and rely on this boundary rather than on each
+    /// symbol's binding. Sections which are not symbol tables have no such count, in
+    /// which case `None` is returned.
+    pub fn local_symbol_count(&self, index: SectionIndex) -> Option<u64> {
+        self.section(index)
+            .filter(|header| {
+                matches!(
+                    header.r#type(),
+                    ElfSectionHeaderType::SymbolTable | ElfSectionHeaderType::DynamicSymbol
+                )
+            })
+            .map(|header| header.info() as u64)
+    }
+
+    /// Read the full symbol table from the `SHT_SYMTAB` section, as with `symbols`,
+    /// split into its local and global symbols at the boundary given by
+    /// `local_symbol_count` rather than by each symbol's binding, which can disagree in
+    /// malformed objects. A boundary past the end of the table makes every symbol local.
+    pub fn local_and_global_symbols<R>(
+        &self,
+        reader: &mut R,
+    ) -> Result<(Vec<ElfSymbol<EC, ED>>, Vec<ElfSymbol<EC, ED>>), Error>
+    where
+        R: Read + Seek,
+    {
+        let Some(index) =
+            self.section_position(|header| header.r#type() == ElfSectionHeaderType::SymbolTable)
+        else {
+            return Ok((Vec::new(), Vec::new()));
+        };
+        let mut locals = self.symbol_table(reader, index)?;
+        let count = self
+            .local_symbol_count(index)
+            .and_then(|count| usize::try_from(count).ok())
+            .unwrap_or(usize::MAX)
+            .min(locals.len());
+        let globals = locals.split_off(count);
+
+        Ok((locals, globals))
+    }
+
+    /// The section `symbol` is defined in. Special section indices are distinguished
+    /// from ordinary ones, so a symbol is in a section of the section header table only
+    /// if this is `ElfSectionIndex::Index`. A `SHN_XINDEX` index is resolved if the
+    /// symbol was read as part of a symbol table with an `SHT_SYMTAB_SHNDX` section, and
+    /// is otherwise left as `ElfSectionIndex::Xindex`.
+    pub fn symbol_section(&self, symbol: &ElfSymbol<EC, ED>) -> ElfSectionIndex {
+        symbol.section_index()
+    }
+
+    /// The name of the section `symbol` is defined in, or `None` if the symbol is
+    /// undefined, absolute, common, or has any other special section index, or if its
+    /// section is not in the section header table
+    pub fn symbol_section_name(&self, symbol: &ElfSymbol<EC, ED>) -> Option<&str> {
+        self.symbol_section(symbol)
+            .index()
+            .and_then(|index| self.section(index))
+            .map(|header| header.name())
+    }
+
+    /// Read every symbol from both the full and dynamic symbol tables, each tagged with
+    /// the table it was read from. A dynamic symbol with the same name and value as a
+    /// symbol in the full symbol table is left out, because the full symbol table's
+    /// entry carries the same information. The link editor appends the version to the
+    /// names of versioned symbols in the full symbol table, as in `free@GLIBC_2.2.5`, so
+    /// names are compared without it.
+    /// The full symbol table's symbols come first, in table order, followed by the
+    /// remaining dynamic symbols.
+    pub fn all_symbols<R>(
+        &self,
+        reader: &mut R,
+    ) -> Result<Vec<(SymbolSource, ElfSymbol<EC, ED>)>, Error>
+    where
+        R: Read + Seek,
+    {
+        let symbols = self.symbols(reader)?;
+        let dynamic_symbols = self.dynamic_symbols(reader)?;
+        let seen = symbols
+            .iter()
+            .map(|symbol| {
+                let name = symbol.name();
+                let name = name.split_once('@').map_or(name, |(name, _)| name);
+                (name, symbol.value())
+            })
+            .collect::<BTreeSet<_>>();
+        let dynamic_symbols = dynamic_symbols
+            .into_iter()
+            .filter(|symbol| !seen.contains(&(symbol.name(), symbol.value())))
+            .map(|symbol| (SymbolSource::DynamicSymbolTable, symbol))
+            .collect::<Vec<_>>();
+
+        Ok(symbols
+            .into_iter()
+            .map(|symbol| (SymbolSource::SymbolTable, symbol))
+            .chain(dynamic_symbols)
+            .collect())
+    }
+
+    /// Find the first symbol named `name` among the symbols returned by `all_symbols`. A
+    /// versioned name such as `free@GLIBC_2.2.5` is also found by the name without the
+    /// version. This reads and scans the symbol tables on each call, so use
+    /// `build_symbol_index` to look up many names.
+    pub fn symbol_by_name<R>(
+        &self,
+        reader: &mut R,
+        name: &str,
+    ) -> Result<Option<(SymbolSource, ElfSymbol<EC, ED>)>, Error>
+    where
+        R: Read + Seek,
+    {
+        Ok(self
+            .all_symbols(reader)?
+            .into_iter()
+            .find(|(_, symbol)| lookup_names(symbol.name()).any(|n| n == name)))
+    }
+
+    /// Read the symbols returned by `all_symbols` once and index them by name, so that
+    /// each later lookup neither reads nor scans the symbol tables
+    pub fn build_symbol_index<R>(&self, reader: &mut R) -> Result<SymbolIndex<EC, ED>, Error>
+    where
+        R: Read + Seek,
+    {
+        Ok(SymbolIndex::new(self.all_symbols(reader)?))
+    }
+
+    /// The index of the first section which is the GNU or Sun section of type `gnu` or
+    /// `sun`, which share their values
+    fn version_section(
+        &self,
+        gnu: ElfSectionHeaderTypeGNU,
+        sun: ElfSectionHeaderTypeSUN,
+    ) -> Option<SectionIndex> {
+        self.section_position(|header| {
+            header.r#type() == ElfSectionHeaderType::Gnu(gnu)
+                || header.r#type() == ElfSectionHeaderType::Sun(sun)
+        })
+    }
+
+    /// Read the names of the versions defined in the `SHT_GNU_verdef` section and needed
+    /// in the `SHT_GNU_verneed` section, keyed by their version index. Versions defined
+    /// by the object are marked as such.
+    fn version_names<R>(&self, reader: &mut R) -> Result<BTreeMap<u16, (String, bool)>, Error>
+    where
+        R: Read + Seek,
+    {
+        let mut names = BTreeMap::new();
+        let sections = [
+            (
+                self.version_section(
+                    ElfSectionHeaderTypeGNU::VerDef,
+                    ElfSectionHeaderTypeSUN::VerDef,
+                ),
+                true,
+            ),
+            (
+                self.version_section(
+                    ElfSectionHeaderTypeGNU::VerNeed,
+                    ElfSectionHeaderTypeSUN::VerNeed,
+                ),
+                false,
+            ),
+        ];
+
+        for (index, defined) in sections {
+            let Some(index) = index else {
+                continue;
+            };
+            let strings = self
+                .section(index)
+                .map(|header| header.link())
+                .filter(|link| link.0 != 0)
+                .map(|link| self.section_data(reader, link))
+                .transpose()?
+                .unwrap_or_default();
+            let mut cursor = Cursor::new(self.section_data(reader, index)?);
+            let half = |cursor: &mut Cursor<Vec<u8>>| {
+                ElfHalfWord::<EC, ED>::from_reader_with(cursor, &mut Config::default()).map(|h| h.0)
+            };
+            let word = |cursor: &mut Cursor<Vec<u8>>| {
+                ElfWord::<EC, ED>::from_reader_with(cursor, &mut Config::default()).map(|w| w.0)
+            };
+            let mut entry = 0u64;
+            let mut visited = BTreeSet::new();
+            let mut visit = |offset: u64| {
+                if visited.len() >= self.max_chain_length {
+                    Err(Error::MalformedVersionChain {
+                        section: index.get(),
+                        limit: self.max_chain_length,
+                    })
+                } else if !visited.insert(offset) {
+                    Err(Error::CyclicStructure {
+                        section: index.get(),
+                        offset,
+                    })
+                } else {
+                    Ok(())
+                }
+            };
+
+            // Each entry links to the next by its offset from the entry, and the last
+            // links to itself. A malformed section ends at the first entry which does
+            // not fit in it, and every entry and auxiliary entry is visited at most
+            // once, up to `max_chain_length` of them.
+            loop {
+                visit(entry)?;
+
+                let auxiliaries = if defined {
+                    // vd_version, vd_flags, vd_ndx, vd_cnt, vd_hash, vd_aux, vd_next
+                    cursor.seek(SeekFrom::Start(entry + 4))?;
+                    let (Ok(version), Ok(_), Ok(_), Ok(aux), Ok(next)) = (
+                        half(&mut cursor),
+                        half(&mut cursor),
+                        word(&mut cursor),
+                        word(&mut cursor),
+                        word(&mut cursor),
+                    ) else {
+                        break;
+                    };
+
+                    // Only the first auxiliary entry names the version itself, and the
+                    // others name its parents
+                    cursor.seek(SeekFrom::Start(entry + aux as u64))?;
+                    if let Ok(name) = word(&mut cursor) {
+                        names.insert(
+                            version,
+                            (string_at(&strings, name as usize).unwrap_or_default(), true),
+                        );
+                    }
+
+                    next
+                } else {
+                    // vn_version, vn_cnt, vn_file, vn_aux, vn_next
+                    cursor.seek(SeekFrom::Start(entry + 2))?;
+                    let (Ok(count), Ok(_), Ok(aux), Ok(next)) = (
+                        half(&mut cursor),
+                        word(&mut cursor),
+                        word(&mut cursor),
+                        word(&mut cursor),
+                    ) else {
+                        break;
+                    };
+                    let mut auxiliary = entry + aux as u64;
+
+                    // vna_hash, vna_flags, vna_other, vna_name, vna_next
+                    for _ in 0..count {
+                        visit(auxiliary)?;
+                        cursor.seek(SeekFrom::Start(auxiliary + 6))?;
+                        let (Ok(version), Ok(name), Ok(next)) =
+                            (half(&mut cursor), word(&mut cursor), word(&mut cursor))
+                        else {
+                            break;
+                        };
+
+                        names.insert(
+                            version & !VERSYM_HIDDEN,
+                            (
+                                string_at(&strings, name as usize).unwrap_or_default(),
+                                false,
+                            ),
+                        );
+
+                        if next == 0 {
+                            break;
+                        }
+
+                        auxiliary += next as u64;
+                    }
+
+                    next
+                };
+
+                if auxiliaries == 0 {
+                    break;
+                }
+
+                entry += auxiliaries as u64;
+            }
+        }
+
+        Ok(names)
+    }
+
+    /// The name of the symbol at `index` in the dynamic symbol table with its version,
+    /// as shown by `nm -D`. The version is found from the symbol's entry in the
+    /// `SHT_GNU_versym` section and the `SHT_GNU_verdef` or `SHT_GNU_verneed` section
+    /// naming it. A defined symbol with the default version of its name is shown as
+    /// `name@@VERSION`, a defined symbol with a hidden version as `name@VERSION`, and
+    /// an undefined symbol as `name@VERSION`. Local and global symbols, which have
+    /// version index 0 or 1, the symbols naming a version definition, and all symbols
+    /// of objects without symbol versions are shown by their name alone. `None` if
+    /// there is no symbol at `index`. Version sections whose entries link back to an
+    /// entry already visited or hold more than `max_chain_length` entries are rejected.
+    pub fn versioned_name<R>(&self, reader: &mut R, index: usize) -> Result<Option<String>, Error>
+    where
+        R: Read + Seek,
+    {
+        let Some(symbol) = self.dynamic_symbols(reader)?.into_iter().nth(index) else {
+            return Ok(None);
+        };
+        let name = symbol.name().to_string();
+        let Some(versions) = self.version_section(
+            ElfSectionHeaderTypeGNU::VerSym,
+            ElfSectionHeaderTypeSUN::VerSym,
+        ) else {
+            return Ok(Some(name));
+        };
+        let mut cursor = Cursor::new(self.section_data(reader, versions)?);
+        cursor.seek(SeekFrom::Start(
+            index as u64 * ElfHalfWord::<EC, ED>::SIZE as u64,
+        ))?;
+        let Ok(version) =
+            ElfHalfWord::<EC, ED>::from_reader_with(&mut cursor, &mut Config::default())
+        else {
+            return Ok(Some(name));
+        };
+        let hidden = version.0 & VERSYM_HIDDEN != 0;
+
+        let Some((version, defined)) = self
+            .version_names(reader)?
+            .remove(&(version.0 & !VERSYM_HIDDEN))
+            .filter(|_| version.0 & !VERSYM_HIDDEN > VER_NDX_GLOBAL)
+        else {
+            return Ok(Some(name));
+        };
+
+        Ok(Some(if version == name {
+            name
+        } else if defined && !hidden && symbol.section_index() != ElfSectionIndex::Undefined {
+            format!("{name}@@{version}")
+        } else {
+            format!("{name}@{version}")
+        }))
+    }
+
+    /// Find the symbol containing the virtual address `address`, and return its name and
+    /// the offset of the address into it. Symbols from both the full and dynamic symbol
+    /// tables are considered. When several symbols contain the address, function and
+    /// object symbols are preferred, then the symbol with the smallest size. If no
+    /// symbol with a size contains the address, the nearest preceding symbol without a
+    /// size is used.
+    pub fn symbolicate<R>(
+        &self,
+        reader: &mut R,
+        address: u64,
+    ) -> Result<Option<(String, u64)>, Error>
+    where
+        R: Read + Seek,
+    {
+        self.symbolicate_with(reader, address, SymbolicationMode::default())
+    }
+
+    /// Find the symbol containing the virtual address `address` like `symbolicate`, but
+    /// with `mode` choosing whether the nearest preceding symbol without a size is used
+    /// when no symbol with a size contains the address
+    pub fn symbolicate_with<R>(
+        &self,
+        reader: &mut R,
+        address: u64,
+        mode: SymbolicationMode,
+    ) -> Result<Option<(String, u64)>, Error>
+    where
+        R: Read + Seek,
+    {
+        let symbols = self.symbols(reader)?;
+        let dynamic_symbols = self.dynamic_symbols(reader)?;
+        let candidates = symbols
+            .iter()
+            .chain(dynamic_symbols.iter())
+            .filter(|symbol| {
+                !symbol.name().is_empty()
+                    && !matches!(
+                        symbol.section_index(),
+                        ElfSectionIndex::Undefined | ElfSectionIndex::Xindex
+                    )
+                    && !matches!(
+                        symbol.r#type(),
+                        Some(ElfSymbolType::Section | ElfSymbolType::File)
+                    )
+                    && symbol.value() <= address
+            });
+        // Sorts function and object symbols before other symbols
+        let preference = |symbol: &ElfSymbol<EC, ED>| {
+            !matches!(
+                symbol.r#type(),
+                Some(ElfSymbolType::Function | ElfSymbolType::Object)
+            )
+        };
+
+        let symbol = candidates
+            .clone()
+            .filter(|symbol| address - symbol.value() < symbol.size())
+            .min_by_key(|symbol| (preference(symbol), symbol.size()))
+            .or_else(|| {
+                candidates
+                    .filter(|_| mode == SymbolicationMode::Nearest)
+                    .filter(|symbol| symbol.size() == 0)
+                    .min_by_key(|symbol| (address - symbol.value(), preference(symbol)))
+            });
+
+        Ok(symbol.map(|symbol| (symbol.name().to_string(), address - symbol.value())))
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(all(test, feature = "std"))]
+mod test {
+    use crate::{
+        header::{
+            elf::identification::{ElfClass, ElfDataEncoding},
+            section::{ElfSectionIndex, SectionIndex},
+        },
+        Elf, FromReader, HasWrittenSize, ToWriter,
+    };
+
+    use super::{Elf32Symbol, ElfSymbol};
+
+    /// A relocatable 64-bit little endian object with a symbol table and an extended
+    /// section index table. The second symbol's section index is `SHN_XINDEX`, and its
+    /// real section index is held in the extended section index table.
+    fn xindex_object() -> Vec<u8> {
+        let mut object = Vec::new();
+        // ELF header
+        object.extend_from_slice(&[0x7f, b'E', b'L', b'F', 2, 1, 1, 0]);
+        object.extend_from_slice(&[0; 8]);
+        object.extend_from_slice(&1u16.to_le_bytes()); // e_type
+        object.extend_from_slice(&62u16.to_le_bytes()); // e_machine
+        object.extend_from_slice(&1u32.to_le_bytes()); // e_version
+        object.extend_from_slice(&0u64.to_le_bytes()); // e_entry
+        object.extend_from_slice(&0u64.to_le_bytes()); // e_phoff
+        object.extend_from_slice(&120u64.to_le_bytes()); // e_shoff
+        object.extend_from_slice(&0u32.to_le_bytes()); // e_flags
+        object.extend_from_slice(&64u16.to_le_bytes()); // e_ehsize
+        object.extend_from_slice(&0u16.to_le_bytes()); // e_phentsize
+        object.extend_from_slice(&0u16.to_le_bytes()); // e_phnum
+        object.extend_from_slice(&64u16.to_le_bytes()); // e_shentsize
+        object.extend_from_slice(&3u16.to_le_bytes()); // e_shnum
+        object.extend_from_slice(&0u16.to_le_bytes()); // e_shstrndx
+                                                       // Symbol table
+        object.extend_from_slice(&[0; 24]);
+        object.extend_from_slice(&1u32.to_le_bytes()); // st_name
+        object.extend_from_slice(&[0x12, 0x00]); // st_info, st_other
+        object.extend_from_slice(&0xffffu16.to_le_bytes()); // st_shndx
+        object.extend_from_slice(&0x1000u64.to_le_bytes()); // st_value
+        object.extend_from_slice(&0x10u64.to_le_bytes()); // st_size
+                                                          // Extended section index table
+        object.extend_from_slice(&0u32.to_le_bytes());
+        object.extend_from_slice(&0x12345u32.to_le_bytes());
+        // Section header table
+        let section = |r#type: u32, offset: u64, size: u64, link: u32, entry_size: u64| {
+            let mut section = Vec::new();
+            section.extend_from_slice(&0u32.to_le_bytes());
+            section.extend_from_slice(&r#type.to_le_bytes());
+            section.extend_from_slice(&0u64.to_le_bytes());
+            section.extend_from_slice(&0u64.to_le_bytes());
+            section.extend_from_slice(&offset.to_le_bytes());
+            section.extend_from_slice(&size.to_le_bytes());
+            section.extend_from_slice(&link.to_le_bytes());
+            section.extend_from_slice(&0u32.to_le_bytes());
+            section.extend_from_slice(&0u64.to_le_bytes());
+            section.extend_from_slice(&entry_size.to_le_bytes());
+            section
+        };
+        object.extend(section(0, 0, 0, 0, 0));
+        object.extend(section(2, 64, 48, 0, 24));
+        object.extend(section(18, 112, 8, 1, 4));
+        object
+    }
+
+    #[test]
+    fn test_symbol_table_xindex() {
+        let mut reader = std::io::Cursor::new(xindex_object());
+        let elf =
+            Elf::<{ ElfClass::Elf64 as u8 }, { ElfDataEncoding::LittleEndian as u8 }>::from_reader(
+                &mut reader,
+            )
+            .unwrap();
+        assert_eq!(elf.section_headers.len(), 3);
+        let symbols = elf.symbol_table(&mut reader, SectionIndex(1)).unwrap();
+        assert_eq!(symbols.len(), 2);
+        assert_eq!(symbols[0].section_index(), ElfSectionIndex::Undefined);
+        assert_eq!(symbols[1].section_index(), ElfSectionIndex::Index(0x12345));
+        assert_eq!(symbols[1].value(), 0x1000);
+        assert_eq!(
+            elf.symbol_section(&symbols[1]),
+            ElfSectionIndex::Index(0x12345)
+        );
+        // The resolved index is beyond the section header table
+        assert_eq!(elf.symbol_section_name(&symbols[1]), None);
+    }
+
+    #[test]
+    fn test_elf32_le_symbol() {
+        let mut symbol: &[u8] = &[
+            0x01, 0x00, 0x00, 0x00, // name
+            0x00, 0x10, 0x00, 0x00, // value
+            0x08, 0x00, 0x00, 0x00, // size
+            0x12, // info
+            0x00, // other
+            0xf1, 0xff, // section index
+        ];
+        let bytes = symbol;
+        let symbol = ElfSymbol::<
+            { ElfClass::Elf32 as u8 },
+            { ElfDataEncoding::LittleEndian as u8 },
+        >::from_reader(&mut std::io::Cursor::new(&mut symbol))
+        .unwrap();
+        assert_eq!(symbol.name_index(), 1);
+        assert_eq!(symbol.value(), 0x1000);
+        assert_eq!(symbol.size(), 8);
+        assert_eq!(symbol.info(), 0x12);
+        assert_eq!(symbol.section_index(), ElfSectionIndex::Absolute);
+        assert_eq!(symbol.section_index().index(), None);
+
+        let mut written = Vec::new();
+        symbol.to_writer(&mut written).unwrap();
+        assert_eq!(written, bytes);
+        assert_eq!(
+            written.len(),
+            Elf32Symbol::<{ ElfDataEncoding::LittleEndian as u8 }>::SIZE
+        );
+    }
+
+    #[test]
+    fn test_elf64_be_symbol() {
+        let mut symbol: &[u8] = &[
+            0x00, 0x00, 0x00, 0x01, // name
+            0x11, // info
+            0x00, // other
+            0x00, 0x07, // section index
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x10, 0x00, // value
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, // size
+        ];
+        let bytes = symbol;
+        let symbol =
+            ElfSymbol::<{ ElfClass::Elf64 as u8 }, { ElfDataEncoding::BigEndian as u8 }>::from_reader(
+                &mut std::io::Cursor::new(&mut symbol),
+            )
+            .unwrap();
+        assert_eq!(symbol.name_index(), 1);
+        assert_eq!(symbol.value(), 0x401000);
+        assert_eq!(symbol.size(), 0x10);
+        assert_eq!(symbol.section_index(), ElfSectionIndex::Index(7));
+        assert_eq!(symbol.section_index().index(), Some(SectionIndex(7)));
+
+        let mut written = Vec::new();
+        symbol.to_writer(&mut written).unwrap();
+        assert_eq!(written, bytes);
+        assert_eq!(
+            written.len(),
+            ElfSymbol::<{ ElfClass::Elf64 as u8 }, { ElfDataEncoding::BigEndian as u8 }>::SIZE
+        );
+    }
+}
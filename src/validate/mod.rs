@@ -0,0 +1,205 @@
+//! Checks for ELF objects which decode successfully but which a loader or linker would
+//! reject
+
+use alloc::vec::Vec;
+
+use crate::{
+    header::{
+        program::{ElfProgramHeaderType, SegmentIndex},
+        section::SectionIndex,
+    },
+    Elf,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// A segment or section whose address does not respect its alignment
+pub enum AlignmentWarning {
+    /// A `PT_LOAD` segment whose virtual address is not congruent to its offset modulo
+    /// its alignment, so it cannot be mapped
+    Segment {
+        /// The index of the segment in the program header table
+        index: SegmentIndex,
+        /// The virtual address of the segment
+        virtual_address: u64,
+        /// The offset of the segment in the file
+        offset: u64,
+        /// The alignment of the segment
+        align: u64,
+    },
+    /// A section whose address is not a multiple of its alignment
+    Section {
+        /// The index of the section in the section header table
+        index: SectionIndex,
+        /// The address of the section
+        address: u64,
+        /// The alignment of the section
+        address_align: u64,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// A `PT_TLS` segment whose sizes do not match the thread-local storage sections it
+/// holds
+pub struct TlsWarning {
+    /// The file size of the segment, which is the size of the initialized part of the
+    /// template
+    pub file_size: u64,
+    /// The memory size of the segment, which is the size of the whole template
+    pub memory_size: u64,
+    /// The size of the initialized part of the template described by the TLS data
+    /// sections
+    pub data_size: u64,
+    /// The size of the whole template described by the TLS data and bss sections
+    pub template_size: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// An identifier whose reserved padding bytes are not zero. Loaders ignore the padding,
+/// but some tools inspect it to recognize the producer of an object.
+pub struct PaddingWarning {
+    /// The padding bytes (`EI_PAD`)
+    pub padding: [u8; 7],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// A `PT_PHDR` segment which does not describe the program header table, which loaders
+/// use it to find at runtime
+pub struct PhdrWarning {
+    /// The index of the segment in the program header table
+    pub index: SegmentIndex,
+    /// The offset of the segment in the file, which should be the offset of the program
+    /// header table (`e_phoff`)
+    pub offset: u64,
+    /// The file size of the segment, which should be the size of the program header
+    /// table
+    pub file_size: u64,
+    /// The virtual address of the segment
+    pub virtual_address: u64,
+    /// Whether the segment is in the file contents of a `PT_LOAD` segment, without which
+    /// the program header table is not in memory at its virtual address
+    pub loaded: bool,
+    /// The offset of the program header table (`e_phoff`)
+    pub table_offset: u64,
+    /// The size of the program header table, which is the number of program headers
+    /// times their size (`e_phentsize`)
+    pub table_size: u64,
+}
+
+impl<const EC: u8, const ED: u8> Elf<EC, ED> {
+    /// Check that each `PT_LOAD` segment's virtual address is congruent to its offset
+    /// modulo its alignment, and that each section's address is a multiple of its
+    /// alignment. An alignment of 0 or 1 means the segment or section has no alignment
+    /// constraint.
+    pub fn validate_alignment(&self) -> Vec<AlignmentWarning> {
+        let segments = self
+            .program_headers
+            .iter()
+            .enumerate()
+            .filter(|(_, header)| matches!(header.r#type(), ElfProgramHeaderType::Load))
+            .filter(|(_, header)| {
+                header.align() > 1
+                    && header.virtual_address() % header.align() != header.offset() % header.align()
+            })
+            .map(|(index, header)| AlignmentWarning::Segment {
+                index: SegmentIndex(index as u32),
+                virtual_address: header.virtual_address(),
+                offset: header.offset(),
+                align: header.align(),
+            });
+        let sections = self
+            .indexed_sections()
+            .filter(|(_, header)| {
+                header.address_align() > 1 && header.address() % header.address_align() != 0
+            })
+            .map(|(index, header)| AlignmentWarning::Section {
+                index,
+                address: header.address(),
+                address_align: header.address_align(),
+            });
+
+        segments.chain(sections).collect()
+    }
+
+    /// Check that the padding bytes at the end of the identifier are zero, as they are
+    /// reserved. `None` if they are.
+    pub fn validate_padding(&self) -> Option<PaddingWarning> {
+        let padding = self.header.identifier.padding();
+
+        padding
+            .iter()
+            .any(|byte| *byte != 0)
+            .then_some(PaddingWarning { padding })
+    }
+
+    /// Check that the sizes of the `PT_TLS` segment match the thread-local storage
+    /// sections. Its file size must span the TLS data sections, such as `.tdata`, and
+    /// its memory size must also span the TLS bss sections, such as `.tbss`, which take
+    /// no space in the file. `None` if the sizes match, or if there is no `PT_TLS`
+    /// segment or no TLS sections to check it against.
+    pub fn validate_tls(&self) -> Option<TlsWarning> {
+        let segment = self
+            .program_headers
+            .iter()
+            .find(|header| matches!(header.r#type(), ElfProgramHeaderType::ThreadLocalStorage))?;
+        let data = self.tls_data_sections();
+        let bss = self.tls_bss_sections();
+        let end = |sections: &[SectionIndex]| {
+            sections
+                .iter()
+                .filter_map(|index| self.section(*index))
+                .map(|header| header.address().saturating_add(header.size()))
+                .max()
+        };
+        let start = data
+            .iter()
+            .chain(bss.iter())
+            .filter_map(|index| self.section(*index))
+            .map(|header| header.address())
+            .min()?;
+        let data_size = end(&data).map_or(0, |end| end.saturating_sub(start));
+        let template_size = end(&bss)
+            .map_or(0, |end| end.saturating_sub(start))
+            .max(data_size);
+
+        (segment.file_size() != data_size || segment.memory_size() != template_size).then_some(
+            TlsWarning {
+                file_size: segment.file_size(),
+                memory_size: segment.memory_size(),
+                data_size,
+                template_size,
+            },
+        )
+    }
+
+    /// Check that the `PT_PHDR` segment describes the program header table. Its offset
+    /// must be the offset of the table, its file size must be the size of the table,
+    /// and it must be in a `PT_LOAD` segment so the table is in memory at its virtual
+    /// address. `None` if it does, or if there is no `PT_PHDR` segment.
+    pub fn validate_phdr_segment(&self) -> Option<PhdrWarning> {
+        let (index, segment) = self
+            .program_headers
+            .iter()
+            .enumerate()
+            .find(|(_, header)| matches!(header.r#type(), ElfProgramHeaderType::ProgramHeader))?;
+        let table_offset = self
+            .header
+            .program_header_offset
+            .map_or(0, |offset| offset.0);
+        let table_size = (self.program_headers.len() as u64)
+            .saturating_mul(self.header.program_header_entry_size.0 as u64);
+        let loaded = self
+            .address_offset(segment.virtual_address(), segment.file_size())
+            .is_some();
+
+        (segment.offset() != table_offset || segment.file_size() != table_size || !loaded)
+            .then_some(PhdrWarning {
+                index: SegmentIndex(index as u32),
+                offset: segment.offset(),
+                file_size: segment.file_size(),
+                virtual_address: segment.virtual_address(),
+                loaded,
+                table_offset,
+                table_size,
+            })
+    }
+}
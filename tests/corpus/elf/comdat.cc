@@ -0,0 +1,3 @@
+template <typename T> T twice(T value) { return value + value; }
+inline int shared_counter() { static int counter; return ++counter; }
+int use(int value) { return twice(value) + twice(value * 1.5) + shared_counter(); }
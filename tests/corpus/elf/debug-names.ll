@@ -0,0 +1,38 @@
+; A translation unit with a global variable and two functions, equivalent to:
+;   int counter;
+;   int add(int a, int b) { return a + b; }
+;   int main(void) { return add(counter, 1); }
+target triple = "x86_64-unknown-linux-gnu"
+
+@counter = dso_local global i32 0, align 4, !dbg !0
+
+define dso_local i32 @add(i32 %a, i32 %b) !dbg !12 {
+  %sum = add i32 %a, %b, !dbg !16
+  ret i32 %sum, !dbg !16
+}
+
+define dso_local i32 @main() !dbg !17 {
+  %value = load i32, i32* @counter, align 4, !dbg !20
+  %result = call i32 @add(i32 %value, i32 1), !dbg !20
+  ret i32 %result, !dbg !20
+}
+
+!llvm.dbg.cu = !{!2}
+!llvm.module.flags = !{!9, !10}
+
+!0 = !DIGlobalVariableExpression(var: !1, expr: !DIExpression())
+!1 = distinct !DIGlobalVariable(name: "counter", scope: !2, file: !3, line: 1, type: !6, isLocal: false, isDefinition: true)
+!2 = distinct !DICompileUnit(language: DW_LANG_C99, file: !3, producer: "llc", isOptimized: false, runtimeVersion: 0, emissionKind: FullDebug, globals: !5, nameTableKind: Default)
+!3 = !DIFile(filename: "names.c", directory: "/tmp")
+!5 = !{!0}
+!6 = !DIBasicType(name: "int", size: 32, encoding: DW_ATE_signed)
+!9 = !{i32 7, !"Dwarf Version", i32 5}
+!10 = !{i32 2, !"Debug Info Version", i32 3}
+!12 = distinct !DISubprogram(name: "add", scope: !3, file: !3, line: 2, type: !13, scopeLine: 2, spFlags: DISPFlagDefinition, unit: !2)
+!13 = !DISubroutineType(types: !14)
+!14 = !{!6, !6, !6}
+!16 = !DILocation(line: 2, scope: !12)
+!17 = distinct !DISubprogram(name: "main", scope: !3, file: !3, line: 3, type: !18, scopeLine: 3, spFlags: DISPFlagDefinition, unit: !2)
+!18 = !DISubroutineType(types: !19)
+!19 = !{!6}
+!20 = !DILocation(line: 3, scope: !17)
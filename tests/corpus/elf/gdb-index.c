@@ -0,0 +1,3 @@
+int counter;
+int add(int a, int b) { return a + b; }
+int main(void) { return add(counter, 1); }
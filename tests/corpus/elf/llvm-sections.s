@@ -0,0 +1,29 @@
+	.text
+	.globl	main
+	.type	main,@function
+main:
+	leaq	callback(%rip), %rax
+	callq	helper
+	xorl	%eax, %eax
+	retq
+	.size	main, .-main
+
+	.type	callback,@function
+callback:
+	retq
+	.size	callback, .-callback
+
+	.type	helper,@function
+helper:
+	retq
+	.size	helper, .-helper
+
+	.section	.deplibs,"MS",@llvm_dependent_libraries,1
+	.asciz	"m"
+	.asciz	"pthread"
+
+	.cg_profile main, helper, 32
+
+	.addrsig
+	.addrsig_sym callback
+	.addrsig_sym main